@@ -0,0 +1,80 @@
+//! Terminal inspector for stores exposed with `redust::remote::RemoteServer`
+//! using the JSON codec.
+//!
+//! ```text
+//! cargo run --example redust-inspect --features serde -- 127.0.0.1:7878 state
+//! cargo run --example redust-inspect --features serde -- 127.0.0.1:7878 actions
+//! cargo run --example redust-inspect --features serde -- 127.0.0.1:7878 dispatch '"Increment"'
+//! cargo run --example redust-inspect --features serde -- 127.0.0.1:7878 travel 2
+//! cargo run --example redust-inspect --features serde -- 127.0.0.1:7878 watch
+//! ```
+
+use std::env;
+use std::process;
+
+use redust::codec::Codec;
+use redust::remote::{RemoteError, RemoteStore};
+use serde_json::Value;
+
+const USAGE: &str =
+    "Usage: redust-inspect <address> <state | actions | dispatch <json> | travel <steps> | watch>";
+
+fn print(value: &Value) {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string())
+    );
+}
+
+fn run(address: &str, command: &[String]) -> Result<(), String> {
+    let mut store = RemoteStore::<Value, Value>::connect(address, Codec::Json)
+        .map_err(|err| err.to_string())?;
+
+    match command {
+        [command] if command == "state" => print(&store.state().map_err(|err| err.to_string())?),
+        [command] if command == "actions" => {
+            let actions = store.recent_actions().map_err(|err| err.to_string())?;
+            for (index, action) in actions.iter().enumerate() {
+                print!("{:>4}: ", index);
+                print(action);
+            }
+        }
+        [command, action] if command == "dispatch" => {
+            let action: Value = serde_json::from_str(action)
+                .map_err(|err| format!("Cannot parse the action: {}", err))?;
+            store.dispatch(action).map_err(|err| err.to_string())?;
+        }
+        [command, steps] if command == "travel" => {
+            let steps = steps
+                .parse()
+                .map_err(|err| format!("Cannot parse the number of steps: {}", err))?;
+            store.travel_back(steps).map_err(|err| err.to_string())?;
+        }
+        [command] if command == "watch" => {
+            let mut subscription = store.subscribe().map_err(|err| err.to_string())?;
+            loop {
+                match subscription.recv() {
+                    Ok(state) => print(&state),
+                    Err(RemoteError::Io(_)) => return Ok(()),
+                    Err(err) => return Err(err.to_string()),
+                }
+            }
+        }
+        _ => return Err(USAGE.to_string()),
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.split_first() {
+        Some((address, command)) => run(address, command),
+        None => Err(USAGE.to_string()),
+    };
+
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,143 @@
+use std::time::{Duration, Instant};
+
+use crate::{CancellationToken, DispatchError, SharedClock, Store};
+
+/// Deadline and/or cancellation token attached to a dispatch.
+///
+/// The store checks the signal before every middleware and before the reducer.
+/// Long-running middleware should check it too via `Next::check_abort`.
+#[derive(Debug, Clone, Default)]
+pub struct AbortSignal {
+    deadline: Option<Instant>,
+    token: Option<CancellationToken>,
+    clock: Option<SharedClock>,
+}
+
+impl AbortSignal {
+    /// Aborts the dispatch once the `deadline` has passed
+    pub fn deadline(deadline: Instant) -> Self {
+        Self {
+            deadline: Some(deadline),
+            token: None,
+            clock: None,
+        }
+    }
+
+    /// Aborts the dispatch once the `timeout` has passed from now
+    pub fn timeout(timeout: Duration) -> Self {
+        Self::deadline(Instant::now() + timeout)
+    }
+
+    /// Aborts the dispatch once the `token` is cancelled, e.g. from another thread
+    pub fn token(token: CancellationToken) -> Self {
+        Self {
+            deadline: None,
+            token: Some(token),
+            clock: None,
+        }
+    }
+
+    /// Aborts the dispatch once the `timeout` has passed from now, as told by the `clock`
+    pub fn timeout_with_clock(timeout: Duration, clock: SharedClock) -> Self {
+        Self::deadline(clock.now() + timeout).with_clock(clock)
+    }
+
+    /// Checks the deadline against the `clock` instead of the system time
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = Some(clock);
+
+        self
+    }
+
+    /// Adds the cancellation token to the signal
+    pub fn with_token(mut self, token: CancellationToken) -> Self {
+        self.token = Some(token);
+
+        self
+    }
+
+    /// Returns `true` if the deadline has passed or the token was cancelled
+    pub fn is_aborted(&self) -> bool {
+        self.deadline.is_some_and(|deadline| self.now() >= deadline)
+            || self
+                .token
+                .as_ref()
+                .is_some_and(|token| token.is_cancelled())
+    }
+
+    /// Returns the time left until the deadline
+    pub fn remaining(&self) -> Option<Duration> {
+        self.deadline
+            .map(|deadline| deadline.saturating_duration_since(self.now()))
+    }
+
+    fn now(&self) -> Instant {
+        match &self.clock {
+            Some(clock) => clock.now(),
+            None => Instant::now(),
+        }
+    }
+}
+
+impl<State, Action, Env> Store<State, Action, Env> {
+    /// Dispatches the action with the abort signal attached.
+    ///
+    /// Returns `DispatchError::Aborted` if the signal fired before the reducer ran,
+    /// so a slow middleware chain fails fast instead of blocking the store.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::{AbortSignal, DispatchError, Next, Store};
+    /// use std::thread;
+    /// use std::time::Duration;
+    ///
+    /// type MyStore = u8;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Increment,
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Increment => state + 1,
+    ///     }
+    /// }
+    ///
+    /// // Validates the action against a slow remote service
+    /// fn validate(action: MyAction, next: Next<'_, MyStore, MyAction>) -> Result<MyAction, DispatchError> {
+    ///     thread::sleep(Duration::from_millis(20));
+    ///     next.run(action)
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, 0);
+    /// store.add_middleware("validate", validate);
+    ///
+    /// let signal = AbortSignal::timeout(Duration::from_millis(5));
+    ///
+    /// assert_eq!(
+    ///     store.dispatch_with_abort(signal, MyAction::Increment).err(),
+    ///     Some(DispatchError::Aborted)
+    /// );
+    /// assert_eq!(*store.state(), 0);
+    /// ```
+    pub fn dispatch_with_abort(
+        &mut self,
+        signal: AbortSignal,
+        action: Action,
+    ) -> Result<&mut Self, DispatchError> {
+        let previous = self.abort.replace(signal);
+        let result = self.try_dispatch(action).map(|_| ());
+        self.abort = previous;
+
+        result.map(|_| self)
+    }
+
+    /// Returns `Err(DispatchError::Aborted)` if the signal of the current dispatch fired
+    pub(crate) fn check_abort(&self) -> Result<(), DispatchError> {
+        match &self.abort {
+            Some(signal) if signal.is_aborted() => Err(DispatchError::Aborted),
+            _ => Ok(()),
+        }
+    }
+}
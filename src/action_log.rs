@@ -0,0 +1,114 @@
+//! Action history as newline-delimited JSON (JSONL), one action per line.
+//!
+//! The log can be grepped, attached to a bug report and read back into
+//! a `Fixture` for replay. Available behind the `serde` feature.
+
+use std::io::{self, Read, Write};
+use std::time::UNIX_EPOCH;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{Fixture, FixtureError, Store};
+
+/// Line of an action log
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActionRecord<Action> {
+    /// Position of the action among all actions recorded since the history was enabled
+    pub seq: usize,
+
+    /// Milliseconds since the Unix epoch when the action was reduced
+    pub timestamp_ms: u64,
+
+    pub action: Action,
+}
+
+/// Reads the records of an action log written by `Store::export_action_log`
+pub fn read_action_log<Action: DeserializeOwned, R: Read>(
+    reader: R,
+) -> impl Iterator<Item = Result<ActionRecord<Action>, FixtureError>> {
+    serde_json::Deserializer::from_reader(reader)
+        .into_iter::<ActionRecord<Action>>()
+        .map(|record| record.map_err(|err| FixtureError::Json(err.to_string())))
+}
+
+impl<State, Action: DeserializeOwned> Fixture<State, Action> {
+    /// Creates a fixture which replays the actions of the log from the `initial_state`,
+    /// e.g. `store.history_state(0)` of the exporting store
+    pub fn from_action_log<R: Read>(initial_state: State, reader: R) -> Result<Self, FixtureError> {
+        let actions = read_action_log(reader)
+            .map(|record| record.map(|record| record.action))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            initial_state,
+            actions,
+        })
+    }
+}
+
+impl<State, Action: Serialize, Env> Store<State, Action, Env> {
+    /// Streams the kept history actions from the oldest one into the `writer`,
+    /// one JSON record per line. Returns the number of written records,
+    /// zero when the history is disabled
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::action_log::read_action_log;
+    /// use redust::{HistoryPolicy, Store};
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// type MyStore = u8;
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    /// enum MyAction {
+    ///     Increment,
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Increment => state + 1,
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, 0);
+    /// store.enable_history(HistoryPolicy::unbounded());
+    /// store.dispatch(MyAction::Increment).dispatch(MyAction::Increment);
+    ///
+    /// let mut log = vec![];
+    /// assert_eq!(store.export_action_log(&mut log).unwrap(), 2);
+    ///
+    /// let records: Vec<_> = read_action_log::<MyAction, _>(&log[..])
+    ///     .collect::<Result<_, _>>()
+    ///     .unwrap();
+    /// assert_eq!(records[1].seq, 1);
+    /// assert_eq!(records[1].action, MyAction::Increment);
+    /// ```
+    pub fn export_action_log<W: Write>(&self, mut writer: W) -> io::Result<usize> {
+        let history = match self.history.as_ref() {
+            Some(history) => history,
+            None => return Ok(0),
+        };
+
+        let (now, system_time) = (self.clock.now(), self.clock.system_time());
+        let mut written = 0;
+        for (seq, recorded_at, action) in history.records() {
+            let reduced_at = system_time - now.saturating_duration_since(recorded_at);
+            let timestamp_ms = reduced_at
+                .duration_since(UNIX_EPOCH)
+                .map(|since| since.as_millis() as u64)
+                .unwrap_or(0);
+
+            let record = ActionRecord {
+                seq,
+                timestamp_ms,
+                action,
+            };
+            serde_json::to_writer(&mut writer, &record)?;
+            writer.write_all(b"\n")?;
+            written += 1;
+        }
+        writer.flush()?;
+
+        Ok(written)
+    }
+}
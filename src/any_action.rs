@@ -0,0 +1,170 @@
+use std::any::{type_name, Any, TypeId};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::{Reducer, Store};
+
+/// Action of any type, dispatched into a store with per-type reducers
+pub struct AnyAction {
+    action: Box<dyn Any + Send + Sync>,
+    type_name: &'static str,
+}
+
+impl AnyAction {
+    pub fn new<A: Any + Send + Sync>(action: A) -> Self {
+        Self {
+            action: Box::new(action),
+            type_name: type_name::<A>(),
+        }
+    }
+
+    /// Returns the action if it has the type `A`
+    pub fn downcast_ref<A: Any>(&self) -> Option<&A> {
+        self.action.downcast_ref()
+    }
+
+    /// Returns `true` if the action has the type `A`
+    pub fn is<A: Any>(&self) -> bool {
+        self.action.is::<A>()
+    }
+
+    /// Returns the name of the action type
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+}
+
+impl std::fmt::Debug for AnyAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "AnyAction({})", self.type_name)
+    }
+}
+
+/// Type-erased reducer of one action type
+trait ActionReducer<State> {
+    fn reduce(&self, state: &State, action: &AnyAction) -> Option<State>;
+}
+
+struct ActionEntry<State, A> {
+    reducer: Reducer<State, A>,
+}
+
+impl<State, A: Any> ActionReducer<State> for ActionEntry<State, A> {
+    fn reduce(&self, state: &State, action: &AnyAction) -> Option<State> {
+        action
+            .downcast_ref::<A>()
+            .map(|action| (self.reducer)(state, action))
+    }
+}
+
+/// Reducers of a store with actions of many types, one per action type.
+/// It is the environment of the store created with `Store::typed`
+pub struct ActionReducers<State> {
+    reducers: HashMap<TypeId, Arc<dyn ActionReducer<State> + Send + Sync>>,
+}
+
+impl<State> ActionReducers<State> {
+    /// Returns `true` if a reducer of the action type `A` is registered
+    pub fn handles<A: Any>(&self) -> bool {
+        self.reducers.contains_key(&TypeId::of::<A>())
+    }
+
+    /// Returns the number of registered reducers
+    pub fn len(&self) -> usize {
+        self.reducers.len()
+    }
+
+    /// Returns `true` if no reducer is registered
+    pub fn is_empty(&self) -> bool {
+        self.reducers.is_empty()
+    }
+}
+
+impl<State> Clone for ActionReducers<State> {
+    fn clone(&self) -> Self {
+        Self {
+            reducers: self.reducers.clone(),
+        }
+    }
+}
+
+impl<State> std::fmt::Debug for ActionReducers<State> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "ActionReducers({})", self.reducers.len())
+    }
+}
+
+/// Root reducer which passes the action to the reducer of its type.
+/// Actions without a reducer leave the state unchanged
+fn reduce_typed<State: Clone>(
+    state: &State,
+    action: &AnyAction,
+    reducers: &ActionReducers<State>,
+) -> State {
+    reducers
+        .reducers
+        .get(&action.action.as_ref().type_id())
+        .and_then(|reducer| reducer.reduce(state, action))
+        .unwrap_or_else(|| state.clone())
+}
+
+impl<State: Clone + 'static> Store<State, AnyAction, ActionReducers<State>> {
+    /// Creates a store which accepts actions of any type registered with `on`.
+    ///
+    /// Large codebases may split actions into many small types instead of one enum.
+    /// The reducers are the environment of the store, see `Store::env`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::Store;
+    ///
+    /// #[derive(Clone, Default)]
+    /// struct AppState {
+    ///     todos: Vec<&'static str>,
+    ///     filter: &'static str,
+    /// }
+    ///
+    /// struct AddTodo(&'static str);
+    /// struct SetFilter(&'static str);
+    ///
+    /// fn add_todo(state: &AppState, action: &AddTodo) -> AppState {
+    ///     let mut todos = state.todos.clone();
+    ///     todos.push(action.0);
+    ///
+    ///     AppState { todos, ..state.clone() }
+    /// }
+    ///
+    /// fn set_filter(state: &AppState, action: &SetFilter) -> AppState {
+    ///     AppState { filter: action.0, ..state.clone() }
+    /// }
+    ///
+    /// let mut store = Store::typed(AppState::default());
+    /// store.on(add_todo).on(set_filter);
+    ///
+    /// store.dispatch_typed(AddTodo("Buy milk")).dispatch_typed(SetFilter("done"));
+    ///
+    /// assert_eq!(store.state().todos, ["Buy milk"]);
+    /// assert_eq!(store.state().filter, "done");
+    /// ```
+    pub fn typed(state: State) -> Self {
+        let reducers = ActionReducers {
+            reducers: HashMap::new(),
+        };
+
+        Self::with_env(reduce_typed, state, reducers)
+    }
+
+    /// Registers the reducer of actions of type `A`, replacing the previous one
+    pub fn on<A: Any>(&mut self, reducer: Reducer<State, A>) -> &mut Self {
+        Arc::make_mut(&mut self.env)
+            .reducers
+            .insert(TypeId::of::<A>(), Arc::new(ActionEntry { reducer }));
+
+        self
+    }
+
+    /// Dispatches an action of any type
+    pub fn dispatch_typed<A: Any + Send + Sync>(&mut self, action: A) -> &mut Self {
+        self.dispatch(AnyAction::new(action))
+    }
+}
@@ -0,0 +1,373 @@
+use std::any::{type_name, Any};
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+use std::sync::{Mutex, MutexGuard, OnceLock, PoisonError};
+
+use crate::{DispatchError, StateVersion, Store};
+
+#[derive(Debug, PartialEq)]
+pub enum AnyStoreError {
+    /// No store is registered under the name
+    NotFound(String),
+
+    /// The action has another type than actions of the store
+    ActionMismatch { expected: &'static str },
+
+    /// The store rejected the action
+    Dispatch(DispatchError),
+}
+
+impl std::error::Error for AnyStoreError {}
+impl std::fmt::Display for AnyStoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            AnyStoreError::NotFound(name) => write!(f, "Cannot find the store: {}", name),
+            AnyStoreError::ActionMismatch { expected } => {
+                write!(f, "Cannot dispatch an action which is not {}", expected)
+            }
+            AnyStoreError::Dispatch(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl From<DispatchError> for AnyStoreError {
+    fn from(err: DispatchError) -> Self {
+        AnyStoreError::Dispatch(err)
+    }
+}
+
+/// Object-safe part of a `Store` which does not depend on its state and action types.
+/// Stores are `Send`, so registries may be shared between threads
+pub trait StoreLike: Any + Send {
+    /// Dispatches the action if it has the action type of the store
+    fn dispatch_any(&mut self, action: Box<dyn Any>) -> Result<(), AnyStoreError>;
+
+    /// Returns the version of the current state
+    fn version(&self) -> StateVersion;
+
+    /// Returns the name of the state type
+    fn state_type(&self) -> &'static str;
+
+    /// Returns the name of the action type
+    fn action_type(&self) -> &'static str;
+
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<State: 'static, Action: 'static> StoreLike for Store<State, Action>
+where
+    Self: Send,
+{
+    fn dispatch_any(&mut self, action: Box<dyn Any>) -> Result<(), AnyStoreError> {
+        let action = action
+            .downcast::<Action>()
+            .map_err(|_| AnyStoreError::ActionMismatch {
+                expected: type_name::<Action>(),
+            })?;
+        self.try_dispatch(*action)?;
+
+        Ok(())
+    }
+
+    fn version(&self) -> StateVersion {
+        Store::version(self)
+    }
+
+    fn state_type(&self) -> &'static str {
+        type_name::<State>()
+    }
+
+    fn action_type(&self) -> &'static str {
+        type_name::<Action>()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Type-erased store which can be downcast back to the concrete `Store`
+pub struct AnyStore {
+    store: Box<dyn StoreLike>,
+}
+
+impl AnyStore {
+    pub fn new<State: 'static, Action: 'static>(store: Store<State, Action>) -> Self
+    where
+        Store<State, Action>: Send,
+    {
+        Self {
+            store: Box::new(store),
+        }
+    }
+
+    /// Returns the concrete store if it has the given state and action types
+    pub fn downcast_ref<State: 'static, Action: 'static>(&self) -> Option<&Store<State, Action>> {
+        self.store.as_any().downcast_ref()
+    }
+
+    /// Returns the concrete store if it has the given state and action types
+    pub fn downcast_mut<State: 'static, Action: 'static>(
+        &mut self,
+    ) -> Option<&mut Store<State, Action>> {
+        self.store.as_any_mut().downcast_mut()
+    }
+}
+
+impl std::ops::Deref for AnyStore {
+    type Target = dyn StoreLike;
+
+    fn deref(&self) -> &Self::Target {
+        self.store.as_ref()
+    }
+}
+
+impl std::ops::DerefMut for AnyStore {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.store.as_mut()
+    }
+}
+
+impl<State: 'static, Action: 'static> From<Store<State, Action>> for AnyStore
+where
+    Store<State, Action>: Send,
+{
+    fn from(store: Store<State, Action>) -> Self {
+        Self::new(store)
+    }
+}
+
+/// Typed name of a store in a `StoreRegistry`, which fixes its state and action types
+///
+/// ## Example
+/// ```rust
+/// use redust::{Store, StoreKey, StoreRegistry};
+///
+/// #[derive(Debug)]
+/// enum MyAction {
+///     Increment,
+/// };
+///
+/// fn reducer(state: &u8, action: &MyAction) -> u8 {
+///     match action {
+///         MyAction::Increment => state + 1,
+///     }
+/// }
+///
+/// const COUNTER: StoreKey<u8, MyAction> = StoreKey::new("counter");
+///
+/// let mut registry = StoreRegistry::new();
+/// registry.insert_keyed(COUNTER, Store::new(reducer, 0));
+///
+/// registry.get_keyed_mut(COUNTER).unwrap().dispatch(MyAction::Increment);
+/// assert_eq!(*registry.get_keyed(COUNTER).unwrap().state(), 1);
+/// ```
+pub struct StoreKey<State, Action> {
+    name: &'static str,
+    types: PhantomData<fn(State, Action)>,
+}
+
+impl<State, Action> StoreKey<State, Action> {
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            types: PhantomData,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<State, Action> Clone for StoreKey<State, Action> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<State, Action> Copy for StoreKey<State, Action> {}
+
+impl<State, Action> std::fmt::Debug for StoreKey<State, Action> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "StoreKey({})", self.name)
+    }
+}
+
+/// Named collection of stores with different state and action types.
+///
+/// Registries are usually app-scoped; `global_registry` returns the one shared
+/// by the whole process.
+///
+/// ## Example
+/// ```rust
+/// use redust::{AnyStoreError, Store, StoreRegistry};
+///
+/// #[derive(Debug)]
+/// enum CounterAction {
+///     Increment,
+/// };
+///
+/// fn counter(state: &u8, action: &CounterAction) -> u8 {
+///     match action {
+///         CounterAction::Increment => state + 1,
+///     }
+/// }
+///
+/// fn title(_state: &String, action: &String) -> String {
+///     action.clone()
+/// }
+///
+/// let mut registry = StoreRegistry::new();
+/// registry.insert("counter", Store::new(counter, 0));
+/// registry.insert("title", Store::new(title, String::new()));
+///
+/// registry.dispatch_any("counter", Box::new(CounterAction::Increment)).unwrap();
+///
+/// assert_eq!(
+///     registry.dispatch_any("counter", Box::new("Home".to_string())),
+///     Err(AnyStoreError::ActionMismatch { expected: std::any::type_name::<CounterAction>() })
+/// );
+/// assert_eq!(*registry.get::<u8, CounterAction>("counter").unwrap().state(), 1);
+/// ```
+#[derive(Default)]
+pub struct StoreRegistry {
+    stores: BTreeMap<String, AnyStore>,
+}
+
+impl StoreRegistry {
+    pub fn new() -> Self {
+        Self {
+            stores: BTreeMap::new(),
+        }
+    }
+
+    /// Registers the store under the name and returns the replaced one
+    pub fn insert(&mut self, name: &str, store: impl Into<AnyStore>) -> Option<AnyStore> {
+        self.stores.insert(name.to_string(), store.into())
+    }
+
+    /// Removes the store from the registry
+    pub fn remove(&mut self, name: &str) -> Option<AnyStore> {
+        self.stores.remove(name)
+    }
+
+    /// Returns the type-erased store
+    pub fn get_any(&self, name: &str) -> Option<&AnyStore> {
+        self.stores.get(name)
+    }
+
+    /// Returns the type-erased store
+    pub fn get_any_mut(&mut self, name: &str) -> Option<&mut AnyStore> {
+        self.stores.get_mut(name)
+    }
+
+    /// Returns the store if it has the given state and action types
+    pub fn get<State: 'static, Action: 'static>(
+        &self,
+        name: &str,
+    ) -> Option<&Store<State, Action>> {
+        self.stores.get(name)?.downcast_ref()
+    }
+
+    /// Returns the store if it has the given state and action types
+    pub fn get_mut<State: 'static, Action: 'static>(
+        &mut self,
+        name: &str,
+    ) -> Option<&mut Store<State, Action>> {
+        self.stores.get_mut(name)?.downcast_mut()
+    }
+
+    /// Registers the store under the typed key and returns the replaced one
+    pub fn insert_keyed<State: 'static, Action: 'static>(
+        &mut self,
+        key: StoreKey<State, Action>,
+        store: Store<State, Action>,
+    ) -> Option<AnyStore>
+    where
+        Store<State, Action>: Send,
+    {
+        self.insert(key.name, store)
+    }
+
+    /// Returns the store registered under the typed key
+    pub fn get_keyed<State: 'static, Action: 'static>(
+        &self,
+        key: StoreKey<State, Action>,
+    ) -> Option<&Store<State, Action>> {
+        self.get(key.name)
+    }
+
+    /// Returns the store registered under the typed key
+    pub fn get_keyed_mut<State: 'static, Action: 'static>(
+        &mut self,
+        key: StoreKey<State, Action>,
+    ) -> Option<&mut Store<State, Action>> {
+        self.get_mut(key.name)
+    }
+
+    /// Dispatches the action into the named store
+    pub fn dispatch_any(&mut self, name: &str, action: Box<dyn Any>) -> Result<(), AnyStoreError> {
+        self.stores
+            .get_mut(name)
+            .ok_or_else(|| AnyStoreError::NotFound(name.to_string()))?
+            .dispatch_any(action)
+    }
+
+    /// Returns names of registered stores in alphabetical order
+    pub fn names(&self) -> Vec<&str> {
+        self.stores.keys().map(|name| name.as_str()).collect()
+    }
+
+    /// Iterates over registered stores in alphabetical order of their names,
+    /// e.g. to list them in devtools or an inspector
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &AnyStore)> {
+        self.stores
+            .iter()
+            .map(|(name, store)| (name.as_str(), store))
+    }
+
+    pub fn len(&self) -> usize {
+        self.stores.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stores.is_empty()
+    }
+}
+
+static GLOBAL_REGISTRY: OnceLock<Mutex<StoreRegistry>> = OnceLock::new();
+
+/// Returns the registry shared by the whole process.
+///
+/// Lets code without access to the app, e.g. devtools or FFI bindings, find
+/// stores by name. The registry stays locked while the guard is alive.
+///
+/// ## Example
+/// ```rust
+/// use redust::{global_registry, Store};
+///
+/// #[derive(Debug)]
+/// enum MyAction {};
+///
+/// fn reducer(state: &u8, _action: &MyAction) -> u8 {
+///     *state
+/// }
+///
+/// global_registry().insert("app", Store::new(reducer, 1));
+///
+/// let registry = global_registry();
+/// let store = registry.get::<u8, MyAction>("app").unwrap();
+/// assert_eq!(*store.state(), 1);
+/// ```
+pub fn global_registry() -> MutexGuard<'static, StoreRegistry> {
+    GLOBAL_REGISTRY
+        .get_or_init(|| Mutex::new(StoreRegistry::new()))
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+}
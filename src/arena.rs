@@ -0,0 +1,254 @@
+use std::any::Any;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use crate::Store;
+
+/// Index of a node allocated in an `Arena`
+pub struct ArenaId<Node> {
+    index: usize,
+    node: PhantomData<fn() -> Node>,
+}
+
+impl<Node> ArenaId<Node> {
+    /// Returns the position of the node in its arena
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl<Node> Clone for ArenaId<Node> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Node> Copy for ArenaId<Node> {}
+
+impl<Node> PartialEq for ArenaId<Node> {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl<Node> Eq for ArenaId<Node> {}
+
+impl<Node> fmt::Debug for ArenaId<Node> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ArenaId({})", self.index)
+    }
+}
+
+/// Append-only storage of state nodes in one allocation.
+///
+/// States built of many small heap allocations, e.g. trees, keep their nodes
+/// in the arena and refer to them by `ArenaId` instead.
+pub struct Arena<Node> {
+    nodes: Vec<Node>,
+}
+
+impl<Node> Arena<Node> {
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            nodes: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Moves the node into the arena and returns its id
+    pub fn alloc(&mut self, node: Node) -> ArenaId<Node> {
+        self.nodes.push(node);
+
+        ArenaId {
+            index: self.nodes.len() - 1,
+            node: PhantomData,
+        }
+    }
+
+    /// Returns the node, or `None` if the id belongs to another arena
+    pub fn get(&self, id: ArenaId<Node>) -> Option<&Node> {
+        self.nodes.get(id.index)
+    }
+
+    /// Returns the number of allocated nodes
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if no node was allocated
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the number of nodes the arena can hold without reallocating
+    pub fn capacity(&self) -> usize {
+        self.nodes.capacity()
+    }
+
+    /// Iterates over nodes in the allocation order
+    pub fn iter(&self) -> impl Iterator<Item = (ArenaId<Node>, &Node)> {
+        self.nodes.iter().enumerate().map(|(index, node)| {
+            (
+                ArenaId {
+                    index,
+                    node: PhantomData,
+                },
+                node,
+            )
+        })
+    }
+}
+
+impl<Node> Default for Arena<Node> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Node> std::ops::Index<ArenaId<Node>> for Arena<Node> {
+    type Output = Node;
+
+    fn index(&self, id: ArenaId<Node>) -> &Node {
+        &self.nodes[id.index]
+    }
+}
+
+impl<Node: fmt::Debug> fmt::Debug for Arena<Node> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(&self.nodes).finish()
+    }
+}
+
+/// Reducer of an arena-backed state. It reads the previous state and its `nodes`
+/// and allocates every node of the new state into the fresh arena `into`
+pub type ArenaReducer<State, Node, Action> =
+    fn(&State, &Arena<Node>, &Action, &mut Arena<Node>) -> State;
+
+/// State whose nodes live in an `Arena`. Every reducer run allocates into
+/// a fresh arena sized after the previous one, which is dropped wholesale,
+/// so dispatch-heavy workloads do not free and allocate node by node.
+/// Dereferences to the inner state
+pub struct ArenaState<State, Node> {
+    state: State,
+    nodes: Arena<Node>,
+    // `ArenaReducer<State, Node, Action>`, erased to keep the action type out of the state
+    reducer: Arc<dyn Any + Send + Sync>,
+}
+
+impl<State, Node> ArenaState<State, Node> {
+    /// Returns the arena holding the nodes of the state
+    pub fn nodes(&self) -> &Arena<Node> {
+        &self.nodes
+    }
+
+    /// Returns the node of the state
+    pub fn node(&self, id: ArenaId<Node>) -> Option<&Node> {
+        self.nodes.get(id)
+    }
+
+    /// Root reducer which runs the reducer the store was created with on a fresh arena
+    pub fn reducer<Action: 'static>(state: &Self, action: &Action) -> Self
+    where
+        State: 'static,
+        Node: 'static,
+    {
+        let reducer = state
+            .reducer
+            .downcast_ref::<ArenaReducer<State, Node, Action>>()
+            .expect("The reducer is created together with the store");
+
+        let mut nodes = Arena::with_capacity(state.nodes.len());
+        let new_state = reducer(&state.state, &state.nodes, action, &mut nodes);
+
+        Self {
+            state: new_state,
+            nodes,
+            reducer: Arc::clone(&state.reducer),
+        }
+    }
+}
+
+impl<State, Node> std::ops::Deref for ArenaState<State, Node> {
+    type Target = State;
+
+    fn deref(&self) -> &State {
+        &self.state
+    }
+}
+
+impl<State: fmt::Debug, Node: fmt::Debug> fmt::Debug for ArenaState<State, Node> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ArenaState")
+            .field("state", &self.state)
+            .field("nodes", &self.nodes)
+            .finish()
+    }
+}
+
+impl<State: 'static, Node: 'static, Action: 'static> Store<ArenaState<State, Node>, Action> {
+    /// Creates a store whose state keeps its nodes in an arena, see `ArenaState`.
+    /// The `init` function allocates the nodes of the initial state.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::{Arena, ArenaId, Store};
+    ///
+    /// // Linked list of numbers
+    /// struct Node {
+    ///     value: u32,
+    ///     next: Option<ArenaId<Node>>,
+    /// }
+    ///
+    /// type MyStore = Option<ArenaId<Node>>;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Push(u32),
+    /// };
+    ///
+    /// fn reducer(head: &MyStore, nodes: &Arena<Node>, action: &MyAction, into: &mut Arena<Node>) -> MyStore {
+    ///     let MyAction::Push(value) = action;
+    ///
+    ///     // Copy the list into the fresh arena, then push the new head
+    ///     let mut values = vec![];
+    ///     let mut current = *head;
+    ///     while let Some(id) = current {
+    ///         values.push(nodes[id].value);
+    ///         current = nodes[id].next;
+    ///     }
+    ///     let next = values
+    ///         .into_iter()
+    ///         .rev()
+    ///         .fold(None, |next, value| Some(into.alloc(Node { value, next })));
+    ///
+    ///     Some(into.alloc(Node { value: *value, next }))
+    /// }
+    ///
+    /// let mut store = Store::arena(reducer, |_nodes| None);
+    /// store.dispatch(MyAction::Push(1)).dispatch(MyAction::Push(2));
+    ///
+    /// let head = store.state().unwrap();
+    /// assert_eq!(store.state().nodes().len(), 2);
+    /// assert_eq!(store.state().nodes()[head].value, 2);
+    /// ```
+    pub fn arena(
+        reducer: ArenaReducer<State, Node, Action>,
+        init: fn(&mut Arena<Node>) -> State,
+    ) -> Self {
+        let mut nodes = Arena::new();
+        let state = init(&mut nodes);
+
+        Self::new(
+            ArenaState::reducer,
+            ArenaState {
+                state,
+                nodes,
+                reducer: Arc::new(reducer),
+            },
+        )
+    }
+}
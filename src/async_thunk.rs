@@ -0,0 +1,175 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+use crate::{spawn_effect, CancellationHandle, CancellationToken, Dispatcher, Store};
+
+/// Identifies one call of an async thunk, so its lifecycle actions can be correlated
+pub type RequestId = u64;
+
+/// Does the work of an async thunk on its own thread
+pub type ThunkPayload<Arg, Output, Error> = fn(&Arg, &CancellationToken) -> Result<Output, Error>;
+
+/// Creates the action dispatched before the payload runs
+pub type PendingAction<Arg, Action> = fn(RequestId, &Arg) -> Action;
+
+/// Creates the action dispatched with the result of the payload
+pub type SettledAction<Arg, Result, Action> = fn(RequestId, Arg, Result) -> Action;
+
+/// Async operation with the standard lifecycle: `pending` is dispatched right away,
+/// then the payload runs on its own thread and `fulfilled` or `rejected` is
+/// enqueued with its result.
+///
+/// Each call gets a new `RequestId`, which reducers may keep to ignore results
+/// of outdated requests. Reducers of the lifecycle actions usually update
+/// an `EntityAdapter` or a `QueryEndpoint`.
+pub struct AsyncThunk<Arg, Output, Error, Action> {
+    payload: ThunkPayload<Arg, Output, Error>,
+    pending: PendingAction<Arg, Action>,
+    fulfilled: SettledAction<Arg, Output, Action>,
+    rejected: SettledAction<Arg, Error, Action>,
+    next_request_id: Arc<AtomicU64>,
+}
+
+impl<Arg, Output, Error, Action> Clone for AsyncThunk<Arg, Output, Error, Action> {
+    fn clone(&self) -> Self {
+        Self {
+            payload: self.payload,
+            pending: self.pending,
+            fulfilled: self.fulfilled,
+            rejected: self.rejected,
+            next_request_id: Arc::clone(&self.next_request_id),
+        }
+    }
+}
+
+/// Creates the async thunk from its payload and lifecycle actions.
+///
+/// ## Example
+/// ```rust
+/// use redust::{create_async_thunk, DispatchQueue, RequestId, Store};
+///
+/// #[derive(Debug, Default)]
+/// struct MyStore {
+///     loading: Option<RequestId>,
+///     user: Option<String>,
+///     error: Option<String>,
+/// }
+///
+/// #[derive(Debug)]
+/// enum MyAction {
+///     Pending(RequestId),
+///     Fulfilled(RequestId, String),
+///     Rejected(RequestId, String),
+/// };
+///
+/// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+///     match action {
+///         MyAction::Pending(id) => MyStore { loading: Some(*id), ..MyStore::default() },
+///         MyAction::Fulfilled(id, user) if state.loading == Some(*id) => MyStore {
+///             user: Some(user.clone()),
+///             ..MyStore::default()
+///         },
+///         MyAction::Rejected(id, error) if state.loading == Some(*id) => MyStore {
+///             error: Some(error.clone()),
+///             ..MyStore::default()
+///         },
+///         // A newer request is in flight
+///         _ => MyStore { loading: state.loading, ..MyStore::default() },
+///     }
+/// }
+///
+/// let fetch_user = create_async_thunk(
+///     |id: &u32, _token| match id {
+///         1 => Ok("Ann".to_string()),
+///         _ => Err("Not found".to_string()),
+///     },
+///     |request_id, _id| MyAction::Pending(request_id),
+///     |request_id, _id, user| MyAction::Fulfilled(request_id, user),
+///     |request_id, _id, error| MyAction::Rejected(request_id, error),
+/// );
+///
+/// let queue = DispatchQueue::new();
+/// let mut store = Store::new(reducer, MyStore::default());
+///
+/// let request = fetch_user.dispatch(&mut store, queue.dispatcher(), 1);
+/// assert_eq!(store.state().loading, Some(request.request_id()));
+///
+/// request.join().unwrap();
+/// store.drain(&queue);
+/// assert_eq!(store.state().user.as_deref(), Some("Ann"));
+/// ```
+pub fn create_async_thunk<Arg, Output, Error, Action>(
+    payload: ThunkPayload<Arg, Output, Error>,
+    pending: PendingAction<Arg, Action>,
+    fulfilled: SettledAction<Arg, Output, Action>,
+    rejected: SettledAction<Arg, Error, Action>,
+) -> AsyncThunk<Arg, Output, Error, Action> {
+    AsyncThunk {
+        payload,
+        pending,
+        fulfilled,
+        rejected,
+        next_request_id: Arc::new(AtomicU64::new(0)),
+    }
+}
+
+impl<Arg, Output, Error, Action> AsyncThunk<Arg, Output, Error, Action>
+where
+    Arg: Send + 'static,
+    Output: 'static,
+    Error: 'static,
+    Action: Send + 'static,
+{
+    /// Dispatches the pending action into the `store`, starts the payload and
+    /// enqueues its result with the `dispatcher`.
+    ///
+    /// The result of a cancelled request is dropped.
+    pub fn dispatch<State>(
+        &self,
+        store: &mut Store<State, Action>,
+        dispatcher: Dispatcher<Action>,
+        arg: Arg,
+    ) -> ThunkRequest {
+        let request_id = self.next_request_id.fetch_add(1, Ordering::SeqCst);
+        store.dispatch((self.pending)(request_id, &arg));
+
+        let (payload, fulfilled, rejected) = (self.payload, self.fulfilled, self.rejected);
+        let handle = spawn_effect(dispatcher, move |token| {
+            Some(match payload(&arg, token) {
+                Ok(output) => fulfilled(request_id, arg, output),
+                Err(error) => rejected(request_id, arg, error),
+            })
+        });
+
+        ThunkRequest { request_id, handle }
+    }
+}
+
+/// Handle of a running async thunk
+pub struct ThunkRequest {
+    request_id: RequestId,
+    handle: CancellationHandle,
+}
+
+impl ThunkRequest {
+    /// Returns the id passed to the lifecycle actions
+    pub fn request_id(&self) -> RequestId {
+        self.request_id
+    }
+
+    /// Cancels the request. Its result is not dispatched anymore
+    pub fn cancel(&self) {
+        self.handle.cancel();
+    }
+
+    /// Returns `true` if the payload has finished
+    pub fn is_finished(&self) -> bool {
+        self.handle.is_finished()
+    }
+
+    /// Waits for the payload to finish
+    pub fn join(self) -> thread::Result<()> {
+        self.handle.join()
+    }
+}
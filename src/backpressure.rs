@@ -0,0 +1,340 @@
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+
+use crate::subscription::{StoreObserver, Subscriber, SubscriptionToken};
+use crate::{BoxFuture, Spawner, Store};
+
+/// Async callback of `Store::subscribe_async`
+pub type AsyncSubscription<State> = fn(Arc<State>) -> BoxFuture<()>;
+
+/// Defines what a channel subscription does with new states while its receiver falls behind
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Backpressure {
+    /// Keeps only the newest state; intermediate ones are dropped.
+    /// Suits UIs, which only need to render the current state
+    #[default]
+    Latest,
+
+    /// Keeps up to this many states; the oldest is dropped when the buffer is full
+    Buffer(usize),
+
+    /// Keeps up to this many states; dispatch waits until the receiver makes room.
+    ///
+    /// The receiver must run on another thread than dispatch: with
+    /// `Store::subscribe_async` on a single-threaded executor the blocked
+    /// dispatch keeps the executor from running the callback, so it deadlocks
+    Block(usize),
+}
+
+struct Queue<State> {
+    states: VecDeque<Arc<State>>,
+    dropped: u64,
+    waker: Option<Waker>,
+    sender_alive: bool,
+    receiver_alive: bool,
+}
+
+struct Channel<State> {
+    queue: Mutex<Queue<State>>,
+    changed: Condvar,
+}
+
+impl<State> Channel<State> {
+    fn lock(&self) -> MutexGuard<'_, Queue<State>> {
+        // The queue is updated by plain pushes and pops, so it is never left inconsistent
+        self.queue
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn wait<'a>(&self, queue: MutexGuard<'a, Queue<State>>) -> MutexGuard<'a, Queue<State>> {
+        self.changed
+            .wait(queue)
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn notify(&self, queue: &mut Queue<State>) {
+        self.changed.notify_all();
+        if let Some(waker) = queue.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+struct ChannelSender<State> {
+    channel: Arc<Channel<State>>,
+    backpressure: Backpressure,
+}
+
+impl<State, Action> StoreObserver<State, Action> for ChannelSender<State> {
+    fn on_state(&mut self, state: &Arc<State>) {
+        let mut queue = self.channel.lock();
+        if !queue.receiver_alive {
+            return;
+        }
+
+        match self.backpressure {
+            Backpressure::Latest => {
+                queue.dropped += queue.states.len() as u64;
+                queue.states.clear();
+            }
+            Backpressure::Buffer(capacity) => {
+                while !queue.states.is_empty() && queue.states.len() >= capacity {
+                    queue.states.pop_front();
+                    queue.dropped += 1;
+                }
+            }
+            Backpressure::Block(capacity) => {
+                while queue.receiver_alive && queue.states.len() >= capacity.max(1) {
+                    queue = self.channel.wait(queue);
+                }
+            }
+        }
+
+        queue.states.push_back(Arc::clone(state));
+        self.channel.notify(&mut queue);
+    }
+
+    fn on_action(&mut self, _action: &Action, _state: &Arc<State>) {}
+}
+
+impl<State> Drop for ChannelSender<State> {
+    fn drop(&mut self) {
+        let mut queue = self.channel.lock();
+        queue.sender_alive = false;
+        self.channel.notify(&mut queue);
+    }
+}
+
+/// Receiving end of a channel subscription made with `Store::subscribe_channel`.
+///
+/// Receiving returns `None` once the states are drained and the subscription
+/// was removed or the store dropped. Dropping the receiver unblocks dispatches
+/// waiting with `Backpressure::Block`.
+pub struct StateReceiver<State, Action> {
+    channel: Arc<Channel<State>>,
+    token: SubscriptionToken<State, Action>,
+}
+
+impl<State, Action> StateReceiver<State, Action> {
+    /// Returns the token which removes the subscription
+    pub fn token(&self) -> SubscriptionToken<State, Action> {
+        self.token
+    }
+
+    /// Returns the next state without waiting
+    pub fn try_recv(&self) -> Option<Arc<State>> {
+        let mut queue = self.channel.lock();
+        let state = queue.states.pop_front();
+        self.channel.notify(&mut queue);
+
+        state
+    }
+
+    /// Waits for the next state
+    pub fn recv(&self) -> Option<Arc<State>> {
+        let mut queue = self.channel.lock();
+        loop {
+            if let Some(state) = queue.states.pop_front() {
+                self.channel.notify(&mut queue);
+                return Some(state);
+            }
+            if !queue.sender_alive {
+                return None;
+            }
+            queue = self.channel.wait(queue);
+        }
+    }
+
+    /// Waits for the next state at most the `timeout`
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<Arc<State>> {
+        let queue = self.channel.lock();
+        let (mut queue, _) = self
+            .channel
+            .changed
+            .wait_timeout_while(queue, timeout, |queue| {
+                queue.states.is_empty() && queue.sender_alive
+            })
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let state = queue.states.pop_front();
+        self.channel.notify(&mut queue);
+
+        state
+    }
+
+    /// Returns a future which resolves with the next state
+    pub fn recv_async(&self) -> RecvFuture<'_, State> {
+        RecvFuture {
+            channel: &self.channel,
+        }
+    }
+
+    /// Returns how many states were dropped because the receiver fell behind
+    pub fn dropped(&self) -> u64 {
+        self.channel.lock().dropped
+    }
+}
+
+impl<State, Action> Drop for StateReceiver<State, Action> {
+    fn drop(&mut self) {
+        let mut queue = self.channel.lock();
+        queue.receiver_alive = false;
+        queue.states.clear();
+        self.channel.notify(&mut queue);
+    }
+}
+
+/// Future returned by `StateReceiver::recv_async`
+pub struct RecvFuture<'a, State> {
+    channel: &'a Channel<State>,
+}
+
+impl<'a, State> Future for RecvFuture<'a, State> {
+    type Output = Option<Arc<State>>;
+
+    fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+        let channel = self.channel;
+        let mut queue = channel.lock();
+        if let Some(state) = queue.states.pop_front() {
+            channel.notify(&mut queue);
+            return Poll::Ready(Some(state));
+        }
+        if !queue.sender_alive {
+            return Poll::Ready(None);
+        }
+
+        queue.waker = Some(context.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl<State: Send + Sync + 'static, Action, Env> Store<State, Action, Env> {
+    /// Subscribes a channel which receives new states, e.g. to consume them
+    /// on another thread or in an async task.
+    ///
+    /// The `backpressure` decides what happens while the receiver falls behind.
+    /// `Backpressure::Latest` is the default: the receiver gets the newest state
+    /// and never a backlog of stale ones.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::{Backpressure, Store};
+    ///
+    /// type MyStore = u8;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Increment,
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Increment => state + 1,
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, 0);
+    /// let states = store.subscribe_channel(Backpressure::default());
+    ///
+    /// store.dispatch(MyAction::Increment).dispatch(MyAction::Increment);
+    ///
+    /// // The intermediate state was dropped
+    /// assert_eq!(states.try_recv().as_deref(), Some(&2));
+    /// assert_eq!(states.dropped(), 1);
+    /// ```
+    pub fn subscribe_channel(
+        &mut self,
+        backpressure: Backpressure,
+    ) -> StateReceiver<State, Action> {
+        let channel = Arc::new(Channel {
+            queue: Mutex::new(Queue {
+                states: VecDeque::new(),
+                dropped: 0,
+                waker: None,
+                sender_alive: true,
+                receiver_alive: true,
+            }),
+            changed: Condvar::new(),
+        });
+
+        let token = self.next_subscription_token();
+        let sender = ChannelSender {
+            channel: Arc::clone(&channel),
+            backpressure,
+        };
+        self.subscriptions
+            .insert(token.id(), Subscriber::Observer(Box::new(sender)));
+
+        StateReceiver { channel, token }
+    }
+
+    /// Subscribes an async callback, which runs with the `spawner` one state at a time.
+    ///
+    /// While the callback still processes a state, new ones are handled by the
+    /// `backpressure`. With `Backpressure::Latest` updates are coalesced: the
+    /// callback is next called exactly once, with the newest state. The returned
+    /// token removes the subscription; the callback in progress is finished.
+    ///
+    /// `Backpressure::Block` deadlocks when the `spawner` runs the callback on
+    /// the thread which dispatches, e.g. a single-threaded executor, because
+    /// the full queue stops dispatch before the callback can drain it.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::{Backpressure, BlockingRuntime, Store};
+    /// use std::sync::Mutex;
+    ///
+    /// type MyStore = u8;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Increment,
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Increment => state + 1,
+    ///     }
+    /// }
+    ///
+    /// static RENDERED: Mutex<Vec<u8>> = Mutex::new(vec![]);
+    ///
+    /// let runtime = BlockingRuntime::<MyAction>::new();
+    /// let mut store = Store::new(reducer, 0);
+    /// let token = store.subscribe_async(&*runtime.spawner(), Backpressure::Latest, |state| {
+    ///     Box::pin(async move { RENDERED.lock().unwrap().push(*state) })
+    /// });
+    ///
+    /// // Nothing runs the subscriber until the runtime is driven
+    /// store.dispatch(MyAction::Increment).dispatch(MyAction::Increment);
+    /// store.unsubscribe(token).unwrap();
+    /// runtime.run_until_idle();
+    ///
+    /// assert_eq!(*RENDERED.lock().unwrap(), [2]);
+    /// ```
+    pub fn subscribe_async(
+        &mut self,
+        spawner: &dyn Spawner,
+        backpressure: Backpressure,
+        func: AsyncSubscription<State>,
+    ) -> SubscriptionToken<State, Action>
+    where
+        Action: 'static,
+    {
+        let states = self.subscribe_channel(backpressure);
+        let token = states.token();
+
+        spawner.spawn(Box::pin(async move {
+            while let Some(state) = states.recv_async().await {
+                func(state).await;
+            }
+        }));
+
+        token
+    }
+}
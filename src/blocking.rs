@@ -0,0 +1,248 @@
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, Weak};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::{BoxFuture, DispatchQueue, Dispatcher, SharedSpawner, Spawner, Store};
+
+struct Tasks {
+    ready: VecDeque<Arc<Task>>,
+    // Spawned tasks which have not completed yet
+    unfinished: usize,
+}
+
+struct Executor {
+    tasks: Mutex<Tasks>,
+    woken: Condvar,
+}
+
+impl Executor {
+    fn lock(&self) -> MutexGuard<'_, Tasks> {
+        // Tasks are only pushed and popped, so the queue is never left inconsistent
+        self.tasks
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn schedule(&self, task: Arc<Task>) {
+        self.lock().ready.push_back(task);
+        self.woken.notify_all();
+    }
+}
+
+struct Task {
+    future: Mutex<Option<BoxFuture<()>>>,
+    executor: Weak<Executor>,
+}
+
+impl Wake for Task {
+    fn wake(self: Arc<Self>) {
+        if let Some(executor) = self.executor.upgrade() {
+            executor.schedule(self);
+        }
+    }
+}
+
+/// Spawner of the `BlockingRuntime`
+struct BlockingSpawner {
+    executor: Arc<Executor>,
+}
+
+impl Spawner for BlockingSpawner {
+    fn spawn(&self, future: BoxFuture<()>) {
+        let task = Arc::new(Task {
+            future: Mutex::new(Some(future)),
+            executor: Arc::downgrade(&self.executor),
+        });
+        self.executor.lock().unfinished += 1;
+        self.executor.schedule(task);
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<()> {
+        Box::pin(Sleep {
+            deadline: Instant::now() + duration,
+            timer_started: Arc::new(AtomicBool::new(false)),
+        })
+    }
+}
+
+/// Completes at the deadline. A timer thread wakes the task once it has passed
+struct Sleep {
+    deadline: Instant,
+    timer_started: Arc<AtomicBool>,
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(self: std::pin::Pin<&mut Self>, context: &mut Context<'_>) -> Poll<()> {
+        let remaining = self.deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Poll::Ready(());
+        }
+
+        if !self.timer_started.swap(true, Ordering::SeqCst) {
+            let waker = context.waker().clone();
+            thread::spawn(move || {
+                thread::sleep(remaining);
+                waker.wake();
+            });
+        }
+
+        Poll::Pending
+    }
+}
+
+/// Minimal executor which lets sync code, e.g. CLI tools and tests, wait for
+/// async effects started by middleware.
+///
+/// Middleware spawns effects with `spawner` and enqueues their actions with
+/// `dispatcher`; `Store::dispatch_blocking` runs them to completion.
+pub struct BlockingRuntime<Action> {
+    spawner: Arc<BlockingSpawner>,
+    queue: DispatchQueue<Action>,
+}
+
+impl<Action> BlockingRuntime<Action> {
+    pub fn new() -> Self {
+        Self {
+            spawner: Arc::new(BlockingSpawner {
+                executor: Arc::new(Executor {
+                    tasks: Mutex::new(Tasks {
+                        ready: VecDeque::new(),
+                        unfinished: 0,
+                    }),
+                    woken: Condvar::new(),
+                }),
+            }),
+            queue: DispatchQueue::new(),
+        }
+    }
+
+    /// Returns the spawner which effects should be spawned with
+    pub fn spawner(&self) -> SharedSpawner {
+        Arc::clone(&self.spawner) as SharedSpawner
+    }
+
+    /// Returns the dispatcher which effects should enqueue their actions with
+    pub fn dispatcher(&self) -> Dispatcher<Action> {
+        self.queue.dispatcher()
+    }
+
+    /// Returns the number of spawned effects which have not completed yet
+    pub fn pending(&self) -> usize {
+        self.spawner.executor.lock().unfinished
+    }
+
+    /// Runs spawned futures on the current thread until all of them have completed
+    pub fn run_until_idle(&self) {
+        let executor = &self.spawner.executor;
+        loop {
+            let task = {
+                let mut tasks = executor.lock();
+                loop {
+                    if let Some(task) = tasks.ready.pop_front() {
+                        break task;
+                    }
+                    if tasks.unfinished == 0 {
+                        return;
+                    }
+                    tasks = executor
+                        .woken
+                        .wait(tasks)
+                        .unwrap_or_else(|poisoned| poisoned.into_inner());
+                }
+            };
+
+            let waker = Waker::from(Arc::clone(&task));
+            let mut context = Context::from_waker(&waker);
+            let mut future = task
+                .future
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+            // A task woken several times is polled again after it has completed
+            let completed = match future.as_mut() {
+                Some(pending) => pending.as_mut().poll(&mut context).is_ready(),
+                None => false,
+            };
+            if completed {
+                *future = None;
+                executor.lock().unfinished -= 1;
+            }
+        }
+    }
+}
+
+impl<Action> Default for BlockingRuntime<Action> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<State, Action, Env> Store<State, Action, Env> {
+    /// Dispatches the action, waits until the effects it triggered have completed,
+    /// dispatches the actions they enqueued, and repeats until nothing is left.
+    /// Returns the settled state.
+    ///
+    /// Blocks forever if an effect never completes.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::{spawn_async_effect, BlockingRuntime, Store};
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Debug, Clone, PartialEq)]
+    /// enum MyAction {
+    ///     Fetch,
+    ///     Loaded(&'static str),
+    /// };
+    ///
+    /// fn reducer(state: &Vec<&'static str>, action: &MyAction) -> Vec<&'static str> {
+    ///     let mut users = state.clone();
+    ///     if let MyAction::Loaded(user) = action {
+    ///         users.push(user);
+    ///     }
+    ///
+    ///     users
+    /// }
+    ///
+    /// let runtime = BlockingRuntime::new();
+    /// let (spawner, dispatcher) = (runtime.spawner(), runtime.dispatcher());
+    ///
+    /// let mut store = Store::new(reducer, vec![]);
+    /// store.add_middleware("fetch", move |action, next: redust::Next<_, _>| {
+    ///     let action = next.run(action)?;
+    ///     if action == MyAction::Fetch {
+    ///         let sleep = spawner.sleep(Duration::from_millis(1));
+    ///         spawn_async_effect(spawner.as_ref(), dispatcher.clone(), |_token| async move {
+    ///             sleep.await;
+    ///             Some(MyAction::Loaded("Ann"))
+    ///         });
+    ///     }
+    ///
+    ///     Ok(action)
+    /// });
+    ///
+    /// assert_eq!(*store.dispatch_blocking(&runtime, MyAction::Fetch), ["Ann"]);
+    /// ```
+    pub fn dispatch_blocking(
+        &mut self,
+        runtime: &BlockingRuntime<Action>,
+        action: Action,
+    ) -> &State {
+        self.dispatch(action);
+
+        loop {
+            runtime.run_until_idle();
+            if self.drain(&runtime.queue) == 0 {
+                break;
+            }
+        }
+
+        self.state()
+    }
+}
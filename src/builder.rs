@@ -0,0 +1,171 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use crate::lazy::LazyState;
+use crate::reducer::RootReducer;
+use crate::{DispatchError, ErrorBehavior, FreezePolicy, Next, PanicIsolation, Reducer, Store};
+
+/// Builder which configures a `Store` before creating it
+///
+/// ## Example
+/// ```rust
+/// use redust::Store;
+///
+/// type MyStore = u8;
+///
+/// #[derive(Debug)]
+/// enum MyAction {
+///     Increment,
+/// };
+///
+/// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+///     match action {
+///         MyAction::Increment => state + 1,
+///     }
+/// }
+///
+/// let mut store = Store::default_builder(reducer).build();
+/// store.dispatch(MyAction::Increment);
+///
+/// assert_eq!(*store.state(), 1);
+/// ```
+pub struct StoreBuilder<State, Action> {
+    store: Store<State, Action>,
+}
+
+impl<State, Action> StoreBuilder<State, Action> {
+    pub(crate) fn new(reducer: Reducer<State, Action>, state: LazyState<State>) -> Self {
+        Self {
+            store: Store::with_lazy_state(RootReducer::Plain(reducer), state, Arc::new(())),
+        }
+    }
+
+    /// Creates the store frozen with the `policy`
+    pub fn frozen(mut self, policy: FreezePolicy) -> Self {
+        self.store.freeze(policy);
+
+        self
+    }
+
+    /// Creates the store with paused notifications
+    pub fn notifications_paused(mut self) -> Self {
+        self.store.pause_notifications();
+
+        self
+    }
+
+    /// Creates the store with the dependencies, see `Store::set_context`
+    pub fn context<Deps: Send + Sync + 'static>(mut self, deps: Arc<Deps>) -> Self {
+        self.store.set_context(deps);
+
+        self
+    }
+
+    /// Creates the configured store
+    pub fn build(self) -> Store<State, Action> {
+        self.store
+    }
+}
+
+impl<State: Debug + 'static, Action: Debug + 'static> StoreBuilder<State, Action> {
+    /// Appends the middleware which writes every reduced action with the previous
+    /// and the new state to stderr. With the `log` feature they are logged as
+    /// debug records of the `redust::dispatch` target instead
+    pub fn logger(mut self) -> Self {
+        self.store
+            .add_middleware("logger", log_action::<State, Action>);
+
+        self
+    }
+}
+
+impl<State, Action: Debug> StoreBuilder<State, Action> {
+    /// Catches panics of subscribers and writes them and other failures to stderr
+    /// instead of taking the app down
+    pub fn crash_reporter(mut self) -> Self {
+        self.store.isolate_panics(PanicIsolation::Report);
+        self.store.set_error_behavior(ErrorBehavior::Log);
+
+        self
+    }
+}
+
+impl<State: Hash, Action: Debug> StoreBuilder<State, Action> {
+    /// Creates the store which checks reducers for mutations of the previous state,
+    /// see `Store::check_mutations`
+    pub fn check_mutations(mut self) -> Self {
+        self.store.check_mutations();
+
+        self
+    }
+}
+
+fn log_action<State: Debug, Action: Debug>(
+    action: Action,
+    next: Next<'_, State, Action>,
+) -> Result<Action, DispatchError> {
+    next.run_and_inspect(action, |action, old_state, new_state| {
+        #[cfg(feature = "log")]
+        log::debug!(
+            target: crate::logging::DISPATCH,
+            "{:?}: {:?} -> {:?}",
+            action,
+            old_state,
+            new_state
+        );
+        #[cfg(not(feature = "log"))]
+        eprintln!("{:?}: {:?} -> {:?}", action, old_state, new_state)
+    })
+}
+
+impl<State, Action> Store<State, Action> {
+    /// Returns a builder for the store which starts from the `initial_state`
+    pub fn builder(
+        reducer: Reducer<State, Action>,
+        initial_state: State,
+    ) -> StoreBuilder<State, Action> {
+        StoreBuilder::new(reducer, LazyState::new(initial_state))
+    }
+
+    /// Returns a builder for the store whose initial state is computed on first access
+    pub fn lazy_builder(
+        reducer: Reducer<State, Action>,
+        initializer: impl FnOnce() -> State + Send + 'static,
+    ) -> StoreBuilder<State, Action> {
+        StoreBuilder::new(reducer, LazyState::lazy(initializer))
+    }
+}
+
+impl<State: Default, Action> Store<State, Action> {
+    /// Creates a new store which starts from `State::default()`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::Store;
+    ///
+    /// #[derive(Debug, Default, PartialEq)]
+    /// struct MyStore {
+    ///     counter: u8,
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {};
+    ///
+    /// fn reducer(state: &MyStore, _action: &MyAction) -> MyStore {
+    ///     MyStore { counter: state.counter }
+    /// }
+    ///
+    /// let store = Store::with_default_state(reducer);
+    ///
+    /// assert_eq!(*store.state(), MyStore::default());
+    /// ```
+    pub fn with_default_state(reducer: Reducer<State, Action>) -> Self {
+        Self::new(reducer, State::default())
+    }
+
+    /// Returns a builder for the store which starts from `State::default()`
+    pub fn default_builder(reducer: Reducer<State, Action>) -> StoreBuilder<State, Action> {
+        Self::builder(reducer, State::default())
+    }
+}
@@ -0,0 +1,70 @@
+//! Interop with `crossbeam_channel`. Available behind the `crossbeam-channel` feature.
+
+use std::thread::{self, JoinHandle};
+
+use crossbeam_channel::Receiver;
+
+use crate::{Dispatcher, Store};
+
+impl<State, Action, Env> Store<State, Action, Env> {
+    /// Dispatches all actions which are already waiting in the `receiver`
+    /// and returns how many were dispatched. Does not block.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::Store;
+    ///
+    /// type MyStore = u8;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Increment,
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Increment => state + 1,
+    ///     }
+    /// }
+    ///
+    /// let (sender, receiver) = crossbeam_channel::unbounded();
+    /// sender.send(MyAction::Increment).unwrap();
+    /// sender.send(MyAction::Increment).unwrap();
+    ///
+    /// let mut store = Store::new(reducer, 0);
+    ///
+    /// assert_eq!(store.pump(&receiver), 2);
+    /// assert_eq!(*store.state(), 2);
+    /// ```
+    pub fn pump(&mut self, receiver: &Receiver<Action>) -> usize {
+        let mut dispatched = 0;
+        while let Ok(action) = receiver.try_recv() {
+            self.dispatch(action);
+            dispatched += 1;
+        }
+
+        dispatched
+    }
+}
+
+/// Spawns a thread which forwards actions from the `receiver` into the
+/// dispatch queue behind the `dispatcher`.
+///
+/// The thread stops when all senders are dropped or the queue is dropped.
+/// It returns the number of forwarded actions.
+pub fn forward<Action: Send + 'static>(
+    receiver: Receiver<Action>,
+    dispatcher: Dispatcher<Action>,
+) -> JoinHandle<usize> {
+    thread::spawn(move || {
+        let mut forwarded = 0;
+        for action in receiver.iter() {
+            if dispatcher.dispatch(action).is_err() {
+                break;
+            }
+            forwarded += 1;
+        }
+
+        forwarded
+    })
+}
@@ -0,0 +1,151 @@
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::Store;
+
+/// Source of time for timers, sampling, TTLs and schedules.
+///
+/// The store and its timer threads read the time only through a clock,
+/// so tests can replace `SystemClock` with a `TestClock` and advance it manually.
+pub trait Clock: Debug + Send + Sync {
+    /// Returns the monotonic time, used to measure intervals
+    fn now(&self) -> Instant;
+
+    /// Returns the wall-clock time, used for expirations and cron schedules
+    fn system_time(&self) -> SystemTime;
+
+    /// Returns how long a timer thread might block before it reads the clock again
+    /// when the next timer is due in `remaining`
+    fn wait_timeout(&self, remaining: Duration) -> Duration {
+        remaining
+    }
+}
+
+/// Clock shared between the store and its timer threads
+pub type SharedClock = Arc<dyn Clock>;
+
+/// Clock which reads the time of the operating system
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn system_time(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// How often timer threads look at a `TestClock` which was advanced
+const TEST_CLOCK_POLL: Duration = Duration::from_millis(1);
+
+/// Clock which stands still until it is advanced. Clones share the time.
+///
+/// ## Example
+/// ```rust
+/// use redust::{Clock, TestClock};
+/// use std::time::Duration;
+///
+/// let clock = TestClock::new();
+/// let started = clock.now();
+///
+/// clock.advance(Duration::from_secs(60));
+///
+/// assert_eq!(clock.now() - started, Duration::from_secs(60));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TestClock {
+    time: Arc<Mutex<(Instant, SystemTime)>>,
+}
+
+impl TestClock {
+    /// Creates the clock stopped at the current time
+    pub fn new() -> Self {
+        Self::starting_at(SystemTime::now())
+    }
+
+    /// Creates the clock stopped at the wall-clock time `system_time`
+    pub fn starting_at(system_time: SystemTime) -> Self {
+        Self {
+            time: Arc::new(Mutex::new((Instant::now(), system_time))),
+        }
+    }
+
+    /// Moves the time of the clock and all its clones forward
+    pub fn advance(&self, duration: Duration) {
+        let mut time = self.lock();
+        time.0 += duration;
+        time.1 += duration;
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, (Instant, SystemTime)> {
+        self.time
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Default for TestClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for TestClock {
+    fn now(&self) -> Instant {
+        self.lock().0
+    }
+
+    fn system_time(&self) -> SystemTime {
+        self.lock().1
+    }
+
+    fn wait_timeout(&self, remaining: Duration) -> Duration {
+        remaining.min(TEST_CLOCK_POLL)
+    }
+}
+
+impl<State, Action, Env> Store<State, Action, Env> {
+    /// Replaces the clock of sampled subscribers, history and expirations.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::{Store, TestClock};
+    /// use std::time::Duration;
+    ///
+    /// type MyStore = bool;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Expire,
+    /// };
+    ///
+    /// fn reducer(_state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Expire => true,
+    ///     }
+    /// }
+    ///
+    /// let clock = TestClock::new();
+    /// let mut store = Store::new(reducer, false);
+    /// store.set_clock(clock.clone());
+    ///
+    /// store.expire_after(Duration::from_secs(60), MyAction::Expire);
+    /// assert_eq!(store.dispatch_due(), 0);
+    ///
+    /// clock.advance(Duration::from_secs(60));
+    /// assert_eq!(store.dispatch_due(), 1);
+    /// assert!(*store.state());
+    /// ```
+    pub fn set_clock(&mut self, clock: impl Clock + 'static) {
+        self.clock = Arc::new(clock);
+    }
+
+    /// Returns the clock of the store, e.g. to share it with a `Ticker` or a `Scheduler`
+    pub fn clock(&self) -> SharedClock {
+        Arc::clone(&self.clock)
+    }
+}
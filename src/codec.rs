@@ -0,0 +1,584 @@
+//! Codecs for snapshots and action logs. Available behind the `serde` feature.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{Fixture, Reducer, Store};
+
+/// Binary format of snapshots and action logs.
+///
+/// JSON is human-readable; MessagePack (the `msgpack` feature) is smaller and
+/// faster for high-frequency action logging.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Codec {
+    #[default]
+    Json,
+
+    #[cfg(feature = "msgpack")]
+    MessagePack,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CodecError {
+    Encode(String),
+    Decode(String),
+
+    /// The checksum written with the payload does not match its bytes
+    CorruptSnapshot {
+        expected: u32,
+        actual: u32,
+    },
+}
+
+impl std::error::Error for CodecError {}
+impl std::fmt::Display for CodecError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            CodecError::Encode(message) => write!(f, "Cannot encode the value: {}", message),
+            CodecError::Decode(message) => write!(f, "Cannot decode the value: {}", message),
+            CodecError::CorruptSnapshot { expected, actual } => write!(
+                f,
+                "Cannot decode the corrupted value: the checksum is {:08x} instead of {:08x}",
+                actual, expected
+            ),
+        }
+    }
+}
+
+impl Codec {
+    /// Serializes the value into bytes
+    pub fn encode<T: Serialize>(self, value: &T) -> Result<Vec<u8>, CodecError> {
+        match self {
+            Codec::Json => {
+                serde_json::to_vec(value).map_err(|err| CodecError::Encode(err.to_string()))
+            }
+            #[cfg(feature = "msgpack")]
+            Codec::MessagePack => {
+                // Named fields keep snapshots readable after fields are reordered
+                rmp_serde::to_vec_named(value).map_err(|err| CodecError::Encode(err.to_string()))
+            }
+        }
+    }
+
+    /// Deserializes the value from bytes
+    pub fn decode<T: DeserializeOwned>(self, bytes: &[u8]) -> Result<T, CodecError> {
+        match self {
+            Codec::Json => {
+                serde_json::from_slice(bytes).map_err(|err| CodecError::Decode(err.to_string()))
+            }
+            #[cfg(feature = "msgpack")]
+            Codec::MessagePack => {
+                rmp_serde::from_slice(bytes).map_err(|err| CodecError::Decode(err.to_string()))
+            }
+        }
+    }
+}
+
+/// Encoding of snapshots and action logs: a `Codec`, optionally compressed or encrypted
+pub trait Format: Copy {
+    /// Serializes the value into bytes
+    fn encode<T: Serialize>(self, value: &T) -> Result<Vec<u8>, CodecError>;
+
+    /// Deserializes the value from bytes
+    fn decode<T: DeserializeOwned>(self, bytes: &[u8]) -> Result<T, CodecError>;
+
+    /// Writes a CRC-32 checksum in front of the encoded bytes and verifies it on decoding
+    fn checksummed(self) -> ChecksummedCodec<Self> {
+        ChecksummedCodec { format: self }
+    }
+
+    /// Encrypts the encoded bytes with the `encryption`
+    fn encrypted(self, encryption: &Encryption) -> EncryptedCodec<'_, Self> {
+        EncryptedCodec {
+            format: self,
+            encryption,
+        }
+    }
+}
+
+impl Format for Codec {
+    fn encode<T: Serialize>(self, value: &T) -> Result<Vec<u8>, CodecError> {
+        Codec::encode(self, value)
+    }
+
+    fn decode<T: DeserializeOwned>(self, bytes: &[u8]) -> Result<T, CodecError> {
+        Codec::decode(self, bytes)
+    }
+}
+
+/// Compresses and decompresses bytes, or returns the error message
+pub type CompressFn = fn(&[u8]) -> Result<Vec<u8>, String>;
+
+// Starts compressed payloads. Neither JSON nor MessagePack documents longer
+// than one byte start with it, so uncompressed payloads are told apart
+const COMPRESSED_MAGIC: [u8; 3] = [0x00, b'R', b'Z'];
+
+/// Compression algorithm of encoded snapshots and action logs, e.g. zstd or lz4.
+///
+/// The algorithm is plugged in as a pair of functions, so the crate does not
+/// depend on a particular compression library:
+/// `Compression::new(1, |bytes| zstd::encode_all(bytes, 3).map_err(|err| err.to_string()), ...)`.
+/// The `tag` is written in front of the payload, which lets decoding reject
+/// bytes compressed with another algorithm.
+#[derive(Debug, Clone, Copy)]
+pub struct Compression {
+    tag: u8,
+    compress: CompressFn,
+    decompress: CompressFn,
+}
+
+impl Compression {
+    pub const fn new(tag: u8, compress: CompressFn, decompress: CompressFn) -> Self {
+        Self {
+            tag,
+            compress,
+            decompress,
+        }
+    }
+
+    /// Returns the tag which marks payloads compressed with the algorithm
+    pub fn tag(&self) -> u8 {
+        self.tag
+    }
+}
+
+/// `Codec` which compresses the encoded bytes. Created with `Codec::compressed`.
+///
+/// Decoding is transparent: payloads written before compression was enabled
+/// are decoded as they are.
+#[derive(Debug, Clone, Copy)]
+pub struct CompressedCodec {
+    codec: Codec,
+    compression: Compression,
+}
+
+impl Codec {
+    /// Compresses the encoded bytes with the `compression`
+    pub fn compressed(self, compression: Compression) -> CompressedCodec {
+        CompressedCodec {
+            codec: self,
+            compression,
+        }
+    }
+}
+
+impl Format for CompressedCodec {
+    fn encode<T: Serialize>(self, value: &T) -> Result<Vec<u8>, CodecError> {
+        let encoded = self.codec.encode(value)?;
+        let compressed = (self.compression.compress)(&encoded).map_err(CodecError::Encode)?;
+
+        let mut bytes = Vec::with_capacity(COMPRESSED_MAGIC.len() + 1 + compressed.len());
+        bytes.extend_from_slice(&COMPRESSED_MAGIC);
+        bytes.push(self.compression.tag);
+        bytes.extend_from_slice(&compressed);
+
+        Ok(bytes)
+    }
+
+    fn decode<T: DeserializeOwned>(self, bytes: &[u8]) -> Result<T, CodecError> {
+        let payload = match bytes.strip_prefix(&COMPRESSED_MAGIC[..]) {
+            Some(payload) => payload,
+            None => return self.codec.decode(bytes),
+        };
+
+        match payload.split_first() {
+            Some((tag, compressed)) if *tag == self.compression.tag => {
+                let encoded =
+                    (self.compression.decompress)(compressed).map_err(CodecError::Decode)?;
+                self.codec.decode(&encoded)
+            }
+            _ => Err(CodecError::Decode(format!(
+                "the payload is not compressed with the algorithm {}",
+                self.compression.tag
+            ))),
+        }
+    }
+}
+
+/// Seals or opens bytes with the `key`, or returns the error message.
+/// Arguments are the key, the associated data and the bytes
+pub type AeadFn = fn(&[u8], &[u8], &[u8]) -> Result<Vec<u8>, String>;
+
+// Starts encrypted payloads and is followed by the big-endian id of the key
+const ENCRYPTED_MAGIC: [u8; 3] = [0x00, b'R', b'E'];
+
+/// Supplies the keys of `Encryption`, e.g. from a secret manager or the OS keychain
+pub trait KeyProvider: Send + Sync {
+    /// Returns the id and the key which new payloads are encrypted with
+    fn current_key(&self) -> Option<(u32, Vec<u8>)>;
+
+    /// Returns the key with the `id`, including retired ones,
+    /// so payloads encrypted before a rotation stay readable
+    fn key(&self, id: u32) -> Option<Vec<u8>>;
+}
+
+/// In-memory `KeyProvider` which keeps every key it has seen
+#[derive(Debug, Clone, Default)]
+pub struct KeyRing {
+    current: Option<u32>,
+    keys: HashMap<u32, Vec<u8>>,
+}
+
+impl KeyRing {
+    pub fn new(id: u32, key: Vec<u8>) -> Self {
+        let mut keys = Self::default();
+        keys.rotate(id, key);
+
+        keys
+    }
+
+    /// Makes the `key` current. Older keys are still used for decryption
+    pub fn rotate(&mut self, id: u32, key: Vec<u8>) {
+        self.keys.insert(id, key);
+        self.current = Some(id);
+    }
+
+    /// Forgets the key, payloads encrypted with it cannot be decrypted anymore
+    pub fn retire(&mut self, id: u32) {
+        self.keys.remove(&id);
+        if self.current == Some(id) {
+            self.current = None;
+        }
+    }
+}
+
+impl KeyProvider for KeyRing {
+    fn current_key(&self) -> Option<(u32, Vec<u8>)> {
+        let id = self.current?;
+        Some((id, self.keys.get(&id)?.clone()))
+    }
+
+    fn key(&self, id: u32) -> Option<Vec<u8>> {
+        self.keys.get(&id).cloned()
+    }
+}
+
+/// Authenticated encryption of snapshots and action logs which contain user data.
+///
+/// The AEAD algorithm, e.g. AES-GCM or ChaCha20-Poly1305, is plugged in as
+/// a pair of functions. `seal` has to generate a fresh nonce and put it into
+/// its output, where `open` finds it. The payload header, which holds the key
+/// id, is passed as associated data, so swapping it is detected.
+///
+/// ## Example
+/// ```rust
+/// use redust::codec::{Codec, Encryption, Format, KeyRing};
+/// use redust::Store;
+///
+/// # // Not a real cipher, the example only needs a reversible transformation
+/// fn seal(key: &[u8], _header: &[u8], bytes: &[u8]) -> Result<Vec<u8>, String> {
+///     Ok(bytes.iter().zip(key.iter().cycle()).map(|(byte, key)| byte ^ key).collect())
+/// }
+///
+/// type MyStore = u8;
+///
+/// #[derive(Debug)]
+/// enum MyAction {
+///     Increment,
+/// };
+///
+/// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+///     match action {
+///         MyAction::Increment => state + 1,
+///     }
+/// }
+///
+/// let encryption = Encryption::new(KeyRing::new(1, b"old secret".to_vec()), seal, seal);
+/// let mut store = Store::new(reducer, 0);
+/// store.dispatch(MyAction::Increment);
+/// let snapshot = store.snapshot(Codec::Json.encrypted(&encryption)).unwrap();
+///
+/// // Snapshots encrypted with the old key stay readable after the rotation
+/// let mut keys = KeyRing::new(1, b"old secret".to_vec());
+/// keys.rotate(2, b"new secret".to_vec());
+/// let encryption = Encryption::new(keys, seal, seal);
+///
+/// let restored = Store::restore(reducer, Codec::Json.encrypted(&encryption), &snapshot).unwrap();
+/// assert_eq!(*restored.state(), 1);
+/// assert_eq!(encryption.key_id(&snapshot), Some(1));
+/// ```
+#[derive(Clone)]
+pub struct Encryption {
+    keys: Arc<dyn KeyProvider>,
+    seal: AeadFn,
+    open: AeadFn,
+}
+
+impl std::fmt::Debug for Encryption {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        // Keys are never printed
+        f.debug_struct("Encryption").finish_non_exhaustive()
+    }
+}
+
+impl Encryption {
+    pub fn new<Keys: KeyProvider + 'static>(keys: Keys, seal: AeadFn, open: AeadFn) -> Self {
+        Self {
+            keys: Arc::new(keys),
+            seal,
+            open,
+        }
+    }
+
+    /// Returns the id of the key which the payload was encrypted with, e.g. to find
+    /// snapshots which should be encrypted again after a rotation
+    pub fn key_id(&self, bytes: &[u8]) -> Option<u32> {
+        let id = bytes.strip_prefix(&ENCRYPTED_MAGIC[..])?.get(..4)?;
+        Some(u32::from_be_bytes([id[0], id[1], id[2], id[3]]))
+    }
+
+    fn seal(&self, plaintext: &[u8]) -> Result<Vec<u8>, CodecError> {
+        let (id, key) = self
+            .keys
+            .current_key()
+            .ok_or_else(|| CodecError::Encode("there is no current key".to_string()))?;
+
+        let mut bytes = ENCRYPTED_MAGIC.to_vec();
+        bytes.extend_from_slice(&id.to_be_bytes());
+        let sealed = (self.seal)(&key, &bytes, plaintext).map_err(CodecError::Encode)?;
+        bytes.extend_from_slice(&sealed);
+
+        Ok(bytes)
+    }
+
+    fn open(&self, bytes: &[u8]) -> Result<Vec<u8>, CodecError> {
+        let header_len = ENCRYPTED_MAGIC.len() + 4;
+        let id = self
+            .key_id(bytes)
+            .ok_or_else(|| CodecError::Decode("the payload is not encrypted".to_string()))?;
+        let key = self
+            .keys
+            .key(id)
+            .ok_or_else(|| CodecError::Decode(format!("there is no key {}", id)))?;
+
+        let (header, sealed) = bytes.split_at(header_len);
+        (self.open)(&key, header, sealed).map_err(CodecError::Decode)
+    }
+}
+
+/// `Format` which encrypts the encoded bytes. Created with `Format::encrypted`.
+///
+/// Unlike compression, decoding rejects payloads which are not encrypted.
+#[derive(Debug, Clone, Copy)]
+pub struct EncryptedCodec<'a, F> {
+    format: F,
+    encryption: &'a Encryption,
+}
+
+impl<'a, F: Format> Format for EncryptedCodec<'a, F> {
+    fn encode<T: Serialize>(self, value: &T) -> Result<Vec<u8>, CodecError> {
+        self.encryption.seal(&self.format.encode(value)?)
+    }
+
+    fn decode<T: DeserializeOwned>(self, bytes: &[u8]) -> Result<T, CodecError> {
+        self.format.decode(&self.encryption.open(bytes)?)
+    }
+}
+
+// Starts checksummed payloads and is followed by the big-endian CRC-32 of the rest
+const CHECKSUMMED_MAGIC: [u8; 3] = [0x00, b'R', b'C'];
+
+/// CRC-32 (IEEE) of the bytes
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0u32, |crc, byte| {
+        (0..8).fold(crc ^ u32::from(*byte), |crc, _| {
+            (crc >> 1) ^ (0xEDB8_8320 & 0u32.wrapping_sub(crc & 1))
+        })
+    })
+}
+
+/// `Format` which protects the encoded bytes with a checksum.
+/// Created with `Format::checksummed`.
+///
+/// Bytes which do not match their checksum fail with `CodecError::CorruptSnapshot`
+/// instead of being decoded into a wrong state. Payloads written before
+/// checksums were enabled are decoded as they are.
+#[derive(Debug, Clone, Copy)]
+pub struct ChecksummedCodec<F> {
+    format: F,
+}
+
+impl<F: Format> Format for ChecksummedCodec<F> {
+    fn encode<T: Serialize>(self, value: &T) -> Result<Vec<u8>, CodecError> {
+        let encoded = self.format.encode(value)?;
+
+        let mut bytes = Vec::with_capacity(CHECKSUMMED_MAGIC.len() + 4 + encoded.len());
+        bytes.extend_from_slice(&CHECKSUMMED_MAGIC);
+        bytes.extend_from_slice(&crc32(&encoded).to_be_bytes());
+        bytes.extend_from_slice(&encoded);
+
+        Ok(bytes)
+    }
+
+    fn decode<T: DeserializeOwned>(self, bytes: &[u8]) -> Result<T, CodecError> {
+        let payload = match bytes.strip_prefix(&CHECKSUMMED_MAGIC[..]) {
+            Some(payload) => payload,
+            None => return self.format.decode(bytes),
+        };
+        if payload.len() < 4 {
+            return Err(CodecError::Decode("the checksum is truncated".to_string()));
+        }
+
+        let (checksum, encoded) = payload.split_at(4);
+        let expected = u32::from_be_bytes([checksum[0], checksum[1], checksum[2], checksum[3]]);
+        let actual = crc32(encoded);
+        if actual != expected {
+            return Err(CodecError::CorruptSnapshot { expected, actual });
+        }
+
+        self.format.decode(encoded)
+    }
+}
+
+impl<State, Action> Fixture<State, Action>
+where
+    State: Serialize + DeserializeOwned,
+    Action: Serialize + DeserializeOwned,
+{
+    /// Encodes the recorded session with the `codec`
+    pub fn encode<F: Format>(&self, codec: F) -> Result<Vec<u8>, CodecError> {
+        codec.encode(self)
+    }
+
+    /// Decodes the recorded session with the `codec`
+    pub fn decode<F: Format>(codec: F, bytes: &[u8]) -> Result<Self, CodecError> {
+        codec.decode(bytes)
+    }
+}
+
+impl<State, Action, Env> Store<State, Action, Env>
+where
+    State: Serialize + DeserializeOwned,
+{
+    /// Encodes the current state with the `codec`, which may be compressed
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::codec::Codec;
+    /// use redust::Store;
+    ///
+    /// type MyStore = u8;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Increment,
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Increment => state + 1,
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, 0);
+    /// store.dispatch(MyAction::Increment);
+    ///
+    /// let snapshot = store.snapshot(Codec::Json).unwrap();
+    /// let restored = Store::restore(reducer, Codec::Json, &snapshot).unwrap();
+    ///
+    /// assert_eq!(*restored.state(), 1);
+    /// ```
+    pub fn snapshot<F: Format>(&self, codec: F) -> Result<Vec<u8>, CodecError> {
+        codec.encode(self.state())
+    }
+}
+
+impl<State, Action> Store<State, Action>
+where
+    State: Serialize + DeserializeOwned,
+{
+    /// Creates a new store from the state encoded with the `codec`
+    pub fn restore<F: Format>(
+        reducer: Reducer<State, Action>,
+        codec: F,
+        bytes: &[u8],
+    ) -> Result<Self, CodecError> {
+        Ok(Self::new(reducer, codec.decode(bytes)?))
+    }
+
+    /// Creates a new store from the first snapshot which is not corrupted,
+    /// e.g. the newest one followed by older ones.
+    /// Returns the error of the last snapshot if none of them can be restored
+    pub fn restore_latest<'a, F: Format>(
+        reducer: Reducer<State, Action>,
+        codec: F,
+        snapshots: impl IntoIterator<Item = &'a [u8]>,
+    ) -> Result<Self, CodecError> {
+        let mut error = CodecError::Decode("there are no snapshots".to_string());
+        for snapshot in snapshots {
+            match Self::restore(reducer, codec, snapshot) {
+                Ok(store) => return Ok(store),
+                Err(err) => {
+                    #[cfg(feature = "log")]
+                    log::warn!(
+                        target: crate::logging::PERSIST,
+                        "Skipped the snapshot: {}",
+                        err
+                    );
+                    error = err
+                }
+            }
+        }
+
+        Err(error)
+    }
+
+    /// Creates a new store from the snapshot, or replays the action log
+    /// encoded with `Fixture::encode` if the snapshot is corrupted
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::codec::{Codec, Format};
+    /// use redust::{Fixture, Store};
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// type MyStore = u8;
+    ///
+    /// #[derive(Debug, Serialize, Deserialize)]
+    /// enum MyAction {
+    ///     Increment,
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Increment => state + 1,
+    ///     }
+    /// }
+    ///
+    /// let codec = Codec::Json.checksummed();
+    /// let log = Fixture { initial_state: 0, actions: vec![MyAction::Increment] };
+    /// let log = log.encode(codec).unwrap();
+    ///
+    /// let mut snapshot = Store::replay_fixture(reducer, Fixture::decode(codec, &log).unwrap())
+    ///     .snapshot(codec)
+    ///     .unwrap();
+    /// *snapshot.last_mut().unwrap() ^= 1;
+    ///
+    /// let store = Store::restore_or_replay(reducer, codec, &snapshot, &log).unwrap();
+    /// assert_eq!(*store.state(), 1);
+    /// ```
+    pub fn restore_or_replay<F: Format>(
+        reducer: Reducer<State, Action>,
+        codec: F,
+        snapshot: &[u8],
+        log: &[u8],
+    ) -> Result<Self, CodecError>
+    where
+        Action: Serialize + DeserializeOwned,
+    {
+        match Self::restore(reducer, codec, snapshot) {
+            Err(_err @ CodecError::CorruptSnapshot { .. }) => {
+                #[cfg(feature = "log")]
+                log::warn!(
+                    target: crate::logging::PERSIST,
+                    "Replaying the action log: {}",
+                    _err
+                );
+                Ok(Self::replay_fixture(reducer, Fixture::decode(codec, log)?))
+            }
+            restored => restored,
+        }
+    }
+}
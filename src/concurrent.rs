@@ -0,0 +1,268 @@
+use std::ops::Deref;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError};
+
+use crate::history::History;
+use crate::{DispatchError, Store};
+
+/// Counters which show how often readers and writers of a `ConcurrentStore` waited
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ContentionMetrics {
+    pub reads: u64,
+    pub writes: u64,
+
+    /// Reads which had to wait for a dispatch to finish
+    pub contended_reads: u64,
+
+    /// Dispatches which had to wait for readers or another dispatch
+    pub contended_writes: u64,
+
+    /// Times the lock was recovered after a reducer or subscriber panicked
+    pub poison_recoveries: u64,
+}
+
+#[derive(Default)]
+struct Counters {
+    reads: AtomicU64,
+    writes: AtomicU64,
+    contended_reads: AtomicU64,
+    contended_writes: AtomicU64,
+    poison_recoveries: AtomicU64,
+}
+
+/// Store which many threads read simultaneously while `dispatch` takes the write side.
+///
+/// A panicking reducer or subscriber poisons the lock; the store recovers from
+/// it, because the state is replaced only after the reducer returned the new one,
+/// so it is never left half-updated.
+///
+/// Readers are isolated from batches: `dispatch_batch` keeps the write side for
+/// the whole batch and restores the state from before it if one of its actions
+/// panics, so a read guard shows the state either before the batch or after all
+/// of its actions, never an intermediate one.
+///
+/// ## Example
+/// ```rust
+/// use redust::Store;
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// type MyStore = u8;
+///
+/// #[derive(Debug)]
+/// enum MyAction {
+///     Increment,
+/// };
+///
+/// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+///     match action {
+///         MyAction::Increment => state + 1,
+///     }
+/// }
+///
+/// let store = Arc::new(Store::new(reducer, 0).into_concurrent());
+///
+/// let readers: Vec<_> = (0..4)
+///     .map(|_| {
+///         let store = Arc::clone(&store);
+///         thread::spawn(move || *store.read() <= 1)
+///     })
+///     .collect();
+///
+/// store.dispatch(MyAction::Increment);
+///
+/// assert!(readers.into_iter().all(|reader| reader.join().unwrap()));
+/// assert_eq!(*store.read(), 1);
+/// ```
+pub struct ConcurrentStore<State, Action> {
+    store: RwLock<Store<State, Action>>,
+    counters: Counters,
+}
+
+/// Shared access to the state of a `ConcurrentStore`. Dispatches wait until it is dropped
+pub struct StateReadGuard<'a, State, Action> {
+    guard: RwLockReadGuard<'a, Store<State, Action>>,
+}
+
+impl<'a, State, Action> Deref for StateReadGuard<'a, State, Action> {
+    type Target = State;
+
+    fn deref(&self) -> &Self::Target {
+        self.guard.state()
+    }
+}
+
+impl<State, Action> ConcurrentStore<State, Action> {
+    /// Wraps the store
+    pub fn new(store: Store<State, Action>) -> Self {
+        Self {
+            store: RwLock::new(store),
+            counters: Counters::default(),
+        }
+    }
+
+    /// Returns the current state. Many threads may hold the guard simultaneously
+    pub fn read(&self) -> StateReadGuard<'_, State, Action> {
+        self.counters.reads.fetch_add(1, Ordering::Relaxed);
+
+        let guard = match self.store.try_read() {
+            Ok(guard) => guard,
+            Err(TryLockError::Poisoned(poisoned)) => self.recover(poisoned),
+            Err(TryLockError::WouldBlock) => {
+                self.counters
+                    .contended_reads
+                    .fetch_add(1, Ordering::Relaxed);
+                self.store
+                    .read()
+                    .unwrap_or_else(|poisoned| self.recover(poisoned))
+            }
+        };
+
+        StateReadGuard { guard }
+    }
+
+    /// Returns a snapshot of the current state which does not block dispatches
+    pub fn snapshot(&self) -> Arc<State> {
+        self.read().guard.shared_state()
+    }
+
+    /// Dispatches an action. Waits until all read guards are dropped
+    pub fn dispatch(&self, action: Action) -> &Self {
+        self.write().dispatch(action);
+
+        self
+    }
+
+    /// Dispatches all actions as a single step for the readers.
+    /// They wait until the whole batch is applied and never observe an intermediate state.
+    ///
+    /// If a reducer or subscriber panics in the middle of the batch, the state,
+    /// the history and the last action from before the batch are restored and
+    /// the panic is propagated.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::Store;
+    /// use std::sync::Arc;
+    /// use std::thread;
+    ///
+    /// type MyStore = u8;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Increment,
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Increment => state + 1,
+    ///     }
+    /// }
+    ///
+    /// let store = Arc::new(Store::new(reducer, 0).into_concurrent());
+    ///
+    /// let reader = {
+    ///     let store = Arc::clone(&store);
+    ///     thread::spawn(move || *store.read())
+    /// };
+    ///
+    /// store.dispatch_batch(vec![MyAction::Increment, MyAction::Increment]);
+    ///
+    /// let seen = reader.join().unwrap();
+    /// assert!(seen == 0 || seen == 2);
+    /// assert_eq!(*store.read(), 2);
+    /// ```
+    pub fn dispatch_batch<I>(&self, actions: I) -> &Self
+    where
+        I: IntoIterator<Item = Action>,
+    {
+        let mut store = self.write();
+        let before = store.shared_state();
+        let history = store.history.as_ref().map(History::checkpoint);
+        let forked = store.fork_base.as_ref().map(|base| base.actions.len());
+        let last_action = store.last_action.take();
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            for action in actions {
+                store.dispatch(action);
+            }
+        }));
+        if let Err(panic) = result {
+            store.state.replace_shared(before);
+            store.version += 1;
+            if let (Some(history), Some(checkpoint)) = (store.history.as_mut(), history) {
+                history.rollback(checkpoint);
+            }
+            if let (Some(base), Some(len)) = (store.fork_base.as_mut(), forked) {
+                base.actions.truncate(len);
+            }
+            store.last_action = last_action;
+            panic::resume_unwind(panic);
+        }
+        if store.last_action.is_none() {
+            store.last_action = last_action;
+        }
+
+        self
+    }
+
+    /// Dispatches an action the same way as `Store::try_dispatch` does
+    pub fn try_dispatch(&self, action: Action) -> Result<&Self, DispatchError> {
+        self.write().try_dispatch(action)?;
+
+        Ok(self)
+    }
+
+    /// Returns contention counters collected since the store was wrapped
+    pub fn metrics(&self) -> ContentionMetrics {
+        ContentionMetrics {
+            reads: self.counters.reads.load(Ordering::Relaxed),
+            writes: self.counters.writes.load(Ordering::Relaxed),
+            contended_reads: self.counters.contended_reads.load(Ordering::Relaxed),
+            contended_writes: self.counters.contended_writes.load(Ordering::Relaxed),
+            poison_recoveries: self.counters.poison_recoveries.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Returns the wrapped store
+    pub fn into_inner(self) -> Store<State, Action> {
+        self.store
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Gives exclusive access to the store, e.g. to subscribe
+    pub fn write(&self) -> RwLockWriteGuard<'_, Store<State, Action>> {
+        self.counters.writes.fetch_add(1, Ordering::Relaxed);
+
+        match self.store.try_write() {
+            Ok(guard) => guard,
+            Err(TryLockError::Poisoned(poisoned)) => self.recover(poisoned),
+            Err(TryLockError::WouldBlock) => {
+                self.counters
+                    .contended_writes
+                    .fetch_add(1, Ordering::Relaxed);
+                self.store
+                    .write()
+                    .unwrap_or_else(|poisoned| self.recover(poisoned))
+            }
+        }
+    }
+
+    fn recover<Guard>(&self, poisoned: PoisonError<Guard>) -> Guard {
+        self.counters
+            .poison_recoveries
+            .fetch_add(1, Ordering::Relaxed);
+        self.store.clear_poison();
+
+        poisoned.into_inner()
+    }
+}
+
+impl<State, Action> Store<State, Action> {
+    /// Turns the store into a `ConcurrentStore` which many threads may read simultaneously
+    pub fn into_concurrent(self) -> ConcurrentStore<State, Action> {
+        ConcurrentStore::new(self)
+    }
+}
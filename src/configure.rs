@@ -0,0 +1,95 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::{Reducer, Store, StoreBuilder};
+
+/// Creates a store with sensible defaults in one call, like `configureStore` of
+/// Redux Toolkit:
+/// - the crash reporter, see `StoreBuilder::crash_reporter`;
+/// - the logger in debug builds, see `StoreBuilder::logger`;
+/// - change detection in debug builds, which panics when a reducer mutates
+///   the previous state, see `StoreBuilder::check_mutations`.
+///
+/// Use `Store::configure` to add more options before the store is built.
+/// Devtools are not a builder option: build the store with
+/// `StoreBuilder::build_with_devtools` from the `remote` module, available
+/// behind the `serde` feature, to expose it to them.
+///
+/// Call `Store::skip_mutation_checks` on the built store if the state is too
+/// large to be hashed on every dispatch.
+///
+/// ## Example
+/// ```rust
+/// use redust::configure_store;
+///
+/// type MyStore = u8;
+///
+/// #[derive(Debug)]
+/// enum MyAction {
+///     Increment,
+/// };
+///
+/// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+///     match action {
+///         MyAction::Increment => state + 1,
+///     }
+/// }
+///
+/// let mut store = configure_store(reducer, 0);
+/// store.dispatch(MyAction::Increment);
+///
+/// assert_eq!(*store.state(), 1);
+/// ```
+pub fn configure_store<State, Action>(
+    reducer: Reducer<State, Action>,
+    initial_state: State,
+) -> Store<State, Action>
+where
+    State: Debug + Hash + 'static,
+    Action: Debug + 'static,
+{
+    Store::configure(reducer, initial_state).build()
+}
+
+impl<State, Action> Store<State, Action>
+where
+    State: Debug + Hash + 'static,
+    Action: Debug + 'static,
+{
+    /// Returns a builder with the defaults of `configure_store`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::Store;
+    ///
+    /// type MyStore = u8;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Increment,
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Increment => state + 1,
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::configure(reducer, 0).notifications_paused().build();
+    /// store.dispatch(MyAction::Increment);
+    ///
+    /// assert_eq!(*store.state(), 1);
+    /// ```
+    pub fn configure(
+        reducer: Reducer<State, Action>,
+        initial_state: State,
+    ) -> StoreBuilder<State, Action> {
+        let builder = Self::builder(reducer, initial_state).crash_reporter();
+
+        if cfg!(debug_assertions) {
+            builder.logger().check_mutations()
+        } else {
+            builder
+        }
+    }
+}
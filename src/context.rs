@@ -0,0 +1,100 @@
+use std::any::{self, Any};
+use std::fmt;
+use std::sync::Arc;
+
+use crate::Store;
+
+/// Dependencies of thunks, middleware and effects, e.g. API clients and config
+pub(crate) type Context = Arc<dyn Any + Send + Sync>;
+
+#[derive(Debug, PartialEq)]
+pub enum ContextError {
+    /// The store has no context or its context has another type
+    Missing { expected: &'static str },
+}
+
+impl std::error::Error for ContextError {}
+
+impl fmt::Display for ContextError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContextError::Missing { expected } => {
+                write!(f, "Cannot find the store context of type {}", expected)
+            }
+        }
+    }
+}
+
+impl<State, Action, Env> Store<State, Action, Env> {
+    /// Sets the dependencies which are passed to thunks and available to middleware
+    /// via `Next::context`, like the extra argument of redux-thunk.
+    ///
+    /// Tests swap the dependency container instead of mocking effects.
+    pub fn set_context<Deps: Send + Sync + 'static>(&mut self, deps: Arc<Deps>) {
+        self.context = Some(deps);
+    }
+
+    /// Returns the dependencies if the context has the type `Deps`
+    pub fn context<Deps: Send + Sync + 'static>(&self) -> Option<Arc<Deps>> {
+        self.context
+            .as_ref()
+            .and_then(|context| Arc::clone(context).downcast::<Deps>().ok())
+    }
+
+    /// Removes the dependencies from the store
+    pub fn remove_context(&mut self) {
+        self.context = None;
+    }
+
+    /// Runs the thunk with the store and its dependencies and returns its result.
+    ///
+    /// Returns `ContextError::Missing` without running the thunk if the store
+    /// has no context of the type `Deps`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::Store;
+    /// use std::sync::Arc;
+    ///
+    /// type MyStore = Vec<String>;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Loaded(Vec<String>),
+    /// };
+    ///
+    /// fn reducer(_state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Loaded(users) => users.clone(),
+    ///     }
+    /// }
+    ///
+    /// struct Api {
+    ///     users: Vec<String>,
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, vec![]);
+    /// store.set_context(Arc::new(Api {
+    ///     users: vec!["Ann".to_string()],
+    /// }));
+    ///
+    /// store
+    ///     .dispatch_thunk(|store, api: &Api| {
+    ///         store.dispatch(MyAction::Loaded(api.users.clone()));
+    ///     })
+    ///     .unwrap();
+    ///
+    /// assert_eq!(*store.state(), ["Ann"]);
+    /// ```
+    pub fn dispatch_thunk<Deps, R, F>(&mut self, thunk: F) -> Result<R, ContextError>
+    where
+        Deps: Send + Sync + 'static,
+        F: FnOnce(&mut Self, &Deps) -> R,
+    {
+        let deps = self.context::<Deps>().ok_or(ContextError::Missing {
+            expected: any::type_name::<Deps>(),
+        })?;
+
+        Ok(thunk(self, &deps))
+    }
+}
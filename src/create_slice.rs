@@ -0,0 +1,133 @@
+/// Declares a slice in one place and generates a module with:
+/// - the action enum with a variant per case reducer;
+/// - `initial_state()` and `reducer`, which clones the state and runs the case reducer
+///   of the action with `&mut` access to the copy;
+/// - a typed constructor of every action;
+/// - selectors, which receive the slice state;
+/// - `NAME`, the name of the slice, e.g. to register it in `Slices`.
+///
+/// Arguments of a case reducer are borrowed from the action. Items of the parent
+/// module are visible inside the generated one.
+///
+/// ## Example
+/// ```rust
+/// use redust::{create_slice, Store};
+///
+/// #[derive(Debug, Clone, Default, PartialEq)]
+/// pub struct Counter {
+///     value: u32,
+/// }
+///
+/// create_slice! {
+///     pub mod counter {
+///         state: Counter = Counter::default();
+///
+///         #[derive(Debug, Clone, PartialEq)]
+///         action: CounterAction;
+///
+///         reducers {
+///             Increment {} => fn increment(state) {
+///                 state.value += 1;
+///             }
+///             IncrementBy { amount: u32 } => fn increment_by(state) {
+///                 state.value += amount;
+///             }
+///         }
+///
+///         selectors {
+///             fn value(state) -> u32 {
+///                 state.value
+///             }
+///         }
+///     }
+/// }
+///
+/// # fn main() {
+/// let mut store = Store::new(counter::reducer, counter::initial_state());
+/// store
+///     .dispatch(counter::increment())
+///     .dispatch(counter::increment_by(10));
+///
+/// assert_eq!(counter::value(store.state()), 11);
+/// assert_eq!(counter::increment_by(1), counter::CounterAction::IncrementBy { amount: 1 });
+/// # }
+/// ```
+#[macro_export]
+macro_rules! create_slice {
+    (
+        $(#[$mod_meta: meta])*
+        $vis: vis mod $name: ident {
+            state: $state_ty: ty = $initial: expr;
+
+            $(#[$action_meta: meta])*
+            action: $action: ident;
+
+            reducers {
+                $(
+                    $(#[$case_meta: meta])*
+                    $variant: ident { $( $arg: ident : $arg_ty: ty ),* $(,)? }
+                        => fn $constructor: ident ( $state: ident ) $body: block
+                )*
+            }
+
+            $(
+                selectors {
+                    $(
+                        $(#[$selector_meta: meta])*
+                        fn $selector: ident ( $selector_state: ident ) -> $selected: ty
+                            $selector_body: block
+                    )*
+                }
+            )?
+        }
+    ) => {
+        $(#[$mod_meta])*
+        $vis mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            /// Name of the slice
+            pub const NAME: &str = stringify!($name);
+
+            $(#[$action_meta])*
+            pub enum $action {
+                $(
+                    $(#[$case_meta])*
+                    $variant { $( $arg: $arg_ty ),* },
+                )*
+            }
+
+            /// Returns the initial state of the slice
+            pub fn initial_state() -> $state_ty {
+                $initial
+            }
+
+            /// Runs the case reducer of the action on a copy of the state
+            pub fn reducer(state: &$state_ty, action: &$action) -> $state_ty {
+                let mut new_state = ::std::clone::Clone::clone(state);
+                match action {
+                    $(
+                        $action::$variant { $( $arg ),* } => {
+                            let $state = &mut new_state;
+                            $body
+                        }
+                    )*
+                }
+
+                new_state
+            }
+
+            $(
+                $(#[$case_meta])*
+                pub fn $constructor($( $arg: $arg_ty ),*) -> $action {
+                    $action::$variant { $( $arg ),* }
+                }
+            )*
+
+            $($(
+                $(#[$selector_meta])*
+                pub fn $selector($selector_state: &$state_ty) -> $selected $selector_body
+            )*)?
+        }
+    };
+}
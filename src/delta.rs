@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use crate::subscription::{Subscriber, SubscriptionToken};
+use crate::Store;
+
+/// Computes the changes between the previous and the new state.
+/// Returns `None` if nothing which the subscriber cares about changed
+pub type Differ<State, Delta> = fn(&State, &State) -> Option<Delta>;
+
+/// Subscription which receives only the changes of the state
+pub type DeltaSubscription<Delta> = fn(&Delta);
+
+pub(crate) trait DeltaNotifier<State> {
+    fn notify(&mut self, state: &Arc<State>);
+}
+
+struct DeltaEntry<State, Delta> {
+    differ: Differ<State, Delta>,
+    func: DeltaSubscription<Delta>,
+    last: Arc<State>,
+}
+
+impl<State, Delta> DeltaNotifier<State> for DeltaEntry<State, Delta> {
+    fn notify(&mut self, state: &Arc<State>) {
+        if Arc::ptr_eq(&self.last, state) {
+            return;
+        }
+
+        let last = std::mem::replace(&mut self.last, Arc::clone(state));
+        if let Some(delta) = (self.differ)(&last, state) {
+            (self.func)(&delta);
+        }
+    }
+}
+
+impl<State: Send + Sync + 'static, Action, Env> Store<State, Action, Env> {
+    /// Subscribes a callback which receives the delta between the last state it
+    /// was notified about and the new state, instead of the full state.
+    ///
+    /// The previous state is kept as a shared snapshot, so the store does not clone
+    /// it. While notifications are paused no deltas are computed; the next delta
+    /// covers every missed update.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::Store;
+    ///
+    /// type MyStore = Vec<&'static str>;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Add(&'static str),
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     let mut items = state.clone();
+    ///     match action {
+    ///         MyAction::Add(item) => items.push(item),
+    ///     }
+    ///
+    ///     items
+    /// }
+    ///
+    /// // Items appended since the previous state
+    /// fn appended(old: &MyStore, new: &MyStore) -> Option<Vec<&'static str>> {
+    ///     if new.len() > old.len() {
+    ///         Some(new[old.len()..].to_vec())
+    ///     } else {
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, vec!["milk"]);
+    /// store.subscribe_delta(appended, |items| assert_eq!(*items, ["bread"]));
+    ///
+    /// store.dispatch(MyAction::Add("bread"));
+    /// ```
+    pub fn subscribe_delta<Delta: 'static>(
+        &mut self,
+        differ: Differ<State, Delta>,
+        func: DeltaSubscription<Delta>,
+    ) -> SubscriptionToken<State, Action> {
+        let subscription_token = self.next_subscription_token();
+        let entry = DeltaEntry {
+            differ,
+            func,
+            last: self.shared_state(),
+        };
+
+        self.subscriptions
+            .insert(subscription_token.id(), Subscriber::Delta(Box::new(entry)));
+
+        subscription_token
+    }
+}
@@ -0,0 +1,400 @@
+use std::any::Any;
+use std::collections::BTreeSet;
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex, MutexGuard, Weak};
+
+use crate::subscription::{StoreObserver, Subscriber, SubscriptionToken};
+use crate::Store;
+
+/// Memoized value of the graph computed either from the state or from other selectors
+trait Node<State>: Send + Sync {
+    /// Computes the value again from the state and the earlier nodes,
+    /// returns `true` if it changed
+    fn recompute(&mut self, state: &State, earlier: &[Box<dyn Node<State>>]) -> bool;
+
+    fn value(&self) -> &dyn Any;
+}
+
+fn value_of<State, Input: 'static>(earlier: &[Box<dyn Node<State>>], index: usize) -> &Input {
+    earlier[index]
+        .value()
+        .downcast_ref()
+        .expect("Selector handles keep the type of the node")
+}
+
+fn replace<Output: PartialEq>(value: &mut Output, new_value: Output) -> bool {
+    let changed = *value != new_value;
+    if changed {
+        *value = new_value;
+    }
+
+    changed
+}
+
+struct Root<State, Output> {
+    select: fn(&State) -> Output,
+    value: Output,
+}
+
+impl<State, Output> Node<State> for Root<State, Output>
+where
+    Output: PartialEq + Send + Sync + 'static,
+{
+    fn recompute(&mut self, state: &State, _earlier: &[Box<dyn Node<State>>]) -> bool {
+        replace(&mut self.value, (self.select)(state))
+    }
+
+    fn value(&self) -> &dyn Any {
+        &self.value
+    }
+}
+
+struct Derived<Input, Output> {
+    input: usize,
+    derive: fn(&Input) -> Output,
+    value: Output,
+}
+
+impl<State, Input, Output> Node<State> for Derived<Input, Output>
+where
+    Input: 'static,
+    Output: PartialEq + Send + Sync + 'static,
+{
+    fn recompute(&mut self, _state: &State, earlier: &[Box<dyn Node<State>>]) -> bool {
+        let value = (self.derive)(value_of(earlier, self.input));
+        replace(&mut self.value, value)
+    }
+
+    fn value(&self) -> &dyn Any {
+        &self.value
+    }
+}
+
+struct Combined<First, Second, Output> {
+    inputs: (usize, usize),
+    combine: fn(&First, &Second) -> Output,
+    value: Output,
+}
+
+impl<State, First, Second, Output> Node<State> for Combined<First, Second, Output>
+where
+    First: 'static,
+    Second: 'static,
+    Output: PartialEq + Send + Sync + 'static,
+{
+    fn recompute(&mut self, _state: &State, earlier: &[Box<dyn Node<State>>]) -> bool {
+        let value = (self.combine)(
+            value_of(earlier, self.inputs.0),
+            value_of(earlier, self.inputs.1),
+        );
+        replace(&mut self.value, value)
+    }
+
+    fn value(&self) -> &dyn Any {
+        &self.value
+    }
+}
+
+/// Dependency graph of the selectors. A node only depends on nodes created
+/// before it, so the order of creation is a topological order
+struct Graph<State> {
+    state: Arc<State>,
+    nodes: Vec<Box<dyn Node<State>>>,
+    dependents: Vec<Vec<usize>>,
+    roots: Vec<usize>,
+    recomputations: u64,
+}
+
+impl<State> Graph<State> {
+    fn insert(&mut self, node: Box<dyn Node<State>>, inputs: &[usize]) -> usize {
+        let index = self.nodes.len();
+        self.nodes.push(node);
+        self.dependents.push(vec![]);
+        for input in inputs {
+            self.dependents[*input].push(index);
+        }
+        if inputs.is_empty() {
+            self.roots.push(index);
+        }
+
+        index
+    }
+
+    /// Recomputes the roots, then only the dependents of the nodes whose value changed
+    fn update(&mut self, state: &Arc<State>) {
+        if Arc::ptr_eq(&self.state, state) {
+            return;
+        }
+        self.state = Arc::clone(state);
+
+        let mut dirty = self.roots.iter().copied().collect::<BTreeSet<_>>();
+        while let Some(index) = dirty.pop_first() {
+            let (earlier, rest) = self.nodes.split_at_mut(index);
+            self.recomputations += 1;
+            if rest[0].recompute(&self.state, earlier) {
+                dirty.extend(&self.dependents[index]);
+            }
+        }
+    }
+}
+
+fn lock<State>(graph: &Mutex<Graph<State>>) -> MutexGuard<'_, Graph<State>> {
+    // Nodes are replaced one at a time, so a panicking selector leaves only its own value stale
+    graph
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Memoized selector of the derived state, created with `DerivedState`
+pub struct Selector<State, Output> {
+    graph: Arc<Mutex<Graph<State>>>,
+    index: usize,
+    output: PhantomData<fn() -> Output>,
+}
+
+impl<State, Output> Clone for Selector<State, Output> {
+    fn clone(&self) -> Self {
+        Self {
+            graph: Arc::clone(&self.graph),
+            index: self.index,
+            output: PhantomData,
+        }
+    }
+}
+
+impl<State, Output: Clone + 'static> Selector<State, Output> {
+    /// Returns the memoized value, it is never computed on access
+    pub fn get(&self) -> Output {
+        lock(&self.graph).nodes[self.index]
+            .value()
+            .downcast_ref::<Output>()
+            .expect("Selector handles keep the type of the node")
+            .clone()
+    }
+}
+
+/// Selectors over the store state which may depend on each other.
+///
+/// After every dispatch only the selectors reading the state are computed
+/// again. Selectors built from other selectors are computed in topological
+/// order and only when one of their inputs changed, so a dispatch which does
+/// not touch a chain costs one comparison per state selector.
+///
+/// Selector functions run while the graph is locked and must not read
+/// other selectors with `Selector::get`, take them as inputs instead.
+///
+/// Created with `Store::derived_state` and removed with `Store::unsubscribe`
+/// called with its `token`. Once the derived state and all its selectors are
+/// dropped, dispatches no longer compute anything.
+pub struct DerivedState<State, Action> {
+    graph: Arc<Mutex<Graph<State>>>,
+    token: SubscriptionToken<State, Action>,
+}
+
+impl<State, Action> Clone for DerivedState<State, Action> {
+    fn clone(&self) -> Self {
+        Self {
+            graph: Arc::clone(&self.graph),
+            token: self.token,
+        }
+    }
+}
+
+impl<State: Send + Sync + 'static, Action> DerivedState<State, Action> {
+    fn selector<Output>(&self, index: usize) -> Selector<State, Output> {
+        Selector {
+            graph: Arc::clone(&self.graph),
+            index,
+            output: PhantomData,
+        }
+    }
+
+    fn check_input<Input>(&self, input: &Selector<State, Input>) {
+        assert!(
+            Arc::ptr_eq(&self.graph, &input.graph),
+            "Cannot use a selector of another derived state as an input"
+        );
+    }
+
+    /// Adds a selector computed from the state
+    pub fn select<Output>(&self, select: fn(&State) -> Output) -> Selector<State, Output>
+    where
+        Output: PartialEq + Send + Sync + 'static,
+    {
+        let mut graph = lock(&self.graph);
+        let value = select(&graph.state);
+        let index = graph.insert(Box::new(Root { select, value }), &[]);
+
+        self.selector(index)
+    }
+
+    /// Adds a selector computed from the `input` selector.
+    /// Panics if the `input` belongs to another derived state
+    pub fn derive<Input, Output>(
+        &self,
+        input: &Selector<State, Input>,
+        derive: fn(&Input) -> Output,
+    ) -> Selector<State, Output>
+    where
+        Input: 'static,
+        Output: PartialEq + Send + Sync + 'static,
+    {
+        self.check_input(input);
+
+        let mut graph = lock(&self.graph);
+        let value = derive(value_of(&graph.nodes, input.index));
+        let node = Derived {
+            input: input.index,
+            derive,
+            value,
+        };
+        let index = graph.insert(Box::new(node), &[input.index]);
+
+        self.selector(index)
+    }
+
+    /// Adds a selector computed from the `first` and the `second` selectors.
+    /// Panics if any of them belongs to another derived state
+    pub fn combine<First, Second, Output>(
+        &self,
+        first: &Selector<State, First>,
+        second: &Selector<State, Second>,
+        combine: fn(&First, &Second) -> Output,
+    ) -> Selector<State, Output>
+    where
+        First: 'static,
+        Second: 'static,
+        Output: PartialEq + Send + Sync + 'static,
+    {
+        self.check_input(first);
+        self.check_input(second);
+
+        let mut graph = lock(&self.graph);
+        let value = combine(
+            value_of(&graph.nodes, first.index),
+            value_of(&graph.nodes, second.index),
+        );
+        let node = Combined {
+            inputs: (first.index, second.index),
+            combine,
+            value,
+        };
+        let index = graph.insert(Box::new(node), &[first.index, second.index]);
+
+        self.selector(index)
+    }
+
+    /// Returns how many times selectors were computed again after dispatches
+    pub fn recomputations(&self) -> u64 {
+        lock(&self.graph).recomputations
+    }
+
+    /// Returns the number of selectors
+    pub fn len(&self) -> usize {
+        lock(&self.graph).nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the token which removes the selectors from the store
+    pub fn token(&self) -> SubscriptionToken<State, Action> {
+        self.token
+    }
+}
+
+/// Updates the selectors when the store state changes.
+/// It stops when the derived state and all its selectors are dropped
+struct DerivedObserver<State>(Weak<Mutex<Graph<State>>>);
+
+impl<State> DerivedObserver<State> {
+    fn update(&self, state: &Arc<State>) {
+        if let Some(graph) = self.0.upgrade() {
+            lock(&graph).update(state);
+        }
+    }
+}
+
+impl<State, Action> StoreObserver<State, Action> for DerivedObserver<State> {
+    fn on_state(&mut self, state: &Arc<State>) {
+        self.update(state);
+    }
+
+    // Actions are delivered while notifications are paused, so the selectors never go stale
+    fn on_action(&mut self, _action: &Action, state: &Arc<State>) {
+        self.update(state);
+    }
+}
+
+impl<State, Action, Env> Store<State, Action, Env>
+where
+    State: Send + Sync + 'static,
+    Action: 'static,
+{
+    /// Creates an empty graph of selectors which is kept up to date with the state
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::Store;
+    ///
+    /// #[derive(Clone)]
+    /// struct AppState {
+    ///     todos: Vec<(&'static str, bool)>,
+    ///     clicks: u8,
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Add(&'static str),
+    ///     Click,
+    /// };
+    ///
+    /// fn reducer(state: &AppState, action: &MyAction) -> AppState {
+    ///     let mut new_state = state.clone();
+    ///     match action {
+    ///         MyAction::Add(title) => new_state.todos.push((title, false)),
+    ///         MyAction::Click => new_state.clicks += 1,
+    ///     }
+    ///
+    ///     new_state
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, AppState { todos: vec![], clicks: 0 });
+    /// let derived = store.derived_state();
+    ///
+    /// let todos = derived.select(|state: &AppState| state.todos.clone());
+    /// let pending = derived.derive(&todos, |todos| todos.iter().filter(|todo| !todo.1).count());
+    /// let label = derived.derive(&pending, |pending| format!("{} left", pending));
+    ///
+    /// store.dispatch(MyAction::Add("Buy milk"));
+    /// assert_eq!(label.get(), "1 left");
+    ///
+    /// // Only `todos` is computed again, it did not change, so the chain stops there
+    /// let recomputations = derived.recomputations();
+    /// store.dispatch(MyAction::Click);
+    /// assert_eq!(derived.recomputations(), recomputations + 1);
+    ///
+    /// // The selectors keep the last values once removed from the store
+    /// store.unsubscribe(derived.token()).unwrap();
+    /// store.dispatch(MyAction::Add("Walk the dog"));
+    /// assert_eq!(label.get(), "1 left");
+    /// ```
+    pub fn derived_state(&mut self) -> DerivedState<State, Action> {
+        let graph = Arc::new(Mutex::new(Graph {
+            state: self.shared_state(),
+            nodes: vec![],
+            dependents: vec![],
+            roots: vec![],
+            recomputations: 0,
+        }));
+
+        let token = self.next_subscription_token();
+        self.subscriptions.insert(
+            token.id(),
+            Subscriber::Observer(Box::new(DerivedObserver(Arc::downgrade(&graph)))),
+        );
+
+        DerivedState { graph, token }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::StateVersion;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DispatchError {
+    /// The store was frozen with `FreezePolicy::Reject`
+    Frozen,
+
+    /// An interceptor dropped the action
+    Intercepted,
+
+    /// The abort signal of the dispatch fired before the action was reduced
+    Aborted,
+
+    /// Another action was dispatched after the expected state version
+    VersionMismatch {
+        expected: StateVersion,
+        actual: StateVersion,
+    },
+}
+
+impl std::error::Error for DispatchError {}
+impl std::fmt::Display for DispatchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            DispatchError::Frozen => write!(f, "Cannot dispatch an action into a frozen store"),
+            DispatchError::Intercepted => write!(f, "The action was dropped by an interceptor"),
+            DispatchError::Aborted => write!(f, "The dispatch was aborted before the action was reduced"),
+            DispatchError::VersionMismatch { expected, actual } => write!(
+                f,
+                "Cannot dispatch an action computed from state version {}, the current version is {}",
+                expected, actual
+            ),
+        }
+    }
+}
+
+/// Defines what happens with actions dispatched into a frozen store
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FreezePolicy {
+    /// `try_dispatch` returns `DispatchError::Frozen`
+    Reject,
+
+    /// Actions are silently dropped
+    Ignore,
+}
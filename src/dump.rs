@@ -0,0 +1,300 @@
+//! Human-editable dumps of the state, e.g. to inspect a state from a bug
+//! report, tweak it by hand and load it back for reproduction.
+//!
+//! Unlike `codec`, which is built for compact persistence, dumps are
+//! pretty-printed text. Available behind the `serde` feature.
+
+use std::io::Read;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{Reducer, Store};
+
+/// Text format of a state dump
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum DumpFormat {
+    /// Pretty-printed JSON
+    #[default]
+    Json,
+
+    /// TOML document, available behind the `toml` feature. The state has to be
+    /// a struct or a map, and `None` fields are left out
+    #[cfg(feature = "toml")]
+    Toml,
+
+    /// YAML document, available behind the `yaml` feature
+    #[cfg(feature = "yaml")]
+    Yaml,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DumpError {
+    Export(String),
+    Import(String),
+}
+
+impl std::error::Error for DumpError {}
+impl std::fmt::Display for DumpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            DumpError::Export(message) => write!(f, "Cannot export the state: {}", message),
+            DumpError::Import(message) => write!(f, "Cannot import the state: {}", message),
+        }
+    }
+}
+
+impl DumpFormat {
+    fn export<T: Serialize>(self, value: &T) -> Result<String, String> {
+        match self {
+            DumpFormat::Json => serde_json::to_string_pretty(value).map_err(|err| err.to_string()),
+            #[cfg(feature = "toml")]
+            DumpFormat::Toml => {
+                let value = serde_json::to_value(value).map_err(|err| err.to_string())?;
+                toml::to_string(&value)
+            }
+            #[cfg(feature = "yaml")]
+            DumpFormat::Yaml => serde_yaml_ng::to_string(value).map_err(|err| err.to_string()),
+        }
+    }
+
+    fn import<T: DeserializeOwned>(self, text: &str) -> Result<T, String> {
+        match self {
+            DumpFormat::Json => serde_json::from_str(text).map_err(|err| err.to_string()),
+            #[cfg(feature = "toml")]
+            DumpFormat::Toml => {
+                serde_json::from_value(toml::from_str(text)?).map_err(|err| err.to_string())
+            }
+            #[cfg(feature = "yaml")]
+            DumpFormat::Yaml => serde_yaml_ng::from_str(text).map_err(|err| err.to_string()),
+        }
+    }
+}
+
+impl<State, Action, Env> Store<State, Action, Env>
+where
+    State: Serialize + DeserializeOwned,
+{
+    /// Writes the current state as text in the `format`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::dump::DumpFormat;
+    /// use redust::Store;
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    /// struct MyStore {
+    ///     user: String,
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Login(&'static str),
+    /// };
+    ///
+    /// fn reducer(_state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Login(user) => MyStore { user: user.to_string() },
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, MyStore { user: String::new() });
+    /// store.dispatch(MyAction::Login("Ann"));
+    ///
+    /// let dump = store.export(DumpFormat::Json).unwrap();
+    /// let edited = dump.replace("Ann", "Bob");
+    ///
+    /// let store = Store::import(reducer, edited.as_bytes(), DumpFormat::Json).unwrap();
+    /// assert_eq!(store.state().user, "Bob");
+    /// ```
+    pub fn export(&self, format: DumpFormat) -> Result<String, DumpError> {
+        format.export(self.state()).map_err(DumpError::Export)
+    }
+}
+
+impl<State, Action> Store<State, Action>
+where
+    State: Serialize + DeserializeOwned,
+{
+    /// Creates a new store from the state read as text in the `format`
+    pub fn import<R: Read>(
+        reducer: Reducer<State, Action>,
+        mut reader: R,
+        format: DumpFormat,
+    ) -> Result<Self, DumpError> {
+        let mut text = String::new();
+        reader
+            .read_to_string(&mut text)
+            .map_err(|err| DumpError::Import(err.to_string()))?;
+
+        let state = format.import(&text).map_err(DumpError::Import)?;
+        Ok(Self::new(reducer, state))
+    }
+}
+
+/// Conversion between TOML documents and JSON values, so both formats share
+/// the serde implementations of `serde_json`
+#[cfg(feature = "toml")]
+mod toml {
+    use serde_json::{Map, Number, Value};
+    use toml_edit::{DocumentMut, InlineTable, Item, Table};
+
+    pub(super) fn to_string(value: &Value) -> Result<String, String> {
+        let table = value
+            .as_object()
+            .ok_or("the state has to be a struct or a map")?;
+
+        let mut text = String::new();
+        write_table(&mut text, &mut vec![], table)?;
+
+        Ok(text.trim_start().to_string())
+    }
+
+    fn is_array_of_tables(value: &Value) -> bool {
+        matches!(value, Value::Array(items) if !items.is_empty() && items.iter().all(Value::is_object))
+    }
+
+    // Plain keys come first, TOML assigns keys after a header to that table
+    fn write_table(
+        text: &mut String,
+        path: &mut Vec<String>,
+        table: &Map<String, Value>,
+    ) -> Result<(), String> {
+        for (key, value) in table {
+            if !value.is_null() && !value.is_object() && !is_array_of_tables(value) {
+                text.push_str(&format!("{} = {}\n", key_of(key), inline(value)?));
+            }
+        }
+
+        for (key, value) in table {
+            path.push(key_of(key));
+            match value {
+                Value::Object(nested) => {
+                    text.push_str(&format!("\n[{}]\n", path.join(".")));
+                    write_table(text, path, nested)?;
+                }
+                Value::Array(items) if is_array_of_tables(value) => {
+                    for item in items.iter().filter_map(Value::as_object) {
+                        text.push_str(&format!("\n[[{}]]\n", path.join(".")));
+                        write_table(text, path, item)?;
+                    }
+                }
+                _ => {}
+            }
+            path.pop();
+        }
+
+        Ok(())
+    }
+
+    fn inline(value: &Value) -> Result<String, String> {
+        Ok(match value {
+            Value::Null => return Err("TOML cannot represent null in arrays".to_string()),
+            Value::Bool(value) => value.to_string(),
+            Value::Number(number) if number.is_u64() && number.as_i64().is_none() => {
+                return Err(format!("TOML cannot represent the integer {}", number))
+            }
+            Value::Number(number) => number.to_string(),
+            Value::String(string) => quote(string),
+            Value::Array(items) => {
+                let items = items.iter().map(inline).collect::<Result<Vec<_>, _>>()?;
+                format!("[{}]", items.join(", "))
+            }
+            Value::Object(table) => {
+                let entries = table
+                    .iter()
+                    .filter(|(_, value)| !value.is_null())
+                    .map(|(key, value)| Ok(format!("{} = {}", key_of(key), inline(value)?)))
+                    .collect::<Result<Vec<_>, String>>()?;
+                format!("{{ {} }}", entries.join(", "))
+            }
+        })
+    }
+
+    fn key_of(key: &str) -> String {
+        let bare = !key.is_empty()
+            && key
+                .chars()
+                .all(|char| char.is_ascii_alphanumeric() || char == '_' || char == '-');
+
+        if bare {
+            key.to_string()
+        } else {
+            quote(key)
+        }
+    }
+
+    fn quote(string: &str) -> String {
+        let mut quoted = String::with_capacity(string.len() + 2);
+        quoted.push('"');
+        for char in string.chars() {
+            match char {
+                '"' => quoted.push_str("\\\""),
+                '\\' => quoted.push_str("\\\\"),
+                '\n' => quoted.push_str("\\n"),
+                '\r' => quoted.push_str("\\r"),
+                '\t' => quoted.push_str("\\t"),
+                char if char.is_control() => quoted.push_str(&format!("\\u{:04X}", char as u32)),
+                char => quoted.push(char),
+            }
+        }
+        quoted.push('"');
+
+        quoted
+    }
+
+    pub(super) fn from_str(text: &str) -> Result<Value, String> {
+        let document = text.parse::<DocumentMut>().map_err(|err| err.to_string())?;
+
+        from_table(document.as_table())
+    }
+
+    fn from_table(table: &Table) -> Result<Value, String> {
+        table
+            .iter()
+            .map(|(key, item)| Ok((key.to_string(), from_item(item)?)))
+            .collect::<Result<Map<_, _>, String>>()
+            .map(Value::Object)
+    }
+
+    fn from_inline_table(table: &InlineTable) -> Result<Value, String> {
+        table
+            .iter()
+            .map(|(key, value)| Ok((key.to_string(), from_value(value)?)))
+            .collect::<Result<Map<_, _>, String>>()
+            .map(Value::Object)
+    }
+
+    fn from_item(item: &Item) -> Result<Value, String> {
+        match item {
+            Item::None => Ok(Value::Null),
+            Item::Value(value) => from_value(value),
+            Item::Table(table) => from_table(table),
+            Item::ArrayOfTables(tables) => tables
+                .iter()
+                .map(from_table)
+                .collect::<Result<Vec<_>, _>>()
+                .map(Value::Array),
+        }
+    }
+
+    fn from_value(value: &toml_edit::Value) -> Result<Value, String> {
+        Ok(match value {
+            toml_edit::Value::String(string) => Value::String(string.value().clone()),
+            toml_edit::Value::Integer(integer) => Value::from(*integer.value()),
+            toml_edit::Value::Float(float) => {
+                Number::from_f64(*float.value())
+                    .map(Value::Number)
+                    .ok_or_else(|| format!("JSON cannot represent the float {}", float.value()))?
+            }
+            toml_edit::Value::Boolean(boolean) => Value::Bool(*boolean.value()),
+            toml_edit::Value::Datetime(datetime) => Value::String(datetime.value().to_string()),
+            toml_edit::Value::Array(items) => items
+                .iter()
+                .map(from_value)
+                .collect::<Result<Vec<_>, _>>()
+                .map(Value::Array)?,
+            toml_edit::Value::InlineTable(table) => from_inline_table(table)?,
+        })
+    }
+}
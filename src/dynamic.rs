@@ -0,0 +1,142 @@
+//! Dynamically typed store where the state is a `serde_json::Value` and actions are JSON.
+//! Available behind the `serde` feature.
+
+use serde_json::Value;
+
+use crate::Store;
+
+/// Reducer of the value at a path: receives the current value (`Null` if it does
+/// not exist yet) and the JSON action
+pub type ValueReducer = fn(&Value, &Value) -> Value;
+
+/// JSON state together with reducers registered by JSON Pointer (RFC 6901) paths.
+///
+/// Useful for scripting and plugin layers where the shape of the state is not
+/// known at compile time.
+#[derive(Debug, Clone)]
+pub struct DynamicState {
+    value: Value,
+    reducers: Vec<(String, ValueReducer)>,
+}
+
+impl DynamicState {
+    /// Creates the state without reducers
+    pub fn new(value: Value) -> Self {
+        Self {
+            value,
+            reducers: Vec::new(),
+        }
+    }
+
+    /// Returns the whole JSON state
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+
+    /// Returns the value at the JSON Pointer `path`
+    pub fn get(&self, path: &str) -> Option<&Value> {
+        self.value.pointer(path)
+    }
+
+    /// Returns paths of registered reducers in the order they are called
+    pub fn paths(&self) -> Vec<&str> {
+        self.reducers
+            .iter()
+            .map(|(path, _)| path.as_str())
+            .collect()
+    }
+
+    /// Root reducer which passes the action to the reducer of each path in
+    /// registration order. Missing objects along a path are created
+    pub fn reducer(state: &Self, action: &Value) -> Self {
+        let mut value = state.value.clone();
+        for (path, reducer) in &state.reducers {
+            let current = value.pointer(path).unwrap_or(&Value::Null);
+            let reduced = reducer(current, action);
+            set_path(&mut value, path, reduced);
+        }
+
+        Self {
+            value,
+            reducers: state.reducers.clone(),
+        }
+    }
+}
+
+fn set_path(root: &mut Value, path: &str, value: Value) {
+    let mut target = root;
+    for token in path.split('/').skip(1) {
+        let token = token.replace("~1", "/").replace("~0", "~");
+        target = match target {
+            Value::Array(items) => match token.parse::<usize>() {
+                Ok(index) if index < items.len() => &mut items[index],
+                _ => return,
+            },
+            target => {
+                if !target.is_object() {
+                    *target = Value::Object(Default::default());
+                }
+                target
+                    .as_object_mut()
+                    .map(|map| map.entry(token).or_insert(Value::Null))
+                    .expect("The target was replaced with an object")
+            }
+        };
+    }
+
+    *target = value;
+}
+
+impl Store<DynamicState, Value> {
+    /// Creates a store with the JSON state and no reducers. Reducers are added
+    /// with `register_reducer`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::Store;
+    /// use serde_json::{json, Value};
+    ///
+    /// fn counter(state: &Value, action: &Value) -> Value {
+    ///     let count = state.as_u64().unwrap_or(0);
+    ///     match action["type"].as_str() {
+    ///         Some("increment") => json!(count + 1),
+    ///         _ => json!(count),
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::dynamic(json!({ "title": "Clicks" }));
+    /// store.register_reducer("/stats/clicks", counter);
+    ///
+    /// store.dispatch(json!({ "type": "increment" }));
+    ///
+    /// assert_eq!(
+    ///     *store.state().value(),
+    ///     json!({ "title": "Clicks", "stats": { "clicks": 1 } })
+    /// );
+    /// ```
+    pub fn dynamic(value: Value) -> Self {
+        Self::new(DynamicState::reducer, DynamicState::new(value))
+    }
+
+    /// Registers the reducer of the value at the JSON Pointer `path`.
+    ///
+    /// The current value at the path is kept; the reducer is called on the next
+    /// dispatch. Registering a path again replaces its reducer.
+    pub fn register_reducer(&mut self, path: &str, reducer: ValueReducer) {
+        let reducers = &mut self.state.get_mut().reducers;
+        match reducers.iter_mut().find(|(existing, _)| existing == path) {
+            Some(entry) => entry.1 = reducer,
+            None => reducers.push((path.to_string(), reducer)),
+        }
+    }
+
+    /// Removes the reducer of the `path`. Its value stays in the state.
+    /// Returns `false` if no reducer was registered for the path
+    pub fn unregister_reducer(&mut self, path: &str) -> bool {
+        let reducers = &mut self.state.get_mut().reducers;
+        let len = reducers.len();
+        reducers.retain(|(existing, _)| existing != path);
+
+        reducers.len() != len
+    }
+}
@@ -0,0 +1,254 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+use crate::{ActionFilter, DispatchError, Dispatcher, Middleware, Next};
+
+/// Flag which a running effect checks to stop cooperatively
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests the effect to stop
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns `true` if the effect should stop
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+/// Handle of an effect running on its own thread
+pub struct CancellationHandle {
+    token: CancellationToken,
+    thread: JoinHandle<()>,
+}
+
+impl CancellationHandle {
+    /// Cancels the effect. The action it produces is not dispatched anymore
+    pub fn cancel(&self) {
+        self.token.cancel();
+    }
+
+    /// Returns the token which the effect observes
+    pub fn token(&self) -> &CancellationToken {
+        &self.token
+    }
+
+    /// Returns `true` if the effect thread has finished
+    pub fn is_finished(&self) -> bool {
+        self.thread.is_finished()
+    }
+
+    /// Waits for the effect thread to finish
+    pub fn join(self) -> thread::Result<()> {
+        self.thread.join()
+    }
+}
+
+/// Runs the effect on its own thread and enqueues the action it returns.
+///
+/// The effect should check the token and return early once it is cancelled;
+/// the action of a cancelled effect is dropped even if it was returned.
+///
+/// ## Example
+/// ```rust
+/// use redust::{spawn_effect, DispatchQueue};
+/// use std::thread;
+/// use std::time::Duration;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum MyAction {
+///     Loaded,
+/// };
+///
+/// let queue = DispatchQueue::new();
+/// let handle = spawn_effect(queue.dispatcher(), |token| {
+///     while !token.is_cancelled() {
+///         thread::sleep(Duration::from_millis(1));
+///     }
+///
+///     Some(MyAction::Loaded)
+/// });
+///
+/// handle.cancel();
+/// handle.join().unwrap();
+///
+/// assert_eq!(queue.pop(), None);
+/// ```
+pub fn spawn_effect<Action, F>(dispatcher: Dispatcher<Action>, effect: F) -> CancellationHandle
+where
+    Action: Send + 'static,
+    F: FnOnce(&CancellationToken) -> Option<Action> + Send + 'static,
+{
+    let token = CancellationToken::new();
+    let effect_token = token.clone();
+    let thread = thread::spawn(move || {
+        let action = effect(&effect_token);
+        dispatch_effect_action(&dispatcher, &effect_token, action);
+    });
+
+    CancellationHandle { token, thread }
+}
+
+/// Enqueues the action produced by an effect, unless the effect was cancelled
+pub(crate) fn dispatch_effect_action<Action>(
+    dispatcher: &Dispatcher<Action>,
+    token: &CancellationToken,
+    action: Option<Action>,
+) {
+    let action = match action {
+        Some(action) => action,
+        None => return,
+    };
+    if token.is_cancelled() {
+        #[cfg(feature = "log")]
+        log::debug!(
+            target: crate::logging::EFFECT,
+            "Dropped the action of a cancelled effect"
+        );
+        return;
+    }
+
+    // The queue might be dropped while the effect was running
+    if let Err(_err) = dispatcher.dispatch(action) {
+        #[cfg(feature = "log")]
+        log::warn!(
+            target: crate::logging::EFFECT,
+            "Cannot enqueue the action of an effect: {}",
+            _err
+        );
+    }
+}
+
+/// Effect started by an action, which receives a copy of it
+pub type ActionEffect<Action> = fn(&Action, &CancellationToken) -> Option<Action>;
+
+/// Middleware which starts an effect for every matching action and cancels
+/// the previous in-flight effect, so only the result of the latest one is dispatched.
+///
+/// Typical for search-as-you-type and other requests where older responses are stale.
+///
+/// ## Example
+/// ```rust
+/// use redust::{DispatchQueue, Store, TakeLatest};
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum MyAction {
+///     Search(&'static str),
+///     Results(&'static str),
+/// };
+///
+/// fn reducer(state: &Vec<&'static str>, action: &MyAction) -> Vec<&'static str> {
+///     match action {
+///         MyAction::Results(results) => vec![results],
+///         _ => state.clone(),
+///     }
+/// }
+///
+/// let queue = DispatchQueue::new();
+/// let take_latest = TakeLatest::new(
+///     queue.dispatcher(),
+///     |action| matches!(action, MyAction::Search(_)),
+///     |action, _token| match action {
+///         MyAction::Search(query) => Some(MyAction::Results(query)),
+///         _ => None,
+///     },
+/// );
+///
+/// let mut store = Store::new(reducer, vec![]);
+/// store.add_middleware("search", take_latest.clone());
+/// store.dispatch(MyAction::Search("redux"));
+///
+/// take_latest.wait();
+/// store.drain(&queue);
+///
+/// assert_eq!(*store.state(), ["redux"]);
+/// ```
+pub struct TakeLatest<Action> {
+    dispatcher: Dispatcher<Action>,
+    filter: ActionFilter<Action>,
+    effect: ActionEffect<Action>,
+    running: Arc<Mutex<Option<CancellationHandle>>>,
+}
+
+impl<Action> Clone for TakeLatest<Action> {
+    fn clone(&self) -> Self {
+        Self {
+            dispatcher: self.dispatcher.clone(),
+            filter: self.filter,
+            effect: self.effect,
+            running: Arc::clone(&self.running),
+        }
+    }
+}
+
+impl<Action: Clone + Send + 'static> TakeLatest<Action> {
+    pub fn new(
+        dispatcher: Dispatcher<Action>,
+        filter: ActionFilter<Action>,
+        effect: ActionEffect<Action>,
+    ) -> Self {
+        Self {
+            dispatcher,
+            filter,
+            effect,
+            running: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Cancels the in-flight effect and starts a new one for the action
+    pub fn start(&self, action: &Action) {
+        let (effect, action) = (self.effect, action.clone());
+        let handle = spawn_effect(self.dispatcher.clone(), move |token| effect(&action, token));
+
+        let previous = self.lock().replace(handle);
+        if let Some(previous) = previous {
+            previous.cancel();
+        }
+    }
+
+    /// Cancels the in-flight effect
+    pub fn cancel(&self) {
+        if let Some(running) = self.lock().take() {
+            running.cancel();
+        }
+    }
+
+    /// Waits for the latest effect to finish
+    pub fn wait(&self) {
+        let running = self.lock().take();
+        if let Some(running) = running {
+            let _ = running.join();
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Option<CancellationHandle>> {
+        self.running
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl<State, Action: Clone + Send + 'static> Middleware<State, Action> for TakeLatest<Action> {
+    fn handle(
+        &self,
+        action: Action,
+        next: Next<'_, State, Action>,
+    ) -> Result<Action, DispatchError> {
+        let action = next.run(action)?;
+        if (self.filter)(&action) {
+            self.start(&action);
+        }
+
+        Ok(action)
+    }
+}
@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::subscription::{Subscriber, SubscriptionToken};
+use crate::view::ProjectedSubscription;
+use crate::{Store, Subscription, UnsubscribeError};
+
+/// Topic of the emitter which is emitted on every state change
+pub const CHANGE_TOPIC: &str = "change";
+
+/// Selects the value which the topic watches; the topic is emitted when it changes
+pub type TopicSelector<State, Part> = fn(&State) -> Part;
+
+#[derive(Debug, PartialEq)]
+pub enum EmitterError {
+    UnknownTopic(String),
+}
+
+impl std::error::Error for EmitterError {}
+impl std::fmt::Display for EmitterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            EmitterError::UnknownTopic(topic) => write!(f, "Cannot find the topic: {}", topic),
+        }
+    }
+}
+
+/// Creates listeners of a topic with the selected value type erased
+trait Topic<State> {
+    fn listen(
+        &self,
+        state: &State,
+        func: Subscription<State>,
+    ) -> Box<dyn ProjectedSubscription<State> + Send + Sync>;
+}
+
+struct SelectorTopic<State, Part> {
+    select: TopicSelector<State, Part>,
+}
+
+struct TopicListener<State, Part> {
+    select: TopicSelector<State, Part>,
+    func: Subscription<State>,
+    last: Part,
+}
+
+impl<State, Part> Topic<State> for SelectorTopic<State, Part>
+where
+    State: 'static,
+    Part: PartialEq + Send + Sync + 'static,
+{
+    fn listen(
+        &self,
+        state: &State,
+        func: Subscription<State>,
+    ) -> Box<dyn ProjectedSubscription<State> + Send + Sync> {
+        Box::new(TopicListener {
+            select: self.select,
+            func,
+            last: (self.select)(state),
+        })
+    }
+}
+
+impl<State, Part: PartialEq> ProjectedSubscription<State> for TopicListener<State, Part> {
+    fn notify(&mut self, state: &Arc<State>) {
+        let part = (self.select)(state);
+        if part != self.last {
+            self.last = part;
+            (self.func)(state);
+        }
+    }
+}
+
+/// Adapter which exposes the store through a conventional event-emitter interface.
+///
+/// Listeners are added with `on(topic, listener)` and removed with `off(handle)`.
+/// The `"change"` topic is emitted on every state change; other topics are
+/// registered with a selector and emitted only when the selected value changes.
+/// The emitter dereferences to the store, so actions are dispatched as usual.
+///
+/// ## Example
+/// ```rust
+/// use redust::{EventEmitter, Store};
+///
+/// type MyStore = (u8, u8);
+///
+/// #[derive(Debug)]
+/// enum MyAction {
+///     Click,
+///     Scroll,
+/// };
+///
+/// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+///     match action {
+///         MyAction::Click => (state.0 + 1, state.1),
+///         MyAction::Scroll => (state.0, state.1 + 1),
+///     }
+/// }
+///
+/// let mut emitter = EventEmitter::new(Store::new(reducer, (0, 0)));
+/// emitter.topic("clicks", |state: &MyStore| state.0);
+///
+/// let handle = emitter
+///     .on("clicks", |state| assert_eq!(state.0, 1))
+///     .unwrap();
+///
+/// // The listener is not called on scroll
+/// emitter.dispatch(MyAction::Scroll).dispatch(MyAction::Click);
+///
+/// emitter.off(handle).unwrap();
+/// ```
+pub struct EventEmitter<State, Action> {
+    store: Store<State, Action>,
+    topics: HashMap<String, Arc<dyn Topic<State> + Send + Sync>>,
+}
+
+impl<State: 'static, Action> EventEmitter<State, Action> {
+    /// Wraps the store. Only the `"change"` topic is available initially
+    pub fn new(store: Store<State, Action>) -> Self {
+        Self {
+            store,
+            topics: HashMap::new(),
+        }
+    }
+
+    /// Registers the topic which is emitted when the value selected by `select`
+    /// changes. Listeners added before keep the previous selector
+    pub fn topic<Part>(&mut self, name: &str, select: TopicSelector<State, Part>) -> &mut Self
+    where
+        Part: PartialEq + Send + Sync + 'static,
+    {
+        self.topics
+            .insert(name.to_string(), Arc::new(SelectorTopic { select }));
+
+        self
+    }
+
+    /// Returns `true` if listeners can be added to the topic
+    pub fn has_topic(&self, name: &str) -> bool {
+        name == CHANGE_TOPIC || self.topics.contains_key(name)
+    }
+
+    /// Adds the listener of the topic and returns the handle to remove it
+    pub fn on(
+        &mut self,
+        topic: &str,
+        listener: Subscription<State>,
+    ) -> Result<SubscriptionToken<State, Action>, EmitterError> {
+        if topic == CHANGE_TOPIC {
+            return Ok(self.store.subscribe(listener));
+        }
+
+        let topic = self
+            .topics
+            .get(topic)
+            .ok_or_else(|| EmitterError::UnknownTopic(topic.to_string()))?;
+        let subscriber = topic.listen(self.store.state(), listener);
+        let token = self.store.next_subscription_token();
+        self.store
+            .subscriptions
+            .insert(token.id(), Subscriber::Projected(subscriber));
+
+        Ok(token)
+    }
+
+    /// Removes the listener by the handle returned from `on`
+    pub fn off(
+        &mut self,
+        handle: SubscriptionToken<State, Action>,
+    ) -> Result<(), UnsubscribeError> {
+        self.store.unsubscribe(handle)
+    }
+
+    /// Unwraps the store. Listeners stay subscribed
+    pub fn into_store(self) -> Store<State, Action> {
+        self.store
+    }
+}
+
+impl<State, Action> std::ops::Deref for EventEmitter<State, Action> {
+    type Target = Store<State, Action>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.store
+    }
+}
+
+impl<State, Action> std::ops::DerefMut for EventEmitter<State, Action> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.store
+    }
+}
@@ -0,0 +1,251 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Normalized collection of entities: ids in insertion order and entities by id
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EntityState<Id: Ord, Entity> {
+    pub ids: Vec<Id>,
+    pub entities: BTreeMap<Id, Entity>,
+}
+
+impl<Id: Ord, Entity> EntityState<Id, Entity> {
+    pub fn new() -> Self {
+        Self {
+            ids: Vec::new(),
+            entities: BTreeMap::new(),
+        }
+    }
+}
+
+impl<Id: Ord, Entity> Default for EntityState<Id, Entity> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Reducer helpers and selectors for an `EntityState`.
+///
+/// Helpers update the state in place, so reducers call them on the copy of
+/// the previous state they return.
+///
+/// ## Example
+/// ```rust
+/// use redust::{EntityAdapter, EntityState, Store};
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Book {
+///     id: u32,
+///     title: &'static str,
+/// }
+///
+/// #[derive(Debug)]
+/// enum MyAction {
+///     Added(Book),
+///     Removed(u32),
+/// };
+///
+/// const BOOKS: EntityAdapter<u32, Book> = EntityAdapter::new(|book| book.id);
+///
+/// fn reducer(state: &EntityState<u32, Book>, action: &MyAction) -> EntityState<u32, Book> {
+///     let mut new_state = state.clone();
+///     match action {
+///         MyAction::Added(book) => BOOKS.upsert_one(&mut new_state, book.clone()),
+///         MyAction::Removed(id) => BOOKS.remove_one(&mut new_state, id),
+///     }
+///
+///     new_state
+/// }
+///
+/// let mut store = Store::new(reducer, EntityState::new());
+/// store
+///     .dispatch(MyAction::Added(Book { id: 2, title: "Dune" }))
+///     .dispatch(MyAction::Added(Book { id: 1, title: "Solaris" }))
+///     .dispatch(MyAction::Removed(2));
+///
+/// let titles: Vec<_> = BOOKS.select_all(store.state()).map(|book| book.title).collect();
+/// assert_eq!(titles, ["Solaris"]);
+/// ```
+pub struct EntityAdapter<Id, Entity> {
+    select_id: fn(&Entity) -> Id,
+    compare: Option<EntityComparator<Entity>>,
+}
+
+/// Compares entities of a sorted `EntityAdapter`
+pub type EntityComparator<Entity> = fn(&Entity, &Entity) -> Ordering;
+
+impl<Id, Entity> Clone for EntityAdapter<Id, Entity> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Id, Entity> Copy for EntityAdapter<Id, Entity> {}
+
+impl<Id: Ord + Clone, Entity> EntityAdapter<Id, Entity> {
+    /// Creates an adapter which reads ids of entities with `select_id`
+    pub const fn new(select_id: fn(&Entity) -> Id) -> Self {
+        Self {
+            select_id,
+            compare: None,
+        }
+    }
+
+    /// Creates an adapter which keeps `ids` ordered by `compare`.
+    ///
+    /// The order is maintained on every insert and update, so `select_all`
+    /// returns entities in order without sorting them on each read.
+    /// Entities which compare equal keep their insertion order.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::{EntityAdapter, EntityState};
+    ///
+    /// #[derive(Debug, Clone, PartialEq)]
+    /// struct Book {
+    ///     id: u32,
+    ///     title: &'static str,
+    /// }
+    ///
+    /// let books = EntityAdapter::sorted(|book: &Book| book.id, |a, b| a.title.cmp(b.title));
+    /// let mut state = EntityState::new();
+    /// books.add_one(&mut state, Book { id: 1, title: "Solaris" });
+    /// books.add_one(&mut state, Book { id: 2, title: "Dune" });
+    /// books.upsert_one(&mut state, Book { id: 2, title: "Walden" });
+    ///
+    /// assert_eq!(state.ids, [1, 2]);
+    /// ```
+    pub const fn sorted(select_id: fn(&Entity) -> Id, compare: EntityComparator<Entity>) -> Self {
+        Self {
+            select_id,
+            compare: Some(compare),
+        }
+    }
+
+    /// Returns the id of the entity
+    pub fn id(&self, entity: &Entity) -> Id {
+        (self.select_id)(entity)
+    }
+
+    /// Adds the entity unless an entity with the same id already exists
+    pub fn add_one(&self, state: &mut EntityState<Id, Entity>, entity: Entity) {
+        let id = self.id(&entity);
+        if !state.entities.contains_key(&id) {
+            self.insert_id(state, id.clone(), &entity);
+            state.entities.insert(id, entity);
+        }
+    }
+
+    /// Adds entities whose ids do not exist yet
+    pub fn add_many(
+        &self,
+        state: &mut EntityState<Id, Entity>,
+        entities: impl IntoIterator<Item = Entity>,
+    ) {
+        entities
+            .into_iter()
+            .for_each(|entity| self.add_one(state, entity));
+    }
+
+    /// Adds the entity or replaces the existing one with the same id
+    pub fn upsert_one(&self, state: &mut EntityState<Id, Entity>, entity: Entity) {
+        let id = self.id(&entity);
+        let exists = state.entities.contains_key(&id);
+        if exists && self.compare.is_some() {
+            state.ids.retain(|existing| *existing != id);
+        }
+        if !exists || self.compare.is_some() {
+            self.insert_id(state, id.clone(), &entity);
+        }
+        state.entities.insert(id, entity);
+    }
+
+    /// Adds or replaces all entities
+    pub fn upsert_many(
+        &self,
+        state: &mut EntityState<Id, Entity>,
+        entities: impl IntoIterator<Item = Entity>,
+    ) {
+        entities
+            .into_iter()
+            .for_each(|entity| self.upsert_one(state, entity));
+    }
+
+    /// Replaces all entities with the given ones. A sorted adapter sorts them once
+    pub fn set_all(
+        &self,
+        state: &mut EntityState<Id, Entity>,
+        entities: impl IntoIterator<Item = Entity>,
+    ) {
+        state.ids.clear();
+        state.entities.clear();
+        for entity in entities {
+            let id = self.id(&entity);
+            if state.entities.insert(id.clone(), entity).is_none() {
+                state.ids.push(id);
+            }
+        }
+
+        if let Some(compare) = self.compare {
+            let entities = &state.entities;
+            state
+                .ids
+                .sort_by(|a, b| compare(&entities[a], &entities[b]));
+        }
+    }
+
+    /// Removes the entity with the id if it exists
+    pub fn remove_one(&self, state: &mut EntityState<Id, Entity>, id: &Id) {
+        if state.entities.remove(id).is_some() {
+            state.ids.retain(|existing| existing != id);
+        }
+    }
+
+    /// Returns `true` if the adapter keeps `ids` sorted
+    pub fn is_sorted(&self) -> bool {
+        self.compare.is_some()
+    }
+
+    /// Returns entities in the order of `ids`
+    pub fn select_all<'a>(
+        &self,
+        state: &'a EntityState<Id, Entity>,
+    ) -> impl Iterator<Item = &'a Entity> {
+        state
+            .ids
+            .iter()
+            .filter_map(move |id| state.entities.get(id))
+    }
+
+    /// Returns the entity with the id
+    pub fn select_by_id<'a>(
+        &self,
+        state: &'a EntityState<Id, Entity>,
+        id: &Id,
+    ) -> Option<&'a Entity> {
+        state.entities.get(id)
+    }
+
+    /// Returns the number of entities
+    pub fn select_total(&self, state: &EntityState<Id, Entity>) -> usize {
+        state.ids.len()
+    }
+
+    fn insert_id(&self, state: &mut EntityState<Id, Entity>, id: Id, entity: &Entity) {
+        match self.compare {
+            Some(compare) => {
+                let entities = &state.entities;
+                let index = state.ids.partition_point(|existing| {
+                    entities
+                        .get(existing)
+                        .is_some_and(|other| compare(other, entity) != Ordering::Greater)
+                });
+                state.ids.insert(index, id);
+            }
+            None => state.ids.push(id),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use crate::lazy::LazyState;
+use crate::reducer::RootReducer;
+use crate::Store;
+
+/// Reducer which reads the environment of the store, e.g. locale, feature flags or limits
+pub type EnvReducer<State, Action, Env> = fn(&State, &Action, &Env) -> State;
+
+impl<State, Action, Env> Store<State, Action, Env> {
+    /// Creates a store whose reducer receives the `env` by reference,
+    /// so reducers read configuration without global statics.
+    ///
+    /// The environment belongs to the store, not to the state: it is not
+    /// part of snapshots and replacing it does not notify subscribers.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::Store;
+    ///
+    /// type MyStore = Vec<u32>;
+    ///
+    /// struct Limits {
+    ///     max_items: usize,
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Add(u32),
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction, limits: &Limits) -> MyStore {
+    ///     match action {
+    ///         MyAction::Add(item) if state.len() < limits.max_items => {
+    ///             let mut items = state.clone();
+    ///             items.push(*item);
+    ///
+    ///             items
+    ///         }
+    ///         MyAction::Add(_) => state.clone(),
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::with_env(reducer, vec![], Limits { max_items: 2 });
+    /// store
+    ///     .dispatch(MyAction::Add(1))
+    ///     .dispatch(MyAction::Add(2))
+    ///     .dispatch(MyAction::Add(3));
+    ///
+    /// assert_eq!(*store.state(), [1, 2]);
+    /// ```
+    pub fn with_env(
+        reducer: EnvReducer<State, Action, Env>,
+        initial_state: State,
+        env: Env,
+    ) -> Self {
+        Self::with_lazy_state(
+            RootReducer::Env(reducer),
+            LazyState::new(initial_state),
+            Arc::new(env),
+        )
+    }
+
+    /// Returns the environment passed to the reducer
+    pub fn env(&self) -> &Env {
+        &self.env
+    }
+
+    /// Replaces the environment for the next dispatches. Subscribers are not
+    /// notified because the state did not change
+    pub fn set_env(&mut self, env: Env) {
+        self.env = Arc::new(env);
+    }
+}
@@ -0,0 +1,318 @@
+use std::sync::mpsc::{channel, Receiver, Sender};
+
+use crate::subscription::SubscriberId;
+use crate::{DispatchError, FreezePolicy, StateVersion, Store};
+
+/// Failure of the state layer, delivered to receivers returned by `Store::errors`
+#[derive(Debug, Clone, PartialEq)]
+pub enum StoreError {
+    /// An action could not be dispatched
+    Dispatch(DispatchError),
+
+    /// A subscriber panicked or returned an error with `ErrorPolicy::Report`
+    Subscriber {
+        token: SubscriberId,
+        message: String,
+    },
+
+    /// The state could not be saved, restored or synced
+    Persistence(String),
+
+    /// A side effect triggered by an action failed
+    Effect(String),
+}
+
+impl std::error::Error for StoreError {}
+impl std::fmt::Display for StoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            StoreError::Dispatch(err) => write!(f, "{}", err),
+            StoreError::Subscriber { token, message } => {
+                write!(f, "The subscriber {} failed: {}", token, message)
+            }
+            StoreError::Persistence(message) => {
+                write!(f, "Cannot persist the state: {}", message)
+            }
+            StoreError::Effect(message) => write!(f, "The effect failed: {}", message),
+        }
+    }
+}
+
+impl StoreError {
+    /// Returns the phase in which the error happened
+    pub fn phase(&self) -> ErrorPhase {
+        match self {
+            StoreError::Dispatch(_) => ErrorPhase::Dispatch,
+            StoreError::Subscriber { .. } => ErrorPhase::Notify,
+            StoreError::Persistence(_) => ErrorPhase::Persistence,
+            StoreError::Effect(_) => ErrorPhase::Effect,
+        }
+    }
+}
+
+/// Phase of the store lifecycle in which a failure happened
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ErrorPhase {
+    Dispatch,
+    Notify,
+    Persistence,
+    Effect,
+}
+
+/// Context of a failure passed to the error handler
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorContext {
+    pub phase: ErrorPhase,
+
+    /// Debug representation of the action being dispatched, if any
+    pub action: Option<String>,
+
+    /// Version of the state when the failure happened
+    pub version: StateVersion,
+}
+
+/// Handler called with every internal failure of the store
+pub type ErrorHandler = fn(&StoreError, &ErrorContext);
+
+/// Defines what happens with failures when no error handler is set
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ErrorBehavior {
+    /// Failures are only sent to `Store::errors` receivers
+    #[default]
+    Ignore,
+
+    /// Failures are written to stderr, or logged with the `log` feature
+    Log,
+
+    /// Failures panic in debug builds and are written to stderr in release builds,
+    /// or logged with the `log` feature
+    PanicInDebug,
+}
+
+impl ErrorBehavior {
+    fn handle(self, error: &StoreError, context: &ErrorContext) {
+        let message = || match &context.action {
+            Some(action) => format!(
+                "{:?} failure while handling {}: {}",
+                context.phase, action, error
+            ),
+            None => format!("{:?} failure: {}", context.phase, error),
+        };
+
+        match self {
+            ErrorBehavior::Ignore => {}
+            ErrorBehavior::PanicInDebug if cfg!(debug_assertions) => panic!("{}", message()),
+            #[cfg(feature = "log")]
+            ErrorBehavior::Log | ErrorBehavior::PanicInDebug => {
+                log::error!(target: crate::logging::ERROR, "{}", message())
+            }
+            #[cfg(not(feature = "log"))]
+            ErrorBehavior::Log | ErrorBehavior::PanicInDebug => eprintln!("{}", message()),
+        }
+    }
+}
+
+pub(crate) struct ErrorSink<Action> {
+    senders: Vec<Sender<StoreError>>,
+    handler: Option<ErrorHandler>,
+    behavior: ErrorBehavior,
+    describe: Option<fn(&Action) -> String>,
+
+    /// Action of the failed dispatch, described only when the error is reported
+    rejected: Option<Action>,
+}
+
+impl<Action> ErrorSink<Action> {
+    pub(crate) fn new() -> Self {
+        Self {
+            senders: Vec::new(),
+            handler: None,
+            behavior: ErrorBehavior::Ignore,
+            describe: None,
+            rejected: None,
+        }
+    }
+
+    /// Describes the action for the error context if anything observes failures
+    pub(crate) fn describe(&self, action: &Action) -> Option<String> {
+        self.describe
+            .filter(|_| self.is_described())
+            .map(|describe| describe(action))
+    }
+
+    /// Keeps the action which could not be dispatched for the error context
+    pub(crate) fn reject(&mut self, action: Action) {
+        if self.describe.is_some() && self.is_described() {
+            self.rejected = Some(action);
+        }
+    }
+
+    /// Forgets the action rejected by a previous dispatch
+    pub(crate) fn clear_rejected(&mut self) {
+        self.rejected = None;
+    }
+
+    fn take_rejected_description(&mut self) -> Option<String> {
+        let action = self.rejected.take()?;
+
+        self.describe(&action)
+    }
+
+    fn is_described(&self) -> bool {
+        self.handler.is_some() || self.behavior != ErrorBehavior::Ignore
+    }
+
+    fn is_observed(&self) -> bool {
+        !self.senders.is_empty() || self.handler.is_some() || self.behavior != ErrorBehavior::Ignore
+    }
+}
+
+fn describe<Action: std::fmt::Debug>(action: &Action) -> String {
+    format!("{:?}", action)
+}
+
+impl<State, Action, Env> Store<State, Action, Env> {
+    /// Returns a receiver of every failure of the store: rejected dispatches,
+    /// subscriber panics and errors, persistence and effect failures.
+    ///
+    /// Each receiver gets all errors reported after it was created. Dropping the
+    /// receiver unsubscribes it. Errors are still returned from `try_dispatch`
+    /// and collected by `take_subscriber_errors`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::{DispatchError, FreezePolicy, Store, StoreError};
+    ///
+    /// type MyStore = u8;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Increment,
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Increment => state + 1,
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, 0);
+    /// let errors = store.errors();
+    ///
+    /// store.freeze(FreezePolicy::Reject);
+    /// store.dispatch(MyAction::Increment);
+    ///
+    /// assert_eq!(errors.try_recv(), Ok(StoreError::Dispatch(DispatchError::Frozen)));
+    /// ```
+    pub fn errors(&mut self) -> Receiver<StoreError> {
+        let (sender, receiver) = channel();
+        self.errors.senders.push(sender);
+
+        receiver
+    }
+
+    /// Removes the error handler, so failures are handled by the `ErrorBehavior`
+    pub fn remove_error_handler(&mut self) {
+        self.errors.handler = None;
+    }
+
+    /// Passes the error to the error handler and sends it to every receiver
+    /// returned by `errors`.
+    ///
+    /// Persistence layers and effects use it to report their failures.
+    pub fn report_error(&mut self, error: StoreError) {
+        self.handle_error(error, None);
+    }
+
+    pub(crate) fn handle_error(&mut self, error: StoreError, action: Option<String>) {
+        let context = ErrorContext {
+            phase: error.phase(),
+            action,
+            version: self.version,
+        };
+        match self.errors.handler {
+            Some(handler) => handler(&error, &context),
+            None => self.errors.behavior.handle(&error, &context),
+        }
+
+        self.errors
+            .senders
+            .retain(|sender| sender.send(error.clone()).is_ok());
+    }
+
+    /// Reports the error of a dispatch unless the action was ignored by a frozen store
+    pub(crate) fn report_dispatch_error(&mut self, err: &DispatchError) {
+        let action = self.errors.take_rejected_description();
+        let ignored = *err == DispatchError::Frozen && self.frozen == Some(FreezePolicy::Ignore);
+        if !ignored {
+            self.handle_error(StoreError::Dispatch(err.clone()), action);
+        }
+    }
+
+    /// Reports subscriber errors collected since the `from` index
+    pub(crate) fn report_subscriber_errors(&mut self, from: usize, action: Option<&Action>) {
+        if !self.errors.is_observed() {
+            return;
+        }
+
+        let errors = self.subscriber_errors[from.min(self.subscriber_errors.len())..]
+            .iter()
+            .map(|(token, err)| StoreError::Subscriber {
+                token: *token,
+                message: err.to_string(),
+            })
+            .collect::<Vec<_>>();
+        errors.into_iter().for_each(|error| {
+            let action = action.and_then(|action| self.errors.describe(action));
+            self.handle_error(error, action);
+        });
+    }
+}
+
+impl<State, Action: std::fmt::Debug, Env> Store<State, Action, Env> {
+    /// Sets the handler which is called with every internal failure of the store
+    /// together with the phase, the action being handled and the state version.
+    ///
+    /// While a handler is set, dispatched actions are formatted with `Debug`
+    /// for the error context, which has a small cost on every dispatch.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::{ErrorContext, ErrorPhase, FreezePolicy, Store, StoreError};
+    ///
+    /// type MyStore = u8;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Increment,
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Increment => state + 1,
+    ///     }
+    /// }
+    ///
+    /// fn handler(_error: &StoreError, context: &ErrorContext) {
+    ///     assert_eq!(context.phase, ErrorPhase::Dispatch);
+    ///     assert_eq!(context.action.as_deref(), Some("Increment"));
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, 0);
+    /// store.set_error_handler(handler);
+    ///
+    /// store.freeze(FreezePolicy::Reject);
+    /// store.dispatch(MyAction::Increment);
+    /// ```
+    pub fn set_error_handler(&mut self, handler: ErrorHandler) {
+        self.errors.handler = Some(handler);
+        self.errors.describe = Some(describe::<Action>);
+    }
+
+    /// Defines what happens with failures while no error handler is set.
+    /// Failures are ignored by default
+    pub fn set_error_behavior(&mut self, behavior: ErrorBehavior) {
+        self.errors.behavior = behavior;
+        self.errors.describe = Some(describe::<Action>);
+    }
+}
@@ -0,0 +1,126 @@
+use std::time::{Duration, SystemTime};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{ActionFilter, Store};
+
+/// Action which the store dispatches once the time comes.
+///
+/// Wall-clock time is used, so expirations might be persisted together
+/// with the state and restored with `Store::restore_expirations`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Expiration<Action> {
+    pub at: SystemTime,
+    pub action: Action,
+}
+
+impl<State, Action, Env> Store<State, Action, Env> {
+    /// Registers the `action` to be dispatched at the time `at`
+    /// by `dispatch_expired`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::Store;
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// type MyStore = Vec<u32>;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     SessionStarted(u32),
+    ///     SessionExpired(u32),
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     let mut sessions = state.clone();
+    ///     match action {
+    ///         MyAction::SessionStarted(id) => sessions.push(*id),
+    ///         MyAction::SessionExpired(id) => sessions.retain(|session| session != id),
+    ///     }
+    ///
+    ///     sessions
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, vec![]);
+    /// let started = SystemTime::now();
+    ///
+    /// store.dispatch(MyAction::SessionStarted(1));
+    /// store.expire_at(started + Duration::from_secs(60), MyAction::SessionExpired(1));
+    ///
+    /// assert_eq!(store.dispatch_expired(started + Duration::from_secs(59)), 0);
+    /// assert_eq!(store.dispatch_expired(started + Duration::from_secs(60)), 1);
+    /// assert!(store.state().is_empty());
+    /// ```
+    pub fn expire_at(&mut self, at: SystemTime, action: Action) {
+        // Expirations with the same time are dispatched in registration order
+        let index = self
+            .expirations
+            .partition_point(|expiration| expiration.at <= at);
+        self.expirations.insert(index, Expiration { at, action });
+    }
+
+    /// Registers the `action` to be dispatched when `ttl` passes from now,
+    /// as told by the store clock
+    pub fn expire_after(&mut self, ttl: Duration, action: Action) {
+        self.expire_at(self.clock.system_time() + ttl, action);
+    }
+
+    /// Removes pending expirations whose actions match the `filter`
+    /// and returns how many were removed
+    pub fn cancel_expirations(&mut self, filter: ActionFilter<Action>) -> usize {
+        let before = self.expirations.len();
+        self.expirations
+            .retain(|expiration| !filter(&expiration.action));
+
+        before - self.expirations.len()
+    }
+
+    /// Returns the time of the earliest pending expiration,
+    /// so the runtime knows how long it might sleep
+    pub fn next_expiration(&self) -> Option<SystemTime> {
+        self.expirations.first().map(|expiration| expiration.at)
+    }
+
+    /// Returns pending expirations ordered by time, e.g. to persist them with the state
+    pub fn expirations(&self) -> &[Expiration<Action>] {
+        &self.expirations
+    }
+
+    /// Registers previously persisted expirations. Already due ones are
+    /// dispatched on the next `dispatch_expired`
+    pub fn restore_expirations(&mut self, expirations: Vec<Expiration<Action>>) {
+        expirations.into_iter().for_each(|expiration| {
+            self.expire_at(expiration.at, expiration.action);
+        });
+    }
+
+    /// Dispatches actions of all expirations which are due at `now`
+    /// in time order and returns how many were dispatched.
+    ///
+    /// The store runtime calls it periodically or sleeps until `next_expiration`.
+    pub fn dispatch_expired(&mut self, now: SystemTime) -> usize {
+        let due = self
+            .expirations
+            .partition_point(|expiration| expiration.at <= now);
+        let expired: Vec<_> = self.expirations.drain(..due).collect();
+
+        expired.into_iter().for_each(|expiration| {
+            self.dispatch(expiration.action);
+        });
+
+        due
+    }
+
+    /// Dispatches actions of all expirations which are due at the current
+    /// time of the store clock, see `dispatch_expired`.
+    /// Sampled subscribers whose interval elapsed also receive the state they skipped
+    pub fn dispatch_due(&mut self) -> usize {
+        let now = self.clock.system_time();
+        let dispatched = self.dispatch_expired(now);
+        self.flush_due_sampled();
+
+        dispatched
+    }
+}
@@ -0,0 +1,152 @@
+use crate::isolation;
+use crate::strict::{self, SlowTarget};
+use crate::subscription::{Subscriber, SubscriptionToken};
+use crate::Store;
+
+/// Error returned by a fallible subscriber
+pub type SubscriberError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Subscription which might fail
+pub type FallibleSubscription<State> = fn(&State) -> Result<(), SubscriberError>;
+
+/// Defines what the store does when a fallible subscriber returns an error
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ErrorPolicy {
+    /// Remove the failing subscription
+    Unsubscribe,
+
+    /// Keep the subscription and call it again with the latest state on
+    /// `retry_failed_subscribers` or on the next notification. Errors of the
+    /// calls made by `retry_failed_subscribers` are collected like with `Report`
+    Retry,
+
+    /// Keep the subscription and collect the error, see `take_subscriber_errors`
+    Report,
+}
+
+pub(crate) struct FallibleEntry<State> {
+    pub(crate) func: FallibleSubscription<State>,
+    pub(crate) policy: ErrorPolicy,
+    pub(crate) failed: bool,
+}
+
+impl<State> FallibleEntry<State> {
+    pub(crate) fn call(&mut self, state: &State) -> Result<(), SubscriberError> {
+        self.failed = false;
+
+        (self.func)(state)
+    }
+}
+
+impl<State, Action, Env> Store<State, Action, Env> {
+    /// Subscribes a callback which may fail. The `policy` defines what
+    /// happens when it returns an error.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::{ErrorPolicy, Store};
+    ///
+    /// type MyStore = u8;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Increment,
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Increment => state + 1,
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, 0);
+    ///
+    /// let token = store.subscribe_fallible(ErrorPolicy::Report, |state| {
+    ///     if *state > 1 {
+    ///         return Err("Counter is too big".into());
+    ///     }
+    ///
+    ///     Ok(())
+    /// });
+    ///
+    /// store.dispatch(MyAction::Increment).dispatch(MyAction::Increment);
+    ///
+    /// let errors = store.take_subscriber_errors();
+    /// assert_eq!(errors.len(), 1);
+    /// assert_eq!(errors[0].0, token);
+    /// ```
+    pub fn subscribe_fallible(
+        &mut self,
+        policy: ErrorPolicy,
+        func: FallibleSubscription<State>,
+    ) -> SubscriptionToken<State, Action> {
+        let subscription_token = self.next_subscription_token();
+        self.subscriptions.insert(
+            subscription_token.id(),
+            Subscriber::Fallible(FallibleEntry {
+                func,
+                policy,
+                failed: false,
+            }),
+        );
+
+        subscription_token
+    }
+
+    /// Calls again subscribers with `ErrorPolicy::Retry` which failed last time.
+    /// Returns the number of subscribers which failed again, their errors are
+    /// collected by `take_subscriber_errors`.
+    pub fn retry_failed_subscribers(&mut self) -> usize {
+        let reported_errors = self.subscriber_errors.len();
+        let state = self.state.get();
+        let isolation = self.panic_isolation;
+        let subscriber_errors = &mut self.subscriber_errors;
+        let strict = &mut self.strict;
+        let mut panics = vec![];
+        let mut failed = 0;
+
+        for (id, subscriber) in self.subscriptions.iter_mut() {
+            let entry = match subscriber {
+                Subscriber::Fallible(entry) if entry.failed => entry,
+                _ => continue,
+            };
+
+            let target = SlowTarget::Subscriber(*id);
+            let result = strict::measure(strict, target, None, || {
+                isolation::call(isolation, || entry.call(state))
+            });
+            match result {
+                Ok(Ok(())) => {}
+                Ok(Err(err)) => {
+                    entry.failed = true;
+                    failed += 1;
+                    subscriber_errors.push((*id, err));
+                }
+                Err(err) => {
+                    entry.failed = true;
+                    failed += 1;
+                    panics.push((*id, err));
+                }
+            }
+        }
+
+        self.handle_panics(panics);
+        self.report_subscriber_errors(reported_errors, None);
+
+        failed
+    }
+
+    /// Returns errors collected from subscribers with `ErrorPolicy::Report`,
+    /// retried subscribers and panicking subscribers.
+    ///
+    /// Errors are kept until they are taken, so a store with failing
+    /// subscribers should take them regularly to keep the list from growing.
+    pub fn take_subscriber_errors(
+        &mut self,
+    ) -> Vec<(SubscriptionToken<State, Action>, SubscriberError)> {
+        std::mem::take(&mut self.subscriber_errors)
+            .into_iter()
+            .map(|(id, err)| (SubscriptionToken::new(id), err))
+            .collect()
+    }
+}
@@ -0,0 +1,285 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::{DispatchError, Middleware, Next, SharedClock, SystemClock};
+
+/// Serializes a transition, i.e. the action, the previous and the new state,
+/// into one record of the log. A newline is appended to every record
+pub type LogFormat<State, Action> = fn(&Action, &State, &State) -> String;
+
+/// Defines when the log file is rotated
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LogRotation {
+    Never,
+
+    /// Rotates the file before a record would make it larger than the given number of bytes
+    Size(u64),
+
+    /// Rotates the file once it has been written for the given time
+    Interval(Duration),
+}
+
+struct LogFile {
+    file: Option<File>,
+    written: u64,
+    opened: Instant,
+    failed_writes: usize,
+}
+
+/// Middleware which appends a record of every transition to a log file.
+///
+/// When the file is rotated, it is renamed to `<path>.1`, older files are shifted
+/// to `<path>.2` and so on, and only `keep` rotated files are kept. Write errors
+/// never fail a dispatch; they are counted by `failed_writes`.
+///
+/// The logger is cheap to clone, so a clone kept outside of the store can flush
+/// the file or check for failed writes.
+///
+/// ## Example
+/// ```rust
+/// use redust::{FileLogger, LogRotation, Store};
+///
+/// type MyStore = u8;
+///
+/// #[derive(Debug)]
+/// enum MyAction {
+///     Increment,
+/// };
+///
+/// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+///     match action {
+///         MyAction::Increment => state + 1,
+///     }
+/// }
+///
+/// let path = std::env::temp_dir().join("redust-file-logger-doc.log");
+/// # let _ = std::fs::remove_file(&path);
+/// let logger = FileLogger::debug(&path, LogRotation::Size(1024 * 1024), 3).unwrap();
+///
+/// let mut store = Store::new(reducer, 0);
+/// store.add_middleware("file-logger", logger.clone());
+/// store.dispatch(MyAction::Increment);
+///
+/// logger.flush().unwrap();
+/// let log = std::fs::read_to_string(&path).unwrap();
+/// assert_eq!(log, "Increment: 0 -> 1\n");
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+pub struct FileLogger<State, Action> {
+    path: PathBuf,
+    format: LogFormat<State, Action>,
+    rotation: LogRotation,
+    keep: usize,
+    log: Arc<Mutex<LogFile>>,
+    clock: SharedClock,
+}
+
+impl<State, Action> Clone for FileLogger<State, Action> {
+    fn clone(&self) -> Self {
+        Self {
+            path: self.path.clone(),
+            format: self.format,
+            rotation: self.rotation,
+            keep: self.keep,
+            log: Arc::clone(&self.log),
+            clock: Arc::clone(&self.clock),
+        }
+    }
+}
+
+impl<State, Action> FileLogger<State, Action> {
+    /// Opens the log file for appending, creating it if it does not exist
+    pub fn open(
+        path: impl AsRef<Path>,
+        format: LogFormat<State, Action>,
+        rotation: LogRotation,
+        keep: usize,
+    ) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let written = file.metadata()?.len();
+
+        Ok(Self {
+            path,
+            format,
+            rotation,
+            keep,
+            log: Arc::new(Mutex::new(LogFile {
+                file: Some(file),
+                written,
+                opened: Instant::now(),
+                failed_writes: 0,
+            })),
+            clock: Arc::new(SystemClock),
+        })
+    }
+
+    /// Reads the time of interval rotations from the `clock`,
+    /// so tests can rotate the log with a `TestClock`
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.lock().opened = clock.now();
+        self.clock = clock;
+
+        self
+    }
+
+    /// Returns the path of the current log file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the path of the rotated file, where `1` is the most recent one
+    pub fn rotated_path(&self, index: usize) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(format!(".{}", index));
+
+        path.into()
+    }
+
+    /// Returns the number of records which could not be written
+    pub fn failed_writes(&self) -> usize {
+        self.lock().failed_writes
+    }
+
+    /// Flushes the log file
+    pub fn flush(&self) -> io::Result<()> {
+        match self.lock().file.as_mut() {
+            Some(file) => file.flush(),
+            None => Ok(()),
+        }
+    }
+
+    /// Rotates the log file now
+    pub fn rotate(&self) -> io::Result<()> {
+        let mut log = self.lock();
+        self.rotate_file(&mut log)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LogFile> {
+        self.log
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn rotate_file(&self, log: &mut LogFile) -> io::Result<()> {
+        // Closes the file before it is renamed
+        log.file = None;
+        let rotated = self.shift_files();
+
+        // Reopens the file even when the rotation failed, so later records are not lost
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        log.written = file.metadata()?.len();
+        log.file = Some(file);
+        log.opened = self.clock.now();
+
+        rotated
+    }
+
+    fn shift_files(&self) -> io::Result<()> {
+        if self.keep == 0 {
+            return ignore_not_found(fs::remove_file(&self.path));
+        }
+
+        ignore_not_found(fs::remove_file(self.rotated_path(self.keep)))?;
+        for index in (1..self.keep).rev() {
+            ignore_not_found(fs::rename(
+                self.rotated_path(index),
+                self.rotated_path(index + 1),
+            ))?;
+        }
+
+        ignore_not_found(fs::rename(&self.path, self.rotated_path(1)))
+    }
+
+    fn should_rotate(&self, log: &LogFile, record_len: u64) -> bool {
+        match self.rotation {
+            LogRotation::Never => false,
+            LogRotation::Size(bytes) => log.written > 0 && log.written + record_len > bytes,
+            LogRotation::Interval(interval) => {
+                self.clock.now().saturating_duration_since(log.opened) >= interval
+            }
+        }
+    }
+
+    fn write(&self, record: String) {
+        let record = record + "\n";
+        let mut log = self.lock();
+
+        if log.file.is_none() || self.should_rotate(&log, record.len() as u64) {
+            let _result = self.rotate_file(&mut log);
+            #[cfg(feature = "log")]
+            match _result {
+                Ok(()) => {
+                    log::info!(target: crate::logging::PERSIST, "Rotated the action log {}", self.path.display())
+                }
+                Err(err) => log::warn!(
+                    target: crate::logging::PERSIST,
+                    "Cannot rotate the action log {}: {}",
+                    self.path.display(),
+                    err
+                ),
+            }
+        }
+        let result = match log.file.as_mut() {
+            Some(file) => file.write_all(record.as_bytes()),
+            None => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "The log file is closed",
+            )),
+        };
+
+        match result {
+            Ok(()) => log.written += record.len() as u64,
+            Err(_err) => {
+                #[cfg(feature = "log")]
+                log::warn!(
+                    target: crate::logging::PERSIST,
+                    "Cannot write to the action log {}: {}",
+                    self.path.display(),
+                    _err
+                );
+                log.failed_writes += 1
+            }
+        }
+    }
+}
+
+impl<State: std::fmt::Debug, Action: std::fmt::Debug> FileLogger<State, Action> {
+    /// Opens the log file with records formatted as `{action:?}: {old:?} -> {new:?}`
+    pub fn debug(path: impl AsRef<Path>, rotation: LogRotation, keep: usize) -> io::Result<Self> {
+        Self::open(path, debug_record::<State, Action>, rotation, keep)
+    }
+}
+
+fn debug_record<State: std::fmt::Debug, Action: std::fmt::Debug>(
+    action: &Action,
+    old_state: &State,
+    new_state: &State,
+) -> String {
+    format!("{:?}: {:?} -> {:?}", action, old_state, new_state)
+}
+
+impl<State, Action> Middleware<State, Action> for FileLogger<State, Action> {
+    fn handle(
+        &self,
+        action: Action,
+        next: Next<'_, State, Action>,
+    ) -> Result<Action, DispatchError> {
+        next.run_and_inspect(action, |action, old_state, new_state| {
+            self.write((self.format)(action, old_state, new_state))
+        })
+    }
+}
+
+fn ignore_not_found(result: io::Result<()>) -> io::Result<()> {
+    match result {
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+        result => result,
+    }
+}
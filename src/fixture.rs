@@ -0,0 +1,170 @@
+use std::ops::Deref;
+
+#[cfg(feature = "serde")]
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{Checkpoint, Reducer, Store};
+
+/// Recorded session: the initial state and all actions dispatched after it.
+///
+/// With the `serde` feature fixtures can be saved to JSON and loaded back,
+/// which allows turning real user sessions into regression tests.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Fixture<State, Action> {
+    pub initial_state: State,
+    pub actions: Vec<Action>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum FixtureError {
+    Json(String),
+}
+
+impl std::error::Error for FixtureError {}
+impl std::fmt::Display for FixtureError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            FixtureError::Json(message) => {
+                write!(f, "Cannot convert the fixture from/to JSON: {}", message)
+            }
+        }
+    }
+}
+
+impl<State, Action> Fixture<State, Action> {
+    /// Creates an empty fixture which starts from the `initial_state`
+    pub fn new(initial_state: State) -> Self {
+        Self {
+            initial_state,
+            actions: Vec::new(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<State, Action> Fixture<State, Action>
+where
+    State: Serialize + DeserializeOwned,
+    Action: Serialize + DeserializeOwned,
+{
+    /// Serializes the fixture into a JSON string
+    pub fn to_json(&self) -> Result<String, FixtureError> {
+        serde_json::to_string_pretty(self).map_err(|err| FixtureError::Json(err.to_string()))
+    }
+
+    /// Deserializes the fixture from a JSON string
+    pub fn from_json(json: &str) -> Result<Self, FixtureError> {
+        serde_json::from_str(json).map_err(|err| FixtureError::Json(err.to_string()))
+    }
+
+    /// Writes the fixture as JSON into the `writer`
+    pub fn to_writer<W: std::io::Write>(&self, writer: W) -> Result<(), FixtureError> {
+        serde_json::to_writer_pretty(writer, self)
+            .map_err(|err| FixtureError::Json(err.to_string()))
+    }
+
+    /// Reads the fixture as JSON from the `reader`
+    pub fn from_reader<R: std::io::Read>(reader: R) -> Result<Self, FixtureError> {
+        serde_json::from_reader(reader).map_err(|err| FixtureError::Json(err.to_string()))
+    }
+}
+
+/// Store wrapper which records the session into a `Fixture`
+pub struct Recorder<State, Action> {
+    pub(crate) store: Store<State, Action>,
+    pub(crate) fixture: Fixture<State, Action>,
+    pub(crate) checkpoints: Vec<Checkpoint<State>>,
+    pub(crate) checkpoint_interval: usize,
+}
+
+impl<State: Clone, Action> Recorder<State, Action> {
+    /// Creates a new store and starts recording from the `initial_state`
+    pub fn new(reducer: Reducer<State, Action>, initial_state: State) -> Self {
+        Self {
+            fixture: Fixture::new(initial_state.clone()),
+            store: Store::new(reducer, initial_state),
+            checkpoints: Vec::new(),
+            checkpoint_interval: 0,
+        }
+    }
+
+    /// Dispatches an action into the underlying store and records it.
+    /// Actions queued by middleware are dispatched and recorded right after it
+    pub fn dispatch(&mut self, action: Action) -> &mut Self {
+        let mut action = Some(action);
+        while let Some(next) = action
+            .take()
+            .or_else(|| self.store.queued_actions.pop_front())
+        {
+            if let Ok(next) = self.store.dispatch_action(next) {
+                self.fixture.actions.push(next);
+
+                let interval = self.checkpoint_interval;
+                if interval > 0 && self.fixture.actions.len() % interval == 0 {
+                    self.checkpoint();
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Returns the recorded session
+    pub fn fixture(&self) -> &Fixture<State, Action> {
+        &self.fixture
+    }
+
+    /// Stops recording and returns the recorded session
+    pub fn into_fixture(self) -> Fixture<State, Action> {
+        self.fixture
+    }
+}
+
+impl<State, Action> Deref for Recorder<State, Action> {
+    type Target = Store<State, Action>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.store
+    }
+}
+
+impl<State, Action> Store<State, Action> {
+    /// Creates a new store from the fixture by dispatching all recorded actions
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::{Fixture, Store};
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     IncrementBy(u8),
+    /// };
+    ///
+    /// fn reducer(state: &u8, action: &MyAction) -> u8 {
+    ///     match action {
+    ///         MyAction::IncrementBy(value) => state + value,
+    ///     }
+    /// }
+    ///
+    /// let fixture = Fixture {
+    ///     initial_state: 1,
+    ///     actions: vec![MyAction::IncrementBy(2), MyAction::IncrementBy(3)],
+    /// };
+    ///
+    /// let store = Store::replay_fixture(reducer, fixture);
+    ///
+    /// assert_eq!(*store.state(), 6);
+    /// ```
+    pub fn replay_fixture(
+        reducer: Reducer<State, Action>,
+        fixture: Fixture<State, Action>,
+    ) -> Self {
+        let mut store = Self::new(reducer, fixture.initial_state);
+        fixture.actions.into_iter().for_each(|action| {
+            store.dispatch(action);
+        });
+
+        store
+    }
+}
@@ -0,0 +1,138 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use crate::slices::{AnySlice, LazySlice, SliceEntry};
+use crate::{Reducer, SliceError, SliceKey, Slices, Store};
+
+/// Source of runtime feature flags, e.g. a remote config client
+pub trait FlagProvider {
+    fn is_enabled(&self, flag: &str) -> bool;
+}
+
+/// Whether a gated slice reducer was applied to an action
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlagDecision {
+    pub slice: SliceKey,
+    pub flag: &'static str,
+    pub enabled: bool,
+}
+
+/// Slice which is reduced only while its feature flag is enabled
+struct GatedSlice<SliceState, Action> {
+    entry: SliceEntry<SliceState, Action>,
+    flag: &'static str,
+    provider: Arc<dyn FlagProvider + Send + Sync>,
+}
+
+impl<SliceState, Action> AnySlice<Action> for GatedSlice<SliceState, Action>
+where
+    SliceState: Clone + 'static,
+    Action: 'static,
+{
+    fn reduce(
+        &self,
+        key: SliceKey,
+        action: &Action,
+        decisions: &mut Vec<FlagDecision>,
+    ) -> Arc<dyn AnySlice<Action>> {
+        let enabled = self.provider.is_enabled(self.flag);
+        decisions.push(FlagDecision {
+            slice: key,
+            flag: self.flag,
+            enabled,
+        });
+
+        let state = self.entry.state.get();
+        let state = if enabled {
+            (self.entry.reducer)(state, action)
+        } else {
+            state.clone()
+        };
+
+        Arc::new(GatedSlice {
+            entry: SliceEntry {
+                reducer: self.entry.reducer,
+                state: LazySlice::ready(state),
+            },
+            flag: self.flag,
+            provider: Arc::clone(&self.provider),
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self.entry.state.get()
+    }
+}
+
+impl<Action: 'static> Store<Slices<Action>, Action> {
+    /// Registers a slice reducer which is applied only while the `flag` is enabled.
+    ///
+    /// The `provider` is queried on every dispatch. While the flag is disabled the
+    /// slice state is passed through untouched. Decisions are available via
+    /// `Slices::flag_decisions` for the last reduced action.
+    ///
+    /// Like `inject_reducer`, the slice state is created by `initial_slice`
+    /// on first use and an existing slice of another type is not replaced.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::{FlagProvider, Store};
+    /// use std::sync::Arc;
+    ///
+    /// struct Flags;
+    ///
+    /// impl FlagProvider for Flags {
+    ///     fn is_enabled(&self, flag: &str) -> bool {
+    ///         flag == "new-counter"
+    ///     }
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Increment,
+    /// };
+    ///
+    /// fn counter_reducer(state: &u8, action: &MyAction) -> u8 {
+    ///     match action {
+    ///         MyAction::Increment => state + 1,
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::with_slices();
+    /// let flags = Arc::new(Flags);
+    ///
+    /// store
+    ///     .inject_gated_reducer("new", "new-counter", flags.clone(), counter_reducer, || 0)
+    ///     .unwrap();
+    /// store
+    ///     .inject_gated_reducer("old", "old-counter", flags, counter_reducer, || 0)
+    ///     .unwrap();
+    /// store.dispatch(MyAction::Increment);
+    ///
+    /// assert_eq!(store.state().get::<u8>("new"), Some(&1));
+    /// assert_eq!(store.state().get::<u8>("old"), Some(&0));
+    /// ```
+    pub fn inject_gated_reducer<SliceState, F>(
+        &mut self,
+        key: SliceKey,
+        flag: &'static str,
+        provider: Arc<dyn FlagProvider + Send + Sync>,
+        reducer: Reducer<SliceState, Action>,
+        initial_slice: F,
+    ) -> Result<(), SliceError>
+    where
+        SliceState: Clone + 'static,
+        F: FnOnce() -> SliceState + 'static,
+    {
+        let state = self.injected_slice(key, initial_slice)?;
+        let slice = GatedSlice {
+            entry: SliceEntry { reducer, state },
+            flag,
+            provider,
+        };
+
+        self.state.get_mut().slices.insert(key, Arc::new(slice));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use crate::lazy::LazyState;
+use crate::merge::ForkBase;
+use crate::Store;
+
+impl<State, Action, Env> Store<State, Action, Env> {
+    /// Creates an independent store with the same reducer and the current state,
+    /// so speculative actions can be evaluated without touching this store.
+    ///
+    /// The state is shared until the fork dispatches, so forking is cheap.
+    /// Subscribers, hooks, interceptors and middleware are not copied, so
+    /// previews do not trigger side effects.
+    ///
+    /// The fork remembers the current state and the actions it dispatches,
+    /// so it can be merged back with `Store::merge`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::Store;
+    ///
+    /// type MyStore = Vec<u32>;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     RemoveAbove(u32),
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::RemoveAbove(limit) => {
+    ///             state.iter().copied().filter(|value| value <= limit).collect()
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let store = Store::new(reducer, vec![1, 5, 10]);
+    ///
+    /// let mut preview = store.fork();
+    /// preview.dispatch(MyAction::RemoveAbove(4));
+    ///
+    /// assert_eq!(*preview.state(), vec![1]);
+    /// assert_eq!(*store.state(), vec![1, 5, 10]);
+    /// ```
+    pub fn fork(&self) -> Self {
+        let mut fork = Self::with_lazy_state(
+            self.reducer,
+            LazyState::from_shared(self.shared_state()),
+            Arc::clone(&self.env),
+        );
+        fork.version = self.version;
+        fork.fork_base = Some(ForkBase {
+            state: self.shared_state(),
+            actions: Vec::new(),
+        });
+
+        fork
+    }
+}
@@ -0,0 +1,78 @@
+//! Fuzzing integration which feeds arbitrary action sequences into a reducer.
+//!
+//! Derive `Arbitrary` for the action enum and call `fuzz_actions` from a
+//! `cargo fuzz` target. The fuzzer reports every panic of the reducer and
+//! every violated invariant together with the input which caused it.
+//!
+//! Available behind the `fuzz` feature.
+
+use std::fmt::Debug;
+
+pub use arbitrary::{Arbitrary, Unstructured};
+
+use crate::Reducer;
+
+/// Decodes a sequence of actions from the fuzzer `data`, applies them to the
+/// `initial_state` and panics if the `invariant` does not hold after any of them.
+/// Inputs which are too short to decode an action are skipped.
+///
+/// ## Example
+/// ```rust
+/// // fuzz/fuzz_targets/reducer.rs:
+/// //
+/// // #![no_main]
+/// // libfuzzer_sys::fuzz_target!(|data: &[u8]| {
+/// //     redust::fuzz::fuzz_actions(reducer, 0, data, |state| *state <= 100);
+/// // });
+/// use redust::fuzz::{fuzz_actions, Arbitrary};
+///
+/// #[derive(Debug, Arbitrary)]
+/// enum MyAction {
+///     Increment,
+///     IncrementBy(u8),
+/// };
+///
+/// fn reducer(state: &u8, action: &MyAction) -> u8 {
+///     let value = match action {
+///         MyAction::Increment => state.saturating_add(1),
+///         MyAction::IncrementBy(value) => state.saturating_add(*value),
+///     };
+///
+///     value.min(100)
+/// }
+///
+/// fuzz_actions(reducer, 0, &[1, 7, 0, 0, 1, 200], |state| *state <= 100);
+/// ```
+pub fn fuzz_actions<State, Action>(
+    reducer: Reducer<State, Action>,
+    initial_state: State,
+    data: &[u8],
+    invariant: fn(&State) -> bool,
+) where
+    State: Debug,
+    Action: for<'a> Arbitrary<'a> + Debug,
+{
+    let actions = match Vec::<Action>::arbitrary_take_rest(Unstructured::new(data)) {
+        Ok(actions) => actions,
+        Err(_) => return,
+    };
+
+    assert!(
+        invariant(&initial_state),
+        "Invariant does not hold for the initial state {:?}",
+        initial_state
+    );
+
+    let mut state = initial_state;
+    for (index, action) in actions.iter().enumerate() {
+        state = reducer(&state, action);
+
+        assert!(
+            invariant(&state),
+            "Invariant does not hold after action #{} {:?}: {:?}",
+            index,
+            action,
+            state
+        );
+    }
+}
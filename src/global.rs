@@ -0,0 +1,71 @@
+use std::ops::Deref;
+use std::sync::OnceLock;
+
+use crate::{ConcurrentStore, Store};
+
+/// Process-wide store which can be kept in a `static`.
+///
+/// The store is created by `init` on first access and is wrapped into
+/// a `ConcurrentStore`, so any thread may dispatch and read through it.
+///
+/// ## Example
+/// ```rust
+/// use redust::{GlobalStore, Store};
+/// use std::thread;
+///
+/// type MyStore = u8;
+///
+/// #[derive(Debug)]
+/// enum MyAction {
+///     Increment,
+/// };
+///
+/// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+///     match action {
+///         MyAction::Increment => state + 1,
+///     }
+/// }
+///
+/// static STORE: GlobalStore<MyStore, MyAction> = GlobalStore::new(|| Store::new(reducer, 0));
+///
+/// thread::spawn(|| {
+///     STORE.dispatch(MyAction::Increment);
+/// })
+/// .join()
+/// .unwrap();
+///
+/// assert_eq!(*STORE.read(), 1);
+/// ```
+pub struct GlobalStore<State, Action> {
+    store: OnceLock<ConcurrentStore<State, Action>>,
+    init: fn() -> Store<State, Action>,
+}
+
+impl<State, Action> GlobalStore<State, Action> {
+    /// Creates the holder. The store is not created until it is accessed
+    pub const fn new(init: fn() -> Store<State, Action>) -> Self {
+        Self {
+            store: OnceLock::new(),
+            init,
+        }
+    }
+
+    /// Returns the store, creating it on first access
+    pub fn get(&self) -> &ConcurrentStore<State, Action> {
+        self.store
+            .get_or_init(|| ConcurrentStore::new((self.init)()))
+    }
+
+    /// Returns `true` if the store was already created
+    pub fn is_initialized(&self) -> bool {
+        self.store.get().is_some()
+    }
+}
+
+impl<State, Action> Deref for GlobalStore<State, Action> {
+    type Target = ConcurrentStore<State, Action>;
+
+    fn deref(&self) -> &Self::Target {
+        self.get()
+    }
+}
@@ -0,0 +1,429 @@
+//! gRPC service which lets operational tooling dispatch actions into a running
+//! store and observe its state.
+//!
+//! The `redust.Store` service has three RPCs:
+//! - `Dispatch(Payload) returns (Empty)`
+//! - `GetState(Empty) returns (Payload)`
+//! - `WatchState(Empty) returns (stream Payload)`
+//!
+//! where `Payload { bytes data = 1; }` carries the action or the state
+//! encoded with the user-selected `Codec`.
+//!
+//! Available behind the `grpc` feature.
+
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use serde::{de::DeserializeOwned, Serialize};
+use tokio::sync::watch;
+use tokio_stream::wrappers::WatchStream;
+use tokio_stream::StreamExt;
+use tonic::codec::ProstCodec;
+use tonic::codegen::{empty_body, http, Body, BoxFuture, BoxStream, Context, Poll, StdError};
+use tonic::transport::Channel;
+use tonic::{Request, Response, Status};
+
+use crate::codec::{Codec, CodecError};
+use crate::{DispatchQueue, Dispatcher, OverflowPolicy, QueueError, Store};
+
+const DISPATCH_PATH: &str = "/redust.Store/Dispatch";
+const GET_STATE_PATH: &str = "/redust.Store/GetState";
+const WATCH_STATE_PATH: &str = "/redust.Store/WatchState";
+
+/// How many received actions might wait for `pump` by default
+pub const DEFAULT_MAX_PENDING_ACTIONS: usize = 1024;
+
+/// Action or state encoded with the selected `Codec`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Payload {
+    #[prost(bytes = "vec", tag = "1")]
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum GrpcError {
+    Transport(String),
+    Status(String),
+    Codec(CodecError),
+}
+
+impl std::error::Error for GrpcError {}
+impl std::fmt::Display for GrpcError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            GrpcError::Transport(message) => {
+                write!(f, "Cannot connect to the store service: {}", message)
+            }
+            GrpcError::Status(message) => {
+                write!(f, "Cannot call the store service: {}", message)
+            }
+            GrpcError::Codec(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl From<Status> for GrpcError {
+    fn from(status: Status) -> Self {
+        GrpcError::Status(status.message().to_string())
+    }
+}
+
+impl From<CodecError> for GrpcError {
+    fn from(err: CodecError) -> Self {
+        GrpcError::Codec(err)
+    }
+}
+
+/// Owner side of the gRPC service.
+///
+/// Dispatched actions wait in a queue until the owner of the store calls `pump`,
+/// so the store itself never leaves its thread.
+pub struct GrpcStore<State, Action> {
+    queue: DispatchQueue<Action>,
+    snapshot: watch::Sender<Vec<u8>>,
+    codec: Codec,
+    state: PhantomData<fn(&State)>,
+}
+
+impl<State, Action> GrpcStore<State, Action>
+where
+    State: Serialize,
+    Action: DeserializeOwned + Send + 'static,
+{
+    /// Creates the service backend and publishes the current state of the `store`.
+    ///
+    /// Up to `DEFAULT_MAX_PENDING_ACTIONS` actions wait for `pump`, further ones
+    /// are rejected with `Code::ResourceExhausted`.
+    pub fn new(codec: Codec, store: &Store<State, Action>) -> Result<Self, CodecError> {
+        let (snapshot, _) = watch::channel(codec.encode(store.state())?);
+
+        Ok(Self {
+            queue: DispatchQueue::new()
+                .bounded(DEFAULT_MAX_PENDING_ACTIONS, OverflowPolicy::Reject),
+            snapshot,
+            codec,
+            state: PhantomData,
+        })
+    }
+
+    /// Limits how many received actions might wait for `pump`. The `policy`
+    /// defines what happens with an action received while the queue is full.
+    ///
+    /// `OverflowPolicy::Block` blocks the runtime thread serving the call
+    /// until `pump` drains the queue.
+    pub fn with_max_pending_actions(mut self, capacity: usize, policy: OverflowPolicy) -> Self {
+        self.queue = self.queue.bounded(capacity, policy);
+
+        self
+    }
+
+    /// Returns the tonic service to add to `tonic::transport::Server`
+    pub fn service(&self) -> StoreService<Action> {
+        StoreService {
+            inner: Arc::new(ServiceInner {
+                dispatcher: self.queue.dispatcher(),
+                snapshot: self.snapshot.subscribe(),
+                codec: self.codec,
+            }),
+        }
+    }
+
+    /// Dispatches all received actions into the `store` and publishes the new
+    /// state to watchers. Returns how many actions were dispatched.
+    pub fn pump(&self, store: &mut Store<State, Action>) -> Result<usize, CodecError> {
+        let dispatched = store.drain(&self.queue);
+        if dispatched > 0 {
+            self.publish(store)?;
+        }
+
+        Ok(dispatched)
+    }
+
+    /// Publishes the current state of the `store` to watchers
+    pub fn publish(&self, store: &Store<State, Action>) -> Result<(), CodecError> {
+        self.snapshot
+            .send_replace(self.codec.encode(store.state())?);
+
+        Ok(())
+    }
+}
+
+struct ServiceInner<Action> {
+    dispatcher: Dispatcher<Action>,
+    snapshot: watch::Receiver<Vec<u8>>,
+    codec: Codec,
+}
+
+/// Tonic service created by `GrpcStore::service`
+pub struct StoreService<Action> {
+    inner: Arc<ServiceInner<Action>>,
+}
+
+impl<Action> Clone for StoreService<Action> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<Action> tonic::server::NamedService for StoreService<Action> {
+    const NAME: &'static str = "redust.Store";
+}
+
+struct DispatchMethod<Action>(Arc<ServiceInner<Action>>);
+
+impl<Action: DeserializeOwned + Send + 'static> tonic::server::UnaryService<Payload>
+    for DispatchMethod<Action>
+{
+    type Response = ();
+    type Future = BoxFuture<Response<()>, Status>;
+
+    fn call(&mut self, request: Request<Payload>) -> Self::Future {
+        let inner = Arc::clone(&self.0);
+        Box::pin(async move {
+            let action = inner
+                .codec
+                .decode(&request.into_inner().data)
+                .map_err(|err| Status::invalid_argument(err.to_string()))?;
+
+            inner.dispatcher.dispatch(action).map_err(|err| match err {
+                QueueError::Full => Status::resource_exhausted(err.to_string()),
+                QueueError::Closed => Status::unavailable(err.to_string()),
+            })?;
+
+            Ok(Response::new(()))
+        })
+    }
+}
+
+struct GetStateMethod<Action>(Arc<ServiceInner<Action>>);
+
+impl<Action> tonic::server::UnaryService<()> for GetStateMethod<Action> {
+    type Response = Payload;
+    type Future = BoxFuture<Response<Payload>, Status>;
+
+    fn call(&mut self, _request: Request<()>) -> Self::Future {
+        let data = self.0.snapshot.borrow().clone();
+
+        Box::pin(async move { Ok(Response::new(Payload { data })) })
+    }
+}
+
+struct WatchStateMethod<Action>(Arc<ServiceInner<Action>>);
+
+impl<Action> tonic::server::ServerStreamingService<()> for WatchStateMethod<Action> {
+    type Response = Payload;
+    type ResponseStream = BoxStream<Payload>;
+    type Future = BoxFuture<Response<Self::ResponseStream>, Status>;
+
+    fn call(&mut self, _request: Request<()>) -> Self::Future {
+        let states = WatchStream::new(self.0.snapshot.clone())
+            .map(|data| Payload { data })
+            .map(Ok);
+        let stream: Self::ResponseStream = Box::pin(states);
+
+        Box::pin(async move { Ok(Response::new(stream)) })
+    }
+}
+
+impl<Action, B> tonic::codegen::Service<http::Request<B>> for StoreService<Action>
+where
+    Action: DeserializeOwned + Send + 'static,
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<tonic::body::BoxBody>;
+    type Error = std::convert::Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let inner = Arc::clone(&self.inner);
+        match request.uri().path() {
+            DISPATCH_PATH => Box::pin(async move {
+                let mut grpc = tonic::server::Grpc::new(ProstCodec::<(), Payload>::default());
+
+                Ok(grpc.unary(DispatchMethod(inner), request).await)
+            }),
+            GET_STATE_PATH => Box::pin(async move {
+                let mut grpc = tonic::server::Grpc::new(ProstCodec::<Payload, ()>::default());
+
+                Ok(grpc.unary(GetStateMethod(inner), request).await)
+            }),
+            WATCH_STATE_PATH => Box::pin(async move {
+                let mut grpc = tonic::server::Grpc::new(ProstCodec::<Payload, ()>::default());
+
+                Ok(grpc
+                    .server_streaming(WatchStateMethod(inner), request)
+                    .await)
+            }),
+            _ => Box::pin(async move {
+                let mut response = http::Response::new(empty_body());
+                let headers = response.headers_mut();
+                headers.insert("grpc-status", (tonic::Code::Unimplemented as i32).into());
+                headers.insert(
+                    http::header::CONTENT_TYPE,
+                    http::HeaderValue::from_static("application/grpc"),
+                );
+
+                Ok(response)
+            }),
+        }
+    }
+}
+
+/// Client of the `redust.Store` gRPC service
+///
+/// ## Example
+/// ```rust
+/// use redust::codec::Codec;
+/// use redust::grpc::{GrpcClient, GrpcStore};
+/// use redust::Store;
+/// use serde::{Deserialize, Serialize};
+///
+/// type MyStore = u8;
+///
+/// #[derive(Debug, Serialize, Deserialize)]
+/// enum MyAction {
+///     Increment,
+/// };
+///
+/// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+///     match action {
+///         MyAction::Increment => state + 1,
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut store = Store::new(reducer, 0);
+///     let grpc = GrpcStore::new(Codec::Json, &store).unwrap();
+///
+///     let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+///     let endpoint = format!("http://{}", listener.local_addr().unwrap());
+///     tokio::spawn(
+///         tonic::transport::Server::builder()
+///             .add_service(grpc.service())
+///             .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener)),
+///     );
+///
+///     let mut client = GrpcClient::<MyStore, MyAction>::connect(endpoint, Codec::Json)
+///         .await
+///         .unwrap();
+///     client.dispatch(&MyAction::Increment).await.unwrap();
+///
+///     grpc.pump(&mut store).unwrap();
+///
+///     assert_eq!(client.state().await.unwrap(), 1);
+/// }
+/// ```
+pub struct GrpcClient<State, Action> {
+    grpc: tonic::client::Grpc<Channel>,
+    codec: Codec,
+    types: PhantomData<fn(&Action) -> State>,
+}
+
+impl<State, Action> GrpcClient<State, Action>
+where
+    State: DeserializeOwned,
+    Action: Serialize,
+{
+    /// Connects to the service, e.g. `http://127.0.0.1:50051`.
+    /// The `codec` must match the server's one
+    pub async fn connect(endpoint: String, codec: Codec) -> Result<Self, GrpcError> {
+        let channel = Channel::from_shared(endpoint)
+            .map_err(|err| GrpcError::Transport(err.to_string()))?
+            .connect()
+            .await
+            .map_err(|err| GrpcError::Transport(err.to_string()))?;
+
+        Ok(Self {
+            grpc: tonic::client::Grpc::new(channel),
+            codec,
+            types: PhantomData,
+        })
+    }
+
+    /// Sends the action to the store. It is dispatched on the next `GrpcStore::pump`
+    pub async fn dispatch(&mut self, action: &Action) -> Result<(), GrpcError> {
+        let payload = Payload {
+            data: self.codec.encode(action)?,
+        };
+
+        self.ready().await?;
+        self.grpc
+            .unary(
+                Request::new(payload),
+                http::uri::PathAndQuery::from_static(DISPATCH_PATH),
+                ProstCodec::<Payload, ()>::default(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Returns the latest state published by the store
+    pub async fn state(&mut self) -> Result<State, GrpcError> {
+        self.ready().await?;
+        let payload = self
+            .grpc
+            .unary(
+                Request::new(()),
+                http::uri::PathAndQuery::from_static(GET_STATE_PATH),
+                ProstCodec::<(), Payload>::default(),
+            )
+            .await?
+            .into_inner();
+
+        Ok(self.codec.decode(&payload.data)?)
+    }
+
+    /// Returns the stream of published states, starting with the current one
+    pub async fn watch_state(&mut self) -> Result<StateStream<State>, GrpcError> {
+        self.ready().await?;
+        let stream = self
+            .grpc
+            .server_streaming(
+                Request::new(()),
+                http::uri::PathAndQuery::from_static(WATCH_STATE_PATH),
+                ProstCodec::<(), Payload>::default(),
+            )
+            .await?
+            .into_inner();
+
+        Ok(StateStream {
+            stream,
+            codec: self.codec,
+            state: PhantomData,
+        })
+    }
+
+    async fn ready(&mut self) -> Result<(), GrpcError> {
+        self.grpc
+            .ready()
+            .await
+            .map_err(|err| GrpcError::Transport(err.to_string()))
+    }
+}
+
+/// States published by the store, returned by `GrpcClient::watch_state`
+pub struct StateStream<State> {
+    stream: tonic::Streaming<Payload>,
+    codec: Codec,
+    state: PhantomData<fn() -> State>,
+}
+
+impl<State: DeserializeOwned> StateStream<State> {
+    /// Waits for the next published state. Returns `None` when the server closed the stream
+    pub async fn next(&mut self) -> Option<Result<State, GrpcError>> {
+        match self.stream.message().await {
+            Ok(Some(payload)) => Some(self.codec.decode(&payload.data).map_err(GrpcError::from)),
+            Ok(None) => None,
+            Err(status) => Some(Err(status.into())),
+        }
+    }
+}
@@ -0,0 +1,401 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::Store;
+
+/// Measures how many bytes a state snapshot takes
+pub type StateSize<State> = fn(&State) -> usize;
+
+/// Defines how much history the store keeps. Without limits the history grows
+/// with every action, which is not an option for long-running processes.
+///
+/// The oldest entries are dropped first when any limit is exceeded.
+pub struct HistoryPolicy<State> {
+    max_entries: Option<usize>,
+    max_memory: Option<(usize, StateSize<State>)>,
+    max_age: Option<Duration>,
+    snapshot_every: usize,
+}
+
+impl<State> HistoryPolicy<State> {
+    /// Keeps every action and every state
+    pub fn unbounded() -> Self {
+        Self {
+            max_entries: None,
+            max_memory: None,
+            max_age: None,
+            snapshot_every: 1,
+        }
+    }
+
+    /// Keeps at most `entries` actions
+    pub fn max_entries(mut self, entries: usize) -> Self {
+        self.max_entries = Some(entries);
+
+        self
+    }
+
+    /// Keeps snapshots which take at most `bytes` together, as measured by `size_of`
+    pub fn max_memory(mut self, bytes: usize, size_of: StateSize<State>) -> Self {
+        self.max_memory = Some((bytes, size_of));
+
+        self
+    }
+
+    /// Drops actions recorded more than `age` ago
+    pub fn max_age(mut self, age: Duration) -> Self {
+        self.max_age = Some(age);
+
+        self
+    }
+
+    /// Keeps all actions but only every `nth` state. Other states are
+    /// recomputed by the reducer from the closest older snapshot when requested
+    pub fn snapshot_every(mut self, nth: usize) -> Self {
+        self.snapshot_every = nth.max(1);
+
+        self
+    }
+}
+
+impl<State> Default for HistoryPolicy<State> {
+    fn default() -> Self {
+        Self::unbounded()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum HistoryError {
+    /// History was not enabled with `Store::enable_history`
+    Disabled,
+
+    /// The requested index or number of steps is beyond the kept states
+    OutOfRange(usize),
+}
+
+impl std::error::Error for HistoryError {}
+impl std::fmt::Display for HistoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            HistoryError::Disabled => write!(f, "Cannot travel in time: history is disabled"),
+            HistoryError::OutOfRange(position) => write!(
+                f,
+                "Cannot travel in time: {} is beyond the kept history",
+                position
+            ),
+        }
+    }
+}
+
+struct HistoryEntry<State, Action> {
+    action: Action,
+    state: Option<Arc<State>>,
+    recorded_at: Instant,
+}
+
+/// Position in the timeline which `History::rollback` returns to
+pub(crate) struct Checkpoint {
+    // Recorded actions which stay on the timeline once the next one is recorded
+    recorded: usize,
+}
+
+/// Actions reduced by the store together with snapshots of the states
+pub(crate) struct History<State, Action> {
+    policy: HistoryPolicy<State>,
+    clone_action: fn(&Action) -> Action,
+    // State before the oldest kept action
+    base: Arc<State>,
+    entries: VecDeque<HistoryEntry<State, Action>>,
+    recorded: usize,
+    memory: usize,
+    // Index of the state restored by time travel, later states are dropped on the next record
+    travelled_to: Option<usize>,
+}
+
+impl<State, Action> History<State, Action> {
+    /// Records the reduced action and the state it produced, then drops
+    /// the oldest entries which exceed the policy limits
+    pub(crate) fn record(
+        &mut self,
+        action: &Action,
+        state: Arc<State>,
+        reducer: &dyn Fn(&State, &Action) -> State,
+        now: Instant,
+    ) {
+        self.push(action, state, reducer, now, false);
+    }
+
+    /// Records an action which the store reducer does not reproduce, e.g. one
+    /// dispatched to a single slice, so its state is always kept as a snapshot
+    pub(crate) fn record_snapshot(
+        &mut self,
+        action: &Action,
+        state: Arc<State>,
+        reducer: &dyn Fn(&State, &Action) -> State,
+        now: Instant,
+    ) {
+        self.push(action, state, reducer, now, true);
+    }
+
+    fn push(
+        &mut self,
+        action: &Action,
+        state: Arc<State>,
+        reducer: &dyn Fn(&State, &Action) -> State,
+        now: Instant,
+        snapshot: bool,
+    ) {
+        if let Some(index) = self.travelled_to.take() {
+            self.truncate(index);
+        }
+
+        self.recorded += 1;
+        let state = if snapshot || self.recorded % self.policy.snapshot_every == 0 {
+            self.memory += self.size_of(&state);
+            Some(state)
+        } else {
+            None
+        };
+
+        self.entries.push_back(HistoryEntry {
+            action: (self.clone_action)(action),
+            state,
+            recorded_at: now,
+        });
+
+        while self.exceeds_limits(now) {
+            self.evict(reducer);
+        }
+    }
+
+    pub(crate) fn checkpoint(&self) -> Checkpoint {
+        let undone = self
+            .travelled_to
+            .map_or(0, |index| self.entries.len() - index);
+
+        Checkpoint {
+            recorded: self.recorded - undone,
+        }
+    }
+
+    /// Drops the entries recorded since the `checkpoint`. Entries dropped in
+    /// the meantime by time travel or the policy limits are not restored
+    pub(crate) fn rollback(&mut self, checkpoint: Checkpoint) {
+        if self.travelled_to.is_some() {
+            // Nothing was recorded since the time travel
+            return;
+        }
+
+        let added = self.recorded.saturating_sub(checkpoint.recorded);
+        self.truncate(self.entries.len().saturating_sub(added));
+    }
+
+    fn exceeds_limits(&self, now: Instant) -> bool {
+        let oldest = match self.entries.front() {
+            Some(oldest) => oldest,
+            None => return false,
+        };
+
+        let too_many = matches!(self.policy.max_entries, Some(max) if self.entries.len() > max);
+        let too_big = matches!(self.policy.max_memory, Some((max, _)) if self.memory > max);
+        let too_old = matches!(self.policy.max_age,
+            Some(age) if now.saturating_duration_since(oldest.recorded_at) > age);
+
+        too_many || too_big || too_old
+    }
+
+    fn evict(&mut self, reducer: &dyn Fn(&State, &Action) -> State) {
+        if let Some(oldest) = self.entries.pop_front() {
+            self.base = match oldest.state {
+                Some(state) => {
+                    self.memory -= self.size_of(&state);
+                    state
+                }
+                None => Arc::new(reducer(&self.base, &oldest.action)),
+            };
+        }
+    }
+
+    /// Drops the entries after the state with the `index`, so the next action
+    /// continues the timeline from that state
+    fn truncate(&mut self, index: usize) {
+        let dropped = self.entries.split_off(index);
+        self.recorded -= dropped.len();
+        for state in dropped.into_iter().filter_map(|entry| entry.state) {
+            self.memory -= self.size_of(&state);
+        }
+    }
+
+    fn size_of(&self, state: &State) -> usize {
+        self.policy
+            .max_memory
+            .map(|(_, size_of)| size_of(state))
+            .unwrap_or(0)
+    }
+
+    /// Number of kept states including the one before the oldest action
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len() + 1
+    }
+
+    /// Returns the kept state with the `index`, the oldest one is 0
+    pub(crate) fn state(
+        &self,
+        index: usize,
+        reducer: &dyn Fn(&State, &Action) -> State,
+    ) -> Option<Arc<State>> {
+        if index >= self.len() {
+            return None;
+        }
+
+        // Replay from the closest snapshot which is not newer than the requested state
+        let snapshot = (1..=index)
+            .rev()
+            .find_map(|position| {
+                let state = self.entries[position - 1].state.as_ref()?;
+                Some((position, Arc::clone(state)))
+            })
+            .unwrap_or_else(|| (0, Arc::clone(&self.base)));
+
+        let (position, state) = snapshot;
+        Some(
+            self.entries
+                .range(position..index)
+                .fold(state, |state, entry| {
+                    Arc::new(reducer(&state, &entry.action))
+                }),
+        )
+    }
+
+    /// Returns the kept snapshots including the state before the oldest action
+    pub(crate) fn snapshots(&self) -> impl Iterator<Item = &Arc<State>> {
+        let entries = self.entries.iter().filter_map(|entry| entry.state.as_ref());
+        std::iter::once(&self.base).chain(entries)
+    }
+
+    /// Returns kept actions with their sequence number among all recorded
+    /// actions and the time they were recorded
+    #[cfg(feature = "serde")]
+    pub(crate) fn records(&self) -> impl Iterator<Item = (usize, Instant, &Action)> {
+        let first = self.recorded - self.entries.len();
+        self.entries
+            .iter()
+            .enumerate()
+            .map(move |(index, entry)| (first + index, entry.recorded_at, &entry.action))
+    }
+
+    pub(crate) fn actions(&self) -> impl Iterator<Item = &Action> {
+        self.entries.iter().map(|entry| &entry.action)
+    }
+}
+
+fn clone_action<Action: Clone>(action: &Action) -> Action {
+    action.clone()
+}
+
+impl<State, Action: Clone, Env> Store<State, Action, Env> {
+    /// Starts recording reduced actions and produced states for time travel.
+    /// The `policy` bounds how much history is kept.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::{HistoryPolicy, Store};
+    ///
+    /// type MyStore = u8;
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum MyAction {
+    ///     Increment,
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Increment => state + 1,
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, 0);
+    /// store.enable_history(HistoryPolicy::unbounded().max_entries(2).snapshot_every(10));
+    ///
+    /// store
+    ///     .dispatch(MyAction::Increment)
+    ///     .dispatch(MyAction::Increment)
+    ///     .dispatch(MyAction::Increment);
+    ///
+    /// // The state before the oldest kept action and two more
+    /// assert_eq!(store.history_len(), 3);
+    /// assert_eq!(store.history_state(0).as_deref(), Some(&1));
+    ///
+    /// store.travel_back(2).unwrap();
+    /// assert_eq!(*store.state(), 1);
+    /// ```
+    pub fn enable_history(&mut self, policy: HistoryPolicy<State>) {
+        self.history = Some(History {
+            policy,
+            clone_action: clone_action::<Action>,
+            base: self.shared_state(),
+            entries: VecDeque::new(),
+            recorded: 0,
+            memory: 0,
+            travelled_to: None,
+        });
+    }
+}
+
+impl<State, Action, Env> Store<State, Action, Env> {
+    /// Stops recording and drops the history
+    pub fn disable_history(&mut self) {
+        self.history = None;
+    }
+
+    /// Returns the number of kept states, including the state before the
+    /// oldest kept action. Zero when the history is disabled
+    pub fn history_len(&self) -> usize {
+        self.history.as_ref().map(History::len).unwrap_or(0)
+    }
+
+    /// Returns the kept state with the `index`, the oldest one is 0
+    pub fn history_state(&self, index: usize) -> Option<Arc<State>> {
+        let reduce = |state: &State, action: &Action| self.reducer.reduce(state, action, &self.env);
+        self.history.as_ref()?.state(index, &reduce)
+    }
+
+    /// Returns kept actions from the oldest to the latest one
+    pub fn history_actions(&self) -> Vec<&Action> {
+        self.history
+            .as_ref()
+            .map(|history| history.actions().collect())
+            .unwrap_or_default()
+    }
+
+    /// Restores the state reduced `steps` actions before the current one, which
+    /// is the latest one or the one restored by the previous time travel.
+    /// The history is kept, so it is possible to travel forward again with `travel_to`
+    /// until the next dispatch, which drops the states after the restored one like redo after undo
+    pub fn travel_back(&mut self, steps: usize) -> Result<&mut Self, HistoryError> {
+        let history = self.history.as_ref().ok_or(HistoryError::Disabled)?;
+        let current = history.travelled_to.unwrap_or(history.len() - 1);
+        let index = current
+            .checked_sub(steps)
+            .ok_or(HistoryError::OutOfRange(steps))?;
+
+        self.travel_to(index)
+    }
+
+    /// Restores the kept state with the `index`, the oldest one is 0.
+    /// Subscribers are notified, but the history does not record the change
+    pub fn travel_to(&mut self, index: usize) -> Result<&mut Self, HistoryError> {
+        let (reducer, env) = (self.reducer, &self.env);
+        let reduce = |state: &State, action: &Action| reducer.reduce(state, action, env);
+        let history = self.history.as_mut().ok_or(HistoryError::Disabled)?;
+        let state = history
+            .state(index, &reduce)
+            .ok_or(HistoryError::OutOfRange(index))?;
+        history.travelled_to = Some(index);
+
+        self.replace_state(state);
+
+        Ok(self)
+    }
+}
@@ -0,0 +1,93 @@
+use std::collections::BTreeMap;
+
+use crate::subscription::{SubscriberId, SubscriptionToken};
+use crate::{Store, UnsubscribeError};
+
+/// Hook called with the action before the reducer runs
+pub type BeforeDispatchHook<State, Action> = fn(&Action, &State);
+
+/// Hook called with the action, the previous and the new state after dispatch
+pub type AfterDispatchHook<State, Action> = fn(&Action, &State, &State);
+
+pub(crate) struct Hooks<State, Action> {
+    pub(crate) before: BTreeMap<SubscriberId, BeforeDispatchHook<State, Action>>,
+    pub(crate) after: BTreeMap<SubscriberId, AfterDispatchHook<State, Action>>,
+}
+
+impl<State, Action> Hooks<State, Action> {
+    pub(crate) fn new() -> Self {
+        Self {
+            before: BTreeMap::new(),
+            after: BTreeMap::new(),
+        }
+    }
+}
+
+impl<State, Action, Env> Store<State, Action, Env> {
+    /// Registers a hook which is called before the reducer with every dispatched action.
+    ///
+    /// Hooks are a lighter-weight extension point than middleware, suitable for
+    /// auditing and instrumentation. They are called in registration order.
+    pub fn on_before_dispatch(
+        &mut self,
+        hook: BeforeDispatchHook<State, Action>,
+    ) -> SubscriptionToken<State, Action> {
+        let token = self.next_subscription_token();
+        self.hooks.before.insert(token.id(), hook);
+
+        token
+    }
+
+    /// Registers a hook which is called after dispatch with the action,
+    /// the previous state and the new state.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::Store;
+    ///
+    /// type MyStore = u8;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Increment,
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Increment => state + 1,
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, 0);
+    ///
+    /// store.on_after_dispatch(|action, old_state, new_state| {
+    ///     println!("{:?}: {} -> {}", action, old_state, new_state);
+    ///     assert_eq!(*new_state, old_state + 1);
+    /// });
+    ///
+    /// store.dispatch(MyAction::Increment);
+    /// ```
+    pub fn on_after_dispatch(
+        &mut self,
+        hook: AfterDispatchHook<State, Action>,
+    ) -> SubscriptionToken<State, Action> {
+        let token = self.next_subscription_token();
+        self.hooks.after.insert(token.id(), hook);
+
+        token
+    }
+
+    /// Removes a hook by the token returned from `on_before_dispatch` or `on_after_dispatch`
+    pub fn remove_hook(
+        &mut self,
+        token: SubscriptionToken<State, Action>,
+    ) -> Result<(), UnsubscribeError> {
+        let id = self.check_token(token)?;
+
+        if self.hooks.before.remove(&id).is_none() && self.hooks.after.remove(&id).is_none() {
+            return Err(UnsubscribeError::WrongToken(id));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,413 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex, MutexGuard, Weak};
+
+use crate::subscription::{StoreObserver, Subscriber, SubscriptionToken};
+use crate::Store;
+
+type Value = Box<dyn Any + Send + Sync>;
+type Compute<State> = Arc<dyn Fn(&mut QueryContext<'_, State>) -> Value + Send + Sync>;
+
+/// Memoized computation over the state, read with `QueryDatabase::get`.
+///
+/// The `compute` function reads the state and other queries through the
+/// `QueryContext`, which records what the result depends on. Names identify
+/// the memoized results, so every query needs a unique one.
+pub struct Query<State, Key, Output> {
+    name: &'static str,
+    compute: fn(&mut QueryContext<'_, State>, &Key) -> Output,
+}
+
+impl<State, Key, Output> Clone for Query<State, Key, Output> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<State, Key, Output> Copy for Query<State, Key, Output> {}
+
+impl<State, Key, Output> Query<State, Key, Output> {
+    pub const fn new(
+        name: &'static str,
+        compute: fn(&mut QueryContext<'_, State>, &Key) -> Output,
+    ) -> Self {
+        Self { name, compute }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+enum Dependency<State> {
+    /// Returns `true` if the value read from the state is still the same
+    Input(Box<dyn Fn(&State) -> bool + Send + Sync>),
+    Query(usize),
+}
+
+struct Memo<State> {
+    value: Value,
+    dependencies: Vec<Dependency<State>>,
+    // Revision when the memo was last checked against the state
+    verified_at: u64,
+    // Revision when the value last changed, stays the same when a computation
+    // produces an equal value
+    changed_at: u64,
+}
+
+struct Slot<State> {
+    name: &'static str,
+    compute: Compute<State>,
+    equal: fn(&Value, &Value) -> bool,
+    memo: Option<Memo<State>>,
+    active: bool,
+}
+
+struct Database<State> {
+    state: Arc<State>,
+    revision: u64,
+    slots: Vec<Slot<State>>,
+    // Slots by the key, one `HashMap<Key, usize>` per query name
+    keys: HashMap<&'static str, Box<dyn Any + Send + Sync>>,
+    executions: u64,
+}
+
+impl<State: 'static> Database<State> {
+    fn slot<Key, Output>(&mut self, query: Query<State, Key, Output>, key: Key) -> usize
+    where
+        Key: Clone + Eq + Hash + Send + Sync + 'static,
+        Output: PartialEq + Send + Sync + 'static,
+    {
+        let next = self.slots.len();
+        let slots = self
+            .keys
+            .entry(query.name)
+            .or_insert_with(|| Box::new(HashMap::<Key, usize>::new()))
+            .downcast_mut::<HashMap<Key, usize>>()
+            .unwrap_or_else(|| panic!("Query name `{}` is used twice", query.name));
+
+        if let Some(index) = slots.get(&key) {
+            return *index;
+        }
+        slots.insert(key.clone(), next);
+
+        let compute: Compute<State> =
+            Arc::new(move |context| Box::new((query.compute)(context, &key)));
+        self.slots.push(Slot {
+            name: query.name,
+            compute,
+            equal: |value, new_value| {
+                value.downcast_ref::<Output>() == new_value.downcast_ref::<Output>()
+            },
+            memo: None,
+            active: false,
+        });
+
+        next
+    }
+
+    fn value<Output: Clone + 'static>(&self, index: usize) -> Output {
+        let slot = &self.slots[index];
+        slot.memo
+            .as_ref()
+            .and_then(|memo| memo.value.downcast_ref::<Output>())
+            .unwrap_or_else(|| panic!("Query name `{}` is used twice", slot.name))
+            .clone()
+    }
+
+    /// Brings the memo of the slot up to the current revision
+    fn ensure(&mut self, index: usize) {
+        let slot = &mut self.slots[index];
+        if matches!(&slot.memo, Some(memo) if memo.verified_at == self.revision) {
+            return;
+        }
+        assert!(
+            !slot.active,
+            "Cannot compute the query `{}`, it depends on itself",
+            slot.name
+        );
+
+        slot.active = true;
+        if !self.verify(index) {
+            self.execute(index);
+        }
+        self.slots[index].active = false;
+    }
+
+    /// Checks whether any dependency changed since the memo was verified,
+    /// marks the memo as verified if none did
+    fn verify(&mut self, index: usize) -> bool {
+        let mut memo = match self.slots[index].memo.take() {
+            Some(memo) => memo,
+            None => return false,
+        };
+
+        let unchanged = memo.dependencies.iter().all(|dependency| match dependency {
+            Dependency::Input(unchanged) => unchanged(&self.state),
+            Dependency::Query(dependency) => {
+                self.ensure(*dependency);
+                self.slots[*dependency]
+                    .memo
+                    .as_ref()
+                    .is_some_and(|dependency| dependency.changed_at <= memo.verified_at)
+            }
+        });
+        if unchanged {
+            memo.verified_at = self.revision;
+        }
+        self.slots[index].memo = Some(memo);
+
+        unchanged
+    }
+
+    fn execute(&mut self, index: usize) {
+        let compute = Arc::clone(&self.slots[index].compute);
+        let mut context = QueryContext {
+            database: self,
+            dependencies: vec![],
+        };
+        let value = compute(&mut context);
+        let dependencies = context.dependencies;
+        self.executions += 1;
+
+        let revision = self.revision;
+        let slot = &mut self.slots[index];
+        let changed_at = match slot.memo.take() {
+            Some(memo) if (slot.equal)(&memo.value, &value) => memo.changed_at,
+            _ => revision,
+        };
+        slot.memo = Some(Memo {
+            value,
+            dependencies,
+            verified_at: revision,
+            changed_at,
+        });
+    }
+
+    fn update(&mut self, state: &Arc<State>) {
+        if !Arc::ptr_eq(&self.state, state) {
+            self.state = Arc::clone(state);
+            self.revision += 1;
+        }
+    }
+}
+
+/// Gives a running query access to the state and to other queries,
+/// recording everything it reads as a dependency
+pub struct QueryContext<'a, State> {
+    database: &'a mut Database<State>,
+    dependencies: Vec<Dependency<State>>,
+}
+
+impl<State: 'static> QueryContext<'_, State> {
+    /// Reads a part of the state. The query is computed again only when
+    /// the `select` function returns a different value
+    pub fn input<Input>(&mut self, select: fn(&State) -> Input) -> Input
+    where
+        Input: Clone + PartialEq + Send + Sync + 'static,
+    {
+        let value = select(&self.database.state);
+        let read = value.clone();
+        self.dependencies
+            .push(Dependency::Input(Box::new(move |state| {
+                select(state) == read
+            })));
+
+        value
+    }
+
+    /// Reads the result of another query, computing it if needed.
+    /// Panics if the queries depend on each other in a cycle
+    pub fn query<Key, Output>(&mut self, query: &Query<State, Key, Output>, key: Key) -> Output
+    where
+        Key: Clone + Eq + Hash + Send + Sync + 'static,
+        Output: Clone + PartialEq + Send + Sync + 'static,
+    {
+        let index = self.database.slot(*query, key);
+        self.database.ensure(index);
+        self.dependencies.push(Dependency::Query(index));
+
+        self.database.value(index)
+    }
+}
+
+fn lock<State>(database: &Mutex<Database<State>>) -> MutexGuard<'_, Database<State>> {
+    database.lock().unwrap_or_else(|poisoned| {
+        database.clear_poison();
+        // A panicking query leaves the slots it was computing active. Their memos
+        // are either taken or not verified, so they are checked on the next access
+        let mut database = poisoned.into_inner();
+        database
+            .slots
+            .iter_mut()
+            .for_each(|slot| slot.active = false);
+
+        database
+    })
+}
+
+/// Memoized queries over the store state with fine-grained dependencies.
+///
+/// Queries are computed lazily on `get`. A dispatch only advances the
+/// revision. On the next `get` a memo is reused if none of the state parts
+/// and queries it read changed. Queries which produce a value equal to the
+/// previous one do not invalidate their dependents, so a change stops
+/// propagating as soon as an intermediate result stays the same.
+///
+/// Unlike `DerivedState`, which recomputes selectors eagerly after every
+/// dispatch, the database suits expensive computations of which only a part
+/// is read at a time, e.g. analysis of a document in an editor.
+///
+/// Created with `Store::query_database` and removed with `Store::unsubscribe`
+/// called with its `token`. Once all handles are dropped, dispatches no longer
+/// touch the database.
+pub struct QueryDatabase<State, Action> {
+    database: Arc<Mutex<Database<State>>>,
+    token: SubscriptionToken<State, Action>,
+}
+
+impl<State, Action> Clone for QueryDatabase<State, Action> {
+    fn clone(&self) -> Self {
+        Self {
+            database: Arc::clone(&self.database),
+            token: self.token,
+        }
+    }
+}
+
+impl<State: 'static, Action> QueryDatabase<State, Action> {
+    /// Returns the result of the `query` for the `key`, computed with the
+    /// current state. Panics if the queries depend on each other in a cycle
+    pub fn get<Key, Output>(&self, query: &Query<State, Key, Output>, key: Key) -> Output
+    where
+        Key: Clone + Eq + Hash + Send + Sync + 'static,
+        Output: Clone + PartialEq + Send + Sync + 'static,
+    {
+        let mut database = lock(&self.database);
+        let index = database.slot(*query, key);
+        database.ensure(index);
+
+        database.value(index)
+    }
+
+    /// Returns the number of state changes seen by the database
+    pub fn revision(&self) -> u64 {
+        lock(&self.database).revision
+    }
+
+    /// Returns how many times queries were computed, including the first time
+    pub fn executions(&self) -> u64 {
+        lock(&self.database).executions
+    }
+
+    /// Drops all memoized results
+    pub fn clear(&self) {
+        let mut database = lock(&self.database);
+        database.slots.clear();
+        database.keys.clear();
+    }
+
+    /// Returns the token which removes the database from the store
+    pub fn token(&self) -> SubscriptionToken<State, Action> {
+        self.token
+    }
+}
+
+/// Advances the revision of the database when the store state changes.
+/// It stops when all handles of the database are dropped
+struct DatabaseObserver<State>(Weak<Mutex<Database<State>>>);
+
+impl<State: 'static> DatabaseObserver<State> {
+    fn update(&self, state: &Arc<State>) {
+        if let Some(database) = self.0.upgrade() {
+            lock(&database).update(state);
+        }
+    }
+}
+
+impl<State: 'static, Action> StoreObserver<State, Action> for DatabaseObserver<State> {
+    fn on_state(&mut self, state: &Arc<State>) {
+        self.update(state);
+    }
+
+    // Actions are delivered while notifications are paused, so the database never goes stale
+    fn on_action(&mut self, _action: &Action, state: &Arc<State>) {
+        self.update(state);
+    }
+}
+
+impl<State, Action, Env> Store<State, Action, Env>
+where
+    State: Send + Sync + 'static,
+    Action: 'static,
+{
+    /// Creates an empty query database which follows the state
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::{Query, QueryContext, Store};
+    ///
+    /// #[derive(Clone)]
+    /// struct Editor {
+    ///     text: String,
+    ///     cursor: usize,
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Type(char),
+    ///     Move(usize),
+    /// };
+    ///
+    /// fn reducer(state: &Editor, action: &MyAction) -> Editor {
+    ///     let mut new_state = state.clone();
+    ///     match action {
+    ///         MyAction::Type(letter) => new_state.text.push(*letter),
+    ///         MyAction::Move(cursor) => new_state.cursor = *cursor,
+    ///     }
+    ///
+    ///     new_state
+    /// }
+    ///
+    /// const LINE: Query<Editor, usize, String> = Query::new("line", |context, line| {
+    ///     let text = context.input(|editor| editor.text.clone());
+    ///     text.lines().nth(*line).unwrap_or_default().to_string()
+    /// });
+    ///
+    /// const WORDS: Query<Editor, usize, usize> = Query::new("words", |context, line| {
+    ///     context.query(&LINE, *line).split_whitespace().count()
+    /// });
+    ///
+    /// let mut store = Store::new(reducer, Editor { text: "let a\n".to_string(), cursor: 0 });
+    /// let queries = store.query_database();
+    /// assert_eq!(queries.get(&WORDS, 0), 2);
+    ///
+    /// // The text did not change, nothing is computed again
+    /// store.dispatch(MyAction::Move(3));
+    /// assert_eq!(queries.get(&WORDS, 0), 2);
+    /// assert_eq!(queries.executions(), 2);
+    ///
+    /// // The second line changed, but the first line is the same, so `WORDS` is reused
+    /// store.dispatch(MyAction::Type('x'));
+    /// assert_eq!(queries.get(&WORDS, 0), 2);
+    /// assert_eq!(queries.executions(), 3);
+    /// ```
+    pub fn query_database(&mut self) -> QueryDatabase<State, Action> {
+        let database = Arc::new(Mutex::new(Database {
+            state: self.shared_state(),
+            revision: 0,
+            slots: vec![],
+            keys: HashMap::new(),
+            executions: 0,
+        }));
+
+        let token = self.next_subscription_token();
+        self.subscriptions.insert(
+            token.id(),
+            Subscriber::Observer(Box::new(DatabaseObserver(Arc::downgrade(&database)))),
+        );
+
+        QueryDatabase { database, token }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::subscription::SubscriptionToken;
+use crate::{Store, UnsubscribeError};
+
+/// Rewrites an action before it reaches the reducer.
+/// Returning `None` drops the action.
+pub type Interceptor<State, Action> = fn(Action, &State) -> Option<Action>;
+
+impl<State, Action, Env> Store<State, Action, Env> {
+    /// Registers an interceptor. Interceptors are applied in registration order
+    /// before the reducer, each one receiving the result of the previous one.
+    ///
+    /// Use cases: aliasing deprecated actions to new ones, filling defaults,
+    /// dropping actions which are not allowed in the current state.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::Store;
+    ///
+    /// type MyStore = u8;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     // Deprecated, use `IncrementBy(1)` instead
+    ///     Increment,
+    ///     IncrementBy(u8),
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::IncrementBy(value) => state + value,
+    ///         MyAction::Increment => unreachable!("Rewritten by the interceptor"),
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, 0);
+    ///
+    /// store.intercept(|action, _state| match action {
+    ///     MyAction::Increment => Some(MyAction::IncrementBy(1)),
+    ///     action => Some(action),
+    /// });
+    ///
+    /// store.dispatch(MyAction::Increment);
+    ///
+    /// assert_eq!(*store.state(), 1);
+    /// ```
+    pub fn intercept(
+        &mut self,
+        interceptor: Interceptor<State, Action>,
+    ) -> SubscriptionToken<State, Action> {
+        let token = self.next_subscription_token();
+        self.interceptors.insert(token.id(), interceptor);
+
+        token
+    }
+
+    /// Removes an interceptor by the token returned from `intercept`
+    pub fn remove_interceptor(
+        &mut self,
+        token: SubscriptionToken<State, Action>,
+    ) -> Result<(), UnsubscribeError> {
+        let id = self.check_token(token)?;
+
+        match self.interceptors.remove(&id) {
+            Some(_) => Ok(()),
+            None => Err(UnsubscribeError::WrongToken(id)),
+        }
+    }
+
+    /// Passes the action through all interceptors
+    pub(crate) fn apply_interceptors(&self, action: Action) -> Option<Action> {
+        let state = self.state.get();
+
+        self.interceptors
+            .values()
+            .try_fold(action, |action, interceptor| interceptor(action, state))
+    }
+}
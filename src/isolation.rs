@@ -0,0 +1,88 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::subscription::SubscriberId;
+use crate::{Store, SubscriberError};
+
+/// Defines what the store does with a subscriber which panicked.
+/// In both cases the panic is reported via `take_subscriber_errors`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PanicIsolation {
+    /// Keep the subscription
+    Report,
+
+    /// Remove the subscription
+    Unsubscribe,
+}
+
+/// Calls the subscriber, catching its panic if isolation is enabled
+pub(crate) fn call<R>(
+    isolation: Option<PanicIsolation>,
+    func: impl FnOnce() -> R,
+) -> Result<R, SubscriberError> {
+    if isolation.is_none() {
+        return Ok(func());
+    }
+
+    panic::catch_unwind(AssertUnwindSafe(func)).map_err(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "Unknown panic payload".to_string());
+
+        format!("Subscriber panicked: {}", message).into()
+    })
+}
+
+impl<State, Action, Env> Store<State, Action, Env> {
+    /// Wraps every subscriber call in `catch_unwind`, so one panicking
+    /// subscriber does not break dispatch for everyone else.
+    ///
+    /// Panics are reported via `take_subscriber_errors`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::{PanicIsolation, Store};
+    ///
+    /// type MyStore = u8;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Increment,
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Increment => state + 1,
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, 0);
+    /// store.isolate_panics(PanicIsolation::Unsubscribe);
+    ///
+    /// store.subscribe(|_state| panic!("Broken subscriber"));
+    /// store.dispatch(MyAction::Increment);
+    ///
+    /// assert_eq!(*store.state(), 1);
+    /// assert_eq!(store.take_subscriber_errors().len(), 1);
+    /// ```
+    pub fn isolate_panics(&mut self, isolation: PanicIsolation) {
+        self.panic_isolation = Some(isolation);
+    }
+
+    /// Lets panics of subscribers propagate through `dispatch` again
+    pub fn propagate_panics(&mut self) {
+        self.panic_isolation = None;
+    }
+
+    /// Reports caught panics and removes offending subscriptions if needed
+    pub(crate) fn handle_panics(&mut self, panics: Vec<(SubscriberId, SubscriberError)>) {
+        for (id, err) in panics {
+            if self.panic_isolation == Some(PanicIsolation::Unsubscribe) {
+                self.subscriptions.remove(&id);
+            }
+
+            self.subscriber_errors.push((id, err));
+        }
+    }
+}
@@ -0,0 +1,274 @@
+//! JSON Patch (RFC 6902) documents describing state transitions.
+//! Available behind the `serde` feature.
+
+use std::sync::Arc;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::subscription::SubscriptionToken;
+use crate::{Store, StoreError};
+
+/// One operation of a JSON Patch document
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum PatchOperation {
+    Add { path: String, value: Value },
+    Remove { path: String },
+    Replace { path: String, value: Value },
+}
+
+/// JSON Patch document, serialized as an array of operations
+pub type JsonPatch = Vec<PatchOperation>;
+
+#[derive(Debug, PartialEq)]
+pub enum PatchError {
+    /// The state cannot be converted to or from JSON
+    Serialize(String),
+
+    /// The path of the operation does not exist in the document
+    InvalidPath(String),
+}
+
+impl std::error::Error for PatchError {}
+impl std::fmt::Display for PatchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            PatchError::Serialize(message) => {
+                write!(f, "Cannot convert the state to JSON: {}", message)
+            }
+            PatchError::InvalidPath(path) => write!(f, "Cannot apply the patch at path: {}", path),
+        }
+    }
+}
+
+/// Computes the patch which turns `old` into `new`.
+///
+/// Objects are compared by keys and arrays by indices; items removed from
+/// the end of an array are removed from the last one.
+///
+/// ## Example
+/// ```rust
+/// use redust::json_patch::{diff, PatchOperation};
+/// use serde_json::json;
+///
+/// let patch = diff(&json!({ "todos": ["a"], "filter": "all" }), &json!({ "todos": ["a", "b"] }));
+///
+/// assert_eq!(
+///     patch,
+///     [
+///         PatchOperation::Remove { path: "/filter".to_string() },
+///         PatchOperation::Add { path: "/todos/1".to_string(), value: json!("b") },
+///     ]
+/// );
+/// ```
+pub fn diff(old: &Value, new: &Value) -> JsonPatch {
+    let mut patch = Vec::new();
+    diff_values("", old, new, &mut patch);
+
+    patch
+}
+
+fn diff_values(path: &str, old: &Value, new: &Value, patch: &mut JsonPatch) {
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) => {
+            for (key, old_value) in old {
+                let path = format!("{}/{}", path, escape(key));
+                match new.get(key) {
+                    Some(new_value) => diff_values(&path, old_value, new_value, patch),
+                    None => patch.push(PatchOperation::Remove { path }),
+                }
+            }
+            for (key, new_value) in new {
+                if !old.contains_key(key) {
+                    patch.push(PatchOperation::Add {
+                        path: format!("{}/{}", path, escape(key)),
+                        value: new_value.clone(),
+                    });
+                }
+            }
+        }
+        (Value::Array(old), Value::Array(new)) => {
+            for (index, (old_value, new_value)) in old.iter().zip(new).enumerate() {
+                diff_values(&format!("{}/{}", path, index), old_value, new_value, patch);
+            }
+            for index in (new.len()..old.len()).rev() {
+                patch.push(PatchOperation::Remove {
+                    path: format!("{}/{}", path, index),
+                });
+            }
+            for (index, value) in new.iter().enumerate().skip(old.len()) {
+                patch.push(PatchOperation::Add {
+                    path: format!("{}/{}", path, index),
+                    value: value.clone(),
+                });
+            }
+        }
+        (old, new) if old != new => patch.push(PatchOperation::Replace {
+            path: path.to_string(),
+            value: new.clone(),
+        }),
+        _ => {}
+    }
+}
+
+/// Computes the patch between two states
+pub fn diff_states<State: Serialize>(old: &State, new: &State) -> Result<JsonPatch, PatchError> {
+    Ok(diff(&to_value(old)?, &to_value(new)?))
+}
+
+/// Applies the patch to the document. The document is left unchanged on error
+pub fn apply(document: &mut Value, patch: &[PatchOperation]) -> Result<(), PatchError> {
+    let mut patched = document.clone();
+    for operation in patch {
+        apply_operation(&mut patched, operation)?;
+    }
+    *document = patched;
+
+    Ok(())
+}
+
+fn apply_operation(document: &mut Value, operation: &PatchOperation) -> Result<(), PatchError> {
+    let (path, value) = match operation {
+        PatchOperation::Add { path, value } | PatchOperation::Replace { path, value } => {
+            (path, Some(value.clone()))
+        }
+        PatchOperation::Remove { path } => (path, None),
+    };
+    let invalid = || PatchError::InvalidPath(path.clone());
+
+    let (parent_path, key) = match path.rfind('/') {
+        Some(index) => (&path[..index], unescape(&path[index + 1..])),
+        None if path.is_empty() => {
+            *document = value.ok_or_else(invalid)?;
+            return Ok(());
+        }
+        None => return Err(invalid()),
+    };
+    let parent = document.pointer_mut(parent_path).ok_or_else(invalid)?;
+
+    match (parent, operation) {
+        (Value::Object(map), PatchOperation::Add { value, .. }) => {
+            map.insert(key, value.clone());
+        }
+        (Value::Object(map), PatchOperation::Replace { value, .. }) => {
+            *map.get_mut(&key).ok_or_else(invalid)? = value.clone();
+        }
+        (Value::Object(map), PatchOperation::Remove { .. }) => {
+            map.remove(&key).ok_or_else(invalid)?;
+        }
+        (Value::Array(items), operation) => {
+            let index = match key.as_str() {
+                "-" => items.len(),
+                key => key.parse::<usize>().map_err(|_| invalid())?,
+            };
+            match operation {
+                PatchOperation::Add { value, .. } if index <= items.len() => {
+                    items.insert(index, value.clone())
+                }
+                PatchOperation::Replace { value, .. } if index < items.len() => {
+                    items[index] = value.clone()
+                }
+                PatchOperation::Remove { .. } if index < items.len() => {
+                    items.remove(index);
+                }
+                _ => return Err(invalid()),
+            }
+        }
+        _ => return Err(invalid()),
+    }
+
+    Ok(())
+}
+
+fn escape(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+fn unescape(key: &str) -> String {
+    key.replace("~1", "/").replace("~0", "~")
+}
+
+fn to_value<T: Serialize>(value: &T) -> Result<Value, PatchError> {
+    serde_json::to_value(value).map_err(|err| PatchError::Serialize(err.to_string()))
+}
+
+fn patch_differ<State: Serialize>(old: &State, new: &State) -> Option<JsonPatch> {
+    diff_states(old, new).ok().filter(|patch| !patch.is_empty())
+}
+
+impl<State, Action, Env> Store<State, Action, Env>
+where
+    State: Serialize + Send + Sync + 'static,
+{
+    /// Subscribes a callback which receives a JSON Patch for every change of the state,
+    /// e.g. to sync the state to a browser or to append it to a persisted log.
+    /// States which cannot be serialized produce no patches
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::json_patch::PatchOperation;
+    /// use redust::Store;
+    /// use serde_json::json;
+    ///
+    /// type MyStore = Vec<u8>;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Push(u8),
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     let mut items = state.clone();
+    ///     match action {
+    ///         MyAction::Push(item) => items.push(*item),
+    ///     }
+    ///
+    ///     items
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, vec![]);
+    /// store.subscribe_json_patch(|patch| {
+    ///     assert_eq!(
+    ///         *patch,
+    ///         [PatchOperation::Add { path: "/0".to_string(), value: json!(7) }]
+    ///     );
+    /// });
+    ///
+    /// store.dispatch(MyAction::Push(7));
+    /// ```
+    pub fn subscribe_json_patch(
+        &mut self,
+        func: fn(&JsonPatch),
+    ) -> SubscriptionToken<State, Action> {
+        self.subscribe_delta(patch_differ::<State>, func)
+    }
+}
+
+impl<State, Action, Env> Store<State, Action, Env>
+where
+    State: Serialize + DeserializeOwned,
+{
+    /// Applies a JSON Patch received from another store and notifies subscribers.
+    /// The state is left unchanged if the patch cannot be applied; the failure is
+    /// also reported to `Store::errors`
+    pub fn apply_json_patch(&mut self, patch: &[PatchOperation]) -> Result<(), PatchError> {
+        let result = to_value(self.state()).and_then(|mut document| {
+            apply(&mut document, patch)?;
+            serde_json::from_value(document).map_err(|err| PatchError::Serialize(err.to_string()))
+        });
+
+        match result {
+            Ok(state) => {
+                self.replace_state(Arc::new(state));
+
+                Ok(())
+            }
+            Err(err) => {
+                self.report_error(StoreError::Persistence(err.to_string()));
+
+                Err(err)
+            }
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::hash::{BuildHasher, Hash};
+use std::sync::Arc;
+
+use crate::subscription::{Subscriber, SubscriptionToken};
+use crate::view::ProjectedSubscription;
+use crate::Store;
+
+/// Subscription to a key path, called with the new value of the field
+/// or `None` when the path does not lead to a value anymore
+pub type KeyPathSubscription<Part> = fn(Option<&Part>);
+
+/// Collection which the `[key]` step of a `keypath!` looks values up in.
+/// Missing keys end the path instead of panicking like `Index` does
+pub trait KeyIndex<Key: ?Sized> {
+    type Output: ?Sized;
+
+    /// Returns the value stored under the `key`, if any
+    fn key_at(&self, key: &Key) -> Option<&Self::Output>;
+}
+
+impl<T> KeyIndex<usize> for [T] {
+    type Output = T;
+
+    fn key_at(&self, key: &usize) -> Option<&T> {
+        self.get(*key)
+    }
+}
+
+impl<T> KeyIndex<usize> for Vec<T> {
+    type Output = T;
+
+    fn key_at(&self, key: &usize) -> Option<&T> {
+        self.get(*key)
+    }
+}
+
+impl<T> KeyIndex<usize> for VecDeque<T> {
+    type Output = T;
+
+    fn key_at(&self, key: &usize) -> Option<&T> {
+        self.get(*key)
+    }
+}
+
+impl<K, Q, V, S> KeyIndex<Q> for HashMap<K, V, S>
+where
+    K: Eq + Hash + Borrow<Q>,
+    Q: Eq + Hash + ?Sized,
+    S: BuildHasher,
+{
+    type Output = V;
+
+    fn key_at(&self, key: &Q) -> Option<&V> {
+        self.get(key)
+    }
+}
+
+impl<K, Q, V> KeyIndex<Q> for BTreeMap<K, V>
+where
+    K: Ord + Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    type Output = V;
+
+    fn key_at(&self, key: &Q) -> Option<&V> {
+        self.get(key)
+    }
+}
+
+/// Function which follows a key path in the state
+pub type KeyPathAccessor<State, Part> = dyn Fn(&State) -> Option<&Part> + Send + Sync;
+
+/// Typed accessor of one nested field of the state, created by `keypath!`.
+///
+/// Unlike `Projection`, the accessor may capture values such as the key
+/// of a collection item, and it returns `None` once the item is gone.
+pub struct KeyPath<State, Part: ?Sized> {
+    get: Arc<KeyPathAccessor<State, Part>>,
+}
+
+impl<State, Part: ?Sized> Clone for KeyPath<State, Part> {
+    fn clone(&self) -> Self {
+        Self {
+            get: Arc::clone(&self.get),
+        }
+    }
+}
+
+impl<State, Part: ?Sized> KeyPath<State, Part> {
+    /// Creates a key path from the accessor function, prefer the `keypath!` macro.
+    /// The function is boxed so the type of its argument is inferred from the key path
+    pub fn new(get: Box<KeyPathAccessor<State, Part>>) -> Self {
+        Self {
+            get: Arc::from(get),
+        }
+    }
+
+    /// Returns the field the path leads to in the `state`
+    pub fn get<'a>(&self, state: &'a State) -> Option<&'a Part> {
+        (self.get)(state)
+    }
+}
+
+impl<State, Part: PartialEq + ?Sized> KeyPath<State, Part> {
+    /// Returns whether the field differs between the `old` and the `new` state,
+    /// including when it appeared or disappeared
+    pub fn changed(&self, old: &State, new: &State) -> bool {
+        self.get(old) != self.get(new)
+    }
+}
+
+/// Creates a `KeyPath` to a nested field of the state.
+///
+/// The path is written as an expression over the state: field accesses,
+/// method calls and `[key]` lookups, e.g. `keypath!(state.todos[id].checked)`.
+/// Keys are looked up through `KeyIndex`, so a missing item makes the path
+/// return `None` instead of panicking. Captured keys are moved into the path.
+///
+/// The state type is inferred where the path is passed to a function, e.g.
+/// `Store::watch`. Otherwise it is given in front of the path:
+/// `keypath!(AppState => state.todos[id].checked)`.
+///
+/// ## Example
+/// ```rust
+/// use redust::{keypath, Store};
+/// use std::collections::HashMap;
+///
+/// #[derive(Clone)]
+/// struct Todo {
+///     title: &'static str,
+///     checked: bool,
+/// }
+///
+/// #[derive(Clone)]
+/// struct AppState {
+///     todos: HashMap<u32, Todo>,
+/// }
+///
+/// #[derive(Debug)]
+/// enum MyAction {
+///     Toggle(u32),
+///     Rename(u32, &'static str),
+/// };
+///
+/// fn reducer(state: &AppState, action: &MyAction) -> AppState {
+///     let mut new_state = state.clone();
+///     match action {
+///         MyAction::Toggle(id) => {
+///             let todo = new_state.todos.get_mut(id).unwrap();
+///             todo.checked = !todo.checked;
+///         }
+///         MyAction::Rename(id, title) => new_state.todos.get_mut(id).unwrap().title = title,
+///     }
+///
+///     new_state
+/// }
+///
+/// let mut todos = HashMap::new();
+/// todos.insert(7, Todo { title: "Buy milk", checked: false });
+/// let mut store = Store::new(reducer, AppState { todos });
+///
+/// let id = 7;
+/// let checked = keypath!(AppState => state.todos[id].checked);
+/// assert_eq!(checked.get(store.state()), Some(&false));
+///
+/// store.watch(checked, |checked| {
+///     // Not called on `MyAction::Rename`
+///     assert_eq!(checked, Some(&true));
+/// });
+///
+/// store
+///     .dispatch(MyAction::Rename(7, "Buy oat milk"))
+///     .dispatch(MyAction::Toggle(7));
+/// ```
+#[macro_export]
+macro_rules! keypath {
+    (@path [$($acc: tt)*]) => {
+        ::std::option::Option::Some(&$($acc)*)
+    };
+    (@path [$($acc: tt)*] [$key: expr] $($rest: tt)*) => {
+        $crate::keypath!(@path [(*{
+            #[allow(unused_imports)]
+            use $crate::KeyIndex as _;
+            ($($acc)*).key_at(&$key)
+        }?)] $($rest)*)
+    };
+    (@path [$($acc: tt)*] $next: tt $($rest: tt)*) => {
+        $crate::keypath!(@path [$($acc)* $next] $($rest)*)
+    };
+    ($state_ty: ty => $state: ident $($path: tt)*) => {
+        $crate::KeyPath::<$state_ty, _>::new(::std::boxed::Box::new(move |$state| {
+            $crate::keypath!(@path [$state] $($path)*)
+        }))
+    };
+    ($state: ident $($path: tt)*) => {
+        $crate::KeyPath::new(::std::boxed::Box::new(move |$state| {
+            $crate::keypath!(@path [$state] $($path)*)
+        }))
+    };
+}
+
+struct KeyPathEntry<State, Part: ?Sized> {
+    path: KeyPath<State, Part>,
+    func: KeyPathSubscription<Part>,
+    last: Arc<State>,
+}
+
+impl<State, Part: PartialEq + ?Sized> ProjectedSubscription<State> for KeyPathEntry<State, Part> {
+    fn notify(&mut self, state: &Arc<State>) {
+        if Arc::ptr_eq(&self.last, state) {
+            return;
+        }
+
+        let last = std::mem::replace(&mut self.last, Arc::clone(state));
+        if self.path.changed(&last, state) {
+            (self.func)(self.path.get(state));
+        }
+    }
+}
+
+impl<State, Action, Env> Store<State, Action, Env>
+where
+    State: Send + Sync + 'static,
+{
+    /// Subscribes a callback which is called only when the field the `path`
+    /// leads to changes. Nothing besides the field is compared or cloned
+    pub fn watch<Part>(
+        &mut self,
+        path: KeyPath<State, Part>,
+        func: KeyPathSubscription<Part>,
+    ) -> SubscriptionToken<State, Action>
+    where
+        Part: PartialEq + ?Sized + 'static,
+    {
+        let subscription_token = self.next_subscription_token();
+        let entry = KeyPathEntry {
+            path,
+            func,
+            last: self.shared_state(),
+        };
+
+        self.subscriptions.insert(
+            subscription_token.id(),
+            Subscriber::Projected(Box::new(entry)),
+        );
+
+        subscription_token
+    }
+}
@@ -0,0 +1,74 @@
+use std::time::SystemTime;
+
+use crate::Store;
+
+/// The action which was reduced last and when it was dispatched
+pub(crate) struct LastAction<Action> {
+    pub(crate) action: Option<Action>,
+    pub(crate) dispatched_at: SystemTime,
+}
+
+impl<State, Action, Env> Store<State, Action, Env> {
+    /// Returns the action which most recently changed the state, so debug overlays,
+    /// tests and devtools can show it without a middleware.
+    ///
+    /// Actions which were intercepted, aborted or dropped by a frozen store are not kept.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::Store;
+    ///
+    /// type MyStore = u8;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum MyAction {
+    ///     Increment,
+    ///     Decrement,
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Increment => state + 1,
+    ///         MyAction::Decrement => state - 1,
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, 0);
+    /// assert_eq!(store.last_action(), None);
+    ///
+    /// store.dispatch(MyAction::Increment).dispatch(MyAction::Decrement);
+    ///
+    /// assert_eq!(store.last_action(), Some(&MyAction::Decrement));
+    /// assert!(store.last_dispatched_at().is_some());
+    /// ```
+    pub fn last_action(&self) -> Option<&Action> {
+        // Forks keep every dispatched action for the merge
+        match &self.fork_base {
+            Some(base) => base.actions.last(),
+            None => self.last_action.as_ref()?.action.as_ref(),
+        }
+    }
+
+    /// Returns the time of the store clock when the last action was dispatched
+    pub fn last_dispatched_at(&self) -> Option<SystemTime> {
+        self.last_action
+            .as_ref()
+            .map(|last_action| last_action.dispatched_at)
+    }
+
+    /// Keeps the dispatched action for `last_action`
+    pub(crate) fn record_last_action(&mut self, action: Action) {
+        let action = match self.fork_base.as_mut() {
+            Some(base) => {
+                base.actions.push(action);
+                None
+            }
+            None => Some(action),
+        };
+
+        self.last_action = Some(LastAction {
+            action,
+            dispatched_at: self.clock.system_time(),
+        });
+    }
+}
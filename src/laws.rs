@@ -0,0 +1,140 @@
+//! Property-based testing helpers which check reducer laws.
+//!
+//! Available behind the `proptest` feature.
+
+use std::fmt::Debug;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use proptest::collection::{vec, SizeRange};
+use proptest::prelude::*;
+use proptest::strategy::Union;
+use proptest::test_runner::TestCaseError;
+
+use crate::Reducer;
+
+/// Generates sequences of actions where every action is produced by one of
+/// the `variants` strategies (usually one strategy per action variant)
+///
+/// ## Example
+/// ```rust
+/// use proptest::prelude::*;
+/// use redust::laws::{action_sequence, assert_deterministic};
+///
+/// #[derive(Debug, Clone)]
+/// enum MyAction {
+///     Increment,
+///     IncrementBy(u8),
+/// };
+///
+/// fn reducer(state: &u8, action: &MyAction) -> u8 {
+///     match action {
+///         MyAction::Increment => state.saturating_add(1),
+///         MyAction::IncrementBy(value) => state.saturating_add(*value),
+///     }
+/// }
+///
+/// proptest!(|(actions in action_sequence(vec![
+///     Just(MyAction::Increment).boxed(),
+///     any::<u8>().prop_map(MyAction::IncrementBy).boxed(),
+/// ], 0..32))| {
+///     assert_deterministic(reducer, &0, &actions)?;
+/// });
+/// ```
+pub fn action_sequence<Action: Debug>(
+    variants: Vec<BoxedStrategy<Action>>,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = Vec<Action>> {
+    vec(Union::new(variants), size)
+}
+
+/// Checks that the reducer produces the same states when the same actions
+/// are applied twice to the same initial state
+pub fn assert_deterministic<State, Action>(
+    reducer: Reducer<State, Action>,
+    initial_state: &State,
+    actions: &[Action],
+) -> Result<(), TestCaseError>
+where
+    State: Clone + PartialEq + Debug,
+    Action: Debug,
+{
+    let mut first = initial_state.clone();
+    let mut second = initial_state.clone();
+
+    for (index, action) in actions.iter().enumerate() {
+        first = reducer(&first, action);
+        second = reducer(&second, action);
+
+        if first != second {
+            return Err(TestCaseError::fail(format!(
+                "Reducer is not deterministic on action #{} {:?}: {:?} != {:?}",
+                index, action, first, second
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the `invariant` holds for the initial state and after every transition
+pub fn assert_invariant<State, Action>(
+    reducer: Reducer<State, Action>,
+    initial_state: State,
+    actions: &[Action],
+    invariant: fn(&State) -> bool,
+) -> Result<(), TestCaseError>
+where
+    State: Debug,
+    Action: Debug,
+{
+    if !invariant(&initial_state) {
+        return Err(TestCaseError::fail(format!(
+            "Invariant does not hold for the initial state {:?}",
+            initial_state
+        )));
+    }
+
+    let mut state = initial_state;
+    for (index, action) in actions.iter().enumerate() {
+        state = reducer(&state, action);
+
+        if !invariant(&state) {
+            return Err(TestCaseError::fail(format!(
+                "Invariant does not hold after action #{} {:?}: {:?}",
+                index, action, state
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the reducer does not panic for any of the `actions`,
+/// e.g. on an out of bounds index or an arithmetic overflow
+pub fn assert_total<State, Action>(
+    reducer: Reducer<State, Action>,
+    initial_state: State,
+    actions: &[Action],
+) -> Result<(), TestCaseError>
+where
+    State: Debug,
+    Action: Debug,
+{
+    let mut state = initial_state;
+    for (index, action) in actions.iter().enumerate() {
+        state = catch_unwind(AssertUnwindSafe(|| reducer(&state, action))).map_err(|payload| {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "Unknown panic payload".to_string());
+
+            TestCaseError::fail(format!(
+                "Reducer panicked on action #{} {:?} with the state {:?}: {}",
+                index, action, state, message
+            ))
+        })?;
+    }
+
+    Ok(())
+}
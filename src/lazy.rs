@@ -0,0 +1,142 @@
+use std::sync::{Arc, Mutex, OnceLock};
+
+use crate::reducer::RootReducer;
+use crate::{Reducer, Store};
+
+/// Computes the initial state of a lazy store
+type Initializer<State> = Box<dyn FnOnce() -> State + Send>;
+
+/// State which might be computed only on first access.
+///
+/// The state is kept behind an `Arc`, so snapshots are pointer copies.
+pub(crate) struct LazyState<State> {
+    cell: OnceLock<Arc<State>>,
+    /// Taken out by the first access, so it runs at most once
+    initializer: Mutex<Option<Initializer<State>>>,
+}
+
+impl<State> LazyState<State> {
+    pub(crate) fn new(state: State) -> Self {
+        Self {
+            cell: OnceLock::from(Arc::new(state)),
+            initializer: Mutex::new(None),
+        }
+    }
+
+    pub(crate) fn from_shared(state: Arc<State>) -> Self {
+        Self {
+            cell: OnceLock::from(state),
+            initializer: Mutex::new(None),
+        }
+    }
+
+    pub(crate) fn lazy(initializer: impl FnOnce() -> State + Send + 'static) -> Self {
+        Self {
+            cell: OnceLock::new(),
+            initializer: Mutex::new(Some(Box::new(initializer))),
+        }
+    }
+
+    pub(crate) fn get(&self) -> &State {
+        self.shared_ref()
+    }
+
+    /// Returns a snapshot which shares the state with the store
+    pub(crate) fn shared(&self) -> Arc<State> {
+        Arc::clone(self.shared_ref())
+    }
+
+    pub(crate) fn shared_ref(&self) -> &Arc<State> {
+        self.cell.get_or_init(|| {
+            let initializer = self
+                .initializer
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .take();
+            match initializer {
+                Some(initializer) => Arc::new(initializer()),
+                None => unreachable!("State without initializer is always set"),
+            }
+        })
+    }
+
+    /// Clones the state only if snapshots of it are still alive
+    pub(crate) fn get_mut(&mut self) -> &mut State
+    where
+        State: Clone,
+    {
+        self.get();
+
+        Arc::make_mut(self.cell.get_mut().expect("State was initialized above"))
+    }
+
+    /// Sets the new state and returns the previous one
+    pub(crate) fn replace(&mut self, state: State) -> Arc<State> {
+        self.replace_shared(Arc::new(state))
+    }
+
+    /// Sets the state shared with snapshots and returns the previous one
+    pub(crate) fn replace_shared(&mut self, state: Arc<State>) -> Arc<State> {
+        self.get();
+
+        let current = self.cell.get_mut().expect("State was initialized above");
+        std::mem::replace(current, state)
+    }
+
+    pub(crate) fn into_shared(mut self) -> Arc<State> {
+        self.get();
+
+        self.cell.take().expect("State was initialized above")
+    }
+
+    /// Returns the shared state back if snapshots of it are still alive
+    pub(crate) fn into_inner(self) -> Result<State, Arc<State>> {
+        Arc::try_unwrap(self.into_shared())
+    }
+
+    pub(crate) fn is_initialized(&self) -> bool {
+        self.cell.get().is_some()
+    }
+}
+
+impl<State, Action> Store<State, Action> {
+    /// Creates a new store whose initial state is computed only on first
+    /// access or dispatch.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::Store;
+    ///
+    /// type MyStore = Vec<u32>;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {};
+    ///
+    /// fn reducer(state: &MyStore, _action: &MyAction) -> MyStore {
+    ///     state.clone()
+    /// }
+    ///
+    /// let store = Store::new_lazy(reducer, || (0..1000).collect());
+    /// assert!(!store.is_initialized());
+    ///
+    /// assert_eq!(store.state().len(), 1000);
+    /// assert!(store.is_initialized());
+    /// ```
+    pub fn new_lazy(
+        reducer: Reducer<State, Action>,
+        initializer: impl FnOnce() -> State + Send + 'static,
+    ) -> Self {
+        Self::with_lazy_state(
+            RootReducer::Plain(reducer),
+            LazyState::lazy(initializer),
+            Arc::new(()),
+        )
+    }
+}
+
+impl<State, Action, Env> Store<State, Action, Env> {
+    /// Returns `true` if the initial state was already computed
+    pub fn is_initialized(&self) -> bool {
+        self.state.is_initialized()
+    }
+}
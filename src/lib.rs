@@ -1,7 +1,179 @@
+mod abort;
+#[cfg(feature = "serde")]
+pub mod action_log;
+mod any_action;
+mod any_store;
+mod arena;
+mod async_thunk;
+mod backpressure;
+mod blocking;
+mod builder;
+#[cfg(feature = "crossbeam-channel")]
+pub mod channel;
+mod clock;
+#[cfg(feature = "serde")]
+pub mod codec;
+mod concurrent;
+mod configure;
+mod context;
+mod create_slice;
+mod delta;
+mod derived;
+mod dispatch;
+#[cfg(feature = "serde")]
+pub mod dump;
+#[cfg(feature = "serde")]
+pub mod dynamic;
+mod effect;
+mod emitter;
+mod entity;
+mod env;
+mod errors;
+mod expiration;
+mod fallible;
+mod file_log;
+mod fixture;
+mod flags;
+mod fork;
+#[cfg(feature = "fuzz")]
+pub mod fuzz;
+mod global;
+#[cfg(feature = "grpc")]
+pub mod grpc;
+mod history;
+mod hooks;
+mod incremental;
+mod interceptors;
+mod isolation;
+#[cfg(feature = "serde")]
+pub mod json_patch;
+mod keypath;
+mod last_action;
+#[cfg(feature = "proptest")]
+pub mod laws;
+mod lazy;
+mod local;
+#[cfg(feature = "local-effects")]
+pub mod local_effect;
+#[cfg(feature = "log")]
+pub mod logging;
+mod memory;
+mod merge;
+mod metrics;
+mod middleware;
+mod migration;
+mod mutation;
+mod nested;
+mod optics;
+#[cfg(feature = "otel")]
+pub mod otel;
+mod pagination;
+mod parent;
+mod produce;
+#[cfg(feature = "prometheus")]
+pub mod prometheus;
+#[cfg(feature = "prost")]
+pub mod proto;
+mod query;
+mod queue;
 mod reducer;
+#[cfg(feature = "serde")]
+pub mod remote;
+mod replay;
+mod retry;
+#[cfg(feature = "rxrust")]
+pub mod rx;
+mod sampling;
+mod schedule;
+mod selector;
+#[cfg(feature = "shared-memory")]
+pub mod shared_memory;
+mod slices;
+mod spawner;
 mod store;
+mod strict;
 mod subscription;
+pub mod test;
+mod ticker;
+mod version;
+mod view;
 
+pub use abort::AbortSignal;
+pub use any_action::{ActionReducers, AnyAction};
+pub use any_store::{global_registry, AnyStore, AnyStoreError, StoreKey, StoreLike, StoreRegistry};
+pub use arena::{Arena, ArenaId, ArenaReducer, ArenaState};
+pub use async_thunk::{
+    create_async_thunk, AsyncThunk, PendingAction, RequestId, SettledAction, ThunkPayload,
+    ThunkRequest,
+};
+pub use backpressure::{AsyncSubscription, Backpressure, RecvFuture, StateReceiver};
+pub use blocking::BlockingRuntime;
+pub use builder::StoreBuilder;
+pub use clock::{Clock, SharedClock, SystemClock, TestClock};
+pub use concurrent::{ConcurrentStore, ContentionMetrics, StateReadGuard};
+pub use configure::configure_store;
+pub use context::ContextError;
+pub use delta::{DeltaSubscription, Differ};
+pub use derived::{DerivedState, Selector};
+pub use dispatch::{DispatchError, FreezePolicy};
+pub use effect::{spawn_effect, ActionEffect, CancellationHandle, CancellationToken, TakeLatest};
+pub use emitter::{EmitterError, EventEmitter, TopicSelector, CHANGE_TOPIC};
+pub use entity::{EntityAdapter, EntityComparator, EntityState};
+pub use env::EnvReducer;
+pub use errors::{ErrorBehavior, ErrorContext, ErrorHandler, ErrorPhase, StoreError};
+pub use expiration::Expiration;
+pub use fallible::{ErrorPolicy, FallibleSubscription, SubscriberError};
+pub use file_log::{FileLogger, LogFormat, LogRotation};
+pub use fixture::{Fixture, FixtureError, Recorder};
+pub use flags::{FlagDecision, FlagProvider};
+pub use global::GlobalStore;
+pub use history::{HistoryError, HistoryPolicy, StateSize};
+pub use hooks::{AfterDispatchHook, BeforeDispatchHook};
+pub use incremental::{Query, QueryContext, QueryDatabase};
+pub use interceptors::Interceptor;
+pub use isolation::PanicIsolation;
+pub use keypath::{KeyIndex, KeyPath, KeyPathAccessor, KeyPathSubscription};
+pub use local::{LocalStore, LocalStoreError};
+#[cfg(feature = "serde")]
+pub use memory::serialized_size;
+pub use memory::{MemSize, MemoryReport};
+pub use merge::{MergeError, MergeResolver, MergeStrategy};
+pub use metrics::{DispatchMetrics, REDUCER_DURATION_BUCKETS};
+pub use middleware::{Middleware, MiddlewareStack, Next};
+pub use migration::{Migration, MigrationError, Migrations, Version, Versioned, VersionedState};
+pub use pagination::{Page, PaginatedState};
+pub use parent::{CombinedState, ParentStore, RouteError};
+pub use produce::{produce, Draft};
+pub use query::{
+    InvalidatesTags, MutationEndpoint, ProvidesTags, QueryCache, QueryEndpoint, QueryEntry,
+    QueryStatus, Tag,
+};
+pub use queue::{
+    DispatchQueue, Dispatcher, OverflowPolicy, Priority, QueueError, StarvationPolicy,
+};
 pub use reducer::Reducer;
-pub use store::Store;
-pub use subscription::{Subscription, UnsubscribeError};
+pub use replay::{Checkpoint, ReplayError};
+pub use retry::{Backoff, ExhaustedAction, RetryExhausted, RetryPolicy};
+pub use sampling::Sample;
+pub use schedule::{Cron, ScheduleError, ScheduleHandle, Scheduler};
+pub use selector::{AsyncCombiner, AsyncSelector};
+pub use slices::{Slice, SliceError, SliceKey, Slices};
+#[cfg(feature = "async-std")]
+pub use spawner::AsyncStdSpawner;
+#[cfg(feature = "smol")]
+pub use spawner::SmolSpawner;
+#[cfg(feature = "tokio")]
+pub use spawner::TokioSpawner;
+pub use spawner::{
+    dispatch_after, spawn_async_effect, spawn_local_effect, AsyncEffectHandle, BoxFuture,
+    Debouncer, LocalBoxFuture, SharedSpawner, SpawnError, Spawner,
+};
+pub use store::{Store, StoreParts};
+pub use strict::{SlowCall, SlowTarget};
+pub use subscription::{
+    ActionFilter, ActionSubscription, NotifyPolicy, StateFilter, SubscriberId, Subscription,
+    SubscriptionToken, UnsubscribeError,
+};
+pub use ticker::{TickAction, Ticker};
+pub use version::StateVersion;
+pub use view::{Projection, StoreView, ViewSnapshot, ViewToken};
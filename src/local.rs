@@ -0,0 +1,182 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread::{self, ThreadId};
+
+use crate::Store;
+
+thread_local! {
+    // Stores owned by the current thread, keyed by the id of their handle
+    static LOCAL_STORES: RefCell<HashMap<u64, Rc<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+static NEXT_LOCAL_STORE_ID: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, PartialEq)]
+pub enum LocalStoreError {
+    /// The store is accessed from a thread which does not own it
+    WrongThread { owner: ThreadId },
+
+    /// The store is already borrowed, e.g. `with_store` was called from a subscriber
+    AlreadyBorrowed,
+
+    /// The store was taken out of the holder
+    Taken,
+}
+
+impl std::error::Error for LocalStoreError {}
+impl std::fmt::Display for LocalStoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            LocalStoreError::WrongThread { owner } => write!(
+                f,
+                "Cannot access the store outside of its thread {:?}",
+                owner
+            ),
+            LocalStoreError::AlreadyBorrowed => {
+                write!(f, "Cannot access the store while it is borrowed")
+            }
+            LocalStoreError::Taken => write!(f, "Cannot access the store which was taken"),
+        }
+    }
+}
+
+/// Handle of a store which lives on one thread, e.g. the UI thread of
+/// a single-threaded GUI framework.
+///
+/// The store itself stays in thread-local storage, so neither the state nor
+/// the actions need to be `Send`. The handle is `Copy` and `Send`: callbacks
+/// may capture it freely, and access from another thread returns an error
+/// instead of panicking.
+///
+/// ## Example
+/// ```rust
+/// use redust::{LocalStore, LocalStoreError, Store};
+/// use std::rc::Rc;
+/// use std::thread;
+///
+/// // `Rc` makes the state `!Send`
+/// type MyStore = Rc<Vec<&'static str>>;
+///
+/// #[derive(Debug)]
+/// enum MyAction {
+///     Add(&'static str),
+/// };
+///
+/// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+///     let mut items = state.as_ref().clone();
+///     match action {
+///         MyAction::Add(item) => items.push(item),
+///     }
+///
+///     Rc::new(items)
+/// }
+///
+/// let store = LocalStore::new(Store::new(reducer, Rc::new(vec![])));
+///
+/// store.with_store(|store| store.dispatch(MyAction::Add("milk")).state().len()).unwrap();
+///
+/// let other = thread::spawn(move || store.with_store(|_store| ()))
+///     .join()
+///     .unwrap();
+/// assert!(matches!(other, Err(LocalStoreError::WrongThread { .. })));
+/// ```
+pub struct LocalStore<State, Action> {
+    id: u64,
+    owner: ThreadId,
+    store: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<State, Action> Clone for LocalStore<State, Action> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<State, Action> Copy for LocalStore<State, Action> {}
+
+impl<State, Action> std::fmt::Debug for LocalStore<State, Action> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("LocalStore")
+            .field("id", &self.id)
+            .field("owner", &self.owner)
+            .finish()
+    }
+}
+
+impl<State: 'static, Action: 'static> LocalStore<State, Action> {
+    /// Moves the store into the storage of the current thread, which becomes its owner
+    pub fn new(store: Store<State, Action>) -> Self {
+        let id = NEXT_LOCAL_STORE_ID.fetch_add(1, Ordering::Relaxed);
+        let store: Rc<dyn Any> = Rc::new(RefCell::new(store));
+        LOCAL_STORES.with(|stores| stores.borrow_mut().insert(id, store));
+
+        Self {
+            id,
+            owner: thread::current().id(),
+            store: PhantomData,
+        }
+    }
+
+    /// Returns the id of the thread which owns the store
+    pub fn owner(&self) -> ThreadId {
+        self.owner
+    }
+
+    /// Returns `true` if the store may be accessed from the current thread
+    pub fn is_owned_by_current_thread(&self) -> bool {
+        self.owner == thread::current().id()
+    }
+
+    /// Calls `func` with exclusive access to the store
+    pub fn with_store<R, F>(&self, func: F) -> Result<R, LocalStoreError>
+    where
+        F: FnOnce(&mut Store<State, Action>) -> R,
+    {
+        let store = self.get()?;
+        let mut store = store
+            .try_borrow_mut()
+            .map_err(|_| LocalStoreError::AlreadyBorrowed)?;
+
+        Ok(func(&mut store))
+    }
+
+    /// Dispatches an action into the store
+    pub fn dispatch(&self, action: Action) -> Result<(), LocalStoreError> {
+        self.with_store(|store| {
+            store.dispatch(action);
+        })
+    }
+
+    /// Takes the store out of the thread-local storage. Other copies of the handle
+    /// return `LocalStoreError::Taken` afterwards
+    pub fn take(self) -> Result<Store<State, Action>, LocalStoreError> {
+        let store = self.get()?;
+        if Rc::strong_count(&store) > 2 {
+            return Err(LocalStoreError::AlreadyBorrowed);
+        }
+
+        LOCAL_STORES.with(|stores| stores.borrow_mut().remove(&self.id));
+
+        Rc::try_unwrap(store)
+            .map(RefCell::into_inner)
+            .map_err(|_| LocalStoreError::AlreadyBorrowed)
+    }
+
+    fn get(&self) -> Result<Rc<RefCell<Store<State, Action>>>, LocalStoreError> {
+        if !self.is_owned_by_current_thread() {
+            return Err(LocalStoreError::WrongThread { owner: self.owner });
+        }
+
+        let store = LOCAL_STORES
+            .with(|stores| stores.borrow().get(&self.id).cloned())
+            .ok_or(LocalStoreError::Taken)?;
+
+        Ok(store
+            .downcast::<RefCell<Store<State, Action>>>()
+            .expect("The handle is created together with the store"))
+    }
+}
@@ -0,0 +1,126 @@
+//! Async effects which do not have to be `Send`.
+//!
+//! `LocalEffects` runs effects on a current-thread tokio runtime inside
+//! a `LocalSet`, so they may hold `!Send` resources such as `Rc` caches or
+//! handles of a single-threaded GUI toolkit. Effects are driven only while the
+//! owning thread runs `run_until` or `run_until_idle`, e.g. from its event loop.
+//!
+//! Available behind the `local-effects` feature.
+
+use std::future::Future;
+use std::io;
+
+use tokio::runtime::{Builder, Runtime};
+use tokio::task::{JoinHandle, LocalSet};
+
+use crate::effect::dispatch_effect_action;
+use crate::{CancellationToken, Dispatcher};
+
+/// Handle of an effect spawned on `LocalEffects`
+pub struct LocalEffectHandle {
+    token: CancellationToken,
+    task: JoinHandle<()>,
+}
+
+impl LocalEffectHandle {
+    /// Cancels the effect. Its future is dropped at the next await point
+    /// and the action it produces is not dispatched anymore
+    pub fn cancel(&self) {
+        self.token.cancel();
+        self.task.abort();
+    }
+
+    /// Returns the token which the effect observes
+    pub fn token(&self) -> &CancellationToken {
+        &self.token
+    }
+
+    /// Returns `true` if the effect has finished or was cancelled
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+}
+
+/// Single-threaded runtime for async effects, which enqueues the actions
+/// they return with the `dispatcher`.
+///
+/// ## Example
+/// ```rust
+/// use redust::local_effect::LocalEffects;
+/// use redust::{DispatchQueue, Store};
+/// use std::rc::Rc;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum MyAction {
+///     Loaded(usize),
+/// };
+///
+/// fn reducer(_state: &usize, action: &MyAction) -> usize {
+///     match action {
+///         MyAction::Loaded(len) => *len,
+///     }
+/// }
+///
+/// let queue = DispatchQueue::new();
+/// let mut effects = LocalEffects::new(queue.dispatcher()).unwrap();
+///
+/// // `Rc` cannot be moved into a `Send` future
+/// let cache = Rc::new(vec!["milk", "bread"]);
+/// effects.spawn(move |_token| async move { Some(MyAction::Loaded(cache.len())) });
+/// effects.run_until_idle();
+///
+/// let mut store = Store::new(reducer, 0);
+/// store.drain(&queue);
+/// assert_eq!(*store.state(), 2);
+/// ```
+pub struct LocalEffects<Action> {
+    runtime: Runtime,
+    tasks: LocalSet,
+    dispatcher: Dispatcher<Action>,
+}
+
+impl<Action: 'static> LocalEffects<Action> {
+    /// Creates the runtime. It fails only if the OS cannot provide its resources
+    pub fn new(dispatcher: Dispatcher<Action>) -> io::Result<Self> {
+        Ok(Self {
+            runtime: Builder::new_current_thread().build()?,
+            tasks: LocalSet::new(),
+            dispatcher,
+        })
+    }
+
+    /// Spawns the effect. It starts running when the runtime is driven next time.
+    ///
+    /// The effect should check the token while it runs; the action of
+    /// a cancelled effect is dropped even if it was returned.
+    pub fn spawn<F, Fut>(&self, effect: F) -> LocalEffectHandle
+    where
+        F: FnOnce(CancellationToken) -> Fut,
+        Fut: Future<Output = Option<Action>> + 'static,
+    {
+        let token = CancellationToken::new();
+        let effect_token = token.clone();
+        let future = effect(token.clone());
+        let dispatcher = self.dispatcher.clone();
+
+        let task = async move {
+            let action = future.await;
+            dispatch_effect_action(&dispatcher, &effect_token, action);
+        };
+        #[cfg(feature = "otel")]
+        let task = crate::otel::in_effect_span(task);
+        let task = self.tasks.spawn_local(task);
+
+        LocalEffectHandle { token, task }
+    }
+
+    /// Drives spawned effects until the `future` completes and returns its output
+    pub fn run_until<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(self.tasks.run_until(future))
+    }
+
+    /// Drives spawned effects until all of them have finished
+    pub fn run_until_idle(&mut self) {
+        self.runtime.block_on(&mut self.tasks);
+    }
+}
@@ -0,0 +1,31 @@
+//! Targets of the records which built-in components emit through the `log` facade.
+//!
+//! Filter them with the existing logger configuration, e.g.
+//! `RUST_LOG=redust::dispatch=debug,redust=warn` for `env_logger`.
+//!
+//! | Target | Level | Records |
+//! |---|---|---|
+//! | `redust::dispatch` | debug | Actions and states of the `StoreBuilder::logger` middleware |
+//! | `redust::error` | error | Store failures with `ErrorBehavior::Log` |
+//! | `redust::persist` | info, warn | Action log rotations and failed writes, corrupted snapshots |
+//! | `redust::retry` | warn, error | Failed attempts and exhausted retries of `RetryPolicy` |
+//! | `redust::effect` | debug, warn | Dropped actions of cancelled effects and closed queues |
+//!
+//! Without the feature the logger middleware and `ErrorBehavior::Log` write to stderr.
+//!
+//! Available behind the `log` feature.
+
+/// Actions and states written by the `StoreBuilder::logger` middleware
+pub const DISPATCH: &str = "redust::dispatch";
+
+/// Store failures reported with `ErrorBehavior::Log`
+pub const ERROR: &str = "redust::error";
+
+/// Action log files and snapshots
+pub const PERSIST: &str = "redust::persist";
+
+/// Retries of `RetryPolicy`
+pub const RETRY: &str = "redust::retry";
+
+/// Async and threaded effects
+pub const EFFECT: &str = "redust::effect";
@@ -0,0 +1,258 @@
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::mem;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::{StateSize, Store};
+
+/// Approximate number of bytes a value occupies, used by `Store::memory_report`.
+///
+/// Only `heap_size` has to be implemented: the bytes the value owns outside of
+/// itself. Containers count their allocated capacity, so the estimate is close
+/// to what the allocator handed out rather than what is in use.
+///
+/// ## Example
+/// ```rust
+/// use redust::MemSize;
+///
+/// struct Todo {
+///     title: String,
+///     done: bool,
+/// }
+///
+/// impl MemSize for Todo {
+///     fn heap_size(&self) -> usize {
+///         self.title.heap_size() + self.done.heap_size()
+///     }
+/// }
+///
+/// let todo = Todo { title: String::with_capacity(16), done: false };
+/// assert_eq!(todo.mem_size(), std::mem::size_of::<Todo>() + 16);
+/// ```
+pub trait MemSize {
+    /// Bytes owned by the value outside of its own size
+    fn heap_size(&self) -> usize;
+
+    /// Bytes of the value itself together with what it owns
+    fn mem_size(&self) -> usize
+    where
+        Self: Sized,
+    {
+        mem::size_of::<Self>() + self.heap_size()
+    }
+}
+
+macro_rules! impl_without_heap {
+    ($($ty:ty),*) => {
+        $(impl MemSize for $ty {
+            fn heap_size(&self) -> usize {
+                0
+            }
+        })*
+    };
+}
+
+impl_without_heap!(
+    (),
+    bool,
+    char,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64,
+    &'static str
+);
+
+impl MemSize for String {
+    fn heap_size(&self) -> usize {
+        self.capacity()
+    }
+}
+
+impl<T: MemSize> MemSize for Option<T> {
+    fn heap_size(&self) -> usize {
+        self.as_ref().map_or(0, MemSize::heap_size)
+    }
+}
+
+impl<T: MemSize> MemSize for Box<T> {
+    fn heap_size(&self) -> usize {
+        self.as_ref().mem_size()
+    }
+}
+
+// Shared values are counted in full by every owner, which overestimates
+// states sharing unchanged parts
+impl<T: MemSize> MemSize for Rc<T> {
+    fn heap_size(&self) -> usize {
+        self.as_ref().mem_size()
+    }
+}
+
+impl<T: MemSize> MemSize for Arc<T> {
+    fn heap_size(&self) -> usize {
+        self.as_ref().mem_size()
+    }
+}
+
+impl<T: MemSize> MemSize for Vec<T> {
+    fn heap_size(&self) -> usize {
+        let unused = self.capacity() - self.len();
+        unused * mem::size_of::<T>() + self.iter().map(MemSize::mem_size).sum::<usize>()
+    }
+}
+
+impl<T: MemSize> MemSize for VecDeque<T> {
+    fn heap_size(&self) -> usize {
+        let unused = self.capacity() - self.len();
+        unused * mem::size_of::<T>() + self.iter().map(MemSize::mem_size).sum::<usize>()
+    }
+}
+
+impl<T: MemSize, S> MemSize for HashSet<T, S> {
+    fn heap_size(&self) -> usize {
+        let unused = self.capacity() - self.len();
+        unused * mem::size_of::<T>() + self.iter().map(MemSize::mem_size).sum::<usize>()
+    }
+}
+
+impl<K: MemSize, V: MemSize, S> MemSize for HashMap<K, V, S> {
+    fn heap_size(&self) -> usize {
+        let unused = self.capacity() - self.len();
+        let entries = self
+            .iter()
+            .map(|(key, value)| key.mem_size() + value.mem_size())
+            .sum::<usize>();
+
+        unused * mem::size_of::<(K, V)>() + entries
+    }
+}
+
+impl<K: MemSize, V: MemSize> MemSize for BTreeMap<K, V> {
+    fn heap_size(&self) -> usize {
+        self.iter()
+            .map(|(key, value)| key.mem_size() + value.mem_size())
+            .sum()
+    }
+}
+
+impl<A: MemSize, B: MemSize> MemSize for (A, B) {
+    fn heap_size(&self) -> usize {
+        self.0.heap_size() + self.1.heap_size()
+    }
+}
+
+impl<A: MemSize, B: MemSize, C: MemSize> MemSize for (A, B, C) {
+    fn heap_size(&self) -> usize {
+        self.0.heap_size() + self.1.heap_size() + self.2.heap_size()
+    }
+}
+
+/// Estimates the size of the state as the length of its JSON encoding.
+///
+/// Slower and coarser than `MemSize`, but needs nothing besides `Serialize`.
+/// Fits both `Store::memory_report_with` and `HistoryPolicy::max_memory`.
+#[cfg(feature = "serde")]
+pub fn serialized_size<State: serde::Serialize>(state: &State) -> usize {
+    serde_json::to_vec(state).map_or(0, |bytes| bytes.len())
+}
+
+/// Approximate memory used by a store, returned by `Store::memory_report`
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MemoryReport {
+    /// Bytes of the current state
+    pub state: usize,
+
+    /// Actions kept by the history, 0 if it is not recorded
+    pub history_entries: usize,
+
+    /// Bytes of the state snapshots kept by the history, without the snapshot
+    /// which is the current state
+    pub history_snapshots: usize,
+
+    /// Subscriptions of any kind, including channels and observers
+    pub subscriptions: usize,
+}
+
+impl MemoryReport {
+    /// Bytes of the state and the history snapshots together
+    pub fn total(&self) -> usize {
+        self.state + self.history_snapshots
+    }
+}
+
+impl<State: MemSize, Action, Env> Store<State, Action, Env> {
+    /// Reports approximate sizes of the state and the history together with
+    /// the number of subscriptions, so long-running apps can watch the store grow.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::{HistoryPolicy, Store};
+    ///
+    /// type MyStore = Vec<u8>;
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum MyAction {
+    ///     Push(u8),
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     let mut items = state.clone();
+    ///     match action {
+    ///         MyAction::Push(item) => items.push(*item),
+    ///     }
+    ///
+    ///     items
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, vec![]);
+    /// store.enable_history(HistoryPolicy::unbounded());
+    /// store.subscribe(|_state| {});
+    /// store.dispatch(MyAction::Push(1)).dispatch(MyAction::Push(2));
+    ///
+    /// let report = store.memory_report();
+    /// assert!(report.state >= std::mem::size_of::<MyStore>() + 2);
+    /// assert_eq!(report.history_entries, 2);
+    /// assert_eq!(report.subscriptions, 1);
+    /// ```
+    pub fn memory_report(&self) -> MemoryReport {
+        self.memory_report_with(State::mem_size)
+    }
+}
+
+impl<State, Action, Env> Store<State, Action, Env> {
+    /// Same as `memory_report`, but measures states with the `size_of` function,
+    /// e.g. for states which do not implement `MemSize`
+    pub fn memory_report_with(&self, size_of: StateSize<State>) -> MemoryReport {
+        let state = self.shared_state();
+        // The latest snapshot shares the allocation with the current state
+        let (history_entries, history_snapshots) = match self.history.as_ref() {
+            Some(history) => (
+                history.len() - 1,
+                history
+                    .snapshots()
+                    .filter(|snapshot| !Arc::ptr_eq(snapshot, &state))
+                    .map(|snapshot| size_of(snapshot))
+                    .sum(),
+            ),
+            None => (0, 0),
+        };
+
+        MemoryReport {
+            state: size_of(&state),
+            history_entries,
+            history_snapshots,
+            subscriptions: self.subscriptions.len(),
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use crate::{DispatchError, Store};
+
+/// Reconciles states of a merged fork: `(base, main, fork) -> merged`,
+/// where `base` is the state the fork was created from
+pub type MergeResolver<State> = fn(&State, &State, &State) -> State;
+
+/// Defines how `Store::merge` applies changes of the fork
+pub enum MergeStrategy<State> {
+    /// The resolver computes the merged state. When the main store did not
+    /// change since forking, the fork's state is taken without calling it
+    Resolve(MergeResolver<State>),
+
+    /// Actions dispatched into the fork are dispatched into the main store
+    Replay,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum MergeError {
+    /// The store was not created with `Store::fork`
+    NotAFork,
+
+    /// A replayed action could not be applied. Actions before it were applied
+    Dispatch(DispatchError),
+}
+
+impl std::error::Error for MergeError {}
+impl std::fmt::Display for MergeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            MergeError::NotAFork => write!(f, "Cannot merge a store which is not a fork"),
+            MergeError::Dispatch(err) => write!(f, "Cannot replay the fork: {}", err),
+        }
+    }
+}
+
+/// State the fork was created from and the actions dispatched into it since then
+pub(crate) struct ForkBase<State, Action> {
+    pub(crate) state: Arc<State>,
+    pub(crate) actions: Vec<Action>,
+}
+
+impl<State, Action, Env> Store<State, Action, Env> {
+    /// Applies changes of the `fork` to this store, e.g. to publish a draft.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::{MergeStrategy, Store};
+    ///
+    /// type MyStore = Vec<String>;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Add(String),
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Add(item) => {
+    ///             let mut new_state = state.clone();
+    ///             new_state.push(item.clone());
+    ///
+    ///             new_state
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// fn keep_both(base: &MyStore, main: &MyStore, fork: &MyStore) -> MyStore {
+    ///     let mut merged = main.clone();
+    ///     merged.extend(fork[base.len()..].iter().cloned());
+    ///
+    ///     merged
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, vec![]);
+    ///
+    /// let mut draft = store.fork();
+    /// draft.dispatch(MyAction::Add("draft".to_string()));
+    /// store.dispatch(MyAction::Add("live".to_string()));
+    ///
+    /// Store::merge(&mut store, draft, MergeStrategy::Resolve(keep_both)).unwrap();
+    ///
+    /// assert_eq!(*store.state(), vec!["live", "draft"]);
+    /// ```
+    pub fn merge(
+        &mut self,
+        fork: Store<State, Action>,
+        strategy: MergeStrategy<State>,
+    ) -> Result<&mut Self, MergeError> {
+        let base = fork.fork_base.ok_or(MergeError::NotAFork)?;
+
+        match strategy {
+            MergeStrategy::Resolve(resolver) => {
+                let fork_state = fork.state.into_shared();
+                if Arc::ptr_eq(&base.state, &fork_state) {
+                    return Ok(self);
+                }
+
+                let merged = if Arc::ptr_eq(&base.state, &self.shared_state()) {
+                    fork_state
+                } else {
+                    Arc::new(resolver(&base.state, self.state(), &fork_state))
+                };
+
+                self.replace_state(merged);
+            }
+            MergeStrategy::Replay => {
+                for action in base.actions {
+                    self.try_dispatch(action).map_err(MergeError::Dispatch)?;
+                }
+            }
+        }
+
+        Ok(self)
+    }
+}
@@ -0,0 +1,91 @@
+use std::time::Duration;
+
+use crate::Store;
+
+/// Upper bounds of the buckets of `DispatchMetrics::reducer_buckets`
+pub const REDUCER_DURATION_BUCKETS: [Duration; 7] = [
+    Duration::from_micros(1),
+    Duration::from_micros(10),
+    Duration::from_micros(100),
+    Duration::from_millis(1),
+    Duration::from_millis(10),
+    Duration::from_millis(100),
+    Duration::from_secs(1),
+];
+
+/// Counters of dispatched actions and reducer durations, collected after
+/// `Store::enable_metrics`
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DispatchMetrics {
+    /// Actions which reached the reducer
+    pub dispatched: u64,
+
+    /// Reducer calls per bucket of `REDUCER_DURATION_BUCKETS`.
+    /// The last bucket counts the calls slower than all bounds
+    pub reducer_buckets: [u64; REDUCER_DURATION_BUCKETS.len() + 1],
+
+    /// Time spent in the reducer by all calls
+    pub reducer_total: Duration,
+}
+
+impl DispatchMetrics {
+    pub(crate) fn record(&mut self, elapsed: Duration) {
+        let bucket = REDUCER_DURATION_BUCKETS
+            .iter()
+            .position(|bound| elapsed <= *bound)
+            .unwrap_or(REDUCER_DURATION_BUCKETS.len());
+
+        self.dispatched += 1;
+        self.reducer_buckets[bucket] += 1;
+        self.reducer_total += elapsed;
+    }
+}
+
+impl<State, Action, Env> Store<State, Action, Env> {
+    /// Starts counting dispatched actions and measuring the reducer.
+    /// Metrics cost a clock read per action, so they are disabled by default
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::Store;
+    ///
+    /// type MyStore = u8;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Increment,
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Increment => state + 1,
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, 0);
+    /// assert_eq!(store.dispatch_metrics(), None);
+    ///
+    /// store.enable_metrics();
+    /// store.dispatch(MyAction::Increment).dispatch(MyAction::Increment);
+    ///
+    /// assert_eq!(store.dispatch_metrics().unwrap().dispatched, 2);
+    /// ```
+    pub fn enable_metrics(&mut self) {
+        self.metrics.get_or_insert_with(DispatchMetrics::default);
+    }
+
+    /// Stops collecting and drops the metrics
+    pub fn disable_metrics(&mut self) {
+        self.metrics = None;
+    }
+
+    /// Returns the metrics collected since they were enabled, `None` if they are disabled
+    pub fn dispatch_metrics(&self) -> Option<DispatchMetrics> {
+        self.metrics
+    }
+
+    /// Returns the number of subscriptions of any kind
+    pub fn subscriber_count(&self) -> usize {
+        self.subscriptions.len()
+    }
+}
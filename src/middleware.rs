@@ -0,0 +1,426 @@
+use std::collections::VecDeque;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+
+use crate::context::Context;
+use crate::{AbortSignal, DispatchError, Store};
+
+/// Middleware wraps the dispatching of an action.
+///
+/// It receives the action and `Next`, which continues the chain: the next
+/// middleware or, at the end, the reducer. Middleware may inspect, replace or
+/// drop the action and run any code before and after the rest of the chain.
+///
+/// Like `getState` and `dispatch` of Redux middleware, `Next` provides:
+/// - `state` and `shared_state` to read the current state at any point of the chain;
+/// - `dispatch` and `run_and_dispatch` to dispatch new actions. They are queued
+///   and dispatched through the whole middleware chain right after the current
+///   dispatch finishes, so the store is never re-entered while it is reducing.
+///   Queued actions are dispatched in the order they were queued.
+///
+/// ## Example
+/// ```rust
+/// use redust::{DispatchError, Middleware, Next, Store};
+///
+/// type MyStore = u8;
+///
+/// #[derive(Debug)]
+/// enum MyAction {
+///     IncrementBy(u8),
+///     Reset,
+/// };
+///
+/// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+///     match action {
+///         MyAction::IncrementBy(value) => state.saturating_add(*value),
+///         MyAction::Reset => 0,
+///     }
+/// }
+///
+/// // Resets the counter once it reaches the maximum
+/// struct ResetOnOverflow;
+///
+/// impl Middleware<MyStore, MyAction> for ResetOnOverflow {
+///     fn handle(
+///         &self,
+///         action: MyAction,
+///         next: Next<'_, MyStore, MyAction>,
+///     ) -> Result<MyAction, DispatchError> {
+///         next.run_and_dispatch(action, |_action, state| {
+///             (*state == u8::MAX).then(|| MyAction::Reset)
+///         })
+///     }
+/// }
+///
+/// let mut store = Store::new(reducer, 250);
+/// store.add_middleware("reset-on-overflow", ResetOnOverflow);
+///
+/// store.dispatch(MyAction::IncrementBy(10));
+///
+/// assert_eq!(*store.state(), 0);
+/// ```
+pub trait Middleware<State, Action> {
+    /// Handles the action. Returns the action which was finally reduced
+    fn handle(
+        &self,
+        action: Action,
+        next: Next<'_, State, Action>,
+    ) -> Result<Action, DispatchError>;
+}
+
+impl<State, Action, F> Middleware<State, Action> for F
+where
+    F: for<'a> Fn(Action, Next<'a, State, Action>) -> Result<Action, DispatchError>,
+{
+    fn handle(
+        &self,
+        action: Action,
+        next: Next<'_, State, Action>,
+    ) -> Result<Action, DispatchError> {
+        self(action, next)
+    }
+}
+
+pub(crate) struct Layer<State, Action> {
+    name: String,
+    middleware: Box<dyn Middleware<State, Action> + Send + Sync>,
+}
+
+/// Parts of the store used by the middleware chain, so `Next` and middleware
+/// do not depend on the environment of the store
+pub(crate) trait ChainStore<State, Action> {
+    fn state(&self) -> &State;
+    fn shared_state(&self) -> Arc<State>;
+    fn queued_actions(&mut self) -> &mut VecDeque<Action>;
+    fn abort_signal(&self) -> Option<&AbortSignal>;
+    fn context(&self) -> Option<&Context>;
+    fn check_abort(&self) -> Result<(), DispatchError>;
+    fn reject_action(&mut self, action: Action);
+    fn reduce_action(&mut self, action: Action) -> Result<Action, DispatchError>;
+}
+
+impl<State, Action, Env> ChainStore<State, Action> for Store<State, Action, Env> {
+    fn state(&self) -> &State {
+        self.state.get()
+    }
+
+    fn shared_state(&self) -> Arc<State> {
+        self.state.shared()
+    }
+
+    fn queued_actions(&mut self) -> &mut VecDeque<Action> {
+        &mut self.queued_actions
+    }
+
+    fn abort_signal(&self) -> Option<&AbortSignal> {
+        self.abort.as_ref()
+    }
+
+    fn context(&self) -> Option<&Context> {
+        self.context.as_ref()
+    }
+
+    fn check_abort(&self) -> Result<(), DispatchError> {
+        Store::check_abort(self)
+    }
+
+    fn reject_action(&mut self, action: Action) {
+        self.errors.reject(action)
+    }
+
+    fn reduce_action(&mut self, action: Action) -> Result<Action, DispatchError> {
+        Store::reduce_action(self, action)
+    }
+}
+
+/// Continuation of the middleware chain
+pub struct Next<'a, State, Action> {
+    layers: &'a [Layer<State, Action>],
+    store: &'a mut dyn ChainStore<State, Action>,
+}
+
+impl<'a, State, Action> Next<'a, State, Action> {
+    /// Returns the current state of the store
+    pub fn state(&self) -> &State {
+        self.store.state()
+    }
+
+    /// Returns the current state of the store as a cheap snapshot
+    pub fn shared_state(&self) -> Arc<State> {
+        self.store.shared_state()
+    }
+
+    /// Passes the action to the rest of the chain and then calls `inspect` with the
+    /// reduced action, the previous and the new state
+    pub fn run_and_inspect<F>(self, action: Action, inspect: F) -> Result<Action, DispatchError>
+    where
+        F: FnOnce(&Action, &State, &State),
+    {
+        let old_state = self.store.shared_state();
+        let store = self.store;
+        let result = Next {
+            layers: self.layers,
+            store: &mut *store,
+        }
+        .run(action);
+
+        if let Ok(action) = &result {
+            inspect(action, &old_state, store.state());
+        }
+
+        result
+    }
+
+    /// Queues the action, which is dispatched through the whole middleware chain
+    /// after the current dispatch finishes
+    pub fn dispatch(&mut self, action: Action) {
+        self.store.queued_actions().push_back(action);
+    }
+
+    /// Passes the action to the rest of the chain and then queues the actions
+    /// returned by `follow_up`, which receives the reduced action and the new state
+    pub fn run_and_dispatch<F, I>(
+        self,
+        action: Action,
+        follow_up: F,
+    ) -> Result<Action, DispatchError>
+    where
+        F: FnOnce(&Action, &State) -> I,
+        I: IntoIterator<Item = Action>,
+    {
+        let store = self.store;
+        let result = Next {
+            layers: self.layers,
+            store: &mut *store,
+        }
+        .run(action);
+
+        if let Ok(action) = &result {
+            let actions = follow_up(action, store.state());
+            store.queued_actions().extend(actions);
+        }
+
+        result
+    }
+
+    /// Returns the abort signal attached to the dispatch, see `Store::dispatch_with_abort`
+    pub fn abort_signal(&self) -> Option<&AbortSignal> {
+        self.store.abort_signal()
+    }
+
+    /// Returns `Err(DispatchError::Aborted)` if the abort signal of the dispatch fired.
+    /// Long-running middleware call it between steps to fail fast
+    pub fn check_abort(&self) -> Result<(), DispatchError> {
+        self.store.check_abort()
+    }
+
+    /// Returns the dependencies of the store if the context has the type `Deps`,
+    /// see `Store::set_context`
+    pub fn context<Deps: Send + Sync + 'static>(&self) -> Option<Arc<Deps>> {
+        let context = self.store.context()?;
+        Arc::clone(context).downcast::<Deps>().ok()
+    }
+
+    /// Passes the action to the rest of the chain
+    pub fn run(self, action: Action) -> Result<Action, DispatchError> {
+        if let Err(err) = self.store.check_abort() {
+            self.store.reject_action(action);
+            return Err(err);
+        }
+
+        match self.layers.split_first() {
+            Some((layer, layers)) => layer.middleware.handle(
+                action,
+                Next {
+                    layers,
+                    store: self.store,
+                },
+            ),
+            None => self.store.reduce_action(action),
+        }
+    }
+}
+
+/// Ordered list of named middleware.
+///
+/// The first middleware sees an action first; the reducer is always the last step.
+pub struct MiddlewareStack<State, Action> {
+    layers: Vec<Layer<State, Action>>,
+}
+
+impl<State, Action> MiddlewareStack<State, Action> {
+    /// Creates an empty stack
+    pub fn new() -> Self {
+        Self { layers: Vec::new() }
+    }
+
+    /// Appends a named middleware to the end of the stack
+    pub fn with<M>(mut self, name: &str, middleware: M) -> Self
+    where
+        M: Middleware<State, Action> + Send + Sync + 'static,
+    {
+        self.push(name, middleware);
+
+        self
+    }
+
+    /// Appends a named middleware to the end of the stack
+    pub fn push<M>(&mut self, name: &str, middleware: M)
+    where
+        M: Middleware<State, Action> + Send + Sync + 'static,
+    {
+        self.layers.push(Layer {
+            name: name.to_string(),
+            middleware: Box::new(middleware),
+        });
+    }
+
+    /// Appends all middleware of another stack. Their names are prefixed with `name`
+    pub fn with_stack(mut self, name: &str, stack: MiddlewareStack<State, Action>) -> Self {
+        self.layers
+            .extend(stack.layers.into_iter().map(|layer| Layer {
+                name: format!("{}/{}", name, layer.name),
+                middleware: layer.middleware,
+            }));
+
+        self
+    }
+
+    /// Returns names of the middleware in the order they see an action
+    pub fn names(&self) -> Vec<&str> {
+        self.layers
+            .iter()
+            .map(|layer| layer.name.as_str())
+            .collect()
+    }
+
+    /// Describes the order in which the middleware see an action
+    pub fn describe(&self) -> String {
+        self.layers
+            .iter()
+            .map(|layer| layer.name.as_str())
+            .chain(std::iter::once("reducer"))
+            .enumerate()
+            .map(|(index, name)| format!("{}. {}", index + 1, name))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Returns the number of middleware in the stack
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Returns `true` if the stack has no middleware
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+}
+
+impl<State, Action> Default for MiddlewareStack<State, Action> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Composes named middleware into a `MiddlewareStack`.
+/// The first middleware sees an action first.
+///
+/// ## Example
+/// ```rust
+/// use redust::{compose, DispatchError, MiddlewareStack, Next};
+///
+/// type MyStore = u8;
+///
+/// #[derive(Debug)]
+/// enum MyAction {
+///     Increment,
+/// };
+///
+/// fn pass(action: MyAction, next: Next<'_, MyStore, MyAction>) -> Result<MyAction, DispatchError> {
+///     next.run(action)
+/// }
+///
+/// let stack: MiddlewareStack<MyStore, MyAction> = compose![
+///     "logger" => pass,
+///     "auth" => pass,
+/// ];
+///
+/// assert_eq!(stack.describe(), "1. logger\n2. auth\n3. reducer");
+/// ```
+#[macro_export]
+macro_rules! compose {
+    ($( $name: expr => $middleware: expr ),* $(,)?) => {{
+        let stack = $crate::MiddlewareStack::new();
+        $( let stack = stack.with($name, $middleware); )*
+        stack
+    }};
+}
+
+impl<State, Action, Env> Store<State, Action, Env> {
+    /// Appends a named middleware to the end of the store's middleware stack
+    pub fn add_middleware<M>(&mut self, name: &str, middleware: M)
+    where
+        M: Middleware<State, Action> + Send + Sync + 'static,
+    {
+        self.middleware.push(name, middleware);
+    }
+
+    /// Appends all middleware of the stack to the store's middleware stack
+    pub fn apply_middleware(&mut self, stack: MiddlewareStack<State, Action>) {
+        self.middleware.layers.extend(stack.layers);
+    }
+
+    /// Returns the middleware stack of the store
+    pub fn middleware(&self) -> &MiddlewareStack<State, Action> {
+        &self.middleware
+    }
+
+    /// Dispatches actions queued by middleware. Actions queued meanwhile are
+    /// dispatched by the outermost call, so nested dispatches never recurse
+    pub(crate) fn dispatch_queued(&mut self) {
+        if self.dispatching_queued {
+            return;
+        }
+
+        self.dispatching_queued = true;
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            while let Some(action) = self.queued_actions.pop_front() {
+                self.dispatch(action);
+            }
+        }));
+        // Reset even if a reducer panicked, so the remaining actions are
+        // dispatched by the next call instead of waiting forever
+        self.dispatching_queued = false;
+
+        if let Err(payload) = result {
+            panic::resume_unwind(payload);
+        }
+    }
+
+    /// Runs the action through the middleware chain and then the reducer
+    pub(crate) fn dispatch_action(&mut self, action: Action) -> Result<Action, DispatchError> {
+        // An error returned by middleware might come without the action
+        self.errors.clear_rejected();
+        if self.middleware.is_empty() {
+            return self.reduce_action(action);
+        }
+
+        // The stack is moved out, so middleware may borrow the store mutably
+        let stack = std::mem::take(&mut self.middleware);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            Next {
+                layers: &stack.layers,
+                store: &mut *self,
+            }
+            .run(action)
+        }));
+
+        // Put the stack back before a panic is propagated, otherwise it would be lost.
+        // Keep middleware added while the action was dispatched
+        let added = std::mem::replace(&mut self.middleware, stack);
+        self.middleware.layers.extend(added.layers);
+
+        result.unwrap_or_else(|payload| panic::resume_unwind(payload))
+    }
+}
@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Reducer, Store};
+
+/// Schema version of the state
+pub type Version = u32;
+
+/// State which knows its schema version and how to convert itself
+/// from/to a raw (serializable) representation.
+pub trait VersionedState: Sized {
+    /// Representation shared by all versions of the state, e.g. `serde_json::Value`
+    type Raw;
+
+    /// Current schema version
+    const VERSION: Version;
+
+    fn from_raw(raw: Self::Raw) -> Self;
+    fn to_raw(&self) -> Self::Raw;
+}
+
+/// Raw state tagged with the schema version it was written with
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Versioned<Raw> {
+    pub version: Version,
+    pub state: Raw,
+}
+
+/// Upgrades the raw state from `source_version()` to `source_version() + 1`
+pub trait Migration<Raw> {
+    fn source_version(&self) -> Version;
+    fn migrate(&self, raw: Raw) -> Raw;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum MigrationError {
+    /// The state was written by a newer schema than the application supports
+    UnsupportedVersion { found: Version, supported: Version },
+
+    /// There is no registered migration from this version
+    MissingMigration(Version),
+}
+
+impl std::error::Error for MigrationError {}
+impl std::fmt::Display for MigrationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            MigrationError::UnsupportedVersion { found, supported } => write!(
+                f,
+                "Cannot hydrate state of version {}, the latest supported version is {}",
+                found, supported
+            ),
+            MigrationError::MissingMigration(version) => {
+                write!(f, "Cannot find the migration from version: {}", version)
+            }
+        }
+    }
+}
+
+/// Set of migrations which are applied step-by-step
+pub struct Migrations<Raw> {
+    migrations: HashMap<Version, Box<dyn Migration<Raw>>>,
+}
+
+impl<Raw> Migrations<Raw> {
+    pub fn new() -> Self {
+        Self {
+            migrations: HashMap::new(),
+        }
+    }
+
+    /// Registers a migration. A migration registered for the same version replaces the previous one
+    pub fn register<M: Migration<Raw> + 'static>(mut self, migration: M) -> Self {
+        self.migrations
+            .insert(migration.source_version(), Box::new(migration));
+
+        self
+    }
+
+    /// Upgrades the raw state up to the `target` version
+    pub fn migrate(
+        &self,
+        versioned: Versioned<Raw>,
+        target: Version,
+    ) -> Result<Raw, MigrationError> {
+        if versioned.version > target {
+            return Err(MigrationError::UnsupportedVersion {
+                found: versioned.version,
+                supported: target,
+            });
+        }
+
+        let mut raw = versioned.state;
+        for version in versioned.version..target {
+            let migration = self
+                .migrations
+                .get(&version)
+                .ok_or(MigrationError::MissingMigration(version))?;
+
+            raw = migration.migrate(raw);
+        }
+
+        Ok(raw)
+    }
+}
+
+impl<Raw> Default for Migrations<Raw> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<State: VersionedState, Action> Store<State, Action> {
+    /// Creates a new store from the previously saved state,
+    /// upgrading it to the current `State::VERSION` if necessary.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::{Migration, Migrations, Store, Version, Versioned, VersionedState};
+    ///
+    /// // Version 0 stored the counter as a string, version 1 stores a number
+    /// struct Counter(u8);
+    ///
+    /// impl VersionedState for Counter {
+    ///     type Raw = String;
+    ///     const VERSION: Version = 1;
+    ///
+    ///     fn from_raw(raw: String) -> Self {
+    ///         Counter(raw.parse().unwrap())
+    ///     }
+    ///
+    ///     fn to_raw(&self) -> String {
+    ///         self.0.to_string()
+    ///     }
+    /// }
+    ///
+    /// struct WordsToNumbers;
+    ///
+    /// impl Migration<String> for WordsToNumbers {
+    ///     fn source_version(&self) -> Version {
+    ///         0
+    ///     }
+    ///
+    ///     fn migrate(&self, raw: String) -> String {
+    ///         match raw.as_str() {
+    ///             "one" => "1".to_string(),
+    ///             _ => "0".to_string(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {};
+    ///
+    /// fn reducer(state: &Counter, _action: &MyAction) -> Counter {
+    ///     Counter(state.0)
+    /// }
+    ///
+    /// let migrations = Migrations::new().register(WordsToNumbers);
+    /// let saved = Versioned { version: 0, state: "one".to_string() };
+    ///
+    /// let store = Store::hydrate(reducer, saved, &migrations).unwrap();
+    ///
+    /// assert_eq!(store.state().0, 1);
+    /// ```
+    pub fn hydrate(
+        reducer: Reducer<State, Action>,
+        versioned: Versioned<State::Raw>,
+        migrations: &Migrations<State::Raw>,
+    ) -> Result<Self, MigrationError> {
+        let raw = migrations.migrate(versioned, State::VERSION)?;
+
+        Ok(Self::new(reducer, State::from_raw(raw)))
+    }
+}
+
+impl<State: VersionedState, Action, Env> Store<State, Action, Env> {
+    /// Returns the raw representation of the current state tagged with `State::VERSION`
+    pub fn dehydrate(&self) -> Versioned<State::Raw> {
+        Versioned {
+            version: State::VERSION,
+            state: self.state().to_raw(),
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+
+use crate::Store;
+
+/// Detects reducers which changed the previous state through interior mutability
+pub(crate) struct MutationCheck<State, Action> {
+    fingerprint: fn(&State) -> u64,
+    describe: fn(&Action) -> String,
+}
+
+impl<State, Action> MutationCheck<State, Action> {
+    /// Fingerprints the state before the reducer runs
+    pub(crate) fn before(&self, state: &State) -> u64 {
+        (self.fingerprint)(state)
+    }
+
+    /// Panics if the previous state is not the same as before the reducer ran
+    pub(crate) fn after(&self, before: u64, previous_state: &State, action: &Action) {
+        if (self.fingerprint)(previous_state) != before {
+            panic!(
+                "Reducer mutated the previous state while reducing {}",
+                (self.describe)(action)
+            );
+        }
+    }
+}
+
+fn fingerprint<State: Hash>(state: &State) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    state.hash(&mut hasher);
+
+    hasher.finish()
+}
+
+fn describe<Action: Debug>(action: &Action) -> String {
+    format!("{:?}", action)
+}
+
+impl<State: Hash, Action: Debug, Env> Store<State, Action, Env> {
+    /// Hashes the previous state before and after every reducer call and
+    /// panics with the offending action if the hash changed. Such a reducer
+    /// mutated data shared with the previous state, e.g. through `Arc<Mutex<_>>`.
+    ///
+    /// Hashing the whole state on every dispatch is expensive, so enable it
+    /// only in debug builds and tests.
+    ///
+    /// ## Example
+    /// ```rust,should_panic
+    /// use redust::Store;
+    /// use std::hash::{Hash, Hasher};
+    /// use std::sync::atomic::{AtomicU8, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Debug, Default)]
+    /// struct MyStore {
+    ///     counter: Arc<AtomicU8>,
+    /// }
+    ///
+    /// impl Hash for MyStore {
+    ///     fn hash<H: Hasher>(&self, state: &mut H) {
+    ///         self.counter.load(Ordering::SeqCst).hash(state);
+    ///     }
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Increment,
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Increment => {
+    ///             // Bug: the counter is shared with the previous state
+    ///             state.counter.fetch_add(1, Ordering::SeqCst);
+    ///
+    ///             MyStore {
+    ///                 counter: Arc::clone(&state.counter),
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, MyStore::default());
+    /// store.check_mutations();
+    ///
+    /// // Panics with "Reducer mutated the previous state while reducing Increment"
+    /// store.dispatch(MyAction::Increment);
+    /// ```
+    pub fn check_mutations(&mut self) {
+        self.mutation_check = Some(MutationCheck {
+            fingerprint: fingerprint::<State>,
+            describe: describe::<Action>,
+        });
+    }
+}
+
+impl<State, Action, Env> Store<State, Action, Env> {
+    /// Stops checking reducers for mutations of the previous state
+    pub fn skip_mutation_checks(&mut self) {
+        self.mutation_check = None;
+    }
+}
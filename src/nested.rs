@@ -0,0 +1,69 @@
+use crate::Store;
+
+/// Implements `From` for every slice action wrapped by a variant of the root action enum,
+/// so slice code can dispatch its local actions with `dispatch_from`.
+///
+/// ## Example
+/// ```rust
+/// use redust::{nest_actions, Store};
+///
+/// #[derive(Debug)]
+/// enum TodosAction {
+///     Add(&'static str),
+/// }
+///
+/// #[derive(Debug)]
+/// enum FilterAction {
+///     Set(&'static str),
+/// }
+///
+/// #[derive(Debug)]
+/// enum AppAction {
+///     Todos(TodosAction),
+///     Filter(FilterAction),
+/// }
+///
+/// nest_actions!(AppAction {
+///     Todos(TodosAction),
+///     Filter(FilterAction),
+/// });
+///
+/// type MyStore = (Vec<&'static str>, &'static str);
+///
+/// fn reducer(state: &MyStore, action: &AppAction) -> MyStore {
+///     let (mut todos, filter) = state.clone();
+///     match action {
+///         AppAction::Todos(TodosAction::Add(todo)) => todos.push(todo),
+///         AppAction::Filter(FilterAction::Set(filter)) => return (todos, filter),
+///     }
+///
+///     (todos, filter)
+/// }
+///
+/// let mut store = Store::new(reducer, (vec![], "all"));
+/// store
+///     .dispatch_from(TodosAction::Add("Buy milk"))
+///     .dispatch_from(FilterAction::Set("done"));
+///
+/// assert_eq!(*store.state(), (vec!["Buy milk"], "done"));
+/// ```
+#[macro_export]
+macro_rules! nest_actions {
+    ($root: ident { $( $variant: ident ( $action: ty ) ),* $(,)? }) => {
+        $(
+            impl From<$action> for $root {
+                fn from(action: $action) -> Self {
+                    $root::$variant(action)
+                }
+            }
+        )*
+    };
+}
+
+impl<State, Action, Env> Store<State, Action, Env> {
+    /// Converts the action into the action type of the store and dispatches it.
+    /// Conversions of nested action enums are generated with `nest_actions!`
+    pub fn dispatch_from<A: Into<Action>>(&mut self, action: A) -> &mut Self {
+        self.dispatch(action.into())
+    }
+}
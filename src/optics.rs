@@ -0,0 +1,59 @@
+/// Returns a copy of the state with one nested field changed.
+///
+/// Replaces the clone-then-mutate boilerplate in reducers:
+/// `update!(state.path = value)` clones the `state`, assigns the `value`
+/// to the `path` of the copy and returns it. The path may contain `?` after
+/// fallible steps, e.g. `get_mut(&id)?`; if such step returns `None` the copy
+/// is returned unchanged. A leading `*` replaces the value behind a reference:
+/// `update!(*state.todos.get_mut(&id)? = todo)`.
+///
+/// ## Example
+/// ```rust
+/// use redust::update;
+/// use std::collections::HashMap;
+///
+/// #[derive(Clone)]
+/// struct Todo {
+///     checked: bool,
+/// }
+///
+/// #[derive(Clone)]
+/// struct Todos {
+///     todos: HashMap<u8, Todo>,
+///     filter: &'static str,
+/// }
+///
+/// let mut todos = HashMap::new();
+/// todos.insert(1, Todo { checked: false });
+/// let state = Todos { todos, filter: "all" };
+///
+/// let checked = update!(state.todos.get_mut(&1)?.checked = true);
+/// let unchanged = update!(state.todos.get_mut(&2)?.checked = true);
+/// let filtered = update!(state.filter = "active");
+///
+/// assert!(checked.todos[&1].checked);
+/// assert!(!unchanged.todos[&1].checked);
+/// assert_eq!(filtered.filter, "active");
+/// ```
+#[macro_export]
+macro_rules! update {
+    (@path [$($deref: tt)?] $state: ident [$($path: tt)*] = $value: expr) => {{
+        let mut new_state = $state.clone();
+        // `?` in the path stops the update instead of returning from the reducer
+        let _ = (|| -> ::std::option::Option<()> {
+            $($deref)? new_state $($path)* = $value;
+            ::std::option::Option::Some(())
+        })();
+
+        new_state
+    }};
+    (@path [$($deref: tt)?] $state: ident [$($path: tt)*] $next: tt $($rest: tt)*) => {
+        $crate::update!(@path [$($deref)?] $state [$($path)* $next] $($rest)*)
+    };
+    (* $state: ident $($rest: tt)+) => {
+        $crate::update!(@path [*] $state [] $($rest)+)
+    };
+    ($state: ident $($rest: tt)+) => {
+        $crate::update!(@path [] $state [] $($rest)+)
+    };
+}
@@ -0,0 +1,84 @@
+//! Tracing spans for dispatches and async effects.
+//!
+//! Spans are emitted with the `tracing` crate; export them to OpenTelemetry
+//! with `tracing-opentelemetry` or any other subscriber:
+//!
+//! * `TracingMiddleware` wraps every dispatch in a `redust.dispatch` span.
+//! * `spawn_async_effect` and `spawn_local_effect` run the effect in
+//!   a `redust.effect` span, a child of the span which spawned it.
+//! * `DispatchQueue` remembers the span an action was enqueued in, and
+//!   `Store::drain` dispatches the action inside it. The follow-up action of
+//!   an effect therefore becomes a descendant of the originating dispatch.
+//!
+//! Available behind the `otel` feature.
+
+use std::fmt::Debug;
+use std::future::Future;
+
+use tracing::field::{display, Empty};
+use tracing::instrument::Instrumented;
+use tracing::Instrument;
+
+use crate::{DispatchError, Middleware, Next};
+
+/// Middleware which wraps the dispatch in a `redust.dispatch` span with the
+/// action in its `action` field. Failed dispatches record the error.
+///
+/// The span covers the middleware added after it, the reducer and the
+/// subscribers, so it should be added first.
+///
+/// ## Example
+/// ```rust
+/// use redust::otel::TracingMiddleware;
+/// use redust::Store;
+///
+/// type MyStore = u8;
+///
+/// #[derive(Debug)]
+/// enum MyAction {
+///     Increment,
+/// };
+///
+/// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+///     match action {
+///         MyAction::Increment => state + 1,
+///     }
+/// }
+///
+/// let mut store = Store::new(reducer, 0);
+/// store.add_middleware("otel", TracingMiddleware);
+///
+/// store.dispatch(MyAction::Increment);
+/// assert_eq!(*store.state(), 1);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TracingMiddleware;
+
+impl<State, Action: Debug> Middleware<State, Action> for TracingMiddleware {
+    fn handle(
+        &self,
+        action: Action,
+        next: Next<'_, State, Action>,
+    ) -> Result<Action, DispatchError> {
+        let span = tracing::info_span!(
+            "redust.dispatch",
+            action = ?action,
+            otel.status_code = Empty,
+            error = Empty,
+        );
+        let _entered = span.enter();
+
+        let result = next.run(action);
+        if let Err(err) = &result {
+            span.record("otel.status_code", "ERROR");
+            span.record("error", display(err));
+        }
+
+        result
+    }
+}
+
+/// Runs the effect in a `redust.effect` span, a child of the current span
+pub(crate) fn in_effect_span<F: Future>(future: F) -> Instrumented<F> {
+    future.instrument(tracing::info_span!("redust.effect"))
+}
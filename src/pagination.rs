@@ -0,0 +1,144 @@
+use std::collections::BTreeMap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{EntityAdapter, EntityState};
+
+/// Ids of entities loaded as one page of a server collection
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Page<Id> {
+    pub ids: Vec<Id>,
+
+    /// Cursor which requests the next page. `None` for the last page
+    /// and for offset pagination
+    pub next_key: Option<String>,
+}
+
+/// Server collection loaded page by page, with either cursor or offset pagination
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PaginatedState<Id: Ord, Entity> {
+    pub entities: EntityState<Id, Entity>,
+    pub pages: BTreeMap<usize, Page<Id>>,
+    pub page_size: usize,
+
+    /// Total number of entities on the server if it reported one
+    pub total: Option<usize>,
+}
+
+impl<Id: Ord, Entity> PaginatedState<Id, Entity> {
+    /// Creates an empty collection with pages of `page_size` entities
+    pub fn new(page_size: usize) -> Self {
+        Self {
+            entities: EntityState::new(),
+            pages: BTreeMap::new(),
+            page_size,
+            total: None,
+        }
+    }
+
+    /// Returns the offset of the first entity of the page, for offset pagination
+    pub fn offset(&self, page: usize) -> usize {
+        page * self.page_size
+    }
+
+    /// Returns the number of pages if the total is known
+    pub fn page_count(&self) -> Option<usize> {
+        let page_size = self.page_size.max(1);
+
+        self.total.map(|total| total.div_ceil(page_size))
+    }
+
+    /// Returns the cursor which requests the page after the given one
+    pub fn next_key(&self, page: usize) -> Option<&str> {
+        self.pages.get(&page)?.next_key.as_deref()
+    }
+
+    /// Returns `true` if the page was loaded
+    pub fn is_loaded(&self, page: usize) -> bool {
+        self.pages.contains_key(&page)
+    }
+}
+
+impl<Id: Ord + Clone, Entity> EntityAdapter<Id, Entity> {
+    /// Stores entities of the loaded page, replacing the page if it was loaded before.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::{EntityAdapter, PaginatedState};
+    ///
+    /// #[derive(Debug, Clone, PartialEq)]
+    /// struct User {
+    ///     id: u32,
+    /// }
+    ///
+    /// let users = EntityAdapter::new(|user: &User| user.id);
+    /// let mut state = PaginatedState::new(2);
+    ///
+    /// users.page_loaded(
+    ///     &mut state,
+    ///     0,
+    ///     vec![User { id: 1 }, User { id: 2 }],
+    ///     Some("after-2".to_string()),
+    ///     Some(3),
+    /// );
+    ///
+    /// assert_eq!(state.page_count(), Some(2));
+    /// assert_eq!(state.next_key(0), Some("after-2"));
+    /// assert_eq!(users.select_page(&state, 0).unwrap().len(), 2);
+    /// assert_eq!(users.select_page(&state, 1), None);
+    /// ```
+    pub fn page_loaded(
+        &self,
+        state: &mut PaginatedState<Id, Entity>,
+        page: usize,
+        entities: Vec<Entity>,
+        next_key: Option<String>,
+        total: Option<usize>,
+    ) {
+        let ids = entities.iter().map(|entity| self.id(entity)).collect();
+        self.upsert_many(&mut state.entities, entities);
+        state.pages.insert(page, Page { ids, next_key });
+
+        if total.is_some() {
+            state.total = total;
+        }
+    }
+
+    /// Returns entities of the page in server order or `None` if it is not loaded
+    pub fn select_page<'a>(
+        &self,
+        state: &'a PaginatedState<Id, Entity>,
+        page: usize,
+    ) -> Option<Vec<&'a Entity>> {
+        let page = state.pages.get(&page)?;
+
+        Some(
+            page.ids
+                .iter()
+                .filter_map(|id| state.entities.entities.get(id))
+                .collect(),
+        )
+    }
+
+    /// Returns entities of all loaded pages in page order
+    pub fn select_loaded<'a>(
+        &self,
+        state: &'a PaginatedState<Id, Entity>,
+    ) -> impl Iterator<Item = &'a Entity> {
+        state
+            .pages
+            .values()
+            .flat_map(|page| page.ids.iter())
+            .filter_map(move |id| state.entities.entities.get(id))
+    }
+
+    /// Forgets all loaded pages and entities, e.g. when the filter changes
+    pub fn reset_pages(&self, state: &mut PaginatedState<Id, Entity>) {
+        state.pages.clear();
+        state.total = None;
+        self.set_all(&mut state.entities, Vec::new());
+    }
+}
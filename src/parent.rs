@@ -0,0 +1,204 @@
+use std::any::{type_name, Any};
+
+use crate::{SliceKey, Store};
+
+/// Type-erased store mounted into a `ParentStore`
+trait AnyStore {
+    fn dispatch_any(&mut self, action: &dyn Any) -> bool;
+    fn state_any(&self) -> &dyn Any;
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<State, Action> AnyStore for Store<State, Action>
+where
+    State: 'static,
+    Action: Clone + 'static,
+{
+    fn dispatch_any(&mut self, action: &dyn Any) -> bool {
+        match action.downcast_ref::<Action>() {
+            Some(action) => {
+                self.dispatch(action.clone());
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn state_any(&self) -> &dyn Any {
+        self.state()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RouteError {
+    /// No mounted store accepts actions of this type
+    UnknownAction(&'static str),
+}
+
+impl std::error::Error for RouteError {}
+impl std::fmt::Display for RouteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            RouteError::UnknownAction(action_type) => {
+                write!(
+                    f,
+                    "Cannot find a store for the action type: {}",
+                    action_type
+                )
+            }
+        }
+    }
+}
+
+/// Facade over several independently created stores.
+///
+/// Each action is routed by its type to every mounted store which accepts it.
+/// Useful as a migration path from separate stores to a single state tree.
+///
+/// ## Example
+/// ```rust
+/// use redust::{ParentStore, Store};
+///
+/// #[derive(Debug, Clone)]
+/// enum CounterAction {
+///     Increment,
+/// };
+///
+/// #[derive(Debug, Clone)]
+/// enum LogAction {
+///     Log(&'static str),
+/// };
+///
+/// fn counter_reducer(state: &u8, action: &CounterAction) -> u8 {
+///     match action {
+///         CounterAction::Increment => state + 1,
+///     }
+/// }
+///
+/// fn log_reducer(state: &Vec<&'static str>, action: &LogAction) -> Vec<&'static str> {
+///     match action {
+///         LogAction::Log(message) => {
+///             let mut new_state = state.clone();
+///             new_state.push(message);
+///
+///             new_state
+///         }
+///     }
+/// }
+///
+/// let mut parent = ParentStore::new()
+///     .mount("counter", Store::new(counter_reducer, 0))
+///     .mount("log", Store::new(log_reducer, vec![]));
+///
+/// parent
+///     .dispatch(CounterAction::Increment)
+///     .unwrap()
+///     .dispatch(LogAction::Log("incremented"))
+///     .unwrap();
+///
+/// assert_eq!(parent.state().get::<u8>("counter"), Some(&1));
+/// assert_eq!(parent.state().get::<Vec<&str>>("log").map(Vec::len), Some(1));
+/// ```
+pub struct ParentStore {
+    stores: Vec<(SliceKey, Box<dyn AnyStore>)>,
+}
+
+impl ParentStore {
+    /// Creates a parent without mounted stores
+    pub fn new() -> Self {
+        Self { stores: Vec::new() }
+    }
+
+    /// Mounts the store under the `key`. A store mounted under the same key replaces the previous one
+    pub fn mount<State, Action>(mut self, key: SliceKey, store: Store<State, Action>) -> Self
+    where
+        State: 'static,
+        Action: Clone + 'static,
+    {
+        self.stores.retain(|(mounted_key, _)| *mounted_key != key);
+        self.stores.push((key, Box::new(store)));
+
+        self
+    }
+
+    /// Dispatches the action into every mounted store which accepts actions of its type
+    pub fn dispatch<Action: 'static>(&mut self, action: Action) -> Result<&mut Self, RouteError> {
+        let mut routed = false;
+        for (_, store) in self.stores.iter_mut() {
+            routed |= store.dispatch_any(&action);
+        }
+
+        if routed {
+            Ok(self)
+        } else {
+            Err(RouteError::UnknownAction(type_name::<Action>()))
+        }
+    }
+
+    /// Returns the combined view of all mounted states
+    pub fn state(&self) -> CombinedState<'_> {
+        CombinedState { parent: self }
+    }
+
+    /// Returns the mounted store. Returns `None` if there is no such store or it has other types
+    pub fn store<State: 'static, Action: 'static>(
+        &self,
+        key: SliceKey,
+    ) -> Option<&Store<State, Action>> {
+        self.find(key)?.as_any().downcast_ref()
+    }
+
+    /// Returns the mounted store, e.g. to subscribe to it
+    pub fn store_mut<State: 'static, Action: 'static>(
+        &mut self,
+        key: SliceKey,
+    ) -> Option<&mut Store<State, Action>> {
+        self.stores
+            .iter_mut()
+            .find(|(mounted_key, _)| *mounted_key == key)?
+            .1
+            .as_any_mut()
+            .downcast_mut()
+    }
+
+    fn find(&self, key: SliceKey) -> Option<&dyn AnyStore> {
+        self.stores
+            .iter()
+            .find(|(mounted_key, _)| *mounted_key == key)
+            .map(|(_, store)| store.as_ref())
+    }
+}
+
+impl Default for ParentStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Combined read-only view of the states of all stores mounted into a `ParentStore`
+pub struct CombinedState<'a> {
+    parent: &'a ParentStore,
+}
+
+impl<'a> CombinedState<'a> {
+    /// Returns the state of the store mounted under the `key`.
+    ///
+    /// Returns `None` if there is no such store or its state has another type
+    pub fn get<State: 'static>(&self, key: SliceKey) -> Option<&'a State> {
+        self.parent.find(key)?.state_any().downcast_ref()
+    }
+
+    /// Returns keys of the mounted stores in the order they were mounted
+    pub fn keys(&self) -> Vec<SliceKey> {
+        self.parent.stores.iter().map(|(key, _)| *key).collect()
+    }
+}
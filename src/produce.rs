@@ -0,0 +1,87 @@
+use std::ops::{Deref, DerefMut};
+
+/// Mutable view of a state passed to the recipe of `produce`.
+///
+/// Reading through the draft does not copy anything; the state is cloned
+/// on the first mutable access.
+pub struct Draft<'a, State: Clone> {
+    original: &'a State,
+    copy: Option<State>,
+}
+
+impl<'a, State: Clone> Draft<'a, State> {
+    /// Returns `true` if the recipe accessed the state mutably
+    pub fn is_modified(&self) -> bool {
+        self.copy.is_some()
+    }
+
+    /// Returns the state the draft was created from
+    pub fn original(&self) -> &State {
+        self.original
+    }
+}
+
+impl<'a, State: Clone> Deref for Draft<'a, State> {
+    type Target = State;
+
+    fn deref(&self) -> &State {
+        self.copy.as_ref().unwrap_or(self.original)
+    }
+}
+
+impl<'a, State: Clone> DerefMut for Draft<'a, State> {
+    fn deref_mut(&mut self) -> &mut State {
+        let original = self.original;
+        self.copy.get_or_insert_with(|| original.clone())
+    }
+}
+
+/// Returns the new state produced by the `recipe`, which mutates a draft of the `state`.
+///
+/// Lets reducers be written imperatively while staying pure: the previous
+/// state is never changed.
+///
+/// ## Example
+/// ```rust
+/// use redust::produce;
+/// use std::collections::HashMap;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Todo {
+///     checked: bool,
+/// }
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Todos {
+///     todos: HashMap<u8, Todo>,
+///     filter: &'static str,
+/// }
+///
+/// let mut todos = HashMap::new();
+/// todos.insert(1, Todo { checked: false });
+/// let state = Todos { todos, filter: "all" };
+///
+/// let new_state = produce(&state, |draft| {
+///     if draft.filter == "all" {
+///         draft.todos.get_mut(&1).unwrap().checked = true;
+///         draft.filter = "done";
+///     }
+/// });
+///
+/// assert!(new_state.todos[&1].checked);
+/// assert_eq!(new_state.filter, "done");
+/// assert!(!state.todos[&1].checked);
+/// ```
+pub fn produce<State, F>(state: &State, recipe: F) -> State
+where
+    State: Clone,
+    F: FnOnce(&mut Draft<'_, State>),
+{
+    let mut draft = Draft {
+        original: state,
+        copy: None,
+    };
+    recipe(&mut draft);
+
+    draft.copy.unwrap_or_else(|| state.clone())
+}
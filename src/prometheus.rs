@@ -0,0 +1,202 @@
+//! Store metrics in the Prometheus text exposition format.
+//!
+//! `Exporter` collects dispatch counters, reducer duration histograms,
+//! subscriber counts and queue depths of named stores and queues. Build one
+//! per scrape and serve the `gather` output from the `/metrics` endpoint of
+//! the HTTP server the application already runs.
+//!
+//! Available behind the `prometheus` feature.
+
+use std::fmt::Write;
+
+use crate::{DispatchMetrics, DispatchQueue, Store, REDUCER_DURATION_BUCKETS};
+
+struct StoreSample {
+    name: String,
+    metrics: Option<DispatchMetrics>,
+    subscribers: usize,
+}
+
+/// Collects metrics of stores and queues and renders them for Prometheus.
+///
+/// Dispatch counters and reducer durations are reported only for stores
+/// which called `Store::enable_metrics`.
+///
+/// ## Example
+/// ```rust
+/// use redust::prometheus::Exporter;
+/// use redust::{DispatchQueue, Store};
+///
+/// type MyStore = u8;
+///
+/// #[derive(Debug)]
+/// enum MyAction {
+///     Increment,
+/// };
+///
+/// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+///     match action {
+///         MyAction::Increment => state + 1,
+///     }
+/// }
+///
+/// let mut store = Store::new(reducer, 0);
+/// let queue = DispatchQueue::<MyAction>::new();
+/// store.enable_metrics();
+/// store.dispatch(MyAction::Increment);
+///
+/// let text = Exporter::new()
+///     .store("counter", &store)
+///     .queue("counter", &queue)
+///     .gather();
+///
+/// assert!(text.contains("redust_dispatched_actions_total{store=\"counter\"} 1\n"));
+/// assert!(text.contains("redust_queue_depth{queue=\"counter\"} 0\n"));
+/// ```
+#[derive(Default)]
+pub struct Exporter {
+    stores: Vec<StoreSample>,
+    queues: Vec<(String, usize)>,
+}
+
+impl Exporter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads the metrics of the store, labeled with the `name`
+    pub fn store<State, Action>(&mut self, name: &str, store: &Store<State, Action>) -> &mut Self {
+        self.stores.push(StoreSample {
+            name: name.to_string(),
+            metrics: store.dispatch_metrics(),
+            subscribers: store.subscriber_count(),
+        });
+
+        self
+    }
+
+    /// Reads the depth of the queue, labeled with the `name`
+    pub fn queue<Action>(&mut self, name: &str, queue: &DispatchQueue<Action>) -> &mut Self {
+        self.queues.push((name.to_string(), queue.len()));
+
+        self
+    }
+
+    /// Renders the collected metrics in the Prometheus text format
+    pub fn gather(&self) -> String {
+        let mut text = String::new();
+        let measured = self
+            .stores
+            .iter()
+            .filter_map(|sample| Some((label(&sample.name), sample.metrics?)))
+            .collect::<Vec<_>>();
+
+        if !measured.is_empty() {
+            family(
+                &mut text,
+                "redust_dispatched_actions_total",
+                "counter",
+                "Actions which reached the reducer.",
+            );
+            for (store, metrics) in &measured {
+                sample(
+                    &mut text,
+                    "redust_dispatched_actions_total",
+                    &format!("store=\"{}\"", store),
+                    metrics.dispatched,
+                );
+            }
+
+            family(
+                &mut text,
+                "redust_reducer_duration_seconds",
+                "histogram",
+                "Time spent in the reducer.",
+            );
+            for (store, metrics) in &measured {
+                histogram(&mut text, store, metrics);
+            }
+        }
+
+        if !self.stores.is_empty() {
+            family(
+                &mut text,
+                "redust_subscribers",
+                "gauge",
+                "Subscriptions of the store.",
+            );
+            for store in &self.stores {
+                let labels = format!("store=\"{}\"", label(&store.name));
+                sample(&mut text, "redust_subscribers", &labels, store.subscribers);
+            }
+        }
+
+        if !self.queues.is_empty() {
+            family(
+                &mut text,
+                "redust_queue_depth",
+                "gauge",
+                "Actions waiting in the dispatch queue.",
+            );
+            for (queue, depth) in &self.queues {
+                let labels = format!("queue=\"{}\"", label(queue));
+                sample(&mut text, "redust_queue_depth", &labels, depth);
+            }
+        }
+
+        text
+    }
+}
+
+fn family(text: &mut String, name: &str, kind: &str, help: &str) {
+    // Writing into a `String` cannot fail
+    let _ = writeln!(text, "# HELP {} {}", name, help);
+    let _ = writeln!(text, "# TYPE {} {}", name, kind);
+}
+
+fn sample(text: &mut String, name: &str, labels: &str, value: impl std::fmt::Display) {
+    let _ = writeln!(text, "{}{{{}}} {}", name, labels, value);
+}
+
+// Prometheus buckets are cumulative: each one counts the calls up to its bound
+fn histogram(text: &mut String, store: &str, metrics: &DispatchMetrics) {
+    let name = "redust_reducer_duration_seconds";
+    let mut cumulative = 0;
+    for (bound, count) in REDUCER_DURATION_BUCKETS
+        .iter()
+        .zip(&metrics.reducer_buckets)
+    {
+        cumulative += count;
+        let labels = format!("store=\"{}\",le=\"{}\"", store, bound.as_secs_f64());
+        sample(text, &format!("{}_bucket", name), &labels, cumulative);
+    }
+
+    let labels = format!("store=\"{}\"", store);
+    let infinity = format!("{},le=\"+Inf\"", labels);
+    sample(
+        text,
+        &format!("{}_bucket", name),
+        &infinity,
+        metrics.dispatched,
+    );
+    sample(
+        text,
+        &format!("{}_sum", name),
+        &labels,
+        metrics.reducer_total.as_secs_f64(),
+    );
+    sample(
+        text,
+        &format!("{}_count", name),
+        &labels,
+        metrics.dispatched,
+    );
+}
+
+/// Escapes the label value
+fn label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
@@ -0,0 +1,131 @@
+//! Protobuf codec for exchanging actions and state snapshots with non-Rust services.
+//!
+//! Types generated by `prost` describe the stable schema; `ProtoConvert`
+//! maps them to the application types, e.g. a `oneof` message to the action enum.
+//!
+//! Available behind the `prost` feature.
+
+use prost::Message;
+
+use crate::{Reducer, Store};
+
+/// Conversion between an application type and its protobuf message
+pub trait ProtoConvert: Sized {
+    type Message: Message + Default;
+
+    fn to_proto(&self) -> Self::Message;
+    fn from_proto(message: Self::Message) -> Result<Self, ProtoError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ProtoError {
+    /// Bytes are not a valid protobuf message
+    Decode(String),
+
+    /// The message is valid but cannot be mapped to the application type,
+    /// e.g. the `oneof` field is not set
+    Unmapped(String),
+}
+
+impl std::error::Error for ProtoError {}
+impl std::fmt::Display for ProtoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ProtoError::Decode(message) => {
+                write!(f, "Cannot decode the protobuf message: {}", message)
+            }
+            ProtoError::Unmapped(message) => {
+                write!(f, "Cannot map the protobuf message: {}", message)
+            }
+        }
+    }
+}
+
+/// Encodes the value as a protobuf message
+pub fn encode<T: ProtoConvert>(value: &T) -> Vec<u8> {
+    value.to_proto().encode_to_vec()
+}
+
+/// Decodes the protobuf message and maps it to the value
+pub fn decode<T: ProtoConvert>(bytes: &[u8]) -> Result<T, ProtoError> {
+    let message = T::Message::decode(bytes).map_err(|err| ProtoError::Decode(err.to_string()))?;
+
+    T::from_proto(message)
+}
+
+impl<State: ProtoConvert, Action, Env> Store<State, Action, Env> {
+    /// Encodes the current state as a protobuf message
+    pub fn snapshot_proto(&self) -> Vec<u8> {
+        encode(self.state())
+    }
+}
+
+impl<State: ProtoConvert, Action> Store<State, Action> {
+    /// Creates a new store from the state encoded as a protobuf message
+    pub fn restore_proto(
+        reducer: Reducer<State, Action>,
+        bytes: &[u8],
+    ) -> Result<Self, ProtoError> {
+        Ok(Self::new(reducer, decode(bytes)?))
+    }
+}
+
+impl<State, Action: ProtoConvert, Env> Store<State, Action, Env> {
+    /// Decodes the action sent by another service and dispatches it
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::proto::{self, ProtoConvert, ProtoError};
+    /// use redust::Store;
+    /// use std::convert::TryInto;
+    ///
+    /// // Usually generated by `prost-build` from a `.proto` file
+    /// #[derive(Clone, PartialEq, prost::Message)]
+    /// struct IncrementBy {
+    ///     #[prost(uint32, tag = "1")]
+    ///     value: u32,
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     IncrementBy(u8),
+    /// };
+    ///
+    /// impl ProtoConvert for MyAction {
+    ///     type Message = IncrementBy;
+    ///
+    ///     fn to_proto(&self) -> IncrementBy {
+    ///         match self {
+    ///             MyAction::IncrementBy(value) => IncrementBy { value: *value as u32 },
+    ///         }
+    ///     }
+    ///
+    ///     fn from_proto(message: IncrementBy) -> Result<Self, ProtoError> {
+    ///         let value = message
+    ///             .value
+    ///             .try_into()
+    ///             .map_err(|_| ProtoError::Unmapped("value is too large".to_string()))?;
+    ///
+    ///         Ok(MyAction::IncrementBy(value))
+    ///     }
+    /// }
+    ///
+    /// fn reducer(state: &u8, action: &MyAction) -> u8 {
+    ///     match action {
+    ///         MyAction::IncrementBy(value) => state + value,
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, 0);
+    /// let bytes = proto::encode(&MyAction::IncrementBy(2));
+    ///
+    /// store.dispatch_proto(&bytes).unwrap();
+    ///
+    /// assert_eq!(*store.state(), 2);
+    /// ```
+    pub fn dispatch_proto(&mut self, bytes: &[u8]) -> Result<&mut Self, ProtoError> {
+        let action = decode(bytes)?;
+
+        Ok(self.dispatch(action))
+    }
+}
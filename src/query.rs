@@ -0,0 +1,243 @@
+use std::collections::BTreeMap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Labels cached data so that mutations can invalidate it.
+///
+/// A tag without an id (`Tag::kind`) matches every tag of the same kind.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Tag {
+    pub kind: String,
+    pub id: Option<String>,
+}
+
+impl Tag {
+    /// Creates a tag for the whole collection of the kind, e.g. a list query
+    pub fn kind(kind: &str) -> Self {
+        Self {
+            kind: kind.to_string(),
+            id: None,
+        }
+    }
+
+    /// Creates a tag for a single item of the kind
+    pub fn id(kind: &str, id: impl ToString) -> Self {
+        Self {
+            kind: kind.to_string(),
+            id: Some(id.to_string()),
+        }
+    }
+
+    /// Returns `true` if invalidating `self` invalidates data provided with `provided`
+    pub fn matches(&self, provided: &Tag) -> bool {
+        self.kind == provided.kind && (self.id.is_none() || self.id == provided.id)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum QueryStatus {
+    Pending,
+    Fulfilled,
+    Rejected(String),
+}
+
+/// Cached result of a query
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct QueryEntry<Data> {
+    pub status: QueryStatus,
+
+    /// Last successfully fetched data, kept while the query is refetched
+    pub data: Option<Data>,
+
+    /// Tags provided by the last successful fetch
+    pub tags: Vec<Tag>,
+
+    /// `true` if a mutation invalidated the data and it should be refetched
+    pub stale: bool,
+}
+
+/// Results of queries by their keys, stored as a part of the state
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct QueryCache<Key: Ord, Data> {
+    pub queries: BTreeMap<Key, QueryEntry<Data>>,
+}
+
+impl<Key: Ord + Clone, Data> QueryCache<Key, Data> {
+    pub fn new() -> Self {
+        Self {
+            queries: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the cached query
+    pub fn get(&self, key: &Key) -> Option<&QueryEntry<Data>> {
+        self.queries.get(key)
+    }
+
+    /// Marks stale all queries which provided any of the tags and returns their keys
+    pub fn invalidate_tags(&mut self, tags: &[Tag]) -> Vec<Key> {
+        self.queries
+            .iter_mut()
+            .filter(|(_, entry)| {
+                entry
+                    .tags
+                    .iter()
+                    .any(|provided| tags.iter().any(|tag| tag.matches(provided)))
+            })
+            .map(|(key, entry)| {
+                entry.stale = true;
+                key.clone()
+            })
+            .collect()
+    }
+
+    /// Returns `true` if the query was never fetched, failed or is stale,
+    /// and it is not being fetched right now
+    pub fn needs_fetch(&self, key: &Key) -> bool {
+        match self.queries.get(key) {
+            None => true,
+            Some(entry) => match entry.status {
+                QueryStatus::Pending => false,
+                QueryStatus::Rejected(_) => true,
+                QueryStatus::Fulfilled => entry.stale,
+            },
+        }
+    }
+
+    /// Returns keys of stale queries which should be refetched
+    pub fn stale_keys(&self) -> impl Iterator<Item = &Key> {
+        self.queries
+            .iter()
+            .filter(|(_, entry)| entry.stale)
+            .map(|(key, _)| key)
+    }
+}
+
+impl<Key: Ord + Clone, Data> Default for QueryCache<Key, Data> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Computes tags provided by the fetched data of the query
+pub type ProvidesTags<Key, Data> = fn(&Key, &Data) -> Vec<Tag>;
+
+/// Computes tags invalidated by the successful mutation
+pub type InvalidatesTags<Arg, Output> = fn(&Arg, &Output) -> Vec<Tag>;
+
+/// Reducer helpers for a query which provides tags
+pub struct QueryEndpoint<Key, Data> {
+    provides: ProvidesTags<Key, Data>,
+}
+
+impl<Key, Data> Clone for QueryEndpoint<Key, Data> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Key, Data> Copy for QueryEndpoint<Key, Data> {}
+
+impl<Key: Ord + Clone, Data> QueryEndpoint<Key, Data> {
+    pub const fn new(provides: ProvidesTags<Key, Data>) -> Self {
+        Self { provides }
+    }
+
+    /// Marks the query as being fetched, keeping previously fetched data
+    pub fn query_started(&self, cache: &mut QueryCache<Key, Data>, key: Key) {
+        pending_entry(cache, key).status = QueryStatus::Pending;
+    }
+
+    /// Stores the fetched data together with the tags it provides
+    pub fn query_fulfilled(&self, cache: &mut QueryCache<Key, Data>, key: Key, data: Data) {
+        let tags = (self.provides)(&key, &data);
+        cache.queries.insert(
+            key,
+            QueryEntry {
+                status: QueryStatus::Fulfilled,
+                data: Some(data),
+                tags,
+                stale: false,
+            },
+        );
+    }
+
+    /// Marks the query as failed, keeping previously fetched data
+    pub fn query_rejected(&self, cache: &mut QueryCache<Key, Data>, key: Key, error: String) {
+        pending_entry(cache, key).status = QueryStatus::Rejected(error);
+    }
+}
+
+fn pending_entry<Key: Ord, Data>(
+    cache: &mut QueryCache<Key, Data>,
+    key: Key,
+) -> &mut QueryEntry<Data> {
+    cache.queries.entry(key).or_insert_with(|| QueryEntry {
+        status: QueryStatus::Pending,
+        data: None,
+        tags: Vec::new(),
+        stale: false,
+    })
+}
+
+/// Reducer helper for a mutation which invalidates tags.
+///
+/// A successful mutation marks stale every cached query carrying one of the invalidated
+/// tags. The returned keys are the queries to refetch; `QueryCache::needs_fetch`
+/// reports them until they are fulfilled again.
+///
+/// ## Example
+/// ```rust
+/// use redust::{MutationEndpoint, QueryCache, QueryEndpoint, Tag};
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Post {
+///     id: u32,
+/// }
+///
+/// const POST: QueryEndpoint<u32, Post> = QueryEndpoint::new(|id, _| vec![Tag::id("Post", id)]);
+/// const UPDATE_POST: MutationEndpoint<Post, ()> =
+///     MutationEndpoint::new(|post, _| vec![Tag::id("Post", post.id)]);
+///
+/// let mut cache = QueryCache::new();
+/// POST.query_fulfilled(&mut cache, 1, Post { id: 1 });
+/// POST.query_fulfilled(&mut cache, 2, Post { id: 2 });
+///
+/// let refetch = UPDATE_POST.mutation_fulfilled(&mut cache, &Post { id: 1 }, &());
+///
+/// assert_eq!(refetch, [1]);
+/// assert!(cache.needs_fetch(&1));
+/// assert!(!cache.needs_fetch(&2));
+/// ```
+pub struct MutationEndpoint<Arg, Output> {
+    invalidates: InvalidatesTags<Arg, Output>,
+}
+
+impl<Arg, Output> Clone for MutationEndpoint<Arg, Output> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Arg, Output> Copy for MutationEndpoint<Arg, Output> {}
+
+impl<Arg, Output> MutationEndpoint<Arg, Output> {
+    pub const fn new(invalidates: InvalidatesTags<Arg, Output>) -> Self {
+        Self { invalidates }
+    }
+
+    /// Invalidates tags of the successful mutation and returns keys of queries to refetch
+    pub fn mutation_fulfilled<Key: Ord + Clone, Data>(
+        &self,
+        cache: &mut QueryCache<Key, Data>,
+        arg: &Arg,
+        output: &Output,
+    ) -> Vec<Key> {
+        cache.invalidate_tags(&(self.invalidates)(arg, output))
+    }
+}
@@ -0,0 +1,375 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, Weak};
+
+use crate::Store;
+
+/// Priority of a queued action. Actions with higher priority are reduced first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Priority {
+    Low,
+    #[default]
+    Normal,
+    High,
+    Urgent,
+}
+
+impl Priority {
+    const ALL: [Priority; 4] = [
+        Priority::Urgent,
+        Priority::High,
+        Priority::Normal,
+        Priority::Low,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Defines how low-priority actions are protected from starvation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StarvationPolicy {
+    /// Always reduce the action with the highest priority first
+    Strict,
+
+    /// An action which waited while `n` other actions were reduced is served
+    /// next regardless of its priority
+    MaxWait(usize),
+}
+
+impl Default for StarvationPolicy {
+    fn default() -> Self {
+        StarvationPolicy::MaxWait(64)
+    }
+}
+
+/// Defines what happens when an action is enqueued into a full bounded queue
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OverflowPolicy {
+    /// Wait until the store drains the queue
+    Block,
+
+    /// Drop the oldest action with the lowest priority to make room for the new one
+    DropOldest,
+
+    /// Drop the new action
+    DropNewest,
+
+    /// Return `QueueError::Full`
+    Reject,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum QueueError {
+    /// The queue was dropped and nobody will reduce the action
+    Closed,
+
+    /// The bounded queue is full and its policy is `OverflowPolicy::Reject`
+    Full,
+}
+
+impl std::error::Error for QueueError {}
+impl std::fmt::Display for QueueError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            QueueError::Closed => write!(f, "Cannot enqueue an action: the queue was dropped"),
+            QueueError::Full => write!(f, "Cannot enqueue an action: the queue is full"),
+        }
+    }
+}
+
+struct Entry<Action> {
+    action: Action,
+    sequence: usize,
+    served_before: usize,
+    // Span the action was enqueued in, the store dispatches it inside
+    #[cfg(feature = "otel")]
+    origin: tracing::Span,
+}
+
+struct Queues<Action> {
+    levels: [VecDeque<Entry<Action>>; 4],
+    enqueued: usize,
+    served: usize,
+    starvation_policy: StarvationPolicy,
+    bound: Option<(usize, OverflowPolicy)>,
+    closed: bool,
+}
+
+impl<Action> Queues<Action> {
+    fn len(&self) -> usize {
+        self.levels.iter().map(VecDeque::len).sum()
+    }
+
+    fn push(&mut self, action: Action, priority: Priority) {
+        self.levels[priority.index()].push_back(Entry {
+            action,
+            sequence: self.enqueued,
+            served_before: self.served,
+            #[cfg(feature = "otel")]
+            origin: tracing::Span::current(),
+        });
+        self.enqueued += 1;
+    }
+
+    fn is_full(&self) -> bool {
+        match self.bound {
+            Some((capacity, _)) => self.len() >= capacity,
+            None => false,
+        }
+    }
+
+    fn drop_oldest(&mut self) {
+        if let Some(level) = self.levels.iter_mut().find(|level| !level.is_empty()) {
+            level.pop_front();
+        }
+    }
+
+    fn pop(&mut self) -> Option<Entry<Action>> {
+        let starving = match self.starvation_policy {
+            StarvationPolicy::Strict => None,
+            StarvationPolicy::MaxWait(max_wait) => Priority::ALL
+                .iter()
+                .filter_map(|priority| {
+                    let entry = self.levels[priority.index()].front()?;
+                    let waited = self.served - entry.served_before;
+
+                    Some((*priority, entry.sequence)).filter(|_| waited >= max_wait)
+                })
+                // The oldest starving action goes first
+                .min_by_key(|(_, sequence)| *sequence)
+                .map(|(priority, _)| priority),
+        };
+
+        let priority = starving.or_else(|| {
+            Priority::ALL
+                .iter()
+                .copied()
+                .find(|priority| !self.levels[priority.index()].is_empty())
+        })?;
+
+        let entry = self.levels[priority.index()].pop_front()?;
+        self.served += 1;
+
+        Some(entry)
+    }
+}
+
+/// Queue of actions which might be filled from any thread
+/// and drained into the store with `Store::drain`.
+///
+/// ## Example
+/// ```rust
+/// use redust::{DispatchQueue, Priority, Store};
+/// use std::thread;
+///
+/// type MyStore = Vec<&'static str>;
+///
+/// #[derive(Debug)]
+/// enum MyAction {
+///     Log(&'static str),
+/// };
+///
+/// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+///     match action {
+///         MyAction::Log(message) => {
+///             let mut new_state = state.clone();
+///             new_state.push(message);
+///
+///             new_state
+///         }
+///     }
+/// }
+///
+/// let queue = DispatchQueue::new();
+/// let dispatcher = queue.dispatcher();
+///
+/// thread::spawn(move || {
+///     dispatcher.dispatch(MyAction::Log("bulk")).unwrap();
+///     dispatcher
+///         .dispatch_with_priority(MyAction::Log("shutdown"), Priority::Urgent)
+///         .unwrap();
+/// })
+/// .join()
+/// .unwrap();
+///
+/// let mut store = Store::new(reducer, vec![]);
+/// store.drain(&queue);
+///
+/// assert_eq!(*store.state(), vec!["shutdown", "bulk"]);
+/// ```
+pub struct DispatchQueue<Action> {
+    shared: Arc<Shared<Action>>,
+}
+
+struct Shared<Action> {
+    queues: Mutex<Queues<Action>>,
+    not_full: Condvar,
+}
+
+impl<Action> Shared<Action> {
+    fn lock(&self) -> MutexGuard<'_, Queues<Action>> {
+        // A panicking producer cannot leave the queue in an inconsistent state
+        self.queues
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl<Action> DispatchQueue<Action> {
+    /// Creates an empty queue with the default starvation policy
+    pub fn new() -> Self {
+        Self {
+            shared: Arc::new(Shared {
+                queues: Mutex::new(Queues {
+                    levels: Default::default(),
+                    enqueued: 0,
+                    served: 0,
+                    starvation_policy: StarvationPolicy::default(),
+                    bound: None,
+                    closed: false,
+                }),
+                not_full: Condvar::new(),
+            }),
+        }
+    }
+
+    /// Sets the policy which protects low-priority actions from starvation
+    pub fn starvation_policy(self, policy: StarvationPolicy) -> Self {
+        self.lock().starvation_policy = policy;
+
+        self
+    }
+
+    /// Limits the number of waiting actions. The `policy` defines what
+    /// happens when a producer enqueues an action into the full queue.
+    ///
+    /// Unbounded queues might grow without limit when producers outpace the reducer.
+    pub fn bounded(self, capacity: usize, policy: OverflowPolicy) -> Self {
+        self.lock().bound = Some((capacity, policy));
+
+        self
+    }
+
+    /// Returns a handle which enqueues actions. It might be cloned and sent to other threads
+    pub fn dispatcher(&self) -> Dispatcher<Action> {
+        Dispatcher {
+            shared: Arc::downgrade(&self.shared),
+        }
+    }
+
+    /// Returns the number of actions waiting in the queue
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns `true` if there are no waiting actions
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Takes the next action according to priorities and the starvation policy
+    pub fn pop(&self) -> Option<Action> {
+        self.pop_entry().map(|entry| entry.action)
+    }
+
+    fn pop_entry(&self) -> Option<Entry<Action>> {
+        let entry = self.lock().pop();
+        if entry.is_some() {
+            self.shared.not_full.notify_one();
+        }
+
+        entry
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Queues<Action>> {
+        self.shared.lock()
+    }
+}
+
+impl<Action> Drop for DispatchQueue<Action> {
+    fn drop(&mut self) {
+        // Wake up blocked producers, nobody will drain the queue anymore
+        self.lock().closed = true;
+        self.shared.not_full.notify_all();
+    }
+}
+
+impl<Action> Default for DispatchQueue<Action> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Handle which enqueues actions into a `DispatchQueue`
+pub struct Dispatcher<Action> {
+    shared: Weak<Shared<Action>>,
+}
+
+impl<Action> Dispatcher<Action> {
+    /// Enqueues an action with `Priority::Normal`
+    pub fn dispatch(&self, action: Action) -> Result<(), QueueError> {
+        self.dispatch_with_priority(action, Priority::Normal)
+    }
+
+    /// Enqueues an action with the given priority
+    pub fn dispatch_with_priority(
+        &self,
+        action: Action,
+        priority: Priority,
+    ) -> Result<(), QueueError> {
+        let shared = self.shared.upgrade().ok_or(QueueError::Closed)?;
+        let mut queues = shared.lock();
+
+        if let Some((_, policy)) = queues.bound.filter(|_| queues.is_full()) {
+            match policy {
+                OverflowPolicy::Block => {
+                    queues = shared
+                        .not_full
+                        .wait_while(queues, |queues| queues.is_full() && !queues.closed)
+                        .unwrap_or_else(|poisoned| poisoned.into_inner());
+                }
+                OverflowPolicy::DropOldest => queues.drop_oldest(),
+                OverflowPolicy::DropNewest => return Ok(()),
+                OverflowPolicy::Reject => return Err(QueueError::Full),
+            }
+        }
+
+        if queues.closed {
+            return Err(QueueError::Closed);
+        }
+
+        queues.push(action, priority);
+
+        Ok(())
+    }
+}
+
+impl<Action> Clone for Dispatcher<Action> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: Weak::clone(&self.shared),
+        }
+    }
+}
+
+impl<State, Action, Env> Store<State, Action, Env> {
+    /// Dispatches all actions waiting in the queue and returns how many were dispatched.
+    ///
+    /// The queue is not locked while the reducer runs, so producers may keep
+    /// enqueuing actions; those are dispatched in the same call.
+    /// Once the queue is empty, sampled subscribers which skipped the latest
+    /// state are called with it if their interval elapsed.
+    pub fn drain(&mut self, queue: &DispatchQueue<Action>) -> usize {
+        let mut dispatched = 0;
+        while let Some(entry) = queue.pop_entry() {
+            #[cfg(feature = "otel")]
+            let _origin = entry.origin.enter();
+            self.dispatch(entry.action);
+            dispatched += 1;
+        }
+        self.flush_due_sampled();
+
+        dispatched
+    }
+}
@@ -1 +1,26 @@
+use crate::EnvReducer;
+
 pub type Reducer<State, Action> = fn(&State, &Action) -> State;
+
+/// Reducer of the store, it receives the environment if the store was created with one
+pub(crate) enum RootReducer<State, Action, Env> {
+    Plain(Reducer<State, Action>),
+    Env(EnvReducer<State, Action, Env>),
+}
+
+impl<State, Action, Env> RootReducer<State, Action, Env> {
+    pub(crate) fn reduce(&self, state: &State, action: &Action, env: &Env) -> State {
+        match self {
+            RootReducer::Plain(reducer) => reducer(state, action),
+            RootReducer::Env(reducer) => reducer(state, action, env),
+        }
+    }
+}
+
+impl<State, Action, Env> Clone for RootReducer<State, Action, Env> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<State, Action, Env> Copy for RootReducer<State, Action, Env> {}
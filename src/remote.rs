@@ -0,0 +1,669 @@
+//! Access to a store running in another process over TCP.
+//!
+//! `RemoteServer` exposes the store: clients dispatch serialized actions and
+//! subscribe to state updates with `RemoteStore`. Frames are encoded with the
+//! selected `Codec` and prefixed with their length.
+//!
+//! The server also keeps recent actions and states, so tooling can inspect
+//! what happened and travel back in time.
+//!
+//! Available behind the `serde` feature.
+
+use std::collections::{HashMap, VecDeque};
+use std::io::{self, Read, Write};
+use std::marker::PhantomData;
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::codec::{Codec, CodecError};
+use crate::{DispatchQueue, Dispatcher, OverflowPolicy, QueueError, Store, StoreBuilder};
+
+#[derive(Serialize, Deserialize)]
+enum Request<Action> {
+    Dispatch(Action),
+    GetState,
+    Subscribe,
+    RecentActions,
+    TravelBack(usize),
+}
+
+#[derive(Serialize, Deserialize)]
+enum Response<State> {
+    State(State),
+    /// Recent actions, each encoded separately
+    Actions(Vec<Vec<u8>>),
+    /// The action was enqueued
+    Dispatched,
+    /// The action was rejected because the queue of the server is full
+    QueueFull,
+    Error(String),
+}
+
+/// How many recent actions and states the server keeps
+const RECENT_LIMIT: usize = 64;
+
+/// How many published states may wait for a subscriber before it is dropped as too slow
+const SUBSCRIBER_BACKLOG: usize = 16;
+
+/// Limits which protect the server from misbehaving peers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RemoteLimits {
+    /// Largest frame accepted from a peer in bytes, larger frames close the connection
+    pub max_frame_size: usize,
+
+    /// Connections served at once, further ones are closed right after accepting
+    pub max_connections: usize,
+
+    /// How long writing to a peer may block before the peer is dropped
+    pub write_timeout: Duration,
+
+    /// Received actions which may wait for `RemoteServer::pump` at once
+    pub max_pending_actions: usize,
+
+    /// What happens with an action received while `max_pending_actions` wait.
+    /// With `OverflowPolicy::Reject` the client gets `RemoteError::Queue(QueueError::Full)`
+    pub overflow_policy: OverflowPolicy,
+}
+
+impl Default for RemoteLimits {
+    /// 16 MiB frames, 64 connections, a 5 second write timeout
+    /// and 1024 pending actions, further ones are rejected
+    fn default() -> Self {
+        Self {
+            max_frame_size: 16 * 1024 * 1024,
+            max_connections: 64,
+            write_timeout: Duration::from_secs(5),
+            max_pending_actions: 1024,
+            overflow_policy: OverflowPolicy::Reject,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RemoteError {
+    Io(String),
+    Codec(CodecError),
+
+    /// The server could not enqueue the dispatched action
+    Queue(QueueError),
+
+    /// The server could not handle the request
+    Server(String),
+}
+
+impl std::error::Error for RemoteError {}
+impl std::fmt::Display for RemoteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            RemoteError::Io(message) => write!(f, "Cannot reach the remote store: {}", message),
+            RemoteError::Codec(err) => write!(f, "{}", err),
+            RemoteError::Queue(err) => write!(f, "{}", err),
+            RemoteError::Server(message) => {
+                write!(f, "Cannot handle the request on the server: {}", message)
+            }
+        }
+    }
+}
+
+impl From<io::Error> for RemoteError {
+    fn from(err: io::Error) -> Self {
+        RemoteError::Io(err.to_string())
+    }
+}
+
+impl From<CodecError> for RemoteError {
+    fn from(err: CodecError) -> Self {
+        RemoteError::Codec(err)
+    }
+}
+
+fn write_frame<W: Write>(writer: &mut W, bytes: &[u8]) -> io::Result<()> {
+    writer.write_all(&(bytes.len() as u32).to_be_bytes())?;
+    writer.write_all(bytes)?;
+    writer.flush()
+}
+
+fn read_frame<R: Read>(reader: &mut R, max_frame_size: usize) -> io::Result<Vec<u8>> {
+    let mut len = [0; 4];
+    reader.read_exact(&mut len)?;
+
+    // The length comes from the peer, so it is checked before allocating
+    let len = u32::from_be_bytes(len) as usize;
+    if len > max_frame_size {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "the frame of {} bytes exceeds the limit of {} bytes",
+                len, max_frame_size
+            ),
+        ));
+    }
+
+    let mut bytes = vec![0; len];
+    reader.read_exact(&mut bytes)?;
+
+    Ok(bytes)
+}
+
+/// Queue of the thread which writes published states to a subscriber
+type SubscriberQueue = SyncSender<Arc<Vec<u8>>>;
+
+struct ServerShared {
+    /// The latest state, already encoded as `Response::State`
+    snapshot: Mutex<Arc<Vec<u8>>>,
+    /// Queues of the threads which write published states to subscribers
+    subscribers: Mutex<Vec<SubscriberQueue>>,
+    actions: Mutex<VecDeque<Vec<u8>>>,
+    states: Mutex<VecDeque<Vec<u8>>>,
+    travel: Mutex<Option<usize>>,
+    closed: AtomicBool,
+    peers: Mutex<Peers>,
+    limits: RemoteLimits,
+}
+
+/// Open connections, shut down when the server is dropped
+#[derive(Default)]
+struct Peers {
+    next_id: u64,
+    streams: HashMap<u64, TcpStream>,
+    threads: Vec<JoinHandle<()>>,
+}
+
+fn push_recent(recent: &Mutex<VecDeque<Vec<u8>>>, bytes: Vec<u8>) {
+    let mut recent = lock(recent);
+    if recent.len() == RECENT_LIMIT {
+        recent.pop_front();
+    }
+    recent.push_back(bytes);
+}
+
+/// Exposes a store to other processes.
+///
+/// Received actions wait in a bounded queue until the owner of the store calls
+/// `pump`, so the store itself never leaves its thread. Every subscriber has
+/// its own writer thread, so `publish` never blocks on a slow client; clients
+/// which fall behind are dropped. See `RemoteLimits` for the other limits.
+///
+/// Dropping the server closes all connections and waits for their threads.
+///
+/// ## Example
+/// ```rust
+/// use redust::codec::Codec;
+/// use redust::remote::{RemoteServer, RemoteStore};
+/// use redust::Store;
+/// use serde::{Deserialize, Serialize};
+///
+/// type MyStore = u8;
+///
+/// #[derive(Debug, Serialize, Deserialize)]
+/// enum MyAction {
+///     Increment,
+/// };
+///
+/// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+///     match action {
+///         MyAction::Increment => state + 1,
+///     }
+/// }
+///
+/// let mut store = Store::new(reducer, 0);
+/// let server = RemoteServer::bind("127.0.0.1:0", Codec::Json, &store).unwrap();
+///
+/// let mut client = RemoteStore::<MyStore, MyAction>::connect(server.local_addr(), Codec::Json).unwrap();
+/// client.dispatch(MyAction::Increment).unwrap();
+///
+/// while server.pump(&mut store).unwrap() == 0 {
+///     std::thread::yield_now();
+/// }
+///
+/// assert_eq!(client.state().unwrap(), 1);
+/// ```
+pub struct RemoteServer<State, Action> {
+    queue: DispatchQueue<Action>,
+    shared: Arc<ServerShared>,
+    codec: Codec,
+    local_addr: SocketAddr,
+    accept_thread: Option<JoinHandle<()>>,
+    state: PhantomData<fn(&State)>,
+}
+
+impl<State, Action> RemoteServer<State, Action>
+where
+    State: Serialize + DeserializeOwned,
+    Action: Serialize + DeserializeOwned + Send + 'static,
+{
+    /// Starts accepting connections and publishes the current state of the `store`
+    pub fn bind<A: ToSocketAddrs>(
+        addr: A,
+        codec: Codec,
+        store: &Store<State, Action>,
+    ) -> Result<Self, RemoteError> {
+        Self::bind_with_limits(addr, codec, store, RemoteLimits::default())
+    }
+
+    /// Same as `bind`, but with custom limits for peers
+    pub fn bind_with_limits<A: ToSocketAddrs>(
+        addr: A,
+        codec: Codec,
+        store: &Store<State, Action>,
+        limits: RemoteLimits,
+    ) -> Result<Self, RemoteError> {
+        let listener = TcpListener::bind(addr)?;
+        let local_addr = listener.local_addr()?;
+        let queue =
+            DispatchQueue::new().bounded(limits.max_pending_actions, limits.overflow_policy);
+        let shared = Arc::new(ServerShared {
+            snapshot: Mutex::new(Arc::new(codec.encode(&Response::State(store.state()))?)),
+            subscribers: Mutex::new(Vec::new()),
+            actions: Mutex::new(VecDeque::new()),
+            states: Mutex::new(VecDeque::from(vec![codec.encode(store.state())?])),
+            travel: Mutex::new(None),
+            closed: AtomicBool::new(false),
+            peers: Mutex::new(Peers::default()),
+            limits,
+        });
+
+        let dispatcher = queue.dispatcher();
+        let accept_shared = Arc::clone(&shared);
+        let accept_thread = thread::spawn(move || {
+            for stream in listener.incoming() {
+                let stream = match stream {
+                    Ok(stream) => stream,
+                    Err(_) => continue,
+                };
+
+                // Checked under the lock, so the dropped server sees every registered peer
+                let mut peers = lock(&accept_shared.peers);
+                if accept_shared.closed.load(Ordering::SeqCst) {
+                    break;
+                }
+                if peers.streams.len() >= accept_shared.limits.max_connections {
+                    let _ = stream.shutdown(Shutdown::Both);
+                    continue;
+                }
+                let registered = match stream.try_clone() {
+                    Ok(registered) => registered,
+                    Err(_) => continue,
+                };
+
+                let id = peers.next_id;
+                peers.next_id += 1;
+                peers.streams.insert(id, registered);
+                peers.threads.retain(|thread| !thread.is_finished());
+
+                let dispatcher = dispatcher.clone();
+                let shared = Arc::clone(&accept_shared);
+                peers.threads.push(thread::spawn(move || {
+                    serve_connection(stream, dispatcher, &shared, codec);
+                    lock(&shared.peers).streams.remove(&id);
+                }));
+            }
+        });
+
+        Ok(Self {
+            queue,
+            shared,
+            codec,
+            local_addr,
+            accept_thread: Some(accept_thread),
+            state: PhantomData,
+        })
+    }
+
+    /// Returns the address the server listens on
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Applies a requested time travel, dispatches all received actions into
+    /// the `store` and publishes the new state to subscribers.
+    /// Returns how many actions were dispatched.
+    pub fn pump(&self, store: &mut Store<State, Action>) -> Result<usize, RemoteError> {
+        let travelled = self.travel(store)?;
+        let dispatched = store.drain(&self.queue);
+        if travelled || dispatched > 0 {
+            self.publish(store)?;
+        }
+
+        Ok(dispatched)
+    }
+
+    /// Publishes the current state of the `store` to subscribers.
+    /// Subscribers which fell 16 states behind are dropped
+    pub fn publish(&self, store: &Store<State, Action>) -> Result<(), RemoteError> {
+        let snapshot = Arc::new(self.codec.encode(&Response::State(store.state()))?);
+        push_recent(&self.shared.states, self.codec.encode(store.state())?);
+
+        // Holding the snapshot lock, so a client subscribing meanwhile
+        // gets either the old state followed by this one or only this one
+        let mut current = lock(&self.shared.snapshot);
+        lock(&self.shared.subscribers)
+            .retain(|subscriber| subscriber.try_send(Arc::clone(&snapshot)).is_ok());
+        *current = snapshot;
+
+        Ok(())
+    }
+
+    /// Returns the number of connected subscribers
+    pub fn subscriber_count(&self) -> usize {
+        lock(&self.shared.subscribers).len()
+    }
+
+    /// Restores the state published the requested number of steps ago.
+    /// The restored state becomes the latest one; subscribers of the store are notified
+    fn travel(&self, store: &mut Store<State, Action>) -> Result<bool, RemoteError> {
+        let steps = match lock(&self.shared.travel).take() {
+            Some(steps) => steps,
+            None => return Ok(false),
+        };
+
+        let state = {
+            let states = lock(&self.shared.states);
+            // Steps beyond the kept states lead to the oldest one
+            let index = states.len().saturating_sub(steps + 1);
+            match states.get(index) {
+                Some(bytes) => self.codec.decode(bytes)?,
+                None => return Ok(false),
+            }
+        };
+
+        store.replace_state(Arc::new(state));
+
+        Ok(true)
+    }
+}
+
+/// Store and the server which exposes it to devtools
+pub type WithDevtools<State, Action> = (Store<State, Action>, RemoteServer<State, Action>);
+
+impl<State, Action> StoreBuilder<State, Action>
+where
+    State: Serialize + DeserializeOwned,
+    Action: Serialize + DeserializeOwned + Send + 'static,
+{
+    /// Creates the configured store together with a `RemoteServer` on `addr`,
+    /// so devtools can inspect it and travel back in time.
+    /// The app calls `RemoteServer::pump` from its event loop
+    pub fn build_with_devtools<A: ToSocketAddrs>(
+        self,
+        addr: A,
+        codec: Codec,
+    ) -> Result<WithDevtools<State, Action>, RemoteError> {
+        let store = self.build();
+        let server = RemoteServer::bind(addr, codec, &store)?;
+
+        Ok((store, server))
+    }
+}
+
+impl<State, Action> Drop for RemoteServer<State, Action> {
+    fn drop(&mut self) {
+        let threads = {
+            let mut peers = lock(&self.shared.peers);
+            self.shared.closed.store(true, Ordering::SeqCst);
+            peers.streams.values().for_each(|stream| {
+                let _ = stream.shutdown(Shutdown::Both);
+            });
+
+            std::mem::take(&mut peers.threads)
+        };
+        // Stops subscriber writers and wakes up peers blocked on the full queue
+        lock(&self.shared.subscribers).clear();
+        drop(std::mem::take(&mut self.queue));
+
+        // Wake up the accepting thread, so it notices the server is gone
+        let _ = TcpStream::connect(self.local_addr);
+        if let Some(thread) = self.accept_thread.take() {
+            let _ = thread.join();
+        }
+        threads.into_iter().for_each(|thread| {
+            let _ = thread.join();
+        });
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Writes published states to the subscriber until it disconnects, is too slow
+/// or the server drops its queue
+fn spawn_subscriber_writer(
+    mut stream: TcpStream,
+    write_timeout: Duration,
+) -> io::Result<(SubscriberQueue, JoinHandle<()>)> {
+    stream.set_write_timeout(Some(write_timeout))?;
+    let (sender, receiver) = mpsc::sync_channel::<Arc<Vec<u8>>>(SUBSCRIBER_BACKLOG);
+
+    let thread = thread::spawn(move || {
+        for snapshot in receiver {
+            if write_frame(&mut stream, &snapshot).is_err() {
+                break;
+            }
+        }
+        let _ = stream.shutdown(Shutdown::Both);
+    });
+
+    Ok((sender, thread))
+}
+
+fn serve_connection<Action: Serialize + DeserializeOwned>(
+    mut stream: TcpStream,
+    dispatcher: Dispatcher<Action>,
+    shared: &ServerShared,
+    codec: Codec,
+) {
+    if stream
+        .set_write_timeout(Some(shared.limits.write_timeout))
+        .is_err()
+    {
+        return;
+    }
+
+    loop {
+        let frame = match read_frame(&mut stream, shared.limits.max_frame_size) {
+            Ok(frame) => frame,
+            Err(err) => {
+                if err.kind() == io::ErrorKind::InvalidData {
+                    let _ = codec
+                        .encode(&Response::<()>::Error(err.to_string()))
+                        .map(|bytes| write_frame(&mut stream, &bytes));
+                }
+                return;
+            }
+        };
+
+        let result = match codec.decode::<Request<Action>>(&frame) {
+            Ok(Request::Dispatch(action)) => {
+                let encoded = codec.encode(&action);
+                let response = match dispatcher.dispatch(action) {
+                    Ok(()) => {
+                        if let Ok(bytes) = encoded {
+                            push_recent(&shared.actions, bytes);
+                        }
+                        Response::<()>::Dispatched
+                    }
+                    Err(QueueError::Full) => Response::<()>::QueueFull,
+                    // The server was dropped
+                    Err(QueueError::Closed) => return,
+                };
+                codec
+                    .encode(&response)
+                    .map_or(Ok(()), |bytes| write_frame(&mut stream, &bytes))
+            }
+            Ok(Request::RecentActions) => {
+                let actions = lock(&shared.actions).iter().cloned().collect();
+                codec
+                    .encode(&Response::<()>::Actions(actions))
+                    .map_or(Ok(()), |bytes| write_frame(&mut stream, &bytes))
+            }
+            Ok(Request::TravelBack(steps)) => {
+                *lock(&shared.travel) = Some(steps);
+                Ok(())
+            }
+            Ok(Request::GetState) => write_frame(&mut stream, &lock(&shared.snapshot)),
+            Ok(Request::Subscribe) => {
+                // Registered under the peers lock, so the server either joins
+                // the writer or never lets it start
+                let mut peers = lock(&shared.peers);
+                if shared.closed.load(Ordering::SeqCst) {
+                    return;
+                }
+                let snapshot = lock(&shared.snapshot);
+                stream.try_clone().and_then(|subscriber| {
+                    let (writer, thread) =
+                        spawn_subscriber_writer(subscriber, shared.limits.write_timeout)?;
+                    // The queue is empty, so the current state always fits
+                    let _ = writer.try_send(Arc::clone(&snapshot));
+                    lock(&shared.subscribers).push(writer);
+                    peers.threads.retain(|thread| !thread.is_finished());
+                    peers.threads.push(thread);
+                    Ok(())
+                })
+            }
+            Err(err) => codec
+                .encode(&Response::<()>::Error(err.to_string()))
+                .map_or(Ok(()), |bytes| write_frame(&mut stream, &bytes)),
+        };
+
+        if result.is_err() {
+            return;
+        }
+    }
+}
+
+/// Client of a store exposed by `RemoteServer`
+pub struct RemoteStore<State, Action> {
+    stream: TcpStream,
+    codec: Codec,
+    max_frame_size: usize,
+    types: PhantomData<fn(Action) -> State>,
+}
+
+impl<State, Action> RemoteStore<State, Action>
+where
+    State: DeserializeOwned,
+    Action: Serialize,
+{
+    /// Connects to the server. The `codec` must match the server's one
+    pub fn connect<A: ToSocketAddrs>(addr: A, codec: Codec) -> Result<Self, RemoteError> {
+        Ok(Self {
+            stream: TcpStream::connect(addr)?,
+            codec,
+            max_frame_size: RemoteLimits::default().max_frame_size,
+            types: PhantomData,
+        })
+    }
+
+    /// Replaces the largest frame accepted from the server, 16 MiB by default
+    pub fn with_max_frame_size(mut self, max_frame_size: usize) -> Self {
+        self.max_frame_size = max_frame_size;
+
+        self
+    }
+
+    /// Sends the action to the server. It is dispatched on the next `RemoteServer::pump`.
+    /// Fails with `RemoteError::Queue(QueueError::Full)` when too many actions wait
+    pub fn dispatch(&mut self, action: Action) -> Result<&mut Self, RemoteError> {
+        self.send(&Request::Dispatch(action))?;
+
+        match self
+            .codec
+            .decode(&read_frame(&mut self.stream, self.max_frame_size)?)?
+        {
+            Response::<()>::Dispatched => Ok(self),
+            Response::<()>::QueueFull => Err(RemoteError::Queue(QueueError::Full)),
+            Response::<()>::Error(message) => Err(RemoteError::Server(message)),
+            _ => Err(RemoteError::Server("unexpected response".to_string())),
+        }
+    }
+
+    /// Returns the latest state published by the server
+    pub fn state(&mut self) -> Result<State, RemoteError> {
+        self.send(&Request::<Action>::GetState)?;
+
+        receive(&mut self.stream, self.codec, self.max_frame_size)
+    }
+
+    /// Returns recent actions received by the server, the oldest first
+    pub fn recent_actions(&mut self) -> Result<Vec<Action>, RemoteError>
+    where
+        Action: DeserializeOwned,
+    {
+        self.send(&Request::RecentActions)?;
+
+        match self
+            .codec
+            .decode(&read_frame(&mut self.stream, self.max_frame_size)?)?
+        {
+            Response::<()>::Actions(actions) => actions
+                .iter()
+                .map(|bytes| Ok(self.codec.decode(bytes)?))
+                .collect(),
+            Response::<()>::Error(message) => Err(RemoteError::Server(message)),
+            _ => Err(RemoteError::Server("unexpected response".to_string())),
+        }
+    }
+
+    /// Asks the server to restore the state published `steps` updates ago.
+    /// It is restored on the next `RemoteServer::pump`
+    pub fn travel_back(&mut self, steps: usize) -> Result<&mut Self, RemoteError> {
+        self.send(&Request::TravelBack(steps))?;
+
+        Ok(self)
+    }
+
+    /// Turns the connection into a subscription to state updates
+    pub fn subscribe(mut self) -> Result<RemoteSubscription<State>, RemoteError> {
+        self.send(&Request::<Action>::Subscribe)?;
+
+        Ok(RemoteSubscription {
+            stream: self.stream,
+            codec: self.codec,
+            max_frame_size: self.max_frame_size,
+            state: PhantomData,
+        })
+    }
+
+    fn send(&mut self, request: &Request<Action>) -> Result<(), RemoteError> {
+        let bytes = self.codec.encode(request)?;
+
+        Ok(write_frame(&mut self.stream, &bytes)?)
+    }
+}
+
+/// Stream of states published by `RemoteServer`
+pub struct RemoteSubscription<State> {
+    stream: TcpStream,
+    codec: Codec,
+    max_frame_size: usize,
+    state: PhantomData<fn() -> State>,
+}
+
+impl<State: DeserializeOwned> RemoteSubscription<State> {
+    /// Blocks until the server publishes a state. The first call returns
+    /// the state at the moment of subscribing
+    pub fn recv(&mut self) -> Result<State, RemoteError> {
+        receive(&mut self.stream, self.codec, self.max_frame_size)
+    }
+}
+
+fn receive<State: DeserializeOwned>(
+    stream: &mut TcpStream,
+    codec: Codec,
+    max_frame_size: usize,
+) -> Result<State, RemoteError> {
+    match codec.decode(&read_frame(stream, max_frame_size)?)? {
+        Response::State(state) => Ok(state),
+        Response::Error(message) => Err(RemoteError::Server(message)),
+        _ => Err(RemoteError::Server("unexpected response".to_string())),
+    }
+}
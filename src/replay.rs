@@ -0,0 +1,155 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Fixture, Recorder, Reducer};
+
+/// State recorded after the given number of actions of a session
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Checkpoint<State> {
+    pub actions: usize,
+    pub state: State,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ReplayError<State> {
+    /// The replayed state differs from the checkpoint.
+    /// The first divergent action is among actions `since..checkpoint.actions`;
+    /// `since` is the position of the last matching checkpoint
+    Diverged {
+        since: usize,
+        checkpoint: Checkpoint<State>,
+        actual: State,
+    },
+
+    /// The checkpoint was recorded after more actions than the fixture has
+    OutOfRange(usize),
+}
+
+impl<State: std::fmt::Debug> std::error::Error for ReplayError<State> {}
+impl<State> std::fmt::Display for ReplayError<State> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ReplayError::Diverged {
+                since, checkpoint, ..
+            } if checkpoint.actions == since + 1 => {
+                write!(f, "Replay diverged at action #{}", since)
+            }
+            ReplayError::Diverged {
+                since, checkpoint, ..
+            } => write!(
+                f,
+                "Replay diverged between actions #{} and #{}",
+                since,
+                checkpoint.actions - 1
+            ),
+            ReplayError::OutOfRange(actions) => write!(
+                f,
+                "Cannot verify the checkpoint after {} actions, the fixture is shorter",
+                actions
+            ),
+        }
+    }
+}
+
+impl<State: Clone + PartialEq, Action> Fixture<State, Action> {
+    /// Replays the actions and compares the state with every checkpoint.
+    /// Returns the first divergence, which means the reducer is not pure enough
+    /// for event sourcing and time travel.
+    ///
+    /// Record a checkpoint after every action to find the exact divergent action.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::{Checkpoint, Fixture, ReplayError};
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     IncrementBy(u8),
+    /// };
+    ///
+    /// fn reducer(state: &u8, action: &MyAction) -> u8 {
+    ///     match action {
+    ///         MyAction::IncrementBy(value) => state + value,
+    ///     }
+    /// }
+    ///
+    /// let fixture = Fixture {
+    ///     initial_state: 0,
+    ///     actions: vec![MyAction::IncrementBy(1), MyAction::IncrementBy(2)],
+    /// };
+    ///
+    /// let recorded = [
+    ///     Checkpoint { actions: 1, state: 1 },
+    ///     Checkpoint { actions: 2, state: 4 },
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     fixture.verify_replay(reducer, &recorded),
+    ///     Err(ReplayError::Diverged {
+    ///         since: 1,
+    ///         checkpoint: Checkpoint { actions: 2, state: 4 },
+    ///         actual: 3,
+    ///     })
+    /// );
+    /// ```
+    pub fn verify_replay(
+        &self,
+        reducer: Reducer<State, Action>,
+        checkpoints: &[Checkpoint<State>],
+    ) -> Result<(), ReplayError<State>> {
+        let mut checkpoints: Vec<_> = checkpoints.iter().collect();
+        checkpoints.sort_by_key(|checkpoint| checkpoint.actions);
+
+        let mut state = self.initial_state.clone();
+        let mut replayed = 0;
+        let mut since = 0;
+
+        for checkpoint in checkpoints {
+            if checkpoint.actions > self.actions.len() {
+                return Err(ReplayError::OutOfRange(checkpoint.actions));
+            }
+
+            for action in &self.actions[replayed..checkpoint.actions] {
+                state = reducer(&state, action);
+            }
+            replayed = checkpoint.actions;
+
+            if state != checkpoint.state {
+                return Err(ReplayError::Diverged {
+                    since,
+                    checkpoint: checkpoint.clone(),
+                    actual: state,
+                });
+            }
+            since = checkpoint.actions;
+        }
+
+        Ok(())
+    }
+}
+
+impl<State: Clone, Action> Recorder<State, Action> {
+    /// Records the current state as a checkpoint
+    pub fn checkpoint(&mut self) -> &mut Self {
+        self.checkpoints.push(Checkpoint {
+            actions: self.fixture.actions.len(),
+            state: self.store.state().clone(),
+        });
+
+        self
+    }
+
+    /// Records a checkpoint automatically after every `interval` actions.
+    /// Zero stops automatic checkpoints
+    pub fn checkpoint_every(&mut self, interval: usize) -> &mut Self {
+        self.checkpoint_interval = interval;
+
+        self
+    }
+
+    /// Returns checkpoints recorded so far
+    pub fn checkpoints(&self) -> &[Checkpoint<State>] {
+        &self.checkpoints
+    }
+}
@@ -0,0 +1,225 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use crate::Dispatcher;
+
+/// Defines how the delay between attempts grows
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Backoff {
+    /// The same delay before every retry
+    Fixed(Duration),
+
+    /// The delay doubles after every failed attempt, up to `max`
+    Exponential { initial: Duration, max: Duration },
+}
+
+/// Defines how many times a failing effect is attempted and how long to wait between attempts
+///
+/// ## Example
+/// ```rust
+/// use redust::RetryPolicy;
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::exponential(Duration::from_millis(100), Duration::from_millis(500), 5);
+///
+/// // After the first failed attempt
+/// assert_eq!(policy.delay(1), Some(Duration::from_millis(100)));
+/// assert_eq!(policy.delay(3), Some(Duration::from_millis(400)));
+/// // 800ms is capped
+/// assert_eq!(policy.delay(4), Some(Duration::from_millis(500)));
+/// // The fifth attempt was the last one
+/// assert_eq!(policy.delay(5), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    pub backoff: Backoff,
+
+    /// Total number of attempts including the first one
+    pub max_attempts: u32,
+
+    /// Fraction of the delay which is randomized, from 0 to 1
+    pub jitter: f64,
+}
+
+impl RetryPolicy {
+    /// Retries with the same delay
+    pub fn fixed(delay: Duration, max_attempts: u32) -> Self {
+        Self {
+            backoff: Backoff::Fixed(delay),
+            max_attempts,
+            jitter: 0.0,
+        }
+    }
+
+    /// Retries with a delay which doubles after every failed attempt, up to `max`
+    pub fn exponential(initial: Duration, max: Duration, max_attempts: u32) -> Self {
+        Self {
+            backoff: Backoff::Exponential { initial, max },
+            max_attempts,
+            jitter: 0.0,
+        }
+    }
+
+    /// Randomizes the `fraction` of every delay, so clients which failed together
+    /// do not retry together
+    pub fn with_jitter(mut self, fraction: f64) -> Self {
+        self.jitter = fraction.clamp(0.0, 1.0);
+
+        self
+    }
+
+    /// Returns the delay before the next attempt after `failed` attempts failed,
+    /// without jitter. The count starts from 1, so an exponential backoff waits
+    /// `initial * 2^(failed - 1)`. Returns `None` when no attempts are left
+    pub fn delay(&self, failed: u32) -> Option<Duration> {
+        if failed == 0 || failed >= self.max_attempts {
+            return None;
+        }
+
+        Some(match self.backoff {
+            Backoff::Fixed(delay) => delay,
+            Backoff::Exponential { initial, max } => initial
+                .checked_mul(2u32.saturating_pow(failed - 1))
+                .map_or(max, |delay| delay.min(max)),
+        })
+    }
+
+    /// Returns the delay with jitter applied, where `random` is a number from 0 to 1
+    pub fn jittered_delay(&self, failed: u32, random: f64) -> Option<Duration> {
+        self.delay(failed)
+            .map(|delay| delay.mul_f64(1.0 - self.jitter * random.clamp(0.0, 1.0)))
+    }
+
+    /// Runs the effect until it succeeds or no attempts are left, sleeping between
+    /// attempts. The effect receives the number of the attempt, starting from 1
+    pub fn run<T, E>(
+        &self,
+        mut effect: impl FnMut(u32) -> Result<T, E>,
+    ) -> Result<T, RetryExhausted<E>> {
+        let mut attempt = 1;
+        loop {
+            match effect(attempt) {
+                Ok(value) => return Ok(value),
+                Err(error) => match self.jittered_delay(attempt, random()) {
+                    Some(delay) => {
+                        #[cfg(feature = "log")]
+                        log::warn!(
+                            target: crate::logging::RETRY,
+                            "Attempt {} failed, retrying in {:?}",
+                            attempt,
+                            delay
+                        );
+                        thread::sleep(delay)
+                    }
+                    None => {
+                        #[cfg(feature = "log")]
+                        log::error!(
+                            target: crate::logging::RETRY,
+                            "All {} attempts failed",
+                            attempt
+                        );
+                        return Err(RetryExhausted {
+                            attempts: attempt,
+                            error,
+                        });
+                    }
+                },
+            }
+            attempt += 1;
+        }
+    }
+
+    /// Runs the effect on its own thread with retries and enqueues the action it
+    /// produced. When all attempts failed, the action created by `exhausted`
+    /// from the last error is enqueued instead.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::{DispatchQueue, RetryExhausted, RetryPolicy, Store};
+    /// use std::time::Duration;
+    ///
+    /// type MyStore = Option<Result<String, String>>;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Loaded(String),
+    ///     Failed(RetryExhausted<String>),
+    /// };
+    ///
+    /// fn reducer(_state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Loaded(user) => Some(Ok(user.clone())),
+    ///         MyAction::Failed(failure) => Some(Err(failure.error.clone())),
+    ///     }
+    /// }
+    ///
+    /// let queue = DispatchQueue::new();
+    /// let policy = RetryPolicy::fixed(Duration::from_millis(1), 3);
+    /// policy
+    ///     .spawn(
+    ///         queue.dispatcher(),
+    ///         |attempt| match attempt {
+    ///             3 => Ok(MyAction::Loaded("alice".to_string())),
+    ///             _ => Err("offline".to_string()),
+    ///         },
+    ///         MyAction::Failed,
+    ///     )
+    ///     .join()
+    ///     .unwrap();
+    ///
+    /// let mut store = Store::new(reducer, None);
+    /// store.drain(&queue);
+    ///
+    /// assert_eq!(*store.state(), Some(Ok("alice".to_string())));
+    /// ```
+    pub fn spawn<Action, E, F>(
+        self,
+        dispatcher: Dispatcher<Action>,
+        effect: F,
+        exhausted: ExhaustedAction<E, Action>,
+    ) -> JoinHandle<()>
+    where
+        Action: Send + 'static,
+        E: 'static,
+        F: FnMut(u32) -> Result<Action, E> + Send + 'static,
+    {
+        thread::spawn(move || {
+            let action = self.run(effect).unwrap_or_else(exhausted);
+            // The queue might be dropped while the effect was running
+            let _ = dispatcher.dispatch(action);
+        })
+    }
+}
+
+/// Error of an effect which failed on every attempt
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryExhausted<E> {
+    pub attempts: u32,
+
+    /// Error of the last attempt
+    pub error: E,
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for RetryExhausted<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "The effect failed after {} attempts: {}",
+            self.attempts, self.error
+        )
+    }
+}
+
+impl<E: std::fmt::Debug + std::fmt::Display> std::error::Error for RetryExhausted<E> {}
+
+/// Creates the action which is dispatched when all attempts of an effect failed
+pub type ExhaustedAction<E, Action> = fn(RetryExhausted<E>) -> Action;
+
+/// Returns a random number from 0 to 1 without an external generator
+fn random() -> f64 {
+    let bits = RandomState::new().build_hasher().finish();
+
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
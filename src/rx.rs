@@ -0,0 +1,178 @@
+//! Interop with `rxrust` observables.
+//!
+//! The store exposes its states and reduced actions as subjects, and
+//! `DispatchObserver` enqueues actions emitted by an observable back into
+//! a `DispatchQueue`, so existing Rx pipelines plug in directly.
+//!
+//! Available behind the `rxrust` feature.
+
+use std::sync::Arc;
+
+use rxrust::observer::Observer;
+use rxrust::subject::SharedSubject;
+
+use crate::subscription::{StoreObserver, Subscriber};
+use crate::{Dispatcher, Store};
+
+struct StateSubject<State>(SharedSubject<Arc<State>, ()>);
+
+impl<State, Action> StoreObserver<State, Action> for StateSubject<State> {
+    fn on_state(&mut self, state: &Arc<State>) {
+        self.0.next(Arc::clone(state));
+    }
+
+    fn on_action(&mut self, _action: &Action, _state: &Arc<State>) {}
+}
+
+struct ActionSubject<Action>(SharedSubject<Action, ()>);
+
+impl<State, Action: Clone> StoreObserver<State, Action> for ActionSubject<Action> {
+    fn on_state(&mut self, _state: &Arc<State>) {}
+
+    fn on_action(&mut self, action: &Action, _state: &Arc<State>) {
+        self.0.next(action.clone());
+    }
+}
+
+impl<State, Action, Env> Store<State, Action, Env>
+where
+    State: Send + Sync + 'static,
+    Action: Send + Sync + 'static,
+{
+    /// Returns a subject which emits every new state.
+    /// States are shared with the store, so emitting them is cheap
+    ///
+    /// ## Example
+    /// ```rust
+    /// use rxrust::prelude::*;
+    /// use redust::Store;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// type MyStore = u8;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Increment,
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Increment => state + 1,
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, 0);
+    /// let seen = Arc::new(Mutex::new(vec![]));
+    ///
+    /// let sink = Arc::clone(&seen);
+    /// store
+    ///     .observe_states()
+    ///     .filter(|state| **state % 2 == 0)
+    ///     .into_shared()
+    ///     .subscribe(move |state| sink.lock().unwrap().push(*state));
+    ///
+    /// store
+    ///     .dispatch(MyAction::Increment)
+    ///     .dispatch(MyAction::Increment);
+    ///
+    /// assert_eq!(*seen.lock().unwrap(), [2]);
+    /// ```
+    pub fn observe_states(&mut self) -> SharedSubject<Arc<State>, ()> {
+        let subject = SharedSubject::new();
+        self.subscribe_observer(Box::new(StateSubject(subject.clone())));
+
+        subject
+    }
+
+    /// Returns a subject which emits every reduced action
+    pub fn observe_actions(&mut self) -> SharedSubject<Action, ()>
+    where
+        Action: Clone,
+    {
+        let subject = SharedSubject::new();
+        self.subscribe_observer(Box::new(ActionSubject(subject.clone())));
+
+        subject
+    }
+
+    fn subscribe_observer(
+        &mut self,
+        observer: Box<dyn StoreObserver<State, Action> + Send + Sync>,
+    ) {
+        let token = self.next_subscription_token();
+        self.subscriptions
+            .insert(token.id(), Subscriber::Observer(observer));
+    }
+}
+
+/// Observer which enqueues emitted actions into a `DispatchQueue`.
+/// It stops when the queue is dropped.
+///
+/// ## Example
+/// ```rust
+/// use rxrust::prelude::*;
+/// use redust::rx::DispatchObserver;
+/// use redust::{DispatchQueue, Store};
+///
+/// type MyStore = u32;
+///
+/// #[derive(Debug, Clone)]
+/// enum MyAction {
+///     IncrementBy(u32),
+/// };
+///
+/// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+///     match action {
+///         MyAction::IncrementBy(value) => state + value,
+///     }
+/// }
+///
+/// let queue = DispatchQueue::new();
+///
+/// let actions = observable::from_iter(1..=3).map(MyAction::IncrementBy);
+/// LocalObservable::actual_subscribe(
+///     actions,
+///     Subscriber::local(DispatchObserver::new(queue.dispatcher())),
+/// );
+///
+/// let mut store = Store::new(reducer, 0);
+/// store.drain(&queue);
+///
+/// assert_eq!(*store.state(), 6);
+/// ```
+pub struct DispatchObserver<Action> {
+    dispatcher: Dispatcher<Action>,
+    stopped: bool,
+}
+
+impl<Action> DispatchObserver<Action> {
+    pub fn new(dispatcher: Dispatcher<Action>) -> Self {
+        Self {
+            dispatcher,
+            stopped: false,
+        }
+    }
+}
+
+impl<Action> Observer for DispatchObserver<Action> {
+    type Item = Action;
+    type Err = ();
+
+    fn next(&mut self, action: Action) {
+        if !self.stopped && self.dispatcher.dispatch(action).is_err() {
+            self.stopped = true;
+        }
+    }
+
+    fn error(&mut self, _err: ()) {
+        self.stopped = true;
+    }
+
+    fn complete(&mut self) {
+        self.stopped = true;
+    }
+
+    fn is_stopped(&self) -> bool {
+        self.stopped
+    }
+}
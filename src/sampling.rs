@@ -0,0 +1,174 @@
+use std::time::{Duration, Instant};
+
+use crate::isolation;
+use crate::strict::{self, SlowTarget};
+use crate::subscription::{Subscriber, SubscriptionToken};
+use crate::{Store, Subscription};
+
+/// Defines how often a sampled subscriber observes the state
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Sample {
+    /// Called on every `n`-th update
+    Every(usize),
+
+    /// Called at most once per interval. The state skipped last is delivered
+    /// once the interval has elapsed, see `Store::subscribe_sampled`
+    Interval(Duration),
+}
+
+pub(crate) struct SampledSubscription<State> {
+    func: Subscription<State>,
+    sample: Sample,
+    skipped: usize,
+    last_call: Option<Instant>,
+    pending: bool,
+}
+
+impl<State> SampledSubscription<State> {
+    pub(crate) fn notify(&mut self, state: &State, now: Instant) {
+        let should_call = match self.sample {
+            Sample::Every(n) => self.skipped + 1 >= n,
+            Sample::Interval(_) => self.interval_elapsed(now),
+        };
+
+        if should_call {
+            self.call(state, now);
+        } else {
+            self.skipped += 1;
+            self.pending = true;
+        }
+    }
+
+    /// Returns `true` if the subscriber skipped the latest state and may see it now.
+    /// With `force` the interval is not waited for
+    pub(crate) fn is_due(&self, now: Instant, force: bool) -> bool {
+        self.pending && (force || self.interval_elapsed(now))
+    }
+
+    fn interval_elapsed(&self, now: Instant) -> bool {
+        match self.sample {
+            Sample::Every(_) => true,
+            Sample::Interval(interval) => self
+                .last_call
+                .is_none_or(|last_call| now.saturating_duration_since(last_call) >= interval),
+        }
+    }
+
+    pub(crate) fn flush(&mut self, state: &State, now: Instant) {
+        if self.pending {
+            self.call(state, now);
+        }
+    }
+
+    fn call(&mut self, state: &State, now: Instant) {
+        (self.func)(state);
+
+        self.skipped = 0;
+        self.last_call = Some(now);
+        self.pending = false;
+    }
+}
+
+impl<State, Action, Env> Store<State, Action, Env> {
+    /// Subscribes a callback which observes the state at a reduced rate.
+    ///
+    /// A subscriber always eventually sees the latest state. The state skipped
+    /// last is delivered by a later sampled dispatch or, once dispatches stop,
+    /// when the store goes idle: at the end of `drain`, e.g. of the queue a
+    /// `Ticker` or a `Scheduler` feeds, and on `dispatch_due`. With
+    /// `Sample::Interval` it is delivered there only after the interval has
+    /// elapsed since the last call. `flush_sampled` delivers it right away.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::{Sample, Store};
+    ///
+    /// type MyStore = u8;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Increment,
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Increment => state + 1,
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, 0);
+    ///
+    /// store.subscribe_sampled(Sample::Every(2), |state| {
+    ///     // Called with 2 on dispatch and with 3 on flush
+    ///     assert!(*state == 2 || *state == 3);
+    /// });
+    ///
+    /// store
+    ///     .dispatch(MyAction::Increment)
+    ///     .dispatch(MyAction::Increment)
+    ///     .dispatch(MyAction::Increment);
+    /// store.flush_sampled();
+    /// ```
+    pub fn subscribe_sampled(
+        &mut self,
+        sample: Sample,
+        func: Subscription<State>,
+    ) -> SubscriptionToken<State, Action> {
+        let subscription_token = self.next_subscription_token();
+        self.subscriptions.insert(
+            subscription_token.id(),
+            Subscriber::Sampled(SampledSubscription {
+                func,
+                sample,
+                skipped: 0,
+                last_call: None,
+                pending: false,
+            }),
+        );
+
+        subscription_token
+    }
+
+    /// Calls sampled subscribers which skipped the latest state without waiting
+    /// for their interval. Skipped while notifications are paused
+    pub fn flush_sampled(&mut self) {
+        self.notify_sampled(true);
+    }
+
+    /// Calls sampled subscribers which skipped the latest state and whose interval elapsed
+    pub(crate) fn flush_due_sampled(&mut self) {
+        self.notify_sampled(false);
+    }
+
+    /// Calls pending sampled subscribers the same way as `notify` does:
+    /// with panic isolation and strict mode measurements
+    fn notify_sampled(&mut self, force: bool) {
+        if self.notifications_paused {
+            self.missed_notifications = true;
+            return;
+        }
+
+        let reported_errors = self.subscriber_errors.len();
+        let state = self.state.get();
+        let now = self.clock.now();
+        let isolation = self.panic_isolation;
+        let strict = &mut self.strict;
+        let panics = self
+            .subscriptions
+            .iter_mut()
+            .filter_map(|(id, subscriber)| match subscriber {
+                Subscriber::Sampled(subscription) if subscription.is_due(now, force) => {
+                    let target = SlowTarget::Subscriber(*id);
+                    strict::measure(strict, target, None, || {
+                        isolation::call(isolation, || subscription.flush(state, now))
+                    })
+                    .err()
+                    .map(|err| (*id, err))
+                }
+                _ => None,
+            })
+            .collect();
+        self.handle_panics(panics);
+        self.report_subscriber_errors(reported_errors, None);
+    }
+}
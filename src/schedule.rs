@@ -0,0 +1,451 @@
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, Weak};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::{Clock, Dispatcher, SharedClock, SystemClock};
+
+#[derive(Debug, PartialEq)]
+pub enum ScheduleError {
+    /// The cron expression could not be parsed
+    InvalidCron(String),
+
+    /// The cron expression never matches, e.g. `0 0 31 2 *`
+    NeverFires,
+
+    /// The interval between actions is zero
+    ZeroInterval,
+}
+
+impl std::error::Error for ScheduleError {}
+impl std::fmt::Display for ScheduleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ScheduleError::InvalidCron(message) => {
+                write!(f, "Cannot parse the cron expression: {}", message)
+            }
+            ScheduleError::NeverFires => {
+                write!(f, "Cannot schedule the cron expression: it never fires")
+            }
+            ScheduleError::ZeroInterval => {
+                write!(f, "Cannot schedule an action with a zero interval")
+            }
+        }
+    }
+}
+
+/// Allowed values of one cron field as a bit set
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct CronField {
+    allowed: u64,
+    // Starts with `*`, so like in cron the field does not restrict days even with a step
+    any: bool,
+}
+
+impl CronField {
+    fn parse(field: &str, min: u32, max: u32) -> Result<Self, ScheduleError> {
+        let invalid = || ScheduleError::InvalidCron(format!("invalid field `{}`", field));
+        let number = |value: &str| -> Result<u32, ScheduleError> {
+            value
+                .parse::<u32>()
+                .ok()
+                .filter(|value| (min..=max).contains(value))
+                .ok_or_else(invalid)
+        };
+
+        let mut allowed = 0;
+        for part in field.split(',') {
+            let (range, step) = match part.split_once('/') {
+                Some((range, step)) => (range, step.parse::<u32>().map_err(|_| invalid())?),
+                None => (part, 1),
+            };
+            if step == 0 {
+                return Err(invalid());
+            }
+
+            let (from, to) = match range {
+                "*" => (min, max),
+                range => match range.split_once('-') {
+                    Some((from, to)) => (number(from)?, number(to)?),
+                    None if step > 1 => (number(range)?, max),
+                    None => (number(range)?, number(range)?),
+                },
+            };
+
+            (from..=to)
+                .step_by(step as usize)
+                .for_each(|value| allowed |= 1 << value);
+        }
+
+        if allowed == 0 {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            allowed,
+            any: field.starts_with('*'),
+        })
+    }
+
+    fn matches(&self, value: u32) -> bool {
+        self.allowed & (1 << value) != 0
+    }
+}
+
+/// Parsed cron expression with five fields:
+/// minute, hour, day of month, month and day of week (0 is Sunday).
+///
+/// Every field supports `*`, numbers, ranges `a-b`, lists `a,b` and steps `*/n`.
+/// Times are in UTC.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cron {
+    minute: CronField,
+    hour: CronField,
+    day: CronField,
+    month: CronField,
+    weekday: CronField,
+}
+
+impl Cron {
+    /// Parses the expression, e.g. `*/15 9-17 * * 1-5`
+    pub fn parse(expression: &str) -> Result<Self, ScheduleError> {
+        let fields: Vec<_> = expression.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(ScheduleError::InvalidCron(format!(
+                "expected 5 fields, found {}",
+                fields.len()
+            )));
+        }
+
+        let cron = Self {
+            minute: CronField::parse(fields[0], 0, 59)?,
+            hour: CronField::parse(fields[1], 0, 23)?,
+            day: CronField::parse(fields[2], 1, 31)?,
+            month: CronField::parse(fields[3], 1, 12)?,
+            weekday: CronField::parse(fields[4], 0, 6)?,
+        };
+
+        // Every valid schedule fires at least once in 8 years, leap days included
+        cron.next_after(UNIX_EPOCH)
+            .map(|_| cron)
+            .ok_or(ScheduleError::NeverFires)
+    }
+
+    /// Returns the first matching minute after `time`
+    pub fn next_after(&self, time: SystemTime) -> Option<SystemTime> {
+        let seconds = time.duration_since(UNIX_EPOCH).ok()?.as_secs();
+        let mut minute = seconds / 60 + 1;
+        let limit = minute + 8 * 366 * 24 * 60;
+
+        while minute < limit {
+            let days = minute / (24 * 60);
+            if !self.matches_day(days) {
+                minute = (days + 1) * 24 * 60;
+                continue;
+            }
+
+            let hour = (minute / 60 % 24) as u32;
+            if !self.hour.matches(hour) {
+                minute = (minute / 60 + 1) * 60;
+                continue;
+            }
+
+            if self.minute.matches((minute % 60) as u32) {
+                return Some(UNIX_EPOCH + Duration::from_secs(minute * 60));
+            }
+            minute += 1;
+        }
+
+        None
+    }
+
+    fn matches_day(&self, days: u64) -> bool {
+        let (month, day) = month_and_day(days);
+        let weekday = ((days + 4) % 7) as u32;
+
+        let day_matches = match (self.day.any, self.weekday.any) {
+            // Like in cron, restricted day of month and day of week are alternatives
+            (false, false) => self.day.matches(day) || self.weekday.matches(weekday),
+            _ => self.day.matches(day) && self.weekday.matches(weekday),
+        };
+
+        day_matches && self.month.matches(month)
+    }
+}
+
+/// Converts days since the Unix epoch into the month and the day of month
+fn month_and_day(days: u64) -> (u32, u32) {
+    // Howard Hinnant's `civil_from_days`
+    let z = days + 719_468;
+    let day_of_era = z % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+
+    (month as u32, day as u32)
+}
+
+enum Rule {
+    Every(Duration),
+    Cron(Cron),
+}
+
+impl Rule {
+    fn next(&self, after: Instant, clock: &dyn Clock) -> Option<Instant> {
+        match self {
+            Rule::Every(interval) => Some(after + *interval),
+            Rule::Cron(cron) => {
+                let now = clock.system_time();
+                let next = cron.next_after(now)?;
+
+                Some(clock.now() + next.duration_since(now).unwrap_or_default())
+            }
+        }
+    }
+}
+
+struct Entry<Action> {
+    id: u64,
+    rule: Rule,
+    action: Action,
+    next: Instant,
+}
+
+struct Schedules<Action> {
+    entries: Vec<Entry<Action>>,
+    next_id: u64,
+    closed: bool,
+}
+
+struct Shared<Action> {
+    schedules: Mutex<Schedules<Action>>,
+    changed: Condvar,
+    clock: SharedClock,
+}
+
+impl<Action> Shared<Action> {
+    fn lock(&self) -> MutexGuard<'_, Schedules<Action>> {
+        self.schedules
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Runs recurring schedules on its own thread and enqueues their actions
+/// into a `DispatchQueue`, so apps do not hand-roll timer threads.
+///
+/// The scheduler stops when it is dropped or the queue is dropped.
+///
+/// ## Example
+/// ```rust
+/// use redust::{DispatchQueue, Scheduler, Store};
+/// use std::thread;
+/// use std::time::Duration;
+///
+/// type MyStore = u32;
+///
+/// #[derive(Debug, Clone)]
+/// enum MyAction {
+///     Poll,
+///     Cleanup,
+/// };
+///
+/// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+///     match action {
+///         MyAction::Poll => state + 1,
+///         MyAction::Cleanup => 0,
+///     }
+/// }
+///
+/// let queue = DispatchQueue::new();
+/// let scheduler = Scheduler::new(queue.dispatcher());
+///
+/// let polling = scheduler
+///     .every(Duration::from_millis(5), MyAction::Poll)
+///     .unwrap();
+/// scheduler.cron("0 3 * * *", MyAction::Cleanup).unwrap();
+///
+/// thread::sleep(Duration::from_millis(50));
+/// polling.cancel();
+///
+/// let mut store = Store::new(reducer, 0);
+/// store.drain(&queue);
+///
+/// assert!(*store.state() > 0);
+/// ```
+pub struct Scheduler<Action> {
+    shared: Arc<Shared<Action>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl<Action: Clone + Send + 'static> Scheduler<Action> {
+    /// Starts the scheduler thread without any schedules
+    pub fn new(dispatcher: Dispatcher<Action>) -> Self {
+        Self::with_clock(dispatcher, Arc::new(SystemClock))
+    }
+
+    /// Starts the scheduler thread which reads the time from the `clock`,
+    /// so tests can drive schedules with a `TestClock`
+    pub fn with_clock(dispatcher: Dispatcher<Action>, clock: SharedClock) -> Self {
+        let shared = Arc::new(Shared {
+            schedules: Mutex::new(Schedules {
+                entries: Vec::new(),
+                next_id: 0,
+                closed: false,
+            }),
+            changed: Condvar::new(),
+            clock,
+        });
+
+        let thread = {
+            let shared = Arc::clone(&shared);
+            thread::spawn(move || run(&shared, &dispatcher))
+        };
+
+        Self {
+            shared,
+            thread: Some(thread),
+        }
+    }
+
+    /// Enqueues the `action` every `interval`, the first time after `interval`.
+    /// Returns `ScheduleError::ZeroInterval` if the `interval` is zero
+    pub fn every(
+        &self,
+        interval: Duration,
+        action: Action,
+    ) -> Result<ScheduleHandle<Action>, ScheduleError> {
+        if interval.is_zero() {
+            return Err(ScheduleError::ZeroInterval);
+        }
+
+        Ok(self.add(Rule::Every(interval), action))
+    }
+
+    /// Enqueues the `action` at every time matching the cron expression, see `Cron`
+    pub fn cron(
+        &self,
+        expression: &str,
+        action: Action,
+    ) -> Result<ScheduleHandle<Action>, ScheduleError> {
+        Ok(self.add(Rule::Cron(Cron::parse(expression)?), action))
+    }
+
+    fn add(&self, rule: Rule, action: Action) -> ScheduleHandle<Action> {
+        let mut schedules = self.shared.lock();
+        let id = schedules.next_id;
+        schedules.next_id += 1;
+
+        let clock = self.shared.clock.as_ref();
+        if let Some(next) = rule.next(clock.now(), clock) {
+            schedules.entries.push(Entry {
+                id,
+                rule,
+                action,
+                next,
+            });
+        }
+        self.shared.changed.notify_all();
+
+        ScheduleHandle {
+            id,
+            shared: Arc::downgrade(&self.shared),
+        }
+    }
+
+    /// Returns the number of active schedules
+    pub fn len(&self) -> usize {
+        self.shared.lock().entries.len()
+    }
+
+    /// Returns `true` if there are no active schedules
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<Action> Drop for Scheduler<Action> {
+    fn drop(&mut self) {
+        self.shared.lock().closed = true;
+        self.shared.changed.notify_all();
+
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+fn run<Action: Clone>(shared: &Shared<Action>, dispatcher: &Dispatcher<Action>) {
+    let mut schedules = shared.lock();
+
+    let clock = shared.clock.as_ref();
+    while !schedules.closed {
+        let now = clock.now();
+        let mut due = Vec::new();
+        schedules.entries.retain_mut(|entry| {
+            if entry.next > now {
+                return true;
+            }
+
+            due.push(entry.action.clone());
+            match entry.rule.next(entry.next.max(now), clock) {
+                Some(next) => {
+                    entry.next = next;
+                    true
+                }
+                None => false,
+            }
+        });
+
+        if !due.is_empty() {
+            // Enqueue without the lock, a bounded queue might block
+            drop(schedules);
+            if due
+                .into_iter()
+                .any(|action| dispatcher.dispatch(action).is_err())
+            {
+                return;
+            }
+            schedules = shared.lock();
+            continue;
+        }
+
+        let next = schedules.entries.iter().map(|entry| entry.next).min();
+        schedules = match next {
+            Some(next) => {
+                let remaining = next.saturating_duration_since(clock.now());
+                shared
+                    .changed
+                    .wait_timeout(schedules, clock.wait_timeout(remaining))
+                    .unwrap_or_else(|poisoned| poisoned.into_inner())
+                    .0
+            }
+            None => shared
+                .changed
+                .wait(schedules)
+                .unwrap_or_else(|poisoned| poisoned.into_inner()),
+        };
+    }
+}
+
+/// Handle of a recurring schedule. Dropping it keeps the schedule running
+pub struct ScheduleHandle<Action> {
+    id: u64,
+    shared: Weak<Shared<Action>>,
+}
+
+impl<Action> ScheduleHandle<Action> {
+    /// Stops the schedule. Actions which were already enqueued stay in the queue
+    pub fn cancel(self) {
+        if let Some(shared) = self.shared.upgrade() {
+            shared.lock().entries.retain(|entry| entry.id != self.id);
+            shared.changed.notify_all();
+        }
+    }
+}
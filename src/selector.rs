@@ -0,0 +1,221 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::subscription::{StoreObserver, Subscriber};
+use crate::Store;
+
+/// Computes the selected value from the selector input asynchronously,
+/// e.g. by hashing it on a thread pool or calling into another subsystem
+pub type AsyncCombiner<Input, Output> = fn(Input) -> Pin<Box<dyn Future<Output = Output> + Send>>;
+
+struct Cache<Input, Output> {
+    input: Input,
+    // Grows every time the input changes
+    input_version: u64,
+    resolved: Option<(u64, Output)>,
+}
+
+/// Selector whose combiner is async. The resolved value is cached until
+/// the part of the state selected by the input function changes.
+///
+/// Created with `Store::select_async`.
+pub struct AsyncSelector<State, Input, Output> {
+    cache: Arc<Mutex<Cache<Input, Output>>>,
+    select: fn(&State) -> Input,
+    combiner: AsyncCombiner<Input, Output>,
+}
+
+impl<State, Input, Output> Clone for AsyncSelector<State, Input, Output> {
+    fn clone(&self) -> Self {
+        Self {
+            cache: Arc::clone(&self.cache),
+            select: self.select,
+            combiner: self.combiner,
+        }
+    }
+}
+
+fn lock<Input, Output>(
+    cache: &Mutex<Cache<Input, Output>>,
+) -> MutexGuard<'_, Cache<Input, Output>> {
+    // The cache is updated by plain assignments, so it is never left inconsistent
+    cache
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+impl<State, Input, Output> AsyncSelector<State, Input, Output>
+where
+    Input: Clone,
+    Output: Clone,
+{
+    /// Returns the cached value or resolves it with the combiner.
+    /// When the input changes while the combiner runs, the value is resolved again
+    pub async fn get(&self) -> Output {
+        loop {
+            let (input, version) = {
+                let cache = lock(&self.cache);
+                match &cache.resolved {
+                    Some((version, output)) if *version == cache.input_version => {
+                        return output.clone()
+                    }
+                    _ => (cache.input.clone(), cache.input_version),
+                }
+            };
+
+            let output = (self.combiner)(input).await;
+
+            let mut cache = lock(&self.cache);
+            if cache.input_version == version {
+                cache.resolved = Some((version, output.clone()));
+
+                return output;
+            }
+        }
+    }
+
+    /// Returns the cached value if it is still valid, without resolving it
+    pub fn cached(&self) -> Option<Output> {
+        let cache = lock(&self.cache);
+        match &cache.resolved {
+            Some((version, output)) if *version == cache.input_version => Some(output.clone()),
+            _ => None,
+        }
+    }
+
+    /// Drops the cached value, so the next `get` resolves it again
+    pub fn invalidate(&self) {
+        lock(&self.cache).input_version += 1;
+    }
+}
+
+/// Invalidates the selector cache when the store state changes
+struct SelectorObserver<State, Input, Output>(AsyncSelector<State, Input, Output>);
+
+impl<State, Input: PartialEq, Output> SelectorObserver<State, Input, Output> {
+    fn refresh(&self, state: &State) {
+        let input = (self.0.select)(state);
+
+        let mut cache = lock(&self.0.cache);
+        if cache.input != input {
+            cache.input = input;
+            cache.input_version += 1;
+        }
+    }
+}
+
+impl<State, Action, Input, Output> StoreObserver<State, Action>
+    for SelectorObserver<State, Input, Output>
+where
+    Input: PartialEq,
+{
+    fn on_state(&mut self, state: &Arc<State>) {
+        self.refresh(state);
+    }
+
+    // Actions are delivered while notifications are paused, so the cache never goes stale
+    fn on_action(&mut self, _action: &Action, state: &Arc<State>) {
+        self.refresh(state);
+    }
+}
+
+impl<State, Action, Env> Store<State, Action, Env> {
+    /// Creates a selector which resolves its value with the async `combiner`
+    /// from the input picked by `select`. The value is cached until the input changes.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::Store;
+    /// # use std::future::Future;
+    /// # use std::sync::Arc;
+    /// # use std::task::{Context, Poll, Wake, Waker};
+    /// #
+    /// # // Any executor works, e.g. `tokio` or `futures`
+    /// # fn block_on<F: Future>(future: F) -> F::Output {
+    /// #     struct Thread(std::thread::Thread);
+    /// #     impl Wake for Thread {
+    /// #         fn wake(self: Arc<Self>) {
+    /// #             self.0.unpark();
+    /// #         }
+    /// #     }
+    /// #     let waker = Waker::from(Arc::new(Thread(std::thread::current())));
+    /// #     let mut future = Box::pin(future);
+    /// #     loop {
+    /// #         match future.as_mut().poll(&mut Context::from_waker(&waker)) {
+    /// #             Poll::Ready(output) => return output,
+    /// #             Poll::Pending => std::thread::park(),
+    /// #         }
+    /// #     }
+    /// # }
+    ///
+    /// struct MyStore {
+    ///     document: String,
+    ///     cursor: usize,
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Move(usize),
+    ///     Type(char),
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Move(cursor) => MyStore {
+    ///             document: state.document.clone(),
+    ///             cursor: *cursor,
+    ///         },
+    ///         MyAction::Type(letter) => MyStore {
+    ///             document: format!("{}{}", state.document, letter),
+    ///             cursor: state.cursor + 1,
+    ///         },
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, MyStore { document: "ab".to_string(), cursor: 0 });
+    /// let length = store.select_async(
+    ///     |state| state.document.clone(),
+    ///     |document| Box::pin(async move { document.len() }),
+    /// );
+    ///
+    /// assert_eq!(block_on(length.get()), 2);
+    ///
+    /// // The document did not change, so the value is still cached
+    /// store.dispatch(MyAction::Move(1));
+    /// assert_eq!(length.cached(), Some(2));
+    ///
+    /// store.dispatch(MyAction::Type('c'));
+    /// assert_eq!(length.cached(), None);
+    /// assert_eq!(block_on(length.get()), 3);
+    /// ```
+    pub fn select_async<Input, Output>(
+        &mut self,
+        select: fn(&State) -> Input,
+        combiner: AsyncCombiner<Input, Output>,
+    ) -> AsyncSelector<State, Input, Output>
+    where
+        State: 'static,
+        Action: 'static,
+        Input: PartialEq + Send + 'static,
+        Output: Send + 'static,
+    {
+        let selector = AsyncSelector {
+            cache: Arc::new(Mutex::new(Cache {
+                input: select(self.state()),
+                input_version: 0,
+                resolved: None,
+            })),
+            select,
+            combiner,
+        };
+
+        let token = self.next_subscription_token();
+        self.subscriptions.insert(
+            token.id(),
+            Subscriber::Observer(Box::new(SelectorObserver(selector.clone()))),
+        );
+
+        selector
+    }
+}
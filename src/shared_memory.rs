@@ -0,0 +1,258 @@
+//! Publishing state snapshots into shared memory for readers in sibling processes.
+//!
+//! A single owner process dispatches actions and publishes snapshots with
+//! `SharedStateWriter`; other processes map the same file with
+//! `SharedStateReader` and read the latest state without any round trip.
+//! Use a file on a memory-backed filesystem (e.g. `/dev/shm`) for the lowest latency.
+//!
+//! The region starts with a sequence number which is odd while a snapshot
+//! is being written, so readers detect and retry torn reads.
+//!
+//! Available behind the `shared-memory` feature.
+
+use std::fs::OpenOptions;
+use std::marker::PhantomData;
+use std::path::Path;
+use std::ptr;
+use std::sync::atomic::{fence, AtomicU64, Ordering};
+
+use memmap2::{Mmap, MmapMut};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::codec::{Codec, CodecError};
+
+/// Sequence number (8 bytes) followed by the payload length (8 bytes)
+const HEADER_LEN: usize = 16;
+
+/// How many times a reader retries when the writer keeps overwriting the snapshot
+const READ_ATTEMPTS: usize = 1024;
+
+#[derive(Debug, PartialEq)]
+pub enum SharedMemoryError {
+    Io(String),
+    Codec(CodecError),
+
+    /// The encoded state does not fit into the region
+    TooLarge {
+        size: usize,
+        capacity: usize,
+    },
+
+    /// The region is smaller than its header or the header is corrupted
+    InvalidRegion,
+
+    /// The writer kept overwriting the snapshot during every read attempt
+    Torn,
+}
+
+impl std::error::Error for SharedMemoryError {}
+impl std::fmt::Display for SharedMemoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SharedMemoryError::Io(message) => {
+                write!(f, "Cannot map the shared memory: {}", message)
+            }
+            SharedMemoryError::Codec(err) => write!(f, "{}", err),
+            SharedMemoryError::TooLarge { size, capacity } => write!(
+                f,
+                "Cannot publish state of {} bytes, the capacity is {} bytes",
+                size, capacity
+            ),
+            SharedMemoryError::InvalidRegion => {
+                write!(f, "Cannot read the shared memory: the region is invalid")
+            }
+            SharedMemoryError::Torn => {
+                write!(f, "Cannot read the shared memory: the writer is too busy")
+            }
+        }
+    }
+}
+
+impl From<std::io::Error> for SharedMemoryError {
+    fn from(err: std::io::Error) -> Self {
+        SharedMemoryError::Io(err.to_string())
+    }
+}
+
+impl From<CodecError> for SharedMemoryError {
+    fn from(err: CodecError) -> Self {
+        SharedMemoryError::Codec(err)
+    }
+}
+
+fn sequence(region: &[u8]) -> &AtomicU64 {
+    // The mapping is page-aligned, so the first 8 bytes are aligned for `AtomicU64`
+    unsafe { &*(region.as_ptr() as *const AtomicU64) }
+}
+
+/// Publishes snapshots of the state into a shared memory region
+pub struct SharedStateWriter<State> {
+    region: MmapMut,
+    codec: Codec,
+    state: PhantomData<fn(&State)>,
+}
+
+impl<State: Serialize> SharedStateWriter<State> {
+    /// Creates (or truncates) the file at `path` and maps it with room for
+    /// `capacity` bytes of the encoded state
+    pub fn create<P: AsRef<Path>>(
+        path: P,
+        capacity: usize,
+        codec: Codec,
+    ) -> Result<Self, SharedMemoryError> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+        file.set_len((HEADER_LEN + capacity) as u64)?;
+
+        // Safety: the file is owned by this writer; readers only read it
+        let region = unsafe { MmapMut::map_mut(&file)? };
+
+        Ok(Self {
+            region,
+            codec,
+            state: PhantomData,
+        })
+    }
+
+    /// Returns the number of bytes available for the encoded state
+    pub fn capacity(&self) -> usize {
+        self.region.len() - HEADER_LEN
+    }
+
+    /// Publishes the state and returns the new sequence number
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::codec::Codec;
+    /// use redust::shared_memory::{SharedStateReader, SharedStateWriter};
+    /// use redust::Store;
+    ///
+    /// type MyStore = u8;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Increment,
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Increment => state + 1,
+    ///     }
+    /// }
+    ///
+    /// let path = std::env::temp_dir().join("redust-shared-memory-example");
+    /// let mut writer = SharedStateWriter::create(&path, 64, Codec::Json).unwrap();
+    /// let mut store = Store::new(reducer, 0);
+    ///
+    /// store.dispatch(MyAction::Increment);
+    /// writer.publish(store.state()).unwrap();
+    ///
+    /// // Usually in another process
+    /// let reader = SharedStateReader::<MyStore>::open(&path, Codec::Json).unwrap();
+    ///
+    /// assert_eq!(reader.read().unwrap(), Some(1));
+    /// ```
+    pub fn publish(&mut self, state: &State) -> Result<u64, SharedMemoryError> {
+        let bytes = self.codec.encode(state)?;
+        if bytes.len() > self.capacity() {
+            return Err(SharedMemoryError::TooLarge {
+                size: bytes.len(),
+                capacity: self.capacity(),
+            });
+        }
+
+        let region = self.region.as_mut_ptr();
+        // Safety: the region is page-aligned and the payload fits into it
+        let started = unsafe {
+            let sequence = &*(region as *const AtomicU64);
+            // Odd sequence marks the snapshot as being written
+            let started = sequence.fetch_add(1, Ordering::AcqRel) + 1;
+            fence(Ordering::Release);
+
+            let len = (bytes.len() as u64).to_le_bytes();
+            ptr::copy_nonoverlapping(len.as_ptr(), region.add(8), len.len());
+            ptr::copy_nonoverlapping(bytes.as_ptr(), region.add(HEADER_LEN), bytes.len());
+
+            sequence.store(started + 1, Ordering::Release);
+            started
+        };
+
+        Ok(started + 1)
+    }
+}
+
+/// Reads snapshots published by a `SharedStateWriter`, usually in another process
+pub struct SharedStateReader<State> {
+    region: Mmap,
+    codec: Codec,
+    state: PhantomData<fn() -> State>,
+}
+
+impl<State: DeserializeOwned> SharedStateReader<State> {
+    /// Maps the file created by the writer. The `codec` must match the writer's one
+    pub fn open<P: AsRef<Path>>(path: P, codec: Codec) -> Result<Self, SharedMemoryError> {
+        let file = OpenOptions::new().read(true).open(path)?;
+
+        // Safety: the region is only read; torn reads are detected by the sequence number
+        let region = unsafe { Mmap::map(&file)? };
+        if region.len() < HEADER_LEN {
+            return Err(SharedMemoryError::InvalidRegion);
+        }
+
+        Ok(Self {
+            region,
+            codec,
+            state: PhantomData,
+        })
+    }
+
+    /// Returns the sequence number of the latest complete snapshot.
+    /// It grows with every publish, so it is a cheap way to detect changes
+    pub fn sequence(&self) -> u64 {
+        sequence(&self.region).load(Ordering::Acquire) & !1
+    }
+
+    /// Returns the latest published state or `None` if nothing was published yet
+    pub fn read(&self) -> Result<Option<State>, SharedMemoryError> {
+        Ok(self.read_with_sequence()?.map(|(_, state)| state))
+    }
+
+    /// Returns the latest published state together with its sequence number
+    pub fn read_with_sequence(&self) -> Result<Option<(u64, State)>, SharedMemoryError> {
+        let sequence = sequence(&self.region);
+
+        for _ in 0..READ_ATTEMPTS {
+            let before = sequence.load(Ordering::Acquire);
+            if before == 0 {
+                return Ok(None);
+            }
+            if before % 2 == 1 {
+                std::hint::spin_loop();
+                continue;
+            }
+
+            let mut len = [0; 8];
+            len.copy_from_slice(&self.region[8..HEADER_LEN]);
+            let len = u64::from_le_bytes(len) as usize;
+            let bytes = self
+                .region
+                .get(HEADER_LEN..HEADER_LEN.saturating_add(len))
+                .map(<[u8]>::to_vec);
+
+            fence(Ordering::Acquire);
+            if sequence.load(Ordering::Acquire) != before {
+                continue;
+            }
+
+            let bytes = bytes.ok_or(SharedMemoryError::InvalidRegion)?;
+
+            return Ok(Some((before, self.codec.decode(&bytes)?)));
+        }
+
+        Err(SharedMemoryError::Torn)
+    }
+}
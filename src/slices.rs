@@ -0,0 +1,458 @@
+use std::any::Any;
+use std::cell::{Cell, OnceCell};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::flags::FlagDecision;
+use crate::strict::{self, SlowTarget};
+use crate::subscription::{Subscriber, Subscription, SubscriptionToken};
+use crate::view::ProjectedSubscription;
+use crate::{DispatchError, Reducer, Store};
+
+pub type SliceKey = &'static str;
+
+#[derive(Debug, PartialEq)]
+pub enum SliceError {
+    /// A slice with another state type was already injected under the key
+    TypeMismatch(SliceKey),
+}
+
+impl std::error::Error for SliceError {}
+impl std::fmt::Display for SliceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SliceError::TypeMismatch(key) => write!(
+                f,
+                "Cannot inject the slice \"{}\": a slice of another type is injected under the same key",
+                key
+            ),
+        }
+    }
+}
+
+/// Slice with a statically known key and state type.
+///
+/// Allows dispatching actions directly to the slice with `Store::dispatch_to`
+/// and subscribing to its changes with `Store::subscribe_slice`.
+pub trait Slice {
+    const KEY: SliceKey;
+    type State: Clone + 'static;
+}
+
+/// Type-erased slice: the slice state together with the reducer which owns it.
+///
+/// Slices are immutable and shared between states, so slices which an action
+/// did not reach are not cloned.
+pub(crate) trait AnySlice<Action> {
+    fn reduce(
+        &self,
+        key: SliceKey,
+        action: &Action,
+        decisions: &mut Vec<FlagDecision>,
+    ) -> Arc<dyn AnySlice<Action>>;
+    fn as_any(&self) -> &dyn Any;
+}
+
+/// Slice state which is created by `initial_slice` when it is used for the first time
+pub(crate) struct LazySlice<SliceState> {
+    state: OnceCell<SliceState>,
+    initial_slice: Cell<Option<Box<dyn FnOnce() -> SliceState>>>,
+}
+
+impl<SliceState> LazySlice<SliceState> {
+    pub(crate) fn new(initial_slice: impl FnOnce() -> SliceState + 'static) -> Self {
+        Self {
+            state: OnceCell::new(),
+            initial_slice: Cell::new(Some(Box::new(initial_slice))),
+        }
+    }
+
+    pub(crate) fn ready(state: SliceState) -> Self {
+        Self {
+            state: OnceCell::from(state),
+            initial_slice: Cell::new(None),
+        }
+    }
+
+    pub(crate) fn get(&self) -> &SliceState {
+        self.state.get_or_init(|| match self.initial_slice.take() {
+            Some(initial_slice) => initial_slice(),
+            None => panic!("The initial slice panicked during the previous use"),
+        })
+    }
+}
+
+pub(crate) struct SliceEntry<SliceState, Action> {
+    pub(crate) reducer: Reducer<SliceState, Action>,
+    pub(crate) state: LazySlice<SliceState>,
+}
+
+impl<SliceState, Action> AnySlice<Action> for SliceEntry<SliceState, Action>
+where
+    SliceState: Clone + 'static,
+    Action: 'static,
+{
+    fn reduce(
+        &self,
+        _key: SliceKey,
+        action: &Action,
+        _decisions: &mut Vec<FlagDecision>,
+    ) -> Arc<dyn AnySlice<Action>> {
+        Arc::new(SliceEntry {
+            reducer: self.reducer,
+            state: LazySlice::ready((self.reducer)(self.state.get(), action)),
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self.state.get()
+    }
+}
+
+/// State made of independent slices, each one managed by its own reducer.
+///
+/// Slices are registered at runtime with `Store::inject_reducer`, which allows
+/// loading features on demand without knowing the whole state shape upfront.
+pub struct Slices<Action> {
+    pub(crate) slices: HashMap<SliceKey, Arc<dyn AnySlice<Action>>>,
+    flag_decisions: Vec<FlagDecision>,
+
+    /// Slice reduced by the last slice-targeted dispatch, `None` if all slices were reduced
+    target: Option<SliceKey>,
+}
+
+impl<Action: 'static> Slices<Action> {
+    /// Creates an empty state without any slice
+    pub fn new() -> Self {
+        Self {
+            slices: HashMap::new(),
+            flag_decisions: Vec::new(),
+            target: None,
+        }
+    }
+
+    /// Returns the state of the slice registered under the `key`.
+    ///
+    /// Returns `None` if there is no such slice or it has another type
+    pub fn get<SliceState: 'static>(&self, key: SliceKey) -> Option<&SliceState> {
+        self.slices.get(key)?.as_any().downcast_ref()
+    }
+
+    /// Returns `true` if the slice with the `key` was injected
+    pub fn contains_key(&self, key: SliceKey) -> bool {
+        self.slices.contains_key(key)
+    }
+
+    /// Returns the number of injected slices
+    pub fn len(&self) -> usize {
+        self.slices.len()
+    }
+
+    /// Returns `true` if no slice was injected yet
+    pub fn is_empty(&self) -> bool {
+        self.slices.is_empty()
+    }
+
+    /// Returns feature flag decisions made by gated slices for the last reduced action
+    pub fn flag_decisions(&self) -> &[FlagDecision] {
+        &self.flag_decisions
+    }
+
+    /// Returns `true` if the slice was reduced by the last action
+    pub fn was_reduced(&self, key: SliceKey) -> bool {
+        self.target.is_none_or(|target| target == key) && self.slices.contains_key(key)
+    }
+
+    /// Root reducer which passes the action to the reducer of each slice
+    pub fn reducer(state: &Self, action: &Action) -> Self {
+        let mut flag_decisions = Vec::new();
+        let slices = state
+            .slices
+            .iter()
+            .map(|(key, slice)| (*key, slice.reduce(key, action, &mut flag_decisions)))
+            .collect();
+
+        Self {
+            slices,
+            flag_decisions,
+            target: None,
+        }
+    }
+
+    /// Reducer which passes the action only to the slice with the `key`
+    fn reduce_slice(&self, key: SliceKey, action: &Action) -> Self {
+        let mut flag_decisions = Vec::new();
+        let slices = self
+            .slices
+            .iter()
+            .map(|(slice_key, slice)| match *slice_key == key {
+                true => (
+                    *slice_key,
+                    slice.reduce(slice_key, action, &mut flag_decisions),
+                ),
+                false => (*slice_key, Arc::clone(slice)),
+            })
+            .collect();
+
+        Self {
+            slices,
+            flag_decisions,
+            target: Some(key),
+        }
+    }
+}
+
+impl<Action: 'static> Default for Slices<Action> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Action: 'static> Clone for Slices<Action> {
+    fn clone(&self) -> Self {
+        Self {
+            slices: self
+                .slices
+                .iter()
+                .map(|(key, slice)| (*key, Arc::clone(slice)))
+                .collect(),
+            flag_decisions: self.flag_decisions.clone(),
+            target: self.target,
+        }
+    }
+}
+
+impl<Action: 'static> Store<Slices<Action>, Action> {
+    /// Creates a new store without slices. Slices might be added later
+    /// via `inject_reducer`
+    pub fn with_slices() -> Self {
+        Self::new(Slices::reducer, Slices::new())
+    }
+
+    /// Registers a slice reducer after the store was created.
+    ///
+    /// The state of other slices is preserved. The new slice state is created
+    /// by `initial_slice` when it is reduced or read for the first time.
+    /// If the slice with the same `key` and type already exists its state is
+    /// kept, only the reducer is replaced and `initial_slice` is never called.
+    ///
+    /// Returns `SliceError::TypeMismatch` and keeps the existing slice if
+    /// its state has another type.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::Store;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Increment,
+    /// };
+    ///
+    /// fn counter_reducer(state: &u8, action: &MyAction) -> u8 {
+    ///     match action {
+    ///         MyAction::Increment => state + 1,
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::with_slices();
+    ///
+    /// store.inject_reducer("counter", counter_reducer, || 0).unwrap();
+    /// store.dispatch(MyAction::Increment);
+    ///
+    /// assert_eq!(store.state().get::<u8>("counter"), Some(&1));
+    /// ```
+    pub fn inject_reducer<SliceState, F>(
+        &mut self,
+        key: SliceKey,
+        reducer: Reducer<SliceState, Action>,
+        initial_slice: F,
+    ) -> Result<(), SliceError>
+    where
+        SliceState: Clone + 'static,
+        F: FnOnce() -> SliceState + 'static,
+    {
+        let state = self.injected_slice(key, initial_slice)?;
+
+        self.state
+            .get_mut()
+            .slices
+            .insert(key, Arc::new(SliceEntry { reducer, state }));
+
+        Ok(())
+    }
+
+    /// Returns the state of the slice injected under the `key` or
+    /// the lazy `initial_slice` if there is no such slice
+    pub(crate) fn injected_slice<SliceState, F>(
+        &self,
+        key: SliceKey,
+        initial_slice: F,
+    ) -> Result<LazySlice<SliceState>, SliceError>
+    where
+        SliceState: Clone + 'static,
+        F: FnOnce() -> SliceState + 'static,
+    {
+        match self.state().slices.get(key) {
+            Some(slice) => match slice.as_any().downcast_ref::<SliceState>() {
+                Some(state) => Ok(LazySlice::ready(state.clone())),
+                None => Err(SliceError::TypeMismatch(key)),
+            },
+            None => Ok(LazySlice::new(initial_slice)),
+        }
+    }
+}
+
+struct SliceSubscription<S: Slice, Action> {
+    func: Subscription<S::State>,
+    _action: std::marker::PhantomData<fn(&Action)>,
+}
+
+impl<S: Slice, Action: 'static> ProjectedSubscription<Slices<Action>>
+    for SliceSubscription<S, Action>
+{
+    fn notify(&mut self, state: &Arc<Slices<Action>>) {
+        if !state.was_reduced(S::KEY) {
+            return;
+        }
+        if let Some(slice) = state.get::<S::State>(S::KEY) {
+            (self.func)(slice);
+        }
+    }
+}
+
+impl<Action: 'static> Store<Slices<Action>, Action> {
+    /// Passes the action only to the reducer of the slice `S`, without running
+    /// the root reducer over the other slices.
+    ///
+    /// Only subscriptions made with `subscribe_slice` for this slice are called.
+    /// The action goes through the same guards as `dispatch`: the abort signal,
+    /// interceptors, `check_mutations` and strict mode timing. It skips middleware
+    /// and dispatch hooks, but it is recorded like any other one: in the history,
+    /// as the last action and in the dispatch metrics. A frozen store reports
+    /// `DispatchError::Frozen` to the error stream unless it ignores actions.
+    /// The action is dropped if the slice was not injected.
+    ///
+    /// Actions queued by middleware or subscribers meanwhile are dispatched
+    /// right after it, like `dispatch` does.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::{Slice, Store};
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Increment,
+    /// };
+    ///
+    /// fn counter_reducer(state: &u8, action: &MyAction) -> u8 {
+    ///     match action {
+    ///         MyAction::Increment => state + 1,
+    ///     }
+    /// }
+    ///
+    /// struct Clicks;
+    ///
+    /// impl Slice for Clicks {
+    ///     const KEY: &'static str = "clicks";
+    ///     type State = u8;
+    /// }
+    ///
+    /// let mut store = Store::with_slices();
+    /// store.inject_reducer("clicks", counter_reducer, || 0).unwrap();
+    /// store.inject_reducer("views", counter_reducer, || 0).unwrap();
+    /// store.subscribe_slice::<Clicks>(|clicks| assert_eq!(*clicks, 1));
+    ///
+    /// store.dispatch_to::<Clicks>(MyAction::Increment);
+    ///
+    /// assert_eq!(store.state().get::<u8>("clicks"), Some(&1));
+    /// assert_eq!(store.state().get::<u8>("views"), Some(&0));
+    /// ```
+    pub fn dispatch_to<S: Slice>(&mut self, action: Action) -> &mut Self {
+        match self.reduce_slice_action(S::KEY, action) {
+            Ok(Some(action)) => self.record_last_action(action),
+            Ok(None) => {}
+            Err(err) => self.report_dispatch_error(&err),
+        }
+        self.dispatch_queued();
+
+        self
+    }
+
+    /// Reduces the action with the reducer of one slice and notifies its
+    /// subscriptions. Returns `None` if the slice was not injected
+    fn reduce_slice_action(
+        &mut self,
+        key: SliceKey,
+        action: Action,
+    ) -> Result<Option<Action>, DispatchError> {
+        self.errors.clear_rejected();
+        if self.frozen.is_some() {
+            self.errors.reject(action);
+            return Err(DispatchError::Frozen);
+        }
+        if let Err(err) = self.check_abort() {
+            self.errors.reject(action);
+            return Err(err);
+        }
+        if !self.state().contains_key(key) {
+            return Ok(None);
+        }
+
+        let action = self
+            .apply_interceptors(action)
+            .ok_or(DispatchError::Intercepted)?;
+
+        let fingerprint = self
+            .mutation_check
+            .as_ref()
+            .map(|check| check.before(self.state.get()));
+
+        let state = self.state.get();
+        let started = self.metrics.as_ref().map(|_| Instant::now());
+        let new_state =
+            strict::measure(&mut self.strict, SlowTarget::Reducer, Some(&action), || {
+                state.reduce_slice(key, &action)
+            });
+        if let (Some(metrics), Some(started)) = (self.metrics.as_mut(), started) {
+            metrics.record(started.elapsed());
+        }
+        let old_state = self.state.replace(new_state);
+
+        if let (Some(check), Some(fingerprint)) = (&self.mutation_check, fingerprint) {
+            check.after(fingerprint, &old_state, &action);
+        }
+
+        self.version += 1;
+        if let Some(history) = self.history.as_mut() {
+            // The root reducer would pass the action to every slice, so the state cannot be replayed
+            let (reducer, env) = (self.reducer, &self.env);
+            let reduce =
+                |state: &Slices<Action>, action: &Action| reducer.reduce(state, action, env);
+            history.record_snapshot(&action, self.state.shared(), &reduce, self.clock.now());
+        }
+        self.notify_projected();
+
+        Ok(Some(action))
+    }
+
+    /// Subscribes a callback which is called with the state of the slice `S`
+    /// whenever an action was passed to its reducer
+    pub fn subscribe_slice<S: Slice + 'static>(
+        &mut self,
+        func: Subscription<S::State>,
+    ) -> SubscriptionToken<Slices<Action>, Action> {
+        let subscription_token = self.next_subscription_token();
+        let entry: SliceSubscription<S, Action> = SliceSubscription {
+            func,
+            _action: std::marker::PhantomData,
+        };
+
+        self.subscriptions.insert(
+            subscription_token.id(),
+            Subscriber::Projected(Box::new(entry)),
+        );
+
+        subscription_token
+    }
+}
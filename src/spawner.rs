@@ -0,0 +1,497 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use crate::effect::dispatch_effect_action;
+use crate::{CancellationToken, Dispatcher, SharedClock, SystemClock};
+
+/// Boxed future which a `Spawner` runs
+pub type BoxFuture<Output> = Pin<Box<dyn Future<Output = Output> + Send>>;
+
+/// Boxed future which a `Spawner` runs on the current thread
+pub type LocalBoxFuture<Output> = Pin<Box<dyn Future<Output = Output>>>;
+
+#[derive(Debug, PartialEq)]
+pub enum SpawnError {
+    /// The executor cannot run futures which are not `Send`
+    LocalUnsupported,
+}
+
+impl std::error::Error for SpawnError {}
+impl std::fmt::Display for SpawnError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SpawnError::LocalUnsupported => {
+                write!(f, "Cannot spawn a local future on this executor")
+            }
+        }
+    }
+}
+
+/// Async runtime which runs effects and timers, so async helpers do not
+/// depend on a particular executor. Any executor is plugged in by implementing it.
+///
+/// Implemented by `TokioSpawner` behind the `tokio` feature, by `AsyncStdSpawner`
+/// behind the `async-std` feature and by `SmolSpawner` behind the `smol` feature.
+pub trait Spawner: Send + Sync {
+    /// Runs the future in the background
+    fn spawn(&self, future: BoxFuture<()>);
+
+    /// Runs the future, which does not have to be `Send`, on the current thread.
+    /// Executors without thread-local tasks keep the default, which returns an error
+    fn spawn_local(&self, future: LocalBoxFuture<()>) -> Result<(), SpawnError> {
+        drop(future);
+
+        Err(SpawnError::LocalUnsupported)
+    }
+
+    /// Returns a future which completes after the `duration`
+    fn sleep(&self, duration: Duration) -> BoxFuture<()>;
+}
+
+/// Spawner shared between async helpers
+pub type SharedSpawner = Arc<dyn Spawner>;
+
+/// Handle of an effect started by `spawn_async_effect`
+pub struct AsyncEffectHandle {
+    token: CancellationToken,
+    finished: Arc<AtomicBool>,
+}
+
+impl AsyncEffectHandle {
+    /// Cancels the effect. The action it produces is not dispatched anymore
+    pub fn cancel(&self) {
+        self.token.cancel();
+    }
+
+    /// Returns the token which the effect observes
+    pub fn token(&self) -> &CancellationToken {
+        &self.token
+    }
+
+    /// Returns `true` if the effect has finished
+    pub fn is_finished(&self) -> bool {
+        self.finished.load(Ordering::SeqCst)
+    }
+}
+
+/// Runs the async effect with the `spawner` and enqueues the action it returns.
+///
+/// It is the async counterpart of `spawn_effect`: the effect should check the
+/// token, and the action of a cancelled effect is dropped even if it was returned.
+///
+/// ## Example
+/// ```rust
+/// use redust::{spawn_async_effect, BoxFuture, DispatchQueue, Spawner};
+/// use std::thread;
+/// use std::time::Duration;
+/// # use std::future::Future;
+/// # use std::sync::Arc;
+/// # use std::task::{Context, Poll, Wake, Waker};
+/// #
+/// # // Spawner of the doctest, which runs futures to completion on new threads
+/// # struct ThreadSpawner;
+/// #
+/// # struct ThreadWaker(thread::Thread);
+/// #
+/// # impl Wake for ThreadWaker {
+/// #     fn wake(self: Arc<Self>) {
+/// #         self.0.unpark();
+/// #     }
+/// # }
+/// #
+/// # fn block_on<F: Future>(future: F) -> F::Output {
+/// #     let mut future = Box::pin(future);
+/// #     let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+/// #     let mut context = Context::from_waker(&waker);
+/// #     loop {
+/// #         match future.as_mut().poll(&mut context) {
+/// #             Poll::Ready(output) => return output,
+/// #             Poll::Pending => thread::park(),
+/// #         }
+/// #     }
+/// # }
+/// #
+/// # impl Spawner for ThreadSpawner {
+/// #     fn spawn(&self, future: BoxFuture<()>) {
+/// #         thread::spawn(move || block_on(future));
+/// #     }
+/// #
+/// #     fn sleep(&self, duration: Duration) -> BoxFuture<()> {
+/// #         Box::pin(async move { thread::sleep(duration) })
+/// #     }
+/// # }
+///
+/// #[derive(Debug, PartialEq)]
+/// enum MyAction {
+///     Loaded(&'static str),
+/// };
+///
+/// let queue = DispatchQueue::new();
+/// let handle = spawn_async_effect(&ThreadSpawner, queue.dispatcher(), |_token| async {
+///     Some(MyAction::Loaded("Ann"))
+/// });
+///
+/// while !handle.is_finished() {
+///     thread::sleep(Duration::from_millis(1));
+/// }
+/// assert_eq!(queue.pop(), Some(MyAction::Loaded("Ann")));
+/// ```
+pub fn spawn_async_effect<Action, F, Fut>(
+    spawner: &dyn Spawner,
+    dispatcher: Dispatcher<Action>,
+    effect: F,
+) -> AsyncEffectHandle
+where
+    Action: Send + 'static,
+    F: FnOnce(CancellationToken) -> Fut,
+    Fut: Future<Output = Option<Action>> + Send + 'static,
+{
+    let token = CancellationToken::new();
+    let finished = Arc::new(AtomicBool::new(false));
+    let future = effect(token.clone());
+
+    let (effect_token, effect_finished) = (token.clone(), Arc::clone(&finished));
+    let task = async move {
+        let action = future.await;
+        dispatch_effect_action(&dispatcher, &effect_token, action);
+        effect_finished.store(true, Ordering::SeqCst);
+    };
+    #[cfg(feature = "otel")]
+    let task = crate::otel::in_effect_span(task);
+    spawner.spawn(Box::pin(task));
+
+    AsyncEffectHandle { token, finished }
+}
+
+/// Runs the async effect, which does not have to be `Send`, on the current thread
+/// of the `spawner` and enqueues the action it returns.
+///
+/// Fails if the executor of the `spawner` has no thread-local tasks.
+pub fn spawn_local_effect<Action, F, Fut>(
+    spawner: &dyn Spawner,
+    dispatcher: Dispatcher<Action>,
+    effect: F,
+) -> Result<AsyncEffectHandle, SpawnError>
+where
+    Action: 'static,
+    F: FnOnce(CancellationToken) -> Fut,
+    Fut: Future<Output = Option<Action>> + 'static,
+{
+    let token = CancellationToken::new();
+    let finished = Arc::new(AtomicBool::new(false));
+    let future = effect(token.clone());
+
+    let (effect_token, effect_finished) = (token.clone(), Arc::clone(&finished));
+    let task = async move {
+        let action = future.await;
+        dispatch_effect_action(&dispatcher, &effect_token, action);
+        effect_finished.store(true, Ordering::SeqCst);
+    };
+    #[cfg(feature = "otel")]
+    let task = crate::otel::in_effect_span(task);
+    spawner.spawn_local(Box::pin(task))?;
+
+    Ok(AsyncEffectHandle { token, finished })
+}
+
+/// Enqueues the action after the `delay`, unless the returned handle is cancelled first
+pub fn dispatch_after<Action: Send + 'static>(
+    spawner: &dyn Spawner,
+    dispatcher: Dispatcher<Action>,
+    delay: Duration,
+    action: Action,
+) -> AsyncEffectHandle {
+    let sleep = spawner.sleep(delay);
+
+    spawn_async_effect(spawner, dispatcher, move |_token| async move {
+        sleep.await;
+
+        Some(action)
+    })
+}
+
+/// Enqueues only the last of the actions passed to `dispatch` in quick succession,
+/// once no new action came during the `delay`.
+///
+/// Typical for search-as-you-type, where every keystroke should not start a request.
+/// The delay is measured with the clock of the debouncer, the spawner only
+/// runs the timer and wakes it up.
+///
+/// ## Example
+/// ```rust
+/// use redust::{BoxFuture, Debouncer, DispatchQueue, Spawner, TestClock};
+/// use std::sync::{Arc, Mutex};
+/// use std::task::{Context, Waker};
+/// use std::time::Duration;
+///
+/// // Polls the spawned timers only when asked
+/// #[derive(Default)]
+/// struct ManualSpawner(Mutex<Vec<BoxFuture<()>>>);
+///
+/// impl Spawner for ManualSpawner {
+///     fn spawn(&self, future: BoxFuture<()>) {
+///         self.0.lock().unwrap().push(future);
+///     }
+///
+///     fn sleep(&self, _duration: Duration) -> BoxFuture<()> {
+///         Box::pin(async {})
+///     }
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// enum MyAction {
+///     Search(&'static str),
+/// };
+///
+/// let spawner = Arc::new(ManualSpawner::default());
+/// let clock = TestClock::new();
+/// let queue = DispatchQueue::new();
+/// let debouncer = Debouncer::with_clock(
+///     spawner.clone(),
+///     queue.dispatcher(),
+///     Duration::from_millis(300),
+///     Arc::new(clock.clone()),
+/// );
+///
+/// debouncer.dispatch(MyAction::Search("re"));
+/// debouncer.dispatch(MyAction::Search("redux"));
+/// clock.advance(Duration::from_millis(300));
+///
+/// for mut timer in spawner.0.lock().unwrap().drain(..) {
+///     let _ = timer.as_mut().poll(&mut Context::from_waker(Waker::noop()));
+/// }
+/// assert_eq!(queue.pop(), Some(MyAction::Search("redux")));
+/// assert!(queue.is_empty());
+/// ```
+pub struct Debouncer<Action> {
+    spawner: SharedSpawner,
+    dispatcher: Dispatcher<Action>,
+    delay: Duration,
+    clock: SharedClock,
+    pending: Arc<Mutex<Debounced<Action>>>,
+}
+
+/// Action waiting for its deadline, and whether a timer is waiting for it
+struct Debounced<Action> {
+    action: Option<(Instant, Action)>,
+    timer: bool,
+}
+
+fn lock_debounced<Action>(pending: &Mutex<Debounced<Action>>) -> MutexGuard<'_, Debounced<Action>> {
+    pending
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+impl<Action: Send + 'static> Debouncer<Action> {
+    pub fn new(spawner: SharedSpawner, dispatcher: Dispatcher<Action>, delay: Duration) -> Self {
+        Self::with_clock(spawner, dispatcher, delay, Arc::new(SystemClock))
+    }
+
+    /// Creates a debouncer which measures the delay with the `clock`,
+    /// so tests can drive it with a `TestClock`
+    pub fn with_clock(
+        spawner: SharedSpawner,
+        dispatcher: Dispatcher<Action>,
+        delay: Duration,
+        clock: SharedClock,
+    ) -> Self {
+        Self {
+            spawner,
+            dispatcher,
+            delay,
+            clock,
+            pending: Arc::new(Mutex::new(Debounced {
+                action: None,
+                timer: false,
+            })),
+        }
+    }
+
+    /// Schedules the action and drops the one scheduled before, if it is still waiting
+    pub fn dispatch(&self, action: Action) {
+        let deadline = self.clock.now() + self.delay;
+        {
+            let mut pending = lock_debounced(&self.pending);
+            pending.action = Some((deadline, action));
+            if pending.timer {
+                return;
+            }
+            pending.timer = true;
+        }
+
+        let (spawner, clock, pending) = (
+            Arc::clone(&self.spawner),
+            Arc::clone(&self.clock),
+            Arc::clone(&self.pending),
+        );
+        // One timer serves all actions, it waits again when the deadline moved
+        spawn_async_effect(
+            self.spawner.as_ref(),
+            self.dispatcher.clone(),
+            move |_token| async move {
+                loop {
+                    let remaining = {
+                        let mut pending = lock_debounced(&pending);
+                        let now = clock.now();
+                        match pending.action.as_ref() {
+                            Some((deadline, _)) if *deadline > now => *deadline - now,
+                            _ => {
+                                pending.timer = false;
+                                return pending.action.take().map(|(_, action)| action);
+                            }
+                        }
+                    };
+
+                    spawner.sleep(clock.wait_timeout(remaining)).await;
+                }
+            },
+        );
+    }
+
+    /// Cancels the scheduled action
+    pub fn cancel(&self) {
+        lock_debounced(&self.pending).action = None;
+    }
+}
+
+/// Spawner which runs futures on a tokio runtime. Available behind the `tokio` feature.
+///
+/// The runtime needs the time driver for `sleep`. Local futures are not supported,
+/// `local_effect::LocalEffects` runs them on a `LocalSet` instead.
+#[cfg(feature = "tokio")]
+#[derive(Debug, Clone)]
+pub struct TokioSpawner {
+    handle: tokio::runtime::Handle,
+}
+
+#[cfg(feature = "tokio")]
+impl TokioSpawner {
+    pub fn new(handle: tokio::runtime::Handle) -> Self {
+        Self { handle }
+    }
+
+    /// Returns the spawner of the runtime the caller runs on, if any
+    pub fn current() -> Option<Self> {
+        tokio::runtime::Handle::try_current().ok().map(Self::new)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl Spawner for TokioSpawner {
+    fn spawn(&self, future: BoxFuture<()>) {
+        self.handle.spawn(future);
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<()> {
+        // The timer registers with the runtime when it is created, which might
+        // happen outside of it, e.g. in `dispatch_after`
+        let _runtime = self.handle.enter();
+
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+/// Spawner which runs futures on the global async-std executor.
+/// Available behind the `async-std` feature
+#[cfg(feature = "async-std")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AsyncStdSpawner;
+
+#[cfg(feature = "async-std")]
+impl Spawner for AsyncStdSpawner {
+    fn spawn(&self, future: BoxFuture<()>) {
+        async_std::task::spawn(future);
+    }
+
+    fn spawn_local(&self, future: LocalBoxFuture<()>) -> Result<(), SpawnError> {
+        async_std::task::spawn_local(future);
+
+        Ok(())
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<()> {
+        Box::pin(async_std::task::sleep(duration))
+    }
+}
+
+#[cfg(feature = "smol")]
+thread_local! {
+    static SMOL_LOCAL_EXECUTOR: async_executor::LocalExecutor<'static> =
+        const { async_executor::LocalExecutor::new() };
+}
+
+/// Spawner built on the executor and timer which `smol` consists of.
+/// Available behind the `smol` feature.
+///
+/// It is the reference implementation for plugging in other executors.
+/// Spawned futures run while some thread drives the spawner with `block_on`;
+/// local futures run while their thread does.
+///
+/// ## Example
+/// ```rust
+/// use redust::{spawn_local_effect, DispatchQueue, SmolSpawner};
+/// use std::rc::Rc;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum MyAction {
+///     Loaded(usize),
+/// };
+///
+/// let spawner = SmolSpawner::new();
+/// let queue = DispatchQueue::new();
+///
+/// // `Rc` cannot be moved into a `Send` future
+/// let cache = Rc::new(vec!["milk"]);
+/// let handle = spawn_local_effect(&spawner, queue.dispatcher(), move |_token| async move {
+///     Some(MyAction::Loaded(cache.len()))
+/// })
+/// .unwrap();
+///
+/// spawner.block_on(async {
+///     while !handle.is_finished() {
+///         async_io::Timer::after(std::time::Duration::from_millis(1)).await;
+///     }
+/// });
+/// assert_eq!(queue.pop(), Some(MyAction::Loaded(1)));
+/// ```
+#[cfg(feature = "smol")]
+#[derive(Debug, Clone, Default)]
+pub struct SmolSpawner {
+    executor: Arc<async_executor::Executor<'static>>,
+}
+
+#[cfg(feature = "smol")]
+impl SmolSpawner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs the future on the current thread, driving spawned futures
+    /// and local futures of this thread until it completes
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        SMOL_LOCAL_EXECUTOR.with(|local| async_io::block_on(local.run(self.executor.run(future))))
+    }
+}
+
+#[cfg(feature = "smol")]
+impl Spawner for SmolSpawner {
+    fn spawn(&self, future: BoxFuture<()>) {
+        self.executor.spawn(future).detach();
+    }
+
+    fn spawn_local(&self, future: LocalBoxFuture<()>) -> Result<(), SpawnError> {
+        SMOL_LOCAL_EXECUTOR.with(|local| local.spawn(future).detach());
+
+        Ok(())
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<()> {
+        Box::pin(async move {
+            async_io::Timer::after(duration).await;
+        })
+    }
+}
@@ -1,30 +1,310 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::Arc;
+use std::time::Instant;
 
-use crate::subscription::{SubscriptionToken, UnsubscribeError};
-use crate::{Reducer, Subscription};
+use crate::abort::AbortSignal;
+use crate::clock::{SharedClock, SystemClock};
+use crate::context::Context;
+use crate::errors::ErrorSink;
+use crate::expiration::Expiration;
+use crate::fallible::ErrorPolicy;
+use crate::history::History;
+use crate::hooks::Hooks;
+use crate::isolation::{self, PanicIsolation};
+use crate::last_action::LastAction;
+use crate::lazy::LazyState;
+use crate::merge::ForkBase;
+use crate::metrics::DispatchMetrics;
+use crate::middleware::MiddlewareStack;
+use crate::mutation::MutationCheck;
+use crate::reducer::RootReducer;
+use crate::strict::{self, SlowTarget, StrictMode};
+use crate::subscription::{
+    next_store_id, ActionFilter, ActionSubscription, NotifyPolicy, StateFilter, StoreId,
+    Subscriber, SubscriberId, SubscriptionToken, UnsubscribeError,
+};
+use crate::version::StateVersion;
+use crate::{
+    DispatchError, EnvReducer, FreezePolicy, Interceptor, Reducer, SubscriberError, Subscription,
+};
 
-pub struct Store<State, Action> {
-    reducer: Reducer<State, Action>,
-    state: State,
-    subscriptions: HashMap<SubscriptionToken, Subscription<State>>,
-    subscriptions_index: SubscriptionToken,
+/// Parts of the store returned by `Store::into_parts`.
+/// A store with the same reducer, environment and history is built from them with `Store::from_parts`
+pub struct StoreParts<State, Action, Env = ()> {
+    /// The current state, shared with snapshots which are still alive.
+    /// Use `Arc::try_unwrap` to take ownership of it
+    pub state: Arc<State>,
+
+    /// The environment passed to the reducer, `()` for stores created with `Store::new`
+    pub env: Arc<Env>,
+    reducer: RootReducer<State, Action, Env>,
+    history: Option<History<State, Action>>,
+}
+
+impl<State, Action, Env> StoreParts<State, Action, Env> {
+    /// Returns the reducer of a store created without an environment
+    pub fn reducer(&self) -> Option<Reducer<State, Action>> {
+        match self.reducer {
+            RootReducer::Plain(reducer) => Some(reducer),
+            RootReducer::Env(_) => None,
+        }
+    }
+
+    /// Returns the reducer of a store created with `Store::with_env`
+    pub fn env_reducer(&self) -> Option<EnvReducer<State, Action, Env>> {
+        match self.reducer {
+            RootReducer::Plain(_) => None,
+            RootReducer::Env(reducer) => Some(reducer),
+        }
+    }
+
+    /// Returns the number of kept states, zero when the history was disabled
+    pub fn history_len(&self) -> usize {
+        self.history.as_ref().map(History::len).unwrap_or(0)
+    }
+
+    /// Returns the kept state with the `index`, the oldest one is 0
+    pub fn history_state(&self, index: usize) -> Option<Arc<State>> {
+        let reduce = |state: &State, action: &Action| self.reducer.reduce(state, action, &self.env);
+        self.history.as_ref()?.state(index, &reduce)
+    }
+
+    /// Returns kept actions from the oldest to the latest one
+    pub fn history_actions(&self) -> Vec<&Action> {
+        self.history
+            .as_ref()
+            .map(|history| history.actions().collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Store of the state which is changed only by dispatching actions.
+///
+/// The optional `Env` is a read-only environment passed to the reducer,
+/// see `Store::with_env`. Stores created with `Store::new` have none.
+pub struct Store<State, Action, Env = ()> {
+    pub(crate) reducer: RootReducer<State, Action, Env>,
+    pub(crate) env: Arc<Env>,
+    pub(crate) state: LazyState<State>,
+    // Tokens grow monotonically, so the map keeps subscription order
+    pub(crate) subscriptions: BTreeMap<SubscriberId, Subscriber<State, Action>>,
+    pub(crate) subscriptions_index: u64,
+    pub(crate) id: StoreId,
+    pub(crate) hooks: Hooks<State, Action>,
+    pub(crate) middleware: MiddlewareStack<State, Action>,
+    pub(crate) interceptors: BTreeMap<SubscriberId, Interceptor<State, Action>>,
+    pub(crate) subscriber_errors: Vec<(SubscriberId, SubscriberError)>,
+    pub(crate) errors: ErrorSink<Action>,
+    pub(crate) panic_isolation: Option<PanicIsolation>,
+    pub(crate) mutation_check: Option<MutationCheck<State, Action>>,
+    pub(crate) strict: Option<StrictMode<Action>>,
+    pub(crate) fork_base: Option<ForkBase<State, Action>>,
+    pub(crate) history: Option<History<State, Action>>,
+    pub(crate) metrics: Option<DispatchMetrics>,
+    pub(crate) expirations: Vec<Expiration<Action>>,
+    pub(crate) frozen: Option<FreezePolicy>,
+    pub(crate) abort: Option<AbortSignal>,
+    pub(crate) clock: SharedClock,
+    pub(crate) context: Option<Context>,
+    pub(crate) last_action: Option<LastAction<Action>>,
+    pub(crate) queued_actions: VecDeque<Action>,
+    pub(crate) dispatching_queued: bool,
+    pub(crate) version: StateVersion,
+    pub(crate) notifications_paused: bool,
+    pub(crate) missed_notifications: bool,
 }
 
 impl<State, Action> Store<State, Action> {
     /// Creates a new store
     pub fn new(reducer: Reducer<State, Action>, initial_state: State) -> Self {
+        Self::with_lazy_state(
+            RootReducer::Plain(reducer),
+            LazyState::new(initial_state),
+            Arc::new(()),
+        )
+    }
+}
+
+impl<State, Action, Env> Store<State, Action, Env> {
+    pub(crate) fn with_lazy_state(
+        reducer: RootReducer<State, Action, Env>,
+        state: LazyState<State>,
+        env: Arc<Env>,
+    ) -> Self {
         Self {
             reducer,
-            state: initial_state,
-            subscriptions: HashMap::new(),
+            env,
+            state,
+            subscriptions: BTreeMap::new(),
             subscriptions_index: 0,
+            id: next_store_id(),
+            hooks: Hooks::new(),
+            middleware: MiddlewareStack::new(),
+            interceptors: BTreeMap::new(),
+            subscriber_errors: Vec::new(),
+            errors: ErrorSink::new(),
+            panic_isolation: None,
+            mutation_check: None,
+            strict: None,
+            fork_base: None,
+            history: None,
+            metrics: None,
+            expirations: Vec::new(),
+            frozen: None,
+            abort: None,
+            clock: Arc::new(SystemClock),
+            context: None,
+            last_action: None,
+            queued_actions: VecDeque::new(),
+            dispatching_queued: false,
+            version: 0,
+            notifications_paused: false,
+            missed_notifications: false,
         }
     }
 
     /// Returns the current state tree of your application.
     /// It is equal to the last value returned by the store's reducer.
     pub fn state(&self) -> &State {
-        &self.state
+        self.state.get()
+    }
+
+    /// Returns a snapshot of the current state. It is a pointer copy, so it is
+    /// cheap even for large states and may be sent to other threads.
+    /// Dispatches do not change the snapshot.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::Store;
+    /// use std::sync::Arc;
+    /// use std::thread;
+    ///
+    /// type MyStore = Vec<u32>;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Clear,
+    /// };
+    ///
+    /// fn reducer(_state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Clear => Vec::new(),
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, (0..1000).collect());
+    /// let snapshot: Arc<MyStore> = store.shared_state();
+    ///
+    /// store.dispatch(MyAction::Clear);
+    ///
+    /// let len = thread::spawn(move || snapshot.len()).join().unwrap();
+    /// assert_eq!(len, 1000);
+    /// assert!(store.state().is_empty());
+    /// ```
+    pub fn shared_state(&self) -> Arc<State> {
+        self.state.shared()
+    }
+
+    /// Returns a snapshot of the current state, the same as `shared_state`
+    pub fn state_arc(&self) -> Arc<State> {
+        self.shared_state()
+    }
+
+    /// Consumes the store and returns the current state without cloning it.
+    ///
+    /// If snapshots returned by `shared_state` are still alive, the state
+    /// cannot be taken out of them and is returned back as `Err`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::Store;
+    ///
+    /// type MyStore = Vec<u8>;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Push(u8),
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Push(value) => {
+    ///             let mut new_state = state.clone();
+    ///             new_state.push(*value);
+    ///
+    ///             new_state
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, vec![]);
+    /// store.dispatch(MyAction::Push(1));
+    ///
+    /// let state: Vec<u8> = store.into_inner().unwrap();
+    /// assert_eq!(state, vec![1]);
+    /// ```
+    pub fn into_inner(self) -> Result<State, Arc<State>> {
+        self.state.into_inner()
+    }
+
+    /// Consumes the store and returns the current state shared with snapshots
+    pub fn into_shared(self) -> Arc<State> {
+        self.state.into_shared()
+    }
+
+    /// Consumes the store and returns its reducer, environment, current state
+    /// and history, so it can be handed off and built again with `Store::from_parts`.
+    /// Subscriptions and other registrations are dropped.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::{HistoryPolicy, Store};
+    ///
+    /// type MyStore = u8;
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum MyAction {
+    ///     Increment,
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction, step: &u8) -> MyStore {
+    ///     match action {
+    ///         MyAction::Increment => state + step,
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::with_env(reducer, 0, 2);
+    /// store.enable_history(HistoryPolicy::unbounded());
+    /// store.dispatch(MyAction::Increment);
+    ///
+    /// let parts = store.into_parts();
+    /// assert_eq!(*parts.state, 2);
+    /// assert_eq!(parts.history_len(), 2);
+    ///
+    /// let mut store = Store::from_parts(parts);
+    /// store.dispatch(MyAction::Increment);
+    /// assert_eq!(*store.state(), 4);
+    /// assert_eq!(store.history_len(), 3);
+    /// ```
+    pub fn into_parts(self) -> StoreParts<State, Action, Env> {
+        StoreParts {
+            state: self.state.into_shared(),
+            env: self.env,
+            reducer: self.reducer,
+            history: self.history,
+        }
+    }
+
+    /// Creates a store from the parts of another one returned by `Store::into_parts`
+    pub fn from_parts(parts: StoreParts<State, Action, Env>) -> Self {
+        let mut store = Self::with_lazy_state(
+            parts.reducer,
+            LazyState::from_shared(parts.state),
+            parts.env,
+        );
+        store.history = parts.history;
+
+        store
     }
 
     /// Dispatches an action. This is the only way to trigger a state change
@@ -80,19 +360,313 @@ impl<State, Action> Store<State, Action> {
     ///
     /// assert_eq!(*store.state(), 10);
     /// ```
-    pub fn dispatch(&mut self, action: Action) -> &mut Store<State, Action> {
-        self.state = (&self.reducer)(self.state(), &action);
+    pub fn dispatch(&mut self, action: Action) -> &mut Self {
+        match self.dispatch_action(action) {
+            Ok(action) => self.record_last_action(action),
+            Err(err) => self.report_dispatch_error(&err),
+        }
+        self.dispatch_queued();
+
+        self
+    }
+
+    /// Dispatches an action the same way as `dispatch` does but reports
+    /// when the action could not be applied.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::{DispatchError, FreezePolicy, Store};
+    ///
+    /// type MyStore = u8;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Increment,
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Increment => state + 1,
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, 0);
+    /// store.freeze(FreezePolicy::Reject);
+    ///
+    /// assert_eq!(store.try_dispatch(MyAction::Increment).err(), Some(DispatchError::Frozen));
+    /// assert_eq!(*store.state(), 0);
+    /// ```
+    pub fn try_dispatch(&mut self, action: Action) -> Result<&mut Self, DispatchError> {
+        let result = match self.dispatch_action(action) {
+            Err(DispatchError::Frozen) if self.frozen == Some(FreezePolicy::Ignore) => {
+                self.errors.clear_rejected();
+
+                Ok(())
+            }
+            Err(err) => {
+                self.report_dispatch_error(&err);
+
+                Err(err)
+            }
+            Ok(action) => {
+                self.record_last_action(action);
+
+                Ok(())
+            }
+        };
+        self.dispatch_queued();
+
+        result.map(|_| self)
+    }
+
+    /// Runs interceptors, the reducer and subscribers.
+    /// Returns the action which was actually reduced.
+    pub(crate) fn reduce_action(&mut self, action: Action) -> Result<Action, DispatchError> {
+        if self.frozen.is_some() {
+            self.errors.reject(action);
+            return Err(DispatchError::Frozen);
+        }
+        if let Err(err) = self.check_abort() {
+            self.errors.reject(action);
+            return Err(err);
+        }
+
+        let action = self
+            .apply_interceptors(action)
+            .ok_or(DispatchError::Intercepted)?;
+
+        self.hooks
+            .before
+            .values()
+            .for_each(|hook| hook(&action, self.state.get()));
+
+        let fingerprint = self
+            .mutation_check
+            .as_ref()
+            .map(|check| check.before(self.state.get()));
 
-        self.subscriptions.iter().for_each(|(_, subsciber)| {
-            subsciber(&self.state);
+        let (reducer, state, env) = (self.reducer, self.state.get(), &self.env);
+        let started = self.metrics.as_ref().map(|_| Instant::now());
+        let new_state =
+            strict::measure(&mut self.strict, SlowTarget::Reducer, Some(&action), || {
+                reducer.reduce(state, &action, env)
+            });
+        if let (Some(metrics), Some(started)) = (self.metrics.as_mut(), started) {
+            metrics.record(started.elapsed());
+        }
+        let old_state = self.state.replace(new_state);
+
+        if let (Some(check), Some(fingerprint)) = (&self.mutation_check, fingerprint) {
+            check.after(fingerprint, &old_state, &action);
+        }
+
+        self.version += 1;
+        if let Some(history) = self.history.as_mut() {
+            let (reducer, env) = (self.reducer, &self.env);
+            let reduce = |state: &State, action: &Action| reducer.reduce(state, action, env);
+            history.record(&action, self.state.shared(), &reduce, self.clock.now());
+        }
+        self.notify(Some(&action));
+
+        self.hooks
+            .after
+            .values()
+            .for_each(|hook| hook(&action, old_state.as_ref(), self.state.get()));
+
+        Ok(action)
+    }
+
+    /// Sets the state without the reducer, e.g. on time travel, and notifies
+    /// state subscribers. Returns the previous state
+    pub(crate) fn replace_state(&mut self, state: Arc<State>) -> Arc<State> {
+        let old_state = self.state.replace_shared(state);
+        self.version += 1;
+        self.notify(None);
+
+        old_state
+    }
+
+    /// Calls subscribers in the order they were subscribed.
+    ///
+    /// State subscribers are skipped while notifications are paused.
+    /// Action subscribers are called only when the `action` is passed.
+    pub(crate) fn notify(&mut self, action: Option<&Action>) {
+        let notify_state = !self.notifications_paused;
+        if !notify_state {
+            self.missed_notifications = true;
+        }
+
+        let reported_errors = self.subscriber_errors.len();
+        let shared = self.state.shared_ref();
+        let state = shared.as_ref();
+        let isolation = self.panic_isolation;
+        let now = self.clock.now();
+        let mut panics = vec![];
+        let mut failed_tokens = vec![];
+        let subscriber_errors = &mut self.subscriber_errors;
+        let strict = &mut self.strict;
+
+        for (token, subscriber) in self.subscriptions.iter_mut() {
+            let target = SlowTarget::Subscriber(*token);
+            let result = strict::measure(strict, target, action, || match subscriber {
+                Subscriber::State(func) if notify_state => {
+                    isolation::call(isolation, || func(state))
+                }
+                Subscriber::Filtered(filter, func) if notify_state && filter(state) => {
+                    isolation::call(isolation, || func(state))
+                }
+                Subscriber::Sampled(subscription) if notify_state => {
+                    isolation::call(isolation, || subscription.notify(state, now))
+                }
+                Subscriber::Projected(subscription) if notify_state => {
+                    isolation::call(isolation, || subscription.notify(shared))
+                }
+                Subscriber::Delta(subscription) if notify_state => {
+                    isolation::call(isolation, || subscription.notify(shared))
+                }
+                Subscriber::Fallible(entry) if notify_state => {
+                    isolation::call(isolation, || entry.call(state)).map(|result| {
+                        if let Err(err) = result {
+                            match entry.policy {
+                                ErrorPolicy::Unsubscribe => failed_tokens.push(*token),
+                                ErrorPolicy::Retry => entry.failed = true,
+                                ErrorPolicy::Report => subscriber_errors.push((*token, err)),
+                            }
+                        }
+                    })
+                }
+                Subscriber::Action(filter, func) => match action {
+                    Some(action) if filter(action) => {
+                        isolation::call(isolation, || func(action, state))
+                    }
+                    _ => Ok(()),
+                },
+                Subscriber::Observer(observer) => isolation::call(isolation, || {
+                    if let Some(action) = action {
+                        observer.on_action(action, shared);
+                    }
+                    if notify_state {
+                        observer.on_state(shared);
+                    }
+                }),
+                _ => Ok(()),
+            });
+
+            if let Err(err) = result {
+                panics.push((*token, err));
+            }
+        }
+
+        failed_tokens.iter().for_each(|token| {
+            self.subscriptions.remove(token);
         });
+        self.handle_panics(panics);
+        self.report_subscriber_errors(reported_errors, action);
+    }
 
-        self
+    /// Calls only projected subscribers, which decide themselves whether
+    /// the part of the state they observe has changed
+    pub(crate) fn notify_projected(&mut self) {
+        if self.notifications_paused {
+            self.missed_notifications = true;
+            return;
+        }
+
+        let reported_errors = self.subscriber_errors.len();
+        let state = self.state.shared_ref();
+        let isolation = self.panic_isolation;
+        let panics = self
+            .subscriptions
+            .iter_mut()
+            .filter_map(|(token, subscriber)| match subscriber {
+                Subscriber::Projected(subscription) => {
+                    isolation::call(isolation, || subscription.notify(state))
+                        .err()
+                        .map(|err| (*token, err))
+                }
+                _ => None,
+            })
+            .collect();
+        self.handle_panics(panics);
+        self.report_subscriber_errors(reported_errors, None);
+    }
+
+    /// Stops calling subscribers on dispatch. The state is still updated.
+    ///
+    /// Useful while UI is detached or hidden.
+    pub fn pause_notifications(&mut self) {
+        self.notifications_paused = true;
+    }
+
+    /// Resumes calling subscribers on dispatch.
+    ///
+    /// The `policy` defines what happens with updates which were missed while
+    /// notifications were paused.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::{NotifyPolicy, Store};
+    ///
+    /// type MyStore = u8;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Increment,
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Increment => state + 1,
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, 0);
+    /// store.subscribe(|state| {
+    ///     // Called only once with the latest state
+    ///     assert_eq!(*state, 2);
+    /// });
+    ///
+    /// store.pause_notifications();
+    /// store.dispatch(MyAction::Increment).dispatch(MyAction::Increment);
+    /// store.resume_notifications(NotifyPolicy::CatchUp);
+    /// ```
+    pub fn resume_notifications(&mut self, policy: NotifyPolicy) {
+        self.notifications_paused = false;
+
+        let missed = std::mem::replace(&mut self.missed_notifications, false);
+        if missed && policy == NotifyPolicy::CatchUp {
+            self.notify(None);
+        }
+    }
+
+    /// Returns `true` if notifications are paused
+    pub fn notifications_paused(&self) -> bool {
+        self.notifications_paused
+    }
+
+    /// Freezes the store: the state will not change until `unfreeze` is called.
+    ///
+    /// The `policy` defines whether dispatched actions are rejected or silently ignored.
+    /// Useful during teardown, snapshot export or "view-only" replay of a session.
+    pub fn freeze(&mut self, policy: FreezePolicy) {
+        self.frozen = Some(policy);
+    }
+
+    /// Allows dispatching actions into the store again
+    pub fn unfreeze(&mut self) {
+        self.frozen = None;
+    }
+
+    /// Returns `true` if the store is frozen
+    pub fn is_frozen(&self) -> bool {
+        self.frozen.is_some()
     }
 
     /// Subscribes a callback to any change of the state.
     ///
     /// Subscriptions will be called, whenever an action is dispatched.
+    /// All subscribers, including action, sampled and fallible ones, are
+    /// called in the order they were subscribed.
     ///
     /// ## Example
     /// ```rust
@@ -115,9 +689,119 @@ impl<State, Action> Store<State, Action> {
     ///     assert_eq!(*state, 1);
     /// });
     /// ```
-    pub fn subscribe(&mut self, func: Subscription<State>) -> SubscriptionToken {
-        let subscription_token = self.subscriptions_index;
-        self.subscriptions.insert(subscription_token, func);
+    pub fn subscribe(&mut self, func: Subscription<State>) -> SubscriptionToken<State, Action> {
+        let subscription_token = self.next_subscription_token();
+        self.subscriptions
+            .insert(subscription_token.id(), Subscriber::State(func));
+
+        subscription_token
+    }
+
+    /// Subscribes a callback to dispatched actions which match the `filter`.
+    ///
+    /// The callback receives the action and the state produced by it.
+    /// Unlike `subscribe`, it is called even when notifications are paused
+    /// because it tracks events rather than state changes.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::Store;
+    ///
+    /// type MyStore = u8;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Increment,
+    ///     Reset,
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Increment => state + 1,
+    ///         MyAction::Reset => 0,
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, 0);
+    ///
+    /// store.subscribe_actions(
+    ///     |action| matches!(action, MyAction::Reset),
+    ///     |_action, state| {
+    ///         // Called only when `MyAction::Reset` was dispatched
+    ///         assert_eq!(*state, 0);
+    ///     },
+    /// );
+    ///
+    /// store.dispatch(MyAction::Increment).dispatch(MyAction::Reset);
+    /// ```
+    pub fn subscribe_actions(
+        &mut self,
+        filter: ActionFilter<Action>,
+        func: ActionSubscription<State, Action>,
+    ) -> SubscriptionToken<State, Action> {
+        let subscription_token = self.next_subscription_token();
+        self.subscriptions
+            .insert(subscription_token.id(), Subscriber::Action(filter, func));
+
+        subscription_token
+    }
+
+    /// Subscribes a callback which is called on state changes only while the `filter` holds.
+    ///
+    /// Unlike view subscriptions, the filter gates the execution rather than
+    /// detects changes: the callback is called on every update for which the
+    /// `filter` returns `true`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::Store;
+    ///
+    /// #[derive(Debug, Clone, Copy, PartialEq)]
+    /// enum Page {
+    ///     Home,
+    ///     Settings,
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Open(Page),
+    /// };
+    ///
+    /// fn reducer(_state: &Page, action: &MyAction) -> Page {
+    ///     match action {
+    ///         MyAction::Open(page) => *page,
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, Page::Home);
+    ///
+    /// store.subscribe_filtered(
+    ///     |page| *page == Page::Settings,
+    ///     |page| {
+    ///         // Called only while the settings page is open
+    ///         assert_eq!(*page, Page::Settings);
+    ///     },
+    /// );
+    ///
+    /// store
+    ///     .dispatch(MyAction::Open(Page::Settings))
+    ///     .dispatch(MyAction::Open(Page::Home));
+    /// ```
+    pub fn subscribe_filtered(
+        &mut self,
+        filter: StateFilter<State>,
+        func: Subscription<State>,
+    ) -> SubscriptionToken<State, Action> {
+        let subscription_token = self.next_subscription_token();
+        self.subscriptions
+            .insert(subscription_token.id(), Subscriber::Filtered(filter, func));
+
+        subscription_token
+    }
+
+    pub(crate) fn next_subscription_token(&mut self) -> SubscriptionToken<State, Action> {
+        let subscription_token =
+            SubscriptionToken::new(SubscriberId::new(self.id, self.subscriptions_index));
 
         // Increment subscriptions token
         self.subscriptions_index += 1;
@@ -150,13 +834,29 @@ impl<State, Action> Store<State, Action> {
     /// ```
     pub fn unsubscribe(
         &mut self,
-        subscription_token: SubscriptionToken,
+        subscription_token: SubscriptionToken<State, Action>,
     ) -> Result<(), UnsubscribeError> {
+        let id = self.check_token(subscription_token)?;
+
         // Nothing in the subscription
-        if let None = self.subscriptions.remove(&subscription_token) {
-            return Err(UnsubscribeError::WrongToken(subscription_token));
+        if self.subscriptions.remove(&id).is_none() {
+            return Err(UnsubscribeError::WrongToken(id));
         }
 
         Ok(())
     }
+
+    /// Rejects tokens issued by another store of the same types.
+    /// Returns the id of the subscription
+    pub(crate) fn check_token(
+        &self,
+        token: SubscriptionToken<State, Action>,
+    ) -> Result<SubscriberId, UnsubscribeError> {
+        let id = token.id();
+        if id.store() != self.id {
+            return Err(UnsubscribeError::ForeignToken(id));
+        }
+
+        Ok(id)
+    }
 }
@@ -0,0 +1,211 @@
+use std::backtrace::Backtrace;
+use std::fmt::Debug;
+use std::time::{Duration, Instant};
+
+use crate::subscription::SubscriberId;
+use crate::Store;
+
+/// What exceeded the strict mode budget
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SlowTarget {
+    Reducer,
+    Subscriber(SubscriberId),
+}
+
+/// Reducer or subscriber call which took longer than the strict mode budget
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlowCall {
+    pub target: SlowTarget,
+
+    /// Debug representation of the dispatched action.
+    /// `None` when subscribers were notified without an action, e.g. on resume
+    pub action: Option<String>,
+    pub elapsed: Duration,
+    pub budget: Duration,
+
+    /// Backtrace of the dispatch, captured only with `reducer_deadline`
+    pub backtrace: Option<String>,
+}
+
+impl std::fmt::Display for SlowCall {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let target = match self.target {
+            SlowTarget::Reducer => "The reducer".to_string(),
+            SlowTarget::Subscriber(token) => format!("The subscriber {}", token),
+        };
+        write!(f, "{} took {:?} of {:?}", target, self.elapsed, self.budget)?;
+        if let Some(action) = &self.action {
+            write!(f, " while handling {}", action)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) struct StrictMode<Action> {
+    budget: Duration,
+    describe: fn(&Action) -> String,
+    slow_calls: Vec<SlowCall>,
+
+    /// Measures only the reducer and captures backtraces of slow dispatches
+    reducer_deadline: bool,
+}
+
+impl<Action> StrictMode<Action> {
+    /// Runs the call and reports it if it exceeded the budget
+    pub(crate) fn measure<R>(
+        &mut self,
+        target: SlowTarget,
+        action: Option<&Action>,
+        func: impl FnOnce() -> R,
+    ) -> R {
+        if self.reducer_deadline && target != SlowTarget::Reducer {
+            return func();
+        }
+
+        let started = Instant::now();
+        let result = func();
+        let elapsed = started.elapsed();
+
+        if elapsed > self.budget {
+            self.slow_calls.push(SlowCall {
+                target,
+                action: action.map(self.describe),
+                elapsed,
+                budget: self.budget,
+                backtrace: self
+                    .reducer_deadline
+                    .then(|| Backtrace::force_capture().to_string()),
+            });
+        }
+
+        result
+    }
+}
+
+/// Runs the call, measuring it only if strict mode is enabled
+pub(crate) fn measure<Action, R>(
+    strict: &mut Option<StrictMode<Action>>,
+    target: SlowTarget,
+    action: Option<&Action>,
+    func: impl FnOnce() -> R,
+) -> R {
+    match strict {
+        Some(strict) => strict.measure(target, action, func),
+        None => func(),
+    }
+}
+
+fn describe<Action: Debug>(action: &Action) -> String {
+    format!("{:?}", action)
+}
+
+impl<State, Action: Debug, Env> Store<State, Action, Env> {
+    /// Measures every reducer and subscriber call and reports the ones which
+    /// took longer than the `budget`, see `take_slow_calls`.
+    /// Helps to keep dispatch latency on the UI thread under control.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::{SlowTarget, Store};
+    /// use std::thread;
+    /// use std::time::Duration;
+    ///
+    /// type MyStore = u8;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Increment,
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Increment => state + 1,
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, 0);
+    /// store.strict_mode(Duration::from_millis(2));
+    ///
+    /// let token = store.subscribe(|_state| thread::sleep(Duration::from_millis(5)));
+    /// store.dispatch(MyAction::Increment);
+    ///
+    /// let slow_calls = store.take_slow_calls();
+    /// assert_eq!(slow_calls.len(), 1);
+    /// assert_eq!(slow_calls[0].target, SlowTarget::Subscriber(token.id()));
+    /// assert_eq!(slow_calls[0].action.as_deref(), Some("Increment"));
+    /// ```
+    pub fn strict_mode(&mut self, budget: Duration) {
+        self.strict = Some(StrictMode {
+            budget,
+            describe: describe::<Action>,
+            slow_calls: Vec::new(),
+            reducer_deadline: false,
+        });
+    }
+
+    /// Sets the time budget of the reducer in debug builds. Reducer calls which
+    /// exceed it are reported by `take_slow_calls` together with the action and
+    /// the backtrace of the dispatch. Subscribers are not measured.
+    ///
+    /// Helps to find the action variant which does accidental O(n²) work.
+    /// Does nothing in release builds, so it may stay in the code.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::Store;
+    /// use std::time::Duration;
+    ///
+    /// type MyStore = Vec<u32>;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Dedupe,
+    /// };
+    ///
+    /// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Dedupe => state.iter().fold(vec![], |mut unique, item| {
+    ///             if !unique.contains(item) {
+    ///                 unique.push(*item);
+    ///             }
+    ///             unique
+    ///         }),
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, (0..1_000).collect());
+    /// store.reducer_deadline(Duration::from_nanos(1));
+    /// store.dispatch(MyAction::Dedupe);
+    ///
+    /// for slow_call in store.take_slow_calls() {
+    ///     assert_eq!(slow_call.action.as_deref(), Some("Dedupe"));
+    ///     assert!(slow_call.backtrace.is_some());
+    /// }
+    /// ```
+    pub fn reducer_deadline(&mut self, budget: Duration) {
+        if cfg!(debug_assertions) {
+            self.strict = Some(StrictMode {
+                budget,
+                describe: describe::<Action>,
+                slow_calls: Vec::new(),
+                reducer_deadline: true,
+            });
+        }
+    }
+}
+
+impl<State, Action, Env> Store<State, Action, Env> {
+    /// Stops measuring reducer and subscriber calls. Unreported slow calls are dropped
+    pub fn disable_strict_mode(&mut self) {
+        self.strict = None;
+    }
+
+    /// Returns slow calls collected since the last call and clears them
+    pub fn take_slow_calls(&mut self) -> Vec<SlowCall> {
+        self.strict
+            .as_mut()
+            .map(|strict| std::mem::take(&mut strict.slow_calls))
+            .unwrap_or_default()
+    }
+}
@@ -1,10 +1,185 @@
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use crate::delta::DeltaNotifier;
+use crate::fallible::FallibleEntry;
+use crate::sampling::SampledSubscription;
+use crate::view::ProjectedSubscription;
+
 pub type Subscription<State> = fn(&State);
 
-pub type SubscriptionToken = u8;
+/// Subscription to dispatched actions, called with the action and the new state
+pub type ActionSubscription<State, Action> = fn(&Action, &State);
+
+/// Predicate which decides whether an action subscription should be called
+pub type ActionFilter<Action> = fn(&Action) -> bool;
+
+/// Predicate which decides whether a filtered subscription should be called with the state
+pub type StateFilter<State> = fn(&State) -> bool;
+
+/// Identifies the store which issued a `SubscriptionToken`
+pub(crate) type StoreId = u64;
+
+static NEXT_STORE_ID: AtomicU64 = AtomicU64::new(0);
+
+pub(crate) fn next_store_id() -> StoreId {
+    NEXT_STORE_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Id of a subscription, hook or interceptor which is not tied to the types
+/// of the store, used where failures are reported, e.g. in `StoreError`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SubscriberId {
+    store: StoreId,
+    index: u64,
+}
+
+impl SubscriberId {
+    pub(crate) fn new(store: StoreId, index: u64) -> Self {
+        Self { store, index }
+    }
+
+    pub(crate) fn store(&self) -> StoreId {
+        self.store
+    }
+}
+
+impl std::fmt::Display for SubscriberId {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.index)
+    }
+}
+
+/// Opaque handle of a subscription, hook or interceptor.
+///
+/// The token is branded with the state and action types of the store which
+/// issued it, so passing it to a store of other types does not compile.
+/// Stores of the same types are told apart at runtime: a token of another
+/// store is rejected with `UnsubscribeError::ForeignToken` instead of removing
+/// a subscription which happens to have the same position.
+///
+/// ```compile_fail
+/// use redust::Store;
+///
+/// fn counter(state: &u8, _action: &()) -> u8 {
+///     state + 1
+/// }
+///
+/// fn title(state: &String, _action: &()) -> String {
+///     state.clone()
+/// }
+///
+/// let mut counter_store = Store::new(counter, 0);
+/// let mut title_store = Store::new(title, String::new());
+///
+/// let token = counter_store.subscribe(|_state| {});
+/// title_store.unsubscribe(token).unwrap();
+/// ```
+pub struct SubscriptionToken<State, Action> {
+    id: SubscriberId,
+    brand: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<State, Action> SubscriptionToken<State, Action> {
+    pub(crate) fn new(id: SubscriberId) -> Self {
+        Self {
+            id,
+            brand: PhantomData,
+        }
+    }
+
+    /// Returns the id of the subscription used in failure reports
+    pub fn id(&self) -> SubscriberId {
+        self.id
+    }
+}
+
+impl<State, Action> Clone for SubscriptionToken<State, Action> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<State, Action> Copy for SubscriptionToken<State, Action> {}
+
+impl<State, Action> PartialEq for SubscriptionToken<State, Action> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl<State, Action> Eq for SubscriptionToken<State, Action> {}
+
+impl<State, Action> PartialOrd for SubscriptionToken<State, Action> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<State, Action> Ord for SubscriptionToken<State, Action> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+impl<State, Action> Hash for SubscriptionToken<State, Action> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state)
+    }
+}
+
+impl<State, Action> std::fmt::Debug for SubscriptionToken<State, Action> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_tuple("SubscriptionToken").field(&self.id).finish()
+    }
+}
+
+impl<State, Action> std::fmt::Display for SubscriptionToken<State, Action> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.id)
+    }
+}
+
+/// Boxed subscriber which receives both actions and states,
+/// e.g. an adapter to another library
+pub(crate) trait StoreObserver<State, Action> {
+    /// Called with every new state unless notifications are paused
+    fn on_state(&mut self, state: &Arc<State>);
+
+    /// Called with every reduced action and the state it produced
+    fn on_action(&mut self, action: &Action, state: &Arc<State>);
+}
+
+/// Any kind of subscription registered in the store
+pub(crate) enum Subscriber<State, Action> {
+    State(Subscription<State>),
+    Filtered(StateFilter<State>, Subscription<State>),
+    Sampled(SampledSubscription<State>),
+    Fallible(FallibleEntry<State>),
+    Projected(Box<dyn ProjectedSubscription<State> + Send + Sync>),
+    Delta(Box<dyn DeltaNotifier<State> + Send + Sync>),
+    Action(ActionFilter<Action>, ActionSubscription<State, Action>),
+    Observer(Box<dyn StoreObserver<State, Action> + Send + Sync>),
+}
+
+/// Defines what happens with updates missed while notifications were paused
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NotifyPolicy {
+    /// Missed updates are dropped
+    Drop,
+
+    /// Subscribers are called once with the latest state if anything was missed
+    CatchUp,
+}
 
 #[derive(Debug, PartialEq)]
 pub enum UnsubscribeError {
-    WrongToken(SubscriptionToken),
+    WrongToken(SubscriberId),
+
+    /// The token was issued by another store
+    ForeignToken(SubscriberId),
 }
 
 impl std::error::Error for UnsubscribeError {}
@@ -14,6 +189,13 @@ impl std::fmt::Display for UnsubscribeError {
             UnsubscribeError::WrongToken(token) => {
                 write!(f, "Cannot find the subscription by token: {}", token)
             }
+            UnsubscribeError::ForeignToken(token) => {
+                write!(
+                    f,
+                    "Cannot unsubscribe by the token {} of another store",
+                    token
+                )
+            }
         }
     }
 }
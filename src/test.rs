@@ -0,0 +1,133 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::{Reducer, Store};
+
+/// Store for tests which records every dispatched action and every state
+/// produced by the reducer.
+///
+/// Use it together with `assert_dispatched!` and `assert_state_seq!` macros.
+///
+/// ## Example
+/// ```rust
+/// use redust::test::MockStore;
+/// use redust::{assert_dispatched, assert_state_seq};
+///
+/// #[derive(Debug, Clone)]
+/// enum MyAction {
+///     IncrementBy(u8),
+/// };
+///
+/// fn reducer(state: &u8, action: &MyAction) -> u8 {
+///     match action {
+///         MyAction::IncrementBy(value) => state + value,
+///     }
+/// }
+///
+/// let mut store = MockStore::new(reducer, 0);
+///
+/// store
+///     .dispatch(MyAction::IncrementBy(1))
+///     .dispatch(MyAction::IncrementBy(2));
+///
+/// assert_dispatched!(store, MyAction::IncrementBy(2));
+/// assert_state_seq!(store, [1, 3]);
+/// ```
+pub struct MockStore<State, Action> {
+    store: Store<State, Action>,
+    actions: Vec<Action>,
+    states: Vec<State>,
+}
+
+impl<State: Clone, Action> MockStore<State, Action> {
+    /// Creates a new mock store
+    pub fn new(reducer: Reducer<State, Action>, initial_state: State) -> Self {
+        Self {
+            store: Store::new(reducer, initial_state),
+            actions: Vec::new(),
+            states: Vec::new(),
+        }
+    }
+}
+
+impl<State: Clone, Action: Clone> MockStore<State, Action> {
+    /// Dispatches an action into the underlying store the same way as
+    /// `Store::dispatch` does and records both the action and the resulting
+    /// state. Actions queued by middleware are dispatched and recorded right after it
+    pub fn dispatch(&mut self, action: Action) -> &mut Self {
+        let mut action = Some(action);
+        while let Some(next) = action
+            .take()
+            .or_else(|| self.store.queued_actions.pop_front())
+        {
+            match self.store.dispatch_action(next) {
+                Ok(next) => {
+                    self.actions.push(next.clone());
+                    self.states.push(self.store.state().clone());
+                    self.store.record_last_action(next);
+                }
+                Err(err) => self.store.report_dispatch_error(&err),
+            }
+        }
+
+        self
+    }
+}
+
+impl<State, Action> MockStore<State, Action> {
+    /// Returns all dispatched actions in the order of dispatching
+    pub fn actions(&self) -> &[Action] {
+        &self.actions
+    }
+
+    /// Returns all states produced by the reducer. The initial state is not included
+    pub fn states(&self) -> &[State] {
+        &self.states
+    }
+
+    /// Forgets all recorded actions and states
+    pub fn clear(&mut self) {
+        self.actions.clear();
+        self.states.clear();
+    }
+}
+
+impl<State, Action> Deref for MockStore<State, Action> {
+    type Target = Store<State, Action>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.store
+    }
+}
+
+impl<State, Action> DerefMut for MockStore<State, Action> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.store
+    }
+}
+
+#[doc(hidden)]
+pub fn assert_states_eq<State: PartialEq + std::fmt::Debug>(states: &[State], expected: &[State]) {
+    assert_eq!(states, expected);
+}
+
+/// Asserts that at least one recorded action of the `MockStore` matches the pattern
+#[macro_export]
+macro_rules! assert_dispatched {
+    ($store: expr, $pattern: pat) => {{
+        let actions = $store.actions();
+        assert!(
+            actions.iter().any(|action| matches!(action, $pattern)),
+            "No dispatched action matches `{}`. Dispatched actions: {:?}",
+            stringify!($pattern),
+            actions
+        );
+    }};
+}
+
+/// Asserts that the `MockStore` went exactly through the sequence of states
+#[macro_export]
+macro_rules! assert_state_seq {
+    ($store: expr, [$( $state: expr ),* $(,)?]) => {
+        $crate::test::assert_states_eq($store.states(), &[$( $state ),*])
+    };
+}
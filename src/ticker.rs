@@ -0,0 +1,136 @@
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::{Dispatcher, ScheduleError, SharedClock, SystemClock};
+
+/// Creates the tick action from the time the tick was scheduled at
+pub type TickAction<Action> = fn(Instant) -> Action;
+
+/// Clock which enqueues a tick action into a `DispatchQueue` at a fixed rate
+/// from its own thread. Animations, polling and timeouts may share it as
+/// the canonical tick source.
+///
+/// Ticks which were missed, e.g. when the thread was not scheduled in time,
+/// are skipped instead of being enqueued in a burst.
+/// The ticker stops when it is dropped or the queue is dropped.
+///
+/// ## Example
+/// ```rust
+/// use redust::{DispatchQueue, Store, Ticker};
+/// use std::thread;
+/// use std::time::{Duration, Instant};
+///
+/// type MyStore = u32;
+///
+/// #[derive(Debug)]
+/// enum MyAction {
+///     Tick(Instant),
+/// };
+///
+/// fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+///     match action {
+///         MyAction::Tick(_) => state + 1,
+///     }
+/// }
+///
+/// let queue = DispatchQueue::new();
+/// let ticker =
+///     Ticker::start(queue.dispatcher(), Duration::from_millis(5), MyAction::Tick).unwrap();
+///
+/// thread::sleep(Duration::from_millis(50));
+/// ticker.stop();
+///
+/// let mut store = Store::new(reducer, 0);
+/// store.drain(&queue);
+///
+/// assert!(*store.state() > 0);
+/// ```
+pub struct Ticker {
+    stop: Option<Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Ticker {
+    /// Starts enqueuing `tick` actions every `interval`, the first one after `interval`.
+    /// Returns `ScheduleError::ZeroInterval` if the `interval` is zero
+    pub fn start<Action: Send + 'static>(
+        dispatcher: Dispatcher<Action>,
+        interval: Duration,
+        tick: TickAction<Action>,
+    ) -> Result<Self, ScheduleError> {
+        Self::start_with_clock(dispatcher, interval, tick, Arc::new(SystemClock))
+    }
+
+    /// Starts the ticker which reads the time from the `clock`,
+    /// so tests can drive it with a `TestClock`.
+    /// Returns `ScheduleError::ZeroInterval` if the `interval` is zero
+    pub fn start_with_clock<Action: Send + 'static>(
+        dispatcher: Dispatcher<Action>,
+        interval: Duration,
+        tick: TickAction<Action>,
+        clock: SharedClock,
+    ) -> Result<Self, ScheduleError> {
+        if interval.is_zero() {
+            return Err(ScheduleError::ZeroInterval);
+        }
+        let (stop, stopped) = mpsc::channel::<()>();
+
+        let thread = thread::spawn(move || {
+            let mut next = clock.now() + interval;
+            loop {
+                let remaining = next.saturating_duration_since(clock.now());
+                match stopped.recv_timeout(clock.wait_timeout(remaining)) {
+                    Err(RecvTimeoutError::Timeout) => {}
+                    _ => break,
+                }
+                if clock.now() < next {
+                    continue;
+                }
+
+                if dispatcher.dispatch(tick(next)).is_err() {
+                    break;
+                }
+
+                next += interval;
+                let now = clock.now();
+                if next < now {
+                    next = now + interval;
+                }
+            }
+        });
+
+        Ok(Self {
+            stop: Some(stop),
+            thread: Some(thread),
+        })
+    }
+
+    /// Returns `false` when the queue was dropped and the ticker stopped itself
+    pub fn is_running(&self) -> bool {
+        self.thread
+            .as_ref()
+            .map(|thread| !thread.is_finished())
+            .unwrap_or(false)
+    }
+
+    /// Stops the ticker and waits until its thread exits
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        // Dropping the sender wakes up the thread
+        self.stop.take();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for Ticker {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{DispatchError, Store};
+
+/// Number of state updates since the store was created
+pub type StateVersion = u64;
+
+impl<State, Action, Env> Store<State, Action, Env> {
+    /// Returns the version of the current state. It grows with every reduced action
+    pub fn version(&self) -> StateVersion {
+        self.version
+    }
+
+    /// Dispatches the action only if the state was not updated since the `expected` version.
+    ///
+    /// Protects against lost updates when an action is computed from a snapshot
+    /// which might be outdated by the time it is dispatched.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::{DispatchError, Store};
+    ///
+    /// type MyStore = u8;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     Set(u8),
+    /// };
+    ///
+    /// fn reducer(_state: &MyStore, action: &MyAction) -> MyStore {
+    ///     match action {
+    ///         MyAction::Set(value) => *value,
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, 1);
+    /// let version = store.version();
+    /// let doubled = *store.state() * 2;
+    ///
+    /// // Somebody else updates the state meanwhile
+    /// store.dispatch(MyAction::Set(5));
+    ///
+    /// assert_eq!(
+    ///     store.dispatch_if_version(version, MyAction::Set(doubled)).err(),
+    ///     Some(DispatchError::VersionMismatch { expected: 0, actual: 1 })
+    /// );
+    /// assert_eq!(*store.state(), 5);
+    /// ```
+    pub fn dispatch_if_version(
+        &mut self,
+        expected: StateVersion,
+        action: Action,
+    ) -> Result<&mut Self, DispatchError> {
+        if self.version != expected {
+            return Err(DispatchError::VersionMismatch {
+                expected,
+                actual: self.version,
+            });
+        }
+
+        self.try_dispatch(action)
+    }
+}
@@ -0,0 +1,206 @@
+use std::collections::BTreeMap;
+use std::ops::Deref;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::subscription::{Subscriber, SubscriptionToken};
+use crate::{Store, Subscription};
+
+/// Projects the part of the state which a view observes.
+/// The part is borrowed from the state, so it may be unsized, e.g. a slice
+pub type Projection<State, Part> = fn(&State) -> &Part;
+
+/// Subscription which is called only when the projected part of the state changes
+pub(crate) trait ProjectedSubscription<State> {
+    fn notify(&mut self, state: &Arc<State>);
+}
+
+/// Identifies a subscription made through a `StoreView`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ViewToken(u64);
+
+struct ViewSubscriptions<Part: ?Sized> {
+    entries: BTreeMap<ViewToken, Subscription<Part>>,
+    next_index: u64,
+}
+
+/// State shared between the view and its subscriber in the store
+struct ViewShared<State, Part: ?Sized> {
+    latest: Mutex<Arc<State>>,
+    subscriptions: Mutex<ViewSubscriptions<Part>>,
+}
+
+impl<State, Part: ?Sized> ViewShared<State, Part> {
+    fn latest(&self) -> MutexGuard<'_, Arc<State>> {
+        // Both mutexes only guard plain replacements, so they are never left inconsistent
+        self.latest
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn subscriptions(&self) -> MutexGuard<'_, ViewSubscriptions<Part>> {
+        self.subscriptions
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+struct ViewEntry<State, Part: ?Sized> {
+    shared: Arc<ViewShared<State, Part>>,
+    project: Projection<State, Part>,
+}
+
+impl<State, Part: PartialEq + ?Sized> ProjectedSubscription<State> for ViewEntry<State, Part> {
+    fn notify(&mut self, state: &Arc<State>) {
+        let last = std::mem::replace(&mut *self.shared.latest(), Arc::clone(state));
+        if Arc::ptr_eq(&last, state) {
+            return;
+        }
+
+        let part = (self.project)(state);
+        if (self.project)(&last) != part {
+            // Subscriptions are copied out, so they may subscribe through the view
+            let subscriptions = self
+                .shared
+                .subscriptions()
+                .entries
+                .values()
+                .copied()
+                .collect::<Vec<_>>();
+            subscriptions.iter().for_each(|func| func(part));
+        }
+    }
+}
+
+/// Projected part of a state snapshot returned by `StoreView::state`
+pub struct ViewSnapshot<State, Part: ?Sized> {
+    state: Arc<State>,
+    project: Projection<State, Part>,
+}
+
+impl<State, Part: ?Sized> Deref for ViewSnapshot<State, Part> {
+    type Target = Part;
+
+    fn deref(&self) -> &Part {
+        (self.project)(&self.state)
+    }
+}
+
+/// Read-only view of the part of the store state.
+///
+/// Components which receive a view do not need to know the shape of the whole state.
+/// The view does not borrow the store, so it can be kept by a component or sent to
+/// another thread. It holds the latest snapshot which the store notified its
+/// subscribers about. Subscriptions compare the part projected from the previous
+/// snapshot with the new one, so the part is never cloned.
+///
+/// The view stays subscribed to the store after it is dropped, until the token
+/// returned by `token` is passed to `Store::unsubscribe`.
+pub struct StoreView<State, Action, Part: ?Sized> {
+    shared: Arc<ViewShared<State, Part>>,
+    project: Projection<State, Part>,
+    token: SubscriptionToken<State, Action>,
+}
+
+impl<State, Action, Part> StoreView<State, Action, Part>
+where
+    Part: PartialEq + ?Sized,
+{
+    /// Returns the projected part of the latest state
+    pub fn state(&self) -> ViewSnapshot<State, Part> {
+        ViewSnapshot {
+            state: Arc::clone(&self.shared.latest()),
+            project: self.project,
+        }
+    }
+
+    /// Subscribes a callback which is called only when the projected part changes
+    pub fn subscribe(&self, func: Subscription<Part>) -> ViewToken {
+        let mut subscriptions = self.shared.subscriptions();
+        let token = ViewToken(subscriptions.next_index);
+        subscriptions.next_index += 1;
+        subscriptions.entries.insert(token, func);
+
+        token
+    }
+
+    /// Removes the subscription made through the view.
+    /// Returns `false` if there is no subscription with the `token`
+    pub fn unsubscribe(&self, token: ViewToken) -> bool {
+        self.shared.subscriptions().entries.remove(&token).is_some()
+    }
+
+    /// Returns the token which removes the view from the store
+    pub fn token(&self) -> SubscriptionToken<State, Action> {
+        self.token
+    }
+}
+
+impl<State, Action, Env> Store<State, Action, Env>
+where
+    State: Send + Sync + 'static,
+{
+    /// Creates a view of the part of the state selected by the `project` function
+    ///
+    /// ## Example
+    /// ```rust
+    /// use redust::Store;
+    ///
+    /// struct AppState {
+    ///     todos: Vec<&'static str>,
+    ///     clicks: u8,
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// enum MyAction {
+    ///     AddTodo(&'static str),
+    ///     Click,
+    /// };
+    ///
+    /// fn reducer(state: &AppState, action: &MyAction) -> AppState {
+    ///     let mut todos = state.todos.clone();
+    ///     let mut clicks = state.clicks;
+    ///     match action {
+    ///         MyAction::AddTodo(todo) => todos.push(todo),
+    ///         MyAction::Click => clicks += 1,
+    ///     }
+    ///
+    ///     AppState { todos, clicks }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, AppState { todos: vec![], clicks: 0 });
+    ///
+    /// let todos = store.view(|state: &AppState| &state.todos);
+    /// todos.subscribe(|todos| {
+    ///     // Not called on `MyAction::Click`
+    ///     assert_eq!(todos.len(), 1);
+    /// });
+    ///
+    /// store.dispatch(MyAction::Click).dispatch(MyAction::AddTodo("Buy milk"));
+    /// assert_eq!(*todos.state(), vec!["Buy milk"]);
+    /// ```
+    pub fn view<Part>(&mut self, project: Projection<State, Part>) -> StoreView<State, Action, Part>
+    where
+        Part: PartialEq + ?Sized + 'static,
+    {
+        let shared = Arc::new(ViewShared {
+            latest: Mutex::new(self.shared_state()),
+            subscriptions: Mutex::new(ViewSubscriptions {
+                entries: BTreeMap::new(),
+                next_index: 0,
+            }),
+        });
+        let token = self.next_subscription_token();
+        let entry = ViewEntry {
+            shared: Arc::clone(&shared),
+            project,
+        };
+        self.subscriptions
+            .insert(token.id(), Subscriber::Projected(Box::new(entry)));
+
+        StoreView {
+            shared,
+            project,
+            token,
+        }
+    }
+}
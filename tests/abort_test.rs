@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod abort {
+    use redust::{AbortSignal, CancellationToken, DispatchError, Next, Store};
+    use std::time::{Duration, Instant};
+
+    type MyStore = u8;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Increment,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Increment => state + 1,
+        }
+    }
+
+    // Checks the signal between the steps of a long transformation
+    fn slow_steps(
+        action: MyAction,
+        next: Next<'_, MyStore, MyAction>,
+    ) -> Result<MyAction, DispatchError> {
+        for _ in 0..100 {
+            next.check_abort()?;
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        next.run(action)
+    }
+
+    #[test]
+    fn should_reduce_action_when_signal_did_not_fire() {
+        let mut store = Store::new(reducer, 0);
+        store.add_middleware("slow", slow_steps);
+
+        let result = store
+            .dispatch_with_abort(
+                AbortSignal::timeout(Duration::from_secs(60)),
+                MyAction::Increment,
+            )
+            .map(|store| *store.state());
+
+        assert_eq!(result, Ok(1));
+    }
+
+    #[test]
+    fn should_fail_fast_when_middleware_checks_expired_deadline() {
+        let mut store = Store::new(reducer, 0);
+        store.add_middleware("slow", slow_steps);
+        let started = Instant::now();
+
+        let result = store
+            .dispatch_with_abort(
+                AbortSignal::timeout(Duration::from_millis(5)),
+                MyAction::Increment,
+            )
+            .err();
+
+        assert_eq!(result, Some(DispatchError::Aborted));
+        assert!(started.elapsed() < Duration::from_millis(90));
+        assert_eq!(*store.state(), 0);
+    }
+
+    #[test]
+    fn should_abort_before_reducer_when_token_was_cancelled() {
+        let mut store = Store::new(reducer, 0);
+        let token = CancellationToken::new();
+        token.cancel();
+
+        let result = store
+            .dispatch_with_abort(AbortSignal::token(token), MyAction::Increment)
+            .err();
+
+        assert_eq!(result, Some(DispatchError::Aborted));
+        assert_eq!(store.version(), 0);
+    }
+
+    #[test]
+    fn should_not_abort_later_dispatches_when_signal_fired() {
+        let mut store = Store::new(reducer, 0);
+        let signal = AbortSignal::deadline(Instant::now());
+
+        assert!(store
+            .dispatch_with_abort(signal, MyAction::Increment)
+            .is_err());
+        store.dispatch(MyAction::Increment);
+
+        assert_eq!(*store.state(), 1);
+    }
+}
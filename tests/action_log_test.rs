@@ -0,0 +1,110 @@
+#![cfg(feature = "serde")]
+
+#[cfg(test)]
+mod action_log {
+    use redust::action_log::{read_action_log, ActionRecord};
+    use redust::{Fixture, FixtureError, HistoryPolicy, Store, TestClock};
+    use serde::{Deserialize, Serialize};
+    use std::time::{Duration, UNIX_EPOCH};
+
+    type MyStore = Vec<u8>;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    enum MyAction {
+        Push(u8),
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Push(value) => {
+                let mut new_state = state.clone();
+                new_state.push(*value);
+
+                new_state
+            }
+        }
+    }
+
+    fn records(log: &[u8]) -> Vec<ActionRecord<MyAction>> {
+        read_action_log(log).collect::<Result<_, _>>().unwrap()
+    }
+
+    #[test]
+    fn should_write_one_line_per_action_when_history_is_enabled() {
+        let clock = TestClock::starting_at(UNIX_EPOCH + Duration::from_secs(10));
+        let mut store = Store::new(reducer, vec![]);
+        store.set_clock(clock.clone());
+        store.enable_history(HistoryPolicy::unbounded());
+
+        store.dispatch(MyAction::Push(1));
+        clock.advance(Duration::from_millis(250));
+        store.dispatch(MyAction::Push(2));
+
+        let mut log = vec![];
+        assert_eq!(store.export_action_log(&mut log).unwrap(), 2);
+
+        assert_eq!(
+            String::from_utf8(log).unwrap(),
+            "{\"seq\":0,\"timestamp_ms\":10000,\"action\":{\"Push\":1}}\n\
+             {\"seq\":1,\"timestamp_ms\":10250,\"action\":{\"Push\":2}}\n"
+        );
+    }
+
+    #[test]
+    fn should_continue_sequence_numbers_when_old_entries_were_evicted() {
+        let mut store = Store::new(reducer, vec![]);
+        store.enable_history(HistoryPolicy::unbounded().max_entries(2));
+        store
+            .dispatch(MyAction::Push(1))
+            .dispatch(MyAction::Push(2))
+            .dispatch(MyAction::Push(3));
+
+        let mut log = vec![];
+        store.export_action_log(&mut log).unwrap();
+        let records = records(&log);
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].seq, 1);
+        assert_eq!(records[1].action, MyAction::Push(3));
+    }
+
+    #[test]
+    fn should_write_nothing_when_history_is_disabled() {
+        let mut store = Store::new(reducer, vec![]);
+        store.dispatch(MyAction::Push(1));
+
+        let mut log = vec![];
+
+        assert_eq!(store.export_action_log(&mut log).unwrap(), 0);
+        assert!(log.is_empty());
+    }
+
+    #[test]
+    fn should_replay_to_the_same_state_when_log_is_read_into_fixture() {
+        let mut store = Store::new(reducer, vec![]);
+        store.enable_history(HistoryPolicy::unbounded().max_entries(2));
+        store
+            .dispatch(MyAction::Push(1))
+            .dispatch(MyAction::Push(2))
+            .dispatch(MyAction::Push(3));
+
+        let mut log = vec![];
+        store.export_action_log(&mut log).unwrap();
+        let initial_state = store.history_state(0).unwrap().as_ref().clone();
+        let fixture = Fixture::from_action_log(initial_state, &log[..]).unwrap();
+
+        assert_eq!(
+            Store::replay_fixture(reducer, fixture).state(),
+            store.state()
+        );
+    }
+
+    #[test]
+    fn should_return_error_when_log_line_is_malformed() {
+        let log = b"{\"seq\":0,\"timestamp_ms\":0,\"action\":{\"Push\":1}}\n{\"seq\":";
+
+        let fixture = Fixture::<MyStore, MyAction>::from_action_log(vec![], &log[..]);
+
+        assert!(matches!(fixture, Err(FixtureError::Json(_))));
+    }
+}
@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod action_subscription {
+    use redust::Store;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    type MyStore = u8;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Increment,
+        Reset,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Increment => state + 1,
+            MyAction::Reset => 0,
+        }
+    }
+
+    #[test]
+    fn should_call_subscriber_only_for_matching_actions() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let mut store = Store::new(reducer, 0);
+        store.subscribe_actions(
+            |action| matches!(action, MyAction::Reset),
+            |action, state| {
+                assert!(matches!(action, MyAction::Reset));
+                assert_eq!(*state, 0);
+                CALLS.fetch_add(1, Ordering::SeqCst);
+            },
+        );
+
+        store
+            .dispatch(MyAction::Increment)
+            .dispatch(MyAction::Reset)
+            .dispatch(MyAction::Increment);
+
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn should_not_call_action_subscriber_when_we_unsubscribed() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let mut store = Store::new(reducer, 0);
+        let token = store.subscribe_actions(
+            |_action| true,
+            |_action, _state| {
+                CALLS.fetch_add(1, Ordering::SeqCst);
+            },
+        );
+
+        store.dispatch(MyAction::Increment);
+        assert_eq!(store.unsubscribe(token), Ok(()));
+        store.dispatch(MyAction::Increment);
+
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    }
+}
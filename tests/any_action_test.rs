@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod any_action {
+    use redust::{AnyAction, Store};
+    use std::sync::Mutex;
+
+    #[derive(Debug, Clone, Default, PartialEq)]
+    struct MyStore {
+        todos: Vec<&'static str>,
+        clicks: u8,
+    }
+
+    struct AddTodo(&'static str);
+    struct Click;
+    struct Unknown;
+
+    fn add_todo(state: &MyStore, action: &AddTodo) -> MyStore {
+        let mut todos = state.todos.clone();
+        todos.push(action.0);
+
+        MyStore {
+            todos,
+            ..state.clone()
+        }
+    }
+
+    fn click(state: &MyStore, _: &Click) -> MyStore {
+        MyStore {
+            clicks: state.clicks + 1,
+            ..state.clone()
+        }
+    }
+
+    fn double_click(state: &MyStore, _: &Click) -> MyStore {
+        MyStore {
+            clicks: state.clicks + 2,
+            ..state.clone()
+        }
+    }
+
+    #[test]
+    fn should_call_reducer_of_action_type_when_action_was_dispatched() {
+        let mut store = Store::typed(MyStore::default());
+        store.on(add_todo).on(click);
+        store
+            .dispatch_typed(AddTodo("milk"))
+            .dispatch_typed(Click)
+            .dispatch_typed(Click);
+
+        assert_eq!(
+            *store.state(),
+            MyStore {
+                todos: vec!["milk"],
+                clicks: 2
+            }
+        );
+    }
+
+    #[test]
+    fn should_keep_state_when_action_type_has_no_reducer() {
+        let mut store = Store::typed(MyStore::default());
+        store.on(click);
+        store.dispatch_typed(Unknown);
+
+        assert_eq!(*store.state(), MyStore::default());
+        assert!(store.env().handles::<Click>());
+        assert!(!store.env().handles::<Unknown>());
+    }
+
+    #[test]
+    fn should_replace_reducer_when_action_type_was_registered_again() {
+        let mut store = Store::typed(MyStore::default());
+        store.on(click).on(double_click);
+        store.dispatch_typed(Click);
+
+        assert_eq!(store.state().clicks, 2);
+    }
+
+    #[test]
+    fn should_pass_any_action_to_action_subscriptions() {
+        static TYPES: Mutex<Vec<&str>> = Mutex::new(Vec::new());
+
+        let mut store = Store::typed(MyStore::default());
+        store.on(add_todo);
+        store.subscribe_actions(
+            |action: &AnyAction| action.is::<AddTodo>(),
+            |action, _| TYPES.lock().unwrap().push(action.type_name()),
+        );
+        store.dispatch_typed(Click).dispatch_typed(AddTodo("milk"));
+
+        assert_eq!(*TYPES.lock().unwrap(), [std::any::type_name::<AddTodo>()]);
+    }
+}
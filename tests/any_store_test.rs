@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod any_store {
+    use redust::{
+        global_registry, AnyStore, AnyStoreError, DispatchError, FreezePolicy, Store, StoreKey,
+        StoreRegistry,
+    };
+    use std::any::type_name;
+
+    type TodoStore = Vec<&'static str>;
+
+    const COUNTER: StoreKey<u8, CounterAction> = StoreKey::new("counter");
+
+    #[derive(Debug)]
+    enum CounterAction {
+        Increment,
+    }
+
+    #[derive(Debug)]
+    enum TodoAction {
+        Add(&'static str),
+    }
+
+    fn counter(state: &u8, action: &CounterAction) -> u8 {
+        match action {
+            CounterAction::Increment => state + 1,
+        }
+    }
+
+    fn todos(state: &TodoStore, action: &TodoAction) -> TodoStore {
+        let mut todos = state.clone();
+        match action {
+            TodoAction::Add(todo) => todos.push(todo),
+        }
+
+        todos
+    }
+
+    fn registry() -> StoreRegistry {
+        let mut registry = StoreRegistry::new();
+        registry.insert("counter", Store::new(counter, 0));
+        registry.insert("todos", Store::new(todos, vec![]));
+
+        registry
+    }
+
+    #[test]
+    fn should_dispatch_into_store_when_action_type_matches() {
+        let mut registry = registry();
+        registry
+            .dispatch_any("todos", Box::new(TodoAction::Add("milk")))
+            .unwrap();
+
+        let store = registry.get::<TodoStore, TodoAction>("todos").unwrap();
+        assert_eq!(*store.state(), ["milk"]);
+        assert_eq!(registry.get_any("todos").unwrap().version(), 1);
+    }
+
+    #[test]
+    fn should_return_typed_error_when_action_type_does_not_match() {
+        let mut registry = registry();
+
+        assert_eq!(
+            registry.dispatch_any("counter", Box::new(TodoAction::Add("milk"))),
+            Err(AnyStoreError::ActionMismatch {
+                expected: type_name::<CounterAction>()
+            })
+        );
+        assert_eq!(
+            registry.dispatch_any("missing", Box::new(CounterAction::Increment)),
+            Err(AnyStoreError::NotFound("missing".to_string()))
+        );
+    }
+
+    #[test]
+    fn should_not_downcast_when_types_differ() {
+        let registry = registry();
+
+        assert!(registry.get::<u8, TodoAction>("counter").is_none());
+        assert!(registry.get::<u8, CounterAction>("counter").is_some());
+        assert_eq!(
+            registry.get_any("counter").unwrap().state_type(),
+            type_name::<u8>()
+        );
+        assert_eq!(registry.names(), ["counter", "todos"]);
+    }
+
+    #[test]
+    fn should_return_dispatch_error_when_store_rejects_action() {
+        let mut store = AnyStore::new(Store::new(counter, 0));
+        store
+            .downcast_mut::<u8, CounterAction>()
+            .unwrap()
+            .freeze(FreezePolicy::Reject);
+
+        assert_eq!(
+            store.dispatch_any(Box::new(CounterAction::Increment)),
+            Err(AnyStoreError::Dispatch(DispatchError::Frozen))
+        );
+    }
+
+    #[test]
+    fn should_return_store_when_it_was_looked_up_by_typed_key() {
+        let mut registry = StoreRegistry::new();
+        registry.insert_keyed(COUNTER, Store::new(counter, 0));
+
+        registry
+            .get_keyed_mut(COUNTER)
+            .unwrap()
+            .dispatch(CounterAction::Increment);
+
+        assert_eq!(*registry.get_keyed(COUNTER).unwrap().state(), 1);
+        assert_eq!(
+            *registry
+                .get::<u8, CounterAction>("counter")
+                .unwrap()
+                .state(),
+            1
+        );
+    }
+
+    #[test]
+    fn should_enumerate_stores_when_registry_was_iterated() {
+        let registry = registry();
+
+        let stores: Vec<_> = registry
+            .iter()
+            .map(|(name, store)| (name, store.action_type()))
+            .collect();
+
+        assert_eq!(
+            stores,
+            [
+                ("counter", type_name::<CounterAction>()),
+                ("todos", type_name::<TodoAction>())
+            ]
+        );
+    }
+
+    #[test]
+    fn should_share_stores_when_they_were_registered_globally() {
+        global_registry().insert_keyed(StoreKey::new("global_counter"), Store::new(counter, 0));
+
+        std::thread::spawn(|| {
+            global_registry()
+                .dispatch_any("global_counter", Box::new(CounterAction::Increment))
+                .unwrap();
+        })
+        .join()
+        .unwrap();
+
+        let registry = global_registry();
+        let store = registry.get::<u8, CounterAction>("global_counter").unwrap();
+        assert_eq!(*store.state(), 1);
+    }
+}
@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod arena {
+    use redust::{Arena, ArenaId, Store};
+
+    #[derive(Debug, PartialEq)]
+    struct Node {
+        value: u32,
+        children: Vec<ArenaId<Node>>,
+    }
+
+    type MyStore = ArenaId<Node>;
+
+    #[derive(Debug)]
+    enum MyAction {
+        AddChild(u32),
+        Clear,
+    }
+
+    fn copy(id: ArenaId<Node>, nodes: &Arena<Node>, into: &mut Arena<Node>) -> ArenaId<Node> {
+        let children = nodes[id]
+            .children
+            .iter()
+            .map(|child| copy(*child, nodes, into))
+            .collect();
+
+        into.alloc(Node {
+            value: nodes[id].value,
+            children,
+        })
+    }
+
+    fn reducer(
+        root: &MyStore,
+        nodes: &Arena<Node>,
+        action: &MyAction,
+        into: &mut Arena<Node>,
+    ) -> MyStore {
+        match action {
+            MyAction::AddChild(value) => {
+                let mut children: Vec<_> = nodes[*root]
+                    .children
+                    .iter()
+                    .map(|child| copy(*child, nodes, into))
+                    .collect();
+                children.push(into.alloc(Node {
+                    value: *value,
+                    children: vec![],
+                }));
+
+                into.alloc(Node {
+                    value: nodes[*root].value,
+                    children,
+                })
+            }
+            MyAction::Clear => into.alloc(Node {
+                value: nodes[*root].value,
+                children: vec![],
+            }),
+        }
+    }
+
+    fn init(nodes: &mut Arena<Node>) -> MyStore {
+        nodes.alloc(Node {
+            value: 0,
+            children: vec![],
+        })
+    }
+
+    #[test]
+    fn should_allocate_new_state_into_arena_when_action_was_dispatched() {
+        let mut store = Store::arena(reducer, init);
+
+        store
+            .dispatch(MyAction::AddChild(1))
+            .dispatch(MyAction::AddChild(2));
+
+        let state = store.state();
+        let root = &state.nodes()[**state];
+        let values: Vec<_> = root
+            .children
+            .iter()
+            .map(|child| state.node(*child).unwrap().value)
+            .collect();
+        assert_eq!(values, vec![1, 2]);
+    }
+
+    #[test]
+    fn should_drop_nodes_of_previous_state_when_reducer_ran() {
+        let mut store = Store::arena(reducer, init);
+
+        store
+            .dispatch(MyAction::AddChild(1))
+            .dispatch(MyAction::AddChild(2));
+        assert_eq!(store.state().nodes().len(), 3);
+
+        store.dispatch(MyAction::Clear);
+        assert_eq!(store.state().nodes().len(), 1);
+    }
+
+    #[test]
+    fn should_reserve_previous_arena_size_when_reducer_ran() {
+        let mut store = Store::arena(reducer, init);
+
+        store
+            .dispatch(MyAction::AddChild(1))
+            .dispatch(MyAction::AddChild(2))
+            .dispatch(MyAction::Clear);
+
+        assert!(store.state().nodes().capacity() >= 3);
+    }
+
+    #[test]
+    fn should_iterate_nodes_in_allocation_order_when_arena_was_filled() {
+        let mut nodes = Arena::new();
+        let first = nodes.alloc(Node {
+            value: 1,
+            children: vec![],
+        });
+        let second = nodes.alloc(Node {
+            value: 2,
+            children: vec![first],
+        });
+
+        let ids: Vec<_> = nodes.iter().map(|(id, _node)| id).collect();
+        assert_eq!(ids, vec![first, second]);
+        assert_eq!(nodes.get(second).unwrap().children, vec![first]);
+    }
+}
@@ -0,0 +1,136 @@
+#[cfg(test)]
+mod async_thunk {
+    use redust::{
+        create_async_thunk, AsyncThunk, CancellationToken, DispatchQueue, QueryCache,
+        QueryEndpoint, QueryStatus, RequestId, Store, Tag,
+    };
+    use std::thread;
+    use std::time::Duration;
+
+    type MyStore = QueryCache<u32, String>;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum MyAction {
+        Pending(RequestId, u32),
+        Fulfilled(RequestId, u32, String),
+        Rejected(RequestId, u32, String),
+    }
+
+    const USERS: QueryEndpoint<u32, String> =
+        QueryEndpoint::new(|id, _user| vec![Tag::id("User", id)]);
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        let mut cache = state.clone();
+        match action {
+            MyAction::Pending(_, id) => USERS.query_started(&mut cache, *id),
+            MyAction::Fulfilled(_, id, user) => {
+                USERS.query_fulfilled(&mut cache, *id, user.clone())
+            }
+            MyAction::Rejected(_, id, error) => {
+                USERS.query_rejected(&mut cache, *id, error.clone())
+            }
+        }
+
+        cache
+    }
+
+    fn fetch_user(id: &u32, token: &CancellationToken) -> Result<String, String> {
+        if *id == 0 {
+            // Waits until the request is cancelled
+            while !token.is_cancelled() {
+                thread::sleep(Duration::from_millis(1));
+            }
+        }
+
+        match id {
+            1 => Ok("Ann".to_string()),
+            _ => Err(format!("User {} not found", id)),
+        }
+    }
+
+    fn thunk() -> AsyncThunk<u32, String, String, MyAction> {
+        create_async_thunk(
+            fetch_user,
+            |request_id, id| MyAction::Pending(request_id, *id),
+            MyAction::Fulfilled,
+            MyAction::Rejected,
+        )
+    }
+
+    #[test]
+    fn should_dispatch_pending_then_fulfilled_when_payload_succeeded() {
+        let queue = DispatchQueue::new();
+        let mut store = Store::new(reducer, QueryCache::new());
+
+        let request = thunk().dispatch(&mut store, queue.dispatcher(), 1);
+        assert_eq!(store.state().get(&1).unwrap().status, QueryStatus::Pending);
+
+        request.join().unwrap();
+        assert_eq!(
+            queue.pop(),
+            Some(MyAction::Fulfilled(0, 1, "Ann".to_string()))
+        );
+    }
+
+    #[test]
+    fn should_dispatch_rejected_when_payload_failed() {
+        let queue = DispatchQueue::new();
+        let mut store = Store::new(reducer, QueryCache::new());
+
+        thunk()
+            .dispatch(&mut store, queue.dispatcher(), 2)
+            .join()
+            .unwrap();
+        store.drain(&queue);
+
+        assert_eq!(
+            store.state().get(&2).unwrap().status,
+            QueryStatus::Rejected("User 2 not found".to_string())
+        );
+    }
+
+    #[test]
+    fn should_store_fetched_data_when_result_was_drained() {
+        let queue = DispatchQueue::new();
+        let mut store = Store::new(reducer, QueryCache::new());
+
+        thunk()
+            .dispatch(&mut store, queue.dispatcher(), 1)
+            .join()
+            .unwrap();
+        store.drain(&queue);
+
+        let entry = store.state().get(&1).unwrap();
+        assert_eq!(entry.status, QueryStatus::Fulfilled);
+        assert_eq!(entry.data.as_deref(), Some("Ann"));
+    }
+
+    #[test]
+    fn should_assign_new_request_ids_when_thunk_was_dispatched_again() {
+        let queue = DispatchQueue::new();
+        let mut store = Store::new(reducer, QueryCache::new());
+        let thunk = thunk();
+
+        let first = thunk.dispatch(&mut store, queue.dispatcher(), 1);
+        let second = thunk.clone().dispatch(&mut store, queue.dispatcher(), 1);
+
+        assert_eq!((first.request_id(), second.request_id()), (0, 1));
+        first.join().unwrap();
+        second.join().unwrap();
+    }
+
+    #[test]
+    fn should_drop_result_when_request_was_cancelled() {
+        let queue = DispatchQueue::new();
+        let mut store = Store::new(reducer, QueryCache::new());
+
+        let request = thunk().dispatch(&mut store, queue.dispatcher(), 0);
+        assert!(!request.is_finished());
+
+        request.cancel();
+        request.join().unwrap();
+
+        assert!(queue.is_empty());
+        assert_eq!(store.state().get(&0).unwrap().status, QueryStatus::Pending);
+    }
+}
@@ -0,0 +1,208 @@
+#[cfg(test)]
+mod backpressure {
+    use redust::{Backpressure, BoxFuture, Spawner, Store};
+    use std::future::Future;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{mpsc, Arc, Mutex};
+    use std::task::{Context, Poll, Wake, Waker};
+    use std::thread;
+    use std::time::Duration;
+
+    type MyStore = u8;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Increment,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Increment => state + 1,
+        }
+    }
+
+    /// Runs every future to completion on its own thread
+    struct ThreadSpawner;
+
+    struct ThreadWaker(thread::Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    impl Spawner for ThreadSpawner {
+        fn spawn(&self, mut future: BoxFuture<()>) {
+            thread::spawn(move || {
+                let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+                let mut context = Context::from_waker(&waker);
+                while future.as_mut().poll(&mut context).is_pending() {
+                    thread::park();
+                }
+            });
+        }
+
+        fn sleep(&self, duration: Duration) -> BoxFuture<()> {
+            Box::pin(async move { thread::sleep(duration) })
+        }
+    }
+
+    fn wait_until(condition: impl Fn() -> bool) {
+        for _ in 0..5000 {
+            if condition() {
+                return;
+            }
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn should_keep_latest_state_when_backpressure_is_default() {
+        let mut store = Store::new(reducer, 0);
+        let states = store.subscribe_channel(Backpressure::default());
+
+        for _ in 0..3 {
+            store.dispatch(MyAction::Increment);
+        }
+
+        assert_eq!(Backpressure::default(), Backpressure::Latest);
+        assert_eq!(states.try_recv().as_deref(), Some(&3));
+        assert_eq!(states.try_recv(), None);
+        assert_eq!(states.dropped(), 2);
+    }
+
+    #[test]
+    fn should_drop_oldest_states_when_buffer_is_full() {
+        let mut store = Store::new(reducer, 0);
+        let states = store.subscribe_channel(Backpressure::Buffer(2));
+
+        for _ in 0..4 {
+            store.dispatch(MyAction::Increment);
+        }
+
+        assert_eq!(states.try_recv().as_deref(), Some(&3));
+        assert_eq!(states.try_recv().as_deref(), Some(&4));
+        assert_eq!(states.dropped(), 2);
+    }
+
+    #[test]
+    fn should_block_dispatch_when_receiver_fell_behind() {
+        let mut store = Store::new(reducer, 0);
+        let states = store.subscribe_channel(Backpressure::Block(1));
+        let (sender, dispatched) = mpsc::channel();
+
+        let dispatcher = thread::spawn(move || {
+            for _ in 0..2 {
+                store.dispatch(MyAction::Increment);
+                sender.send(()).unwrap();
+            }
+        });
+
+        dispatched.recv().unwrap();
+        // The second dispatch waits until the first state is received
+        assert!(dispatched.recv_timeout(Duration::from_millis(50)).is_err());
+
+        assert_eq!(states.recv().as_deref(), Some(&1));
+        dispatched.recv().unwrap();
+        assert_eq!(states.recv().as_deref(), Some(&2));
+        assert_eq!(states.dropped(), 0);
+
+        dispatcher.join().unwrap();
+        assert_eq!(states.recv(), None);
+    }
+
+    #[test]
+    fn should_unblock_dispatch_when_receiver_was_dropped() {
+        let mut store = Store::new(reducer, 0);
+        let states = store.subscribe_channel(Backpressure::Block(1));
+
+        let dispatcher = thread::spawn(move || {
+            store
+                .dispatch(MyAction::Increment)
+                .dispatch(MyAction::Increment);
+            *store.state()
+        });
+        thread::sleep(Duration::from_millis(10));
+        drop(states);
+
+        assert_eq!(dispatcher.join().unwrap(), 2);
+    }
+
+    #[test]
+    fn should_stop_receiving_when_subscription_was_removed() {
+        let mut store = Store::new(reducer, 0);
+        let states = store.subscribe_channel(Backpressure::Buffer(4));
+
+        store.dispatch(MyAction::Increment);
+        store.unsubscribe(states.token()).unwrap();
+        store.dispatch(MyAction::Increment);
+
+        assert_eq!(states.recv().as_deref(), Some(&1));
+        assert_eq!(states.recv(), None);
+        assert_eq!(states.recv_timeout(Duration::from_millis(1)), None);
+    }
+
+    #[test]
+    fn should_resolve_async_receive_when_state_changed() {
+        let mut store = Store::new(reducer, 0);
+        let states = store.subscribe_channel(Backpressure::Latest);
+        let mut context = Context::from_waker(Waker::noop());
+
+        let mut next = Box::pin(states.recv_async());
+        assert!(next.as_mut().poll(&mut context).is_pending());
+
+        store.dispatch(MyAction::Increment);
+        match next.as_mut().poll(&mut context) {
+            Poll::Ready(state) => assert_eq!(state.as_deref(), Some(&1)),
+            Poll::Pending => panic!("The state was not received"),
+        }
+    }
+
+    #[test]
+    fn should_call_async_subscriber_once_with_newest_state_when_updates_were_coalesced() {
+        static CALLS: Mutex<Vec<u8>> = Mutex::new(vec![]);
+        static RELEASED: AtomicBool = AtomicBool::new(false);
+
+        let mut store = Store::new(reducer, 0);
+        let token = store.subscribe_async(&ThreadSpawner, Backpressure::Latest, |state| {
+            Box::pin(async move {
+                CALLS.lock().unwrap().push(*state);
+                // The first call is busy until the test releases it
+                while !RELEASED.load(Ordering::SeqCst) {
+                    thread::sleep(Duration::from_millis(1));
+                }
+            })
+        });
+
+        store.dispatch(MyAction::Increment);
+        wait_until(|| CALLS.lock().unwrap().len() == 1);
+
+        for _ in 0..3 {
+            store.dispatch(MyAction::Increment);
+        }
+        RELEASED.store(true, Ordering::SeqCst);
+        wait_until(|| CALLS.lock().unwrap().len() == 2);
+        store.unsubscribe(token).unwrap();
+
+        thread::sleep(Duration::from_millis(10));
+        assert_eq!(*CALLS.lock().unwrap(), [1, 4]);
+    }
+
+    #[test]
+    fn should_call_async_subscriber_with_every_state_when_they_were_buffered() {
+        static CALLS: Mutex<Vec<u8>> = Mutex::new(vec![]);
+
+        let mut store = Store::new(reducer, 0);
+        store.subscribe_async(&ThreadSpawner, Backpressure::Buffer(8), |state| {
+            Box::pin(async move { CALLS.lock().unwrap().push(*state) })
+        });
+
+        for _ in 0..3 {
+            store.dispatch(MyAction::Increment);
+        }
+        wait_until(|| CALLS.lock().unwrap().len() == 3);
+
+        assert_eq!(*CALLS.lock().unwrap(), [1, 2, 3]);
+    }
+}
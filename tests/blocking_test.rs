@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod blocking {
+    use redust::{spawn_async_effect, BlockingRuntime, Next, Store};
+    use std::time::Duration;
+
+    type MyStore = Vec<u8>;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum MyAction {
+        Fetch(u8),
+        Loaded(u8),
+        Other,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        let mut loaded = state.clone();
+        if let MyAction::Loaded(id) = action {
+            loaded.push(*id);
+        }
+
+        loaded
+    }
+
+    /// Loads the id after a delay; ids above 1 fetch the previous one afterwards
+    fn create_store(runtime: &BlockingRuntime<MyAction>) -> Store<MyStore, MyAction> {
+        let (spawner, dispatcher) = (runtime.spawner(), runtime.dispatcher());
+
+        let mut store = Store::new(reducer, vec![]);
+        store.add_middleware("fetch", move |action, next: Next<MyStore, MyAction>| {
+            let action = next.run(action)?;
+            match action {
+                MyAction::Fetch(id) => {
+                    let sleep = spawner.sleep(Duration::from_millis(5));
+                    spawn_async_effect(
+                        spawner.as_ref(),
+                        dispatcher.clone(),
+                        move |_token| async move {
+                            sleep.await;
+                            Some(MyAction::Loaded(id))
+                        },
+                    );
+                }
+                MyAction::Loaded(id) if id > 1 => {
+                    dispatcher.dispatch(MyAction::Fetch(id - 1)).unwrap();
+                }
+                _ => {}
+            }
+
+            Ok(action)
+        });
+
+        store
+    }
+
+    #[test]
+    fn should_return_settled_state_when_effect_completed() {
+        let runtime = BlockingRuntime::new();
+        let mut store = create_store(&runtime);
+
+        assert_eq!(*store.dispatch_blocking(&runtime, MyAction::Fetch(1)), [1]);
+        assert_eq!(runtime.pending(), 0);
+    }
+
+    #[test]
+    fn should_run_follow_up_effects_when_effects_enqueued_more_actions() {
+        let runtime = BlockingRuntime::new();
+        let mut store = create_store(&runtime);
+
+        let state = store.dispatch_blocking(&runtime, MyAction::Fetch(3));
+
+        assert_eq!(*state, [3, 2, 1]);
+    }
+
+    #[test]
+    fn should_return_immediately_when_action_triggered_no_effects() {
+        let runtime = BlockingRuntime::new();
+        let mut store = create_store(&runtime);
+
+        assert!(store
+            .dispatch_blocking(&runtime, MyAction::Other)
+            .is_empty());
+    }
+}
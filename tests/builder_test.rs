@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod builder {
+    use redust::{DispatchError, FreezePolicy, Store};
+
+    type MyStore = u8;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Increment,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Increment => state + 1,
+        }
+    }
+
+    #[test]
+    fn should_start_from_default_state_when_created_with_default_state() {
+        let mut store = Store::with_default_state(reducer);
+        assert_eq!(*store.state(), 0);
+
+        store.dispatch(MyAction::Increment);
+        assert_eq!(*store.state(), 1);
+    }
+
+    #[test]
+    fn should_start_from_default_state_when_built_with_default_builder() {
+        let store = Store::default_builder(reducer).build();
+
+        assert_eq!(*store.state(), 0);
+    }
+
+    #[test]
+    fn should_apply_options_when_store_was_built() {
+        let mut store = Store::builder(reducer, 5)
+            .frozen(FreezePolicy::Reject)
+            .notifications_paused()
+            .build();
+
+        assert_eq!(*store.state(), 5);
+        assert!(store.notifications_paused());
+        assert_eq!(
+            store.try_dispatch(MyAction::Increment).err(),
+            Some(DispatchError::Frozen)
+        );
+    }
+
+    #[test]
+    fn should_compute_state_lazily_when_built_with_lazy_builder() {
+        let store = Store::lazy_builder(reducer, || 7).build();
+
+        assert!(!store.is_initialized());
+        assert_eq!(*store.state(), 7);
+    }
+}
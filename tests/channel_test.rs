@@ -0,0 +1,59 @@
+#![cfg(feature = "crossbeam-channel")]
+
+#[cfg(test)]
+mod channel {
+    use redust::channel::forward;
+    use redust::{DispatchQueue, Store};
+    use std::thread;
+
+    type MyStore = u8;
+
+    #[derive(Debug)]
+    enum MyAction {
+        IncrementBy(u8),
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::IncrementBy(value) => state + value,
+        }
+    }
+
+    #[test]
+    fn should_dispatch_actions_from_many_producers_when_pump_was_called() {
+        let (sender, receiver) = crossbeam_channel::unbounded();
+
+        let producers: Vec<_> = (1..=3)
+            .map(|value| {
+                let sender = sender.clone();
+                thread::spawn(move || sender.send(MyAction::IncrementBy(value)).unwrap())
+            })
+            .collect();
+        producers
+            .into_iter()
+            .for_each(|producer| producer.join().unwrap());
+
+        let mut store = Store::new(reducer, 0);
+
+        assert_eq!(store.pump(&receiver), 3);
+        assert_eq!(store.pump(&receiver), 0);
+        assert_eq!(*store.state(), 6);
+    }
+
+    #[test]
+    fn should_forward_actions_into_queue_until_senders_were_dropped() {
+        let (sender, receiver) = crossbeam_channel::bounded(1);
+        let queue = DispatchQueue::new();
+
+        let forwarder = forward(receiver, queue.dispatcher());
+        sender.send(MyAction::IncrementBy(2)).unwrap();
+        sender.send(MyAction::IncrementBy(3)).unwrap();
+        drop(sender);
+
+        assert_eq!(forwarder.join().unwrap(), 2);
+
+        let mut store = Store::new(reducer, 0);
+        store.drain(&queue);
+        assert_eq!(*store.state(), 5);
+    }
+}
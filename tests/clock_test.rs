@@ -0,0 +1,186 @@
+#[cfg(test)]
+mod clock {
+    use redust::{
+        AbortSignal, Clock, DispatchError, DispatchQueue, HistoryPolicy, Sample, Scheduler,
+        SharedClock, Store, TestClock, Ticker,
+    };
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+    type MyStore = u32;
+
+    #[derive(Debug, Clone)]
+    enum MyAction {
+        Increment,
+        Tick(Instant),
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Increment | MyAction::Tick(_) => state + 1,
+        }
+    }
+
+    fn wait_for_len(queue: &DispatchQueue<MyAction>, len: usize) -> bool {
+        (0..500).any(|_| {
+            thread::sleep(Duration::from_millis(2));
+            queue.len() >= len
+        })
+    }
+
+    #[test]
+    fn should_share_time_between_clones_when_test_clock_is_advanced() {
+        let clock = TestClock::starting_at(UNIX_EPOCH);
+        let started = clock.now();
+        let other = clock.clone();
+
+        other.advance(Duration::from_secs(5));
+
+        assert_eq!(clock.now() - started, Duration::from_secs(5));
+        assert_eq!(clock.system_time(), UNIX_EPOCH + Duration::from_secs(5));
+    }
+
+    #[test]
+    fn should_not_move_when_test_clock_was_not_advanced() {
+        let clock = TestClock::new();
+        let now = clock.now();
+
+        thread::sleep(Duration::from_millis(5));
+
+        assert_eq!(clock.now(), now);
+    }
+
+    #[test]
+    fn should_dispatch_expirations_when_test_clock_passed_ttl() {
+        let clock = TestClock::new();
+        let mut store = Store::new(reducer, 0);
+        store.set_clock(clock.clone());
+
+        store.expire_after(Duration::from_secs(60), MyAction::Increment);
+        clock.advance(Duration::from_secs(59));
+        assert_eq!(store.dispatch_due(), 0);
+
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(store.dispatch_due(), 1);
+        assert_eq!(*store.state(), 1);
+    }
+
+    #[test]
+    fn should_expire_relative_to_test_clock_when_clock_was_set() {
+        let clock = TestClock::starting_at(UNIX_EPOCH);
+        let mut store = Store::new(reducer, 0);
+        store.set_clock(clock);
+
+        store.expire_after(Duration::from_secs(1), MyAction::Increment);
+
+        assert_eq!(
+            store.next_expiration(),
+            Some(UNIX_EPOCH + Duration::from_secs(1))
+        );
+        assert!(store.next_expiration() < Some(SystemTime::now()));
+    }
+
+    static SAMPLED: Mutex<Vec<MyStore>> = Mutex::new(Vec::new());
+
+    fn sampled(state: &MyStore) {
+        SAMPLED.lock().unwrap().push(*state);
+    }
+
+    #[test]
+    fn should_sample_by_test_clock_when_interval_passed() {
+        let clock = TestClock::new();
+        let mut store = Store::new(reducer, 0);
+        store.set_clock(clock.clone());
+        store.subscribe_sampled(Sample::Interval(Duration::from_secs(1)), sampled);
+
+        store.dispatch(MyAction::Increment);
+        store.dispatch(MyAction::Increment);
+        clock.advance(Duration::from_secs(1));
+        store.dispatch(MyAction::Increment);
+
+        assert_eq!(*SAMPLED.lock().unwrap(), vec![1, 3]);
+    }
+
+    #[test]
+    fn should_drop_old_history_entries_when_test_clock_passed_max_age() {
+        let clock = TestClock::new();
+        let mut store = Store::new(reducer, 0);
+        store.set_clock(clock.clone());
+        store.enable_history(HistoryPolicy::unbounded().max_age(Duration::from_secs(10)));
+
+        store.dispatch(MyAction::Increment);
+        clock.advance(Duration::from_secs(11));
+        store.dispatch(MyAction::Increment);
+
+        assert_eq!(store.history_actions().len(), 1);
+        assert_eq!(*store.history_state(0).unwrap(), 1);
+    }
+
+    #[test]
+    fn should_abort_dispatch_when_test_clock_passed_deadline() {
+        let clock = TestClock::new();
+        let signal =
+            AbortSignal::timeout_with_clock(Duration::from_secs(1), Arc::new(clock.clone()));
+        let mut store = Store::new(reducer, 0);
+
+        assert!(store
+            .dispatch_with_abort(signal.clone(), MyAction::Increment)
+            .is_ok());
+
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(
+            store.dispatch_with_abort(signal, MyAction::Increment).err(),
+            Some(DispatchError::Aborted)
+        );
+        assert_eq!(*store.state(), 1);
+    }
+
+    #[test]
+    fn should_tick_only_when_test_clock_was_advanced() {
+        let clock = TestClock::new();
+        let started = clock.now();
+        let queue = DispatchQueue::new();
+        let ticker = Ticker::start_with_clock(
+            queue.dispatcher(),
+            Duration::from_secs(60),
+            MyAction::Tick,
+            Arc::new(clock.clone()),
+        )
+        .unwrap();
+
+        thread::sleep(Duration::from_millis(20));
+        assert!(queue.is_empty());
+
+        clock.advance(Duration::from_secs(60));
+        assert!(wait_for_len(&queue, 1));
+        ticker.stop();
+
+        match queue.pop() {
+            Some(MyAction::Tick(at)) => assert_eq!(at - started, Duration::from_secs(60)),
+            action => panic!("Unexpected action {:?}", action),
+        }
+    }
+
+    #[test]
+    fn should_run_schedule_only_when_test_clock_was_advanced() {
+        let clock = TestClock::new();
+        let shared: SharedClock = Arc::new(clock.clone());
+        let queue = DispatchQueue::new();
+        let scheduler = Scheduler::with_clock(queue.dispatcher(), shared);
+        scheduler
+            .every(Duration::from_secs(3600), MyAction::Increment)
+            .unwrap();
+
+        thread::sleep(Duration::from_millis(20));
+        assert!(queue.is_empty());
+
+        clock.advance(Duration::from_secs(3600));
+        assert!(wait_for_len(&queue, 1));
+
+        let mut store = Store::new(reducer, 0);
+        drop(scheduler);
+        store.drain(&queue);
+        assert_eq!(*store.state(), 1);
+    }
+}
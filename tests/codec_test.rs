@@ -0,0 +1,335 @@
+#![cfg(feature = "serde")]
+
+#[cfg(test)]
+mod codec {
+    use redust::codec::{Codec, CodecError, Compression, Encryption, Format, KeyRing};
+    use redust::{Fixture, Store};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Counter {
+        value: u8,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    enum MyAction {
+        IncrementBy(u8),
+    }
+
+    fn reducer(state: &Counter, action: &MyAction) -> Counter {
+        match action {
+            MyAction::IncrementBy(value) => Counter {
+                value: state.value + value,
+            },
+        }
+    }
+
+    // Run-length encoding: pairs of a repeat count and a byte
+    fn compress(bytes: &[u8]) -> Result<Vec<u8>, String> {
+        let mut compressed: Vec<u8> = vec![];
+        for byte in bytes {
+            match compressed.chunks_exact_mut(2).last() {
+                Some([count, last]) if last == byte && *count < u8::MAX => *count += 1,
+                _ => compressed.extend_from_slice(&[1, *byte]),
+            }
+        }
+
+        Ok(compressed)
+    }
+
+    fn decompress(bytes: &[u8]) -> Result<Vec<u8>, String> {
+        if bytes.len() % 2 != 0 {
+            return Err("truncated payload".to_string());
+        }
+
+        Ok(bytes
+            .chunks_exact(2)
+            .flat_map(|pair| std::iter::repeat_n(pair[1], pair[0] as usize))
+            .collect())
+    }
+
+    const RUN_LENGTH: Compression = Compression::new(1, compress, decompress);
+
+    // Toy cipher: XOR with the key, followed by a checksum of the plaintext and the header
+    fn checksum(header: &[u8], bytes: &[u8]) -> u8 {
+        header
+            .iter()
+            .chain(bytes)
+            .fold(0u8, |sum, byte| sum.wrapping_add(*byte))
+    }
+
+    fn xor(key: &[u8], bytes: &[u8]) -> Vec<u8> {
+        bytes
+            .iter()
+            .zip(key.iter().cycle())
+            .map(|(byte, key)| byte ^ key)
+            .collect()
+    }
+
+    fn seal(key: &[u8], header: &[u8], bytes: &[u8]) -> Result<Vec<u8>, String> {
+        let mut sealed = xor(key, bytes);
+        sealed.push(checksum(header, bytes));
+
+        Ok(sealed)
+    }
+
+    fn open(key: &[u8], header: &[u8], bytes: &[u8]) -> Result<Vec<u8>, String> {
+        let (tag, sealed) = bytes.split_last().ok_or("truncated payload")?;
+        let plaintext = xor(key, sealed);
+        if checksum(header, &plaintext) != *tag {
+            return Err("authentication failed".to_string());
+        }
+
+        Ok(plaintext)
+    }
+
+    fn assert_round_trip<F: Format>(codec: F) {
+        let mut store = Store::new(reducer, Counter { value: 0 });
+        store.dispatch(MyAction::IncrementBy(2));
+
+        let snapshot = store.snapshot(codec).unwrap();
+        let restored = Store::restore(reducer, codec, &snapshot).unwrap();
+
+        assert_eq!(*restored.state(), Counter { value: 2 });
+
+        let fixture = Fixture {
+            initial_state: Counter { value: 1 },
+            actions: vec![MyAction::IncrementBy(3)],
+        };
+        let bytes = fixture.encode(codec).unwrap();
+
+        assert_eq!(Fixture::decode(codec, &bytes), Ok(fixture));
+    }
+
+    #[test]
+    fn should_restore_snapshot_when_it_was_encoded_as_json() {
+        assert_round_trip(Codec::Json);
+    }
+
+    #[test]
+    fn should_return_error_when_bytes_are_corrupted() {
+        let restored = Store::<Counter, MyAction>::restore(reducer, Codec::Json, b"{");
+
+        assert!(matches!(restored, Err(CodecError::Decode(_))));
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn should_restore_snapshot_when_it_was_encoded_as_message_pack() {
+        assert_round_trip(Codec::MessagePack);
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn should_produce_smaller_output_when_message_pack_is_used() {
+        let fixture = Fixture {
+            initial_state: Counter { value: 0 },
+            actions: vec![MyAction::IncrementBy(1); 100],
+        };
+
+        let json = fixture.encode(Codec::Json).unwrap();
+        let message_pack = fixture.encode(Codec::MessagePack).unwrap();
+
+        assert!(message_pack.len() < json.len());
+    }
+
+    #[test]
+    fn should_restore_snapshot_when_it_was_compressed() {
+        assert_round_trip(Codec::Json.compressed(RUN_LENGTH));
+    }
+
+    #[test]
+    fn should_restore_uncompressed_snapshot_when_compression_was_enabled_later() {
+        let mut store = Store::new(reducer, Counter { value: 0 });
+        store.dispatch(MyAction::IncrementBy(4));
+        let snapshot = store.snapshot(Codec::Json).unwrap();
+
+        let restored =
+            Store::restore(reducer, Codec::Json.compressed(RUN_LENGTH), &snapshot).unwrap();
+
+        assert_eq!(*restored.state(), Counter { value: 4 });
+    }
+
+    #[test]
+    fn should_return_error_when_snapshot_was_compressed_with_another_algorithm() {
+        let store = Store::new(reducer, Counter { value: 0 });
+        let other = Compression::new(2, compress, decompress);
+        let snapshot = store.snapshot(Codec::Json.compressed(other)).unwrap();
+
+        let restored = Store::<Counter, MyAction>::restore(
+            reducer,
+            Codec::Json.compressed(RUN_LENGTH),
+            &snapshot,
+        );
+
+        assert!(matches!(restored, Err(CodecError::Decode(_))));
+    }
+
+    #[test]
+    fn should_return_error_when_compressed_payload_is_corrupted() {
+        let store = Store::new(reducer, Counter { value: 0 });
+        let mut snapshot = store.snapshot(Codec::Json.compressed(RUN_LENGTH)).unwrap();
+        snapshot.pop();
+
+        let restored = Store::<Counter, MyAction>::restore(
+            reducer,
+            Codec::Json.compressed(RUN_LENGTH),
+            &snapshot,
+        );
+
+        assert!(matches!(restored, Err(CodecError::Decode(_))));
+    }
+
+    #[test]
+    fn should_restore_snapshot_when_it_was_encrypted() {
+        let encryption = Encryption::new(KeyRing::new(1, b"secret".to_vec()), seal, open);
+
+        assert_round_trip(Codec::Json.encrypted(&encryption));
+        assert_round_trip(Codec::Json.compressed(RUN_LENGTH).encrypted(&encryption));
+    }
+
+    #[test]
+    fn should_not_expose_state_when_snapshot_is_encrypted() {
+        let encryption = Encryption::new(KeyRing::new(1, b"secret".to_vec()), seal, open);
+        let store = Store::new(reducer, Counter { value: 0 });
+
+        let snapshot = store.snapshot(Codec::Json.encrypted(&encryption)).unwrap();
+
+        assert!(!snapshot.windows(5).any(|window| window == b"value"));
+    }
+
+    #[test]
+    fn should_read_old_snapshots_when_key_was_rotated() {
+        let mut keys = KeyRing::new(1, b"old".to_vec());
+        let store = Store::new(reducer, Counter { value: 7 });
+        let old = store
+            .snapshot(Codec::Json.encrypted(&Encryption::new(keys.clone(), seal, open)))
+            .unwrap();
+
+        keys.rotate(2, b"new".to_vec());
+        let encryption = Encryption::new(keys, seal, open);
+        let new = store.snapshot(Codec::Json.encrypted(&encryption)).unwrap();
+        let restored = Store::restore(reducer, Codec::Json.encrypted(&encryption), &old).unwrap();
+
+        assert_eq!(*restored.state(), Counter { value: 7 });
+        assert_eq!(encryption.key_id(&old), Some(1));
+        assert_eq!(encryption.key_id(&new), Some(2));
+    }
+
+    #[test]
+    fn should_return_error_when_key_was_retired() {
+        let mut keys = KeyRing::new(1, b"old".to_vec());
+        let store = Store::new(reducer, Counter { value: 0 });
+        let snapshot = store
+            .snapshot(Codec::Json.encrypted(&Encryption::new(keys.clone(), seal, open)))
+            .unwrap();
+
+        keys.rotate(2, b"new".to_vec());
+        keys.retire(1);
+        let encryption = Encryption::new(keys, seal, open);
+        let restored = Store::<Counter, MyAction>::restore(
+            reducer,
+            Codec::Json.encrypted(&encryption),
+            &snapshot,
+        );
+
+        assert!(matches!(restored, Err(CodecError::Decode(_))));
+    }
+
+    #[test]
+    fn should_return_error_when_encrypted_payload_was_tampered_with() {
+        let encryption = Encryption::new(KeyRing::new(1, b"secret".to_vec()), seal, open);
+        let store = Store::new(reducer, Counter { value: 0 });
+        let mut snapshot = store.snapshot(Codec::Json.encrypted(&encryption)).unwrap();
+        snapshot[8] ^= 1;
+
+        let restored = Store::<Counter, MyAction>::restore(
+            reducer,
+            Codec::Json.encrypted(&encryption),
+            &snapshot,
+        );
+
+        assert_eq!(
+            restored.err(),
+            Some(CodecError::Decode("authentication failed".to_string()))
+        );
+    }
+
+    #[test]
+    fn should_return_error_when_snapshot_is_not_encrypted() {
+        let encryption = Encryption::new(KeyRing::new(1, b"secret".to_vec()), seal, open);
+        let store = Store::new(reducer, Counter { value: 0 });
+        let snapshot = store.snapshot(Codec::Json).unwrap();
+
+        let restored = Store::<Counter, MyAction>::restore(
+            reducer,
+            Codec::Json.encrypted(&encryption),
+            &snapshot,
+        );
+
+        assert!(matches!(restored, Err(CodecError::Decode(_))));
+    }
+
+    #[test]
+    fn should_restore_snapshot_when_checksum_matches() {
+        assert_round_trip(Codec::Json.checksummed());
+        assert_round_trip(Codec::Json.compressed(RUN_LENGTH).checksummed());
+    }
+
+    #[test]
+    fn should_return_corrupt_snapshot_error_when_bytes_were_flipped() {
+        let store = Store::new(reducer, Counter { value: 3 });
+        let mut snapshot = store.snapshot(Codec::Json.checksummed()).unwrap();
+        // Still valid JSON, which would be decoded into a wrong state
+        let last_digit = snapshot.len() - 2;
+        snapshot[last_digit] = b'9';
+
+        let restored =
+            Store::<Counter, MyAction>::restore(reducer, Codec::Json.checksummed(), &snapshot);
+
+        assert!(matches!(restored, Err(CodecError::CorruptSnapshot { .. })));
+        assert!(Store::<Counter, MyAction>::restore(reducer, Codec::Json, &snapshot[7..]).is_ok());
+    }
+
+    #[test]
+    fn should_fall_back_to_older_snapshot_when_newest_is_corrupted() {
+        let codec = Codec::Json.checksummed();
+        let mut store = Store::new(reducer, Counter { value: 0 });
+        store.dispatch(MyAction::IncrementBy(1));
+        let older = store.snapshot(codec).unwrap();
+        store.dispatch(MyAction::IncrementBy(1));
+        let mut newest = store.snapshot(codec).unwrap();
+        newest.truncate(newest.len() - 1);
+
+        let restored = Store::restore_latest(reducer, codec, [&newest[..], &older[..]]).unwrap();
+
+        assert_eq!(*restored.state(), Counter { value: 1 });
+    }
+
+    #[test]
+    fn should_return_last_error_when_every_snapshot_is_corrupted() {
+        let codec = Codec::Json.checksummed();
+        let restored = Store::<Counter, MyAction>::restore_latest(reducer, codec, [&b"{"[..]]);
+
+        assert!(matches!(restored, Err(CodecError::Decode(_))));
+    }
+
+    #[test]
+    fn should_replay_action_log_when_snapshot_is_corrupted() {
+        let codec = Codec::Json.checksummed();
+        let log = Fixture {
+            initial_state: Counter { value: 0 },
+            actions: vec![MyAction::IncrementBy(2), MyAction::IncrementBy(3)],
+        }
+        .encode(codec)
+        .unwrap();
+        let mut snapshot = Store::new(reducer, Counter { value: 5 })
+            .snapshot(codec)
+            .unwrap();
+        snapshot[4] ^= 1;
+
+        let restored = Store::restore_or_replay(reducer, codec, &snapshot, &log).unwrap();
+
+        assert_eq!(*restored.state(), Counter { value: 5 });
+    }
+}
@@ -0,0 +1,219 @@
+#[cfg(test)]
+mod concurrent {
+    use redust::{DispatchError, HistoryPolicy, Next, Store};
+    use std::sync::{Arc, Barrier};
+    use std::thread;
+    use std::time::Duration;
+
+    type MyStore = u8;
+
+    #[derive(Debug, Clone)]
+    enum MyAction {
+        Increment,
+        Panic,
+        PanicThenIncrement,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Increment => state + 1,
+            MyAction::Panic => panic!("reducer failed"),
+            MyAction::PanicThenIncrement => *state,
+        }
+    }
+
+    // Queues `Panic` and then `Increment` after `PanicThenIncrement`
+    fn double(
+        action: MyAction,
+        next: Next<'_, MyStore, MyAction>,
+    ) -> Result<MyAction, DispatchError> {
+        next.run_and_dispatch(action, |action, _state| match action {
+            MyAction::Increment => vec![],
+            MyAction::Panic => vec![],
+            MyAction::PanicThenIncrement => vec![MyAction::Panic, MyAction::Increment],
+        })
+    }
+
+    #[test]
+    fn should_allow_many_readers_at_the_same_time() {
+        let store = Arc::new(Store::new(reducer, 0).into_concurrent());
+        let barrier = Arc::new(Barrier::new(4));
+
+        let readers: Vec<_> = (0..4)
+            .map(|_| {
+                let store = Arc::clone(&store);
+                let barrier = Arc::clone(&barrier);
+                thread::spawn(move || {
+                    let state = store.read();
+                    // Every reader holds its guard until all of them got one
+                    barrier.wait();
+                    *state
+                })
+            })
+            .collect();
+
+        assert!(readers
+            .into_iter()
+            .all(|reader| reader.join().unwrap() == 0));
+        assert_eq!(store.metrics().reads, 4);
+    }
+
+    #[test]
+    fn should_count_contended_write_when_reader_holds_guard() {
+        let store = Arc::new(Store::new(reducer, 0).into_concurrent());
+        let guard = store.read();
+
+        let writer = {
+            let store = Arc::clone(&store);
+            thread::spawn(move || {
+                store.dispatch(MyAction::Increment);
+            })
+        };
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(*guard, 0);
+        drop(guard);
+        writer.join().unwrap();
+
+        assert_eq!(*store.read(), 1);
+        assert_eq!(store.metrics().contended_writes, 1);
+    }
+
+    #[test]
+    fn should_never_expose_intermediate_state_when_batch_is_dispatched() {
+        let store = Arc::new(Store::new(reducer, 0).into_concurrent());
+
+        let reader = {
+            let store = Arc::clone(&store);
+            thread::spawn(move || {
+                (0..1000)
+                    .map(|_| *store.read())
+                    .all(|state| state % 10 == 0)
+            })
+        };
+
+        for _ in 0..20 {
+            store.dispatch_batch((0..10).map(|_| MyAction::Increment));
+        }
+
+        assert!(reader.join().unwrap());
+        assert_eq!(*store.read(), 200);
+    }
+
+    #[test]
+    fn should_recover_when_reducer_panicked_during_dispatch() {
+        let store = Arc::new(Store::new(reducer, 0).into_concurrent());
+        store.dispatch(MyAction::Increment);
+
+        let panicked = {
+            let store = Arc::clone(&store);
+            thread::spawn(move || {
+                store.dispatch(MyAction::Panic);
+            })
+            .join()
+            .is_err()
+        };
+
+        assert!(panicked);
+        assert_eq!(*store.read(), 1);
+        assert_eq!(store.metrics().poison_recoveries, 1);
+
+        store.dispatch(MyAction::Increment);
+        assert_eq!(*store.read(), 2);
+    }
+
+    #[test]
+    fn should_restore_state_before_batch_when_reducer_panicked_during_batch() {
+        let store = Arc::new(Store::new(reducer, 0).into_concurrent());
+        store.dispatch(MyAction::Increment);
+
+        let panicked = {
+            let store = Arc::clone(&store);
+            thread::spawn(move || {
+                store.dispatch_batch(vec![
+                    MyAction::Increment,
+                    MyAction::Panic,
+                    MyAction::Increment,
+                ]);
+            })
+            .join()
+            .is_err()
+        };
+
+        assert!(panicked);
+        assert_eq!(*store.read(), 1);
+
+        store.dispatch_batch(vec![MyAction::Increment, MyAction::Increment]);
+        assert_eq!(*store.read(), 3);
+    }
+
+    #[test]
+    fn should_restore_history_and_last_action_when_reducer_panicked_during_batch() {
+        let mut store = Store::new(reducer, 0);
+        store.enable_history(HistoryPolicy::unbounded());
+        let store = Arc::new(store.into_concurrent());
+        store.dispatch(MyAction::Increment);
+
+        let panicked = {
+            let store = Arc::clone(&store);
+            thread::spawn(move || {
+                store.dispatch_batch(vec![MyAction::PanicThenIncrement, MyAction::Panic]);
+            })
+            .join()
+            .is_err()
+        };
+
+        assert!(panicked);
+        let store = store.write();
+        assert_eq!(store.history_len(), 2);
+        assert!(matches!(
+            store.history_actions().as_slice(),
+            [MyAction::Increment]
+        ));
+        assert!(matches!(store.last_action(), Some(MyAction::Increment)));
+    }
+
+    #[test]
+    fn should_keep_middleware_when_reducer_panicked_during_dispatch() {
+        let mut store = Store::new(reducer, 0);
+        store.add_middleware("double", double);
+        let store = Arc::new(store.into_concurrent());
+
+        let panicked = {
+            let store = Arc::clone(&store);
+            thread::spawn(move || {
+                store.dispatch(MyAction::Panic);
+            })
+            .join()
+            .is_err()
+        };
+
+        assert!(panicked);
+        store.dispatch(MyAction::Increment);
+        assert_eq!(
+            store.write().middleware().describe(),
+            "1. double\n2. reducer"
+        );
+        assert_eq!(*store.read(), 1);
+    }
+
+    #[test]
+    fn should_dispatch_queued_actions_when_queued_action_panicked() {
+        let mut store = Store::new(reducer, 0);
+        store.add_middleware("double", double);
+        let store = Arc::new(store.into_concurrent());
+
+        let panicked = {
+            let store = Arc::clone(&store);
+            thread::spawn(move || {
+                store.dispatch(MyAction::PanicThenIncrement);
+            })
+            .join()
+            .is_err()
+        };
+
+        assert!(panicked);
+        // The `Increment` queued after `Panic` is dispatched with the next action
+        store.dispatch(MyAction::Increment);
+        assert_eq!(*store.read(), 2);
+    }
+}
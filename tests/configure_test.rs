@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod configure {
+    use redust::{configure_store, Store};
+    use std::hash::{Hash, Hasher};
+    use std::sync::atomic::{AtomicU8, Ordering};
+    use std::sync::Arc;
+
+    type MyStore = u8;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Increment,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Increment => state + 1,
+        }
+    }
+
+    #[test]
+    fn should_reduce_actions_when_store_was_configured() {
+        let mut store = configure_store(reducer, 0);
+
+        store.dispatch(MyAction::Increment);
+
+        assert_eq!(*store.state(), 1);
+    }
+
+    #[test]
+    fn should_add_logger_only_in_debug_builds_when_store_was_configured() {
+        let store = configure_store(reducer, 0);
+
+        let expected: &[&str] = if cfg!(debug_assertions) {
+            &["logger"]
+        } else {
+            &[]
+        };
+        assert_eq!(store.middleware().names(), expected);
+    }
+
+    #[test]
+    fn should_report_subscriber_panics_when_store_was_configured() {
+        let mut store = configure_store(reducer, 0);
+        store.subscribe(|_state| panic!("Broken subscriber"));
+
+        store
+            .dispatch(MyAction::Increment)
+            .dispatch(MyAction::Increment);
+
+        assert_eq!(*store.state(), 2);
+        assert_eq!(store.take_subscriber_errors().len(), 2);
+    }
+
+    #[test]
+    fn should_keep_builder_options_when_store_was_configured() {
+        let mut store = Store::configure(reducer, 0).notifications_paused().build();
+
+        store.dispatch(MyAction::Increment);
+
+        assert_eq!(*store.state(), 1);
+    }
+
+    #[test]
+    fn should_add_logger_when_builder_enabled_it() {
+        let store = Store::builder(reducer, 0).logger().crash_reporter().build();
+
+        assert_eq!(store.middleware().names(), ["logger"]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Reducer mutated the previous state while reducing Increment")]
+    fn should_detect_mutations_in_debug_builds_when_store_was_configured() {
+        #[derive(Debug)]
+        struct Shared(Arc<AtomicU8>);
+
+        impl Hash for Shared {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.0.load(Ordering::SeqCst).hash(state);
+            }
+        }
+
+        fn mutating_reducer(state: &Shared, _action: &MyAction) -> Shared {
+            state.0.fetch_add(1, Ordering::SeqCst);
+
+            Shared(Arc::clone(&state.0))
+        }
+
+        let mut store = configure_store(mutating_reducer, Shared(Arc::new(AtomicU8::new(0))));
+
+        store.dispatch(MyAction::Increment);
+    }
+}
@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod context {
+    use redust::{ContextError, DispatchError, Middleware, Next, Store};
+    use std::sync::Arc;
+
+    type MyStore = Vec<String>;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum MyAction {
+        Load,
+        Loaded(Vec<String>),
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Load => state.clone(),
+            MyAction::Loaded(users) => users.clone(),
+        }
+    }
+
+    struct Api {
+        users: Vec<String>,
+    }
+
+    fn api(users: &[&str]) -> Arc<Api> {
+        Arc::new(Api {
+            users: users.iter().map(|user| user.to_string()).collect(),
+        })
+    }
+
+    fn load_users(store: &mut Store<MyStore, MyAction>, api: &Api) -> usize {
+        store.dispatch(MyAction::Loaded(api.users.clone()));
+
+        api.users.len()
+    }
+
+    #[test]
+    fn should_pass_context_to_thunk_when_context_was_set() {
+        let mut store = Store::new(reducer, vec![]);
+        store.set_context(api(&["Ann", "Bob"]));
+
+        assert_eq!(store.dispatch_thunk(load_users), Ok(2));
+        assert_eq!(*store.state(), ["Ann", "Bob"]);
+    }
+
+    #[test]
+    fn should_use_swapped_context_when_context_was_replaced() {
+        let mut store = Store::new(reducer, vec![]);
+        store.set_context(api(&["Ann"]));
+        store.set_context(api(&["Fake"]));
+
+        store.dispatch_thunk(load_users).unwrap();
+
+        assert_eq!(*store.state(), ["Fake"]);
+    }
+
+    #[test]
+    fn should_not_run_thunk_when_context_is_missing() {
+        let mut store = Store::new(reducer, vec![]);
+
+        assert_eq!(
+            store.dispatch_thunk(load_users),
+            Err(ContextError::Missing {
+                expected: std::any::type_name::<Api>()
+            })
+        );
+        assert!(store.state().is_empty());
+    }
+
+    #[test]
+    fn should_not_run_thunk_when_context_has_another_type() {
+        let mut store = Store::new(reducer, vec![]);
+        store.set_context(Arc::new(42_u32));
+
+        assert!(store.dispatch_thunk(load_users).is_err());
+        assert_eq!(store.context::<u32>().as_deref(), Some(&42));
+    }
+
+    #[test]
+    fn should_return_none_when_context_was_removed() {
+        let mut store = Store::builder(reducer, vec![])
+            .context(api(&["Ann"]))
+            .build();
+        assert!(store.context::<Api>().is_some());
+
+        store.remove_context();
+
+        assert!(store.context::<Api>().is_none());
+    }
+
+    struct LoadUsers;
+
+    impl Middleware<MyStore, MyAction> for LoadUsers {
+        fn handle(
+            &self,
+            action: MyAction,
+            next: Next<'_, MyStore, MyAction>,
+        ) -> Result<MyAction, DispatchError> {
+            match (&action, next.context::<Api>()) {
+                (MyAction::Load, Some(api)) => next.run(MyAction::Loaded(api.users.clone())),
+                _ => next.run(action),
+            }
+        }
+    }
+
+    #[test]
+    fn should_pass_context_to_middleware_when_context_was_set() {
+        let mut store = Store::new(reducer, vec![]);
+        store.add_middleware("load-users", LoadUsers);
+        store.set_context(api(&["Ann"]));
+
+        store.dispatch(MyAction::Load);
+
+        assert_eq!(*store.state(), ["Ann"]);
+    }
+}
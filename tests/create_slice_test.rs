@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod create_slice {
+    use redust::{create_slice, Store};
+
+    #[derive(Debug, Clone, Default, PartialEq)]
+    pub struct Todos {
+        items: Vec<String>,
+        filter: String,
+    }
+
+    const DEFAULT_FILTER: &str = "all";
+
+    create_slice! {
+        mod todos {
+            state: Todos = Todos {
+                items: vec![],
+                filter: DEFAULT_FILTER.to_string(),
+            };
+
+            #[derive(Debug, Clone, PartialEq)]
+            action: TodosAction;
+
+            reducers {
+                Add { title: String } => fn add(state) {
+                    state.items.push(title.clone());
+                }
+                Remove { index: usize } => fn remove(state) {
+                    if *index < state.items.len() {
+                        state.items.remove(*index);
+                    }
+                }
+                SetFilter { filter: String, } => fn set_filter(state) {
+                    state.filter = filter.clone();
+                }
+                Clear {} => fn clear(state) {
+                    *state = initial_state();
+                }
+            }
+
+            selectors {
+                fn count(state) -> usize {
+                    state.items.len()
+                }
+                fn filter(state) -> &str {
+                    &state.filter
+                }
+            }
+        }
+    }
+
+    create_slice! {
+        pub mod flag {
+            state: bool = false;
+
+            #[derive(Debug)]
+            action: FlagAction;
+
+            reducers {
+                Toggle {} => fn toggle(state) {
+                    *state = !*state;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn should_start_from_initial_state_when_slice_was_created() {
+        let store = Store::new(todos::reducer, todos::initial_state());
+
+        assert_eq!(todos::count(store.state()), 0);
+        assert_eq!(todos::filter(store.state()), "all");
+        assert_eq!(todos::NAME, "todos");
+    }
+
+    #[test]
+    fn should_run_case_reducers_when_actions_were_dispatched() {
+        let mut store = Store::new(todos::reducer, todos::initial_state());
+
+        store
+            .dispatch(todos::add("Buy milk".to_string()))
+            .dispatch(todos::add("Walk the dog".to_string()))
+            .dispatch(todos::remove(0))
+            .dispatch(todos::set_filter("done".to_string()));
+
+        assert_eq!(store.state().items, ["Walk the dog"]);
+        assert_eq!(todos::filter(store.state()), "done");
+    }
+
+    #[test]
+    fn should_reset_state_when_case_reducer_replaced_it() {
+        let mut store = Store::new(todos::reducer, todos::initial_state());
+
+        store
+            .dispatch(todos::add("Buy milk".to_string()))
+            .dispatch(todos::clear());
+
+        assert_eq!(*store.state(), todos::initial_state());
+    }
+
+    #[test]
+    fn should_not_change_previous_state_when_action_was_reduced() {
+        let state = todos::initial_state();
+
+        let new_state = todos::reducer(&state, &todos::add("Buy milk".to_string()));
+
+        assert_eq!(todos::count(&state), 0);
+        assert_eq!(todos::count(&new_state), 1);
+    }
+
+    #[test]
+    fn should_build_typed_actions_when_constructors_were_called() {
+        assert_eq!(todos::remove(1), todos::TodosAction::Remove { index: 1 });
+        assert_eq!(format!("{:?}", flag::toggle()), "Toggle");
+    }
+
+    #[test]
+    fn should_generate_slice_without_selectors_when_they_were_omitted() {
+        let mut store = Store::new(flag::reducer, flag::initial_state());
+
+        store.dispatch(flag::toggle());
+
+        assert!(*store.state());
+    }
+}
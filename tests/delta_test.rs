@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod delta {
+    use redust::{NotifyPolicy, Store};
+    use std::collections::BTreeMap;
+    use std::sync::Mutex;
+
+    type MyStore = BTreeMap<&'static str, u32>;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Set(&'static str, u32),
+        Remove(&'static str),
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Change {
+        Set(&'static str, u32),
+        Removed(&'static str),
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        let mut new_state = state.clone();
+        match action {
+            MyAction::Set(key, value) => {
+                new_state.insert(key, *value);
+            }
+            MyAction::Remove(key) => {
+                new_state.remove(key);
+            }
+        }
+
+        new_state
+    }
+
+    fn differ(old: &MyStore, new: &MyStore) -> Option<Vec<Change>> {
+        let set = new
+            .iter()
+            .filter(|(key, value)| old.get(*key) != Some(value))
+            .map(|(key, value)| Change::Set(key, *value));
+        let removed = old
+            .keys()
+            .filter(|key| !new.contains_key(*key))
+            .map(|key| Change::Removed(key));
+        let changes: Vec<_> = set.chain(removed).collect();
+
+        if changes.is_empty() {
+            None
+        } else {
+            Some(changes)
+        }
+    }
+
+    #[test]
+    fn should_receive_only_changes_when_action_was_dispatched() {
+        static DELTAS: Mutex<Vec<Vec<Change>>> = Mutex::new(Vec::new());
+
+        let mut store = Store::new(reducer, BTreeMap::from([("a", 1), ("b", 2)]));
+        store.subscribe_delta(differ, |changes| {
+            DELTAS.lock().unwrap().push(changes.clone())
+        });
+        store
+            .dispatch(MyAction::Set("b", 3))
+            .dispatch(MyAction::Remove("a"));
+
+        assert_eq!(
+            *DELTAS.lock().unwrap(),
+            [vec![Change::Set("b", 3)], vec![Change::Removed("a")]]
+        );
+    }
+
+    #[test]
+    fn should_not_be_called_when_differ_found_no_changes() {
+        static CALLS: Mutex<u32> = Mutex::new(0);
+
+        let mut store = Store::new(reducer, BTreeMap::from([("a", 1)]));
+        store.subscribe_delta(differ, |_| *CALLS.lock().unwrap() += 1);
+        store.dispatch(MyAction::Set("a", 1));
+
+        assert_eq!(*CALLS.lock().unwrap(), 0);
+    }
+
+    #[test]
+    fn should_receive_combined_delta_when_notifications_were_paused() {
+        static DELTAS: Mutex<Vec<Vec<Change>>> = Mutex::new(Vec::new());
+
+        let mut store = Store::new(reducer, BTreeMap::new());
+        store.subscribe_delta(differ, |changes| {
+            DELTAS.lock().unwrap().push(changes.clone())
+        });
+        store.pause_notifications();
+        store
+            .dispatch(MyAction::Set("a", 1))
+            .dispatch(MyAction::Set("b", 2))
+            .dispatch(MyAction::Remove("a"));
+        store.resume_notifications(NotifyPolicy::CatchUp);
+
+        assert_eq!(*DELTAS.lock().unwrap(), [vec![Change::Set("b", 2)]]);
+    }
+}
@@ -0,0 +1,186 @@
+#[cfg(test)]
+mod derived {
+    use redust::Store;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Clone)]
+    struct AppState {
+        prices: Vec<u32>,
+        discount: u32,
+        clicks: u8,
+    }
+
+    #[derive(Debug)]
+    enum MyAction {
+        AddPrice(u32),
+        SetDiscount(u32),
+        Click,
+    }
+
+    fn reducer(state: &AppState, action: &MyAction) -> AppState {
+        let mut new_state = state.clone();
+        match action {
+            MyAction::AddPrice(price) => new_state.prices.push(*price),
+            MyAction::SetDiscount(discount) => new_state.discount = *discount,
+            MyAction::Click => new_state.clicks += 1,
+        }
+
+        new_state
+    }
+
+    fn create_store() -> Store<AppState, MyAction> {
+        Store::new(
+            reducer,
+            AppState {
+                prices: vec![10],
+                discount: 0,
+                clicks: 0,
+            },
+        )
+    }
+
+    #[test]
+    fn should_compute_selectors_when_created() {
+        let mut store = create_store();
+        let derived = store.derived_state();
+
+        let prices = derived.select(|state: &AppState| state.prices.clone());
+        let total = derived.derive(&prices, |prices| prices.iter().sum::<u32>());
+
+        assert_eq!(prices.get(), vec![10]);
+        assert_eq!(total.get(), 10);
+        assert_eq!(derived.len(), 2);
+        assert_eq!(derived.recomputations(), 0);
+    }
+
+    #[test]
+    fn should_update_chained_selectors_when_state_changed() {
+        let mut store = create_store();
+        let derived = store.derived_state();
+
+        let prices = derived.select(|state: &AppState| state.prices.clone());
+        let discount = derived.select(|state: &AppState| state.discount);
+        let total = derived.derive(&prices, |prices| prices.iter().sum::<u32>());
+        let to_pay = derived.combine(&total, &discount, |total, discount| {
+            total.saturating_sub(*discount)
+        });
+
+        store
+            .dispatch(MyAction::AddPrice(5))
+            .dispatch(MyAction::SetDiscount(3));
+
+        assert_eq!(total.get(), 15);
+        assert_eq!(to_pay.get(), 12);
+    }
+
+    #[test]
+    fn should_recompute_only_state_selectors_when_inputs_did_not_change() {
+        let mut store = create_store();
+        let derived = store.derived_state();
+
+        let prices = derived.select(|state: &AppState| state.prices.clone());
+        let discount = derived.select(|state: &AppState| state.discount);
+        let total = derived.derive(&prices, |prices| prices.iter().sum::<u32>());
+        derived.combine(&total, &discount, |total, discount| total - discount);
+
+        store.dispatch(MyAction::Click);
+
+        // Only `prices` and `discount`
+        assert_eq!(derived.recomputations(), 2);
+    }
+
+    #[test]
+    fn should_recompute_only_dirty_subgraph_when_one_input_changed() {
+        let mut store = create_store();
+        let derived = store.derived_state();
+
+        let prices = derived.select(|state: &AppState| state.prices.clone());
+        let discount = derived.select(|state: &AppState| state.discount);
+        let total = derived.derive(&prices, |prices| prices.iter().sum::<u32>());
+        derived.derive(&total, |total| total * 2);
+        let to_pay = derived.combine(&total, &discount, |total, discount| total - discount);
+
+        store.dispatch(MyAction::SetDiscount(4));
+
+        // `prices`, `discount` and `to_pay`, the `total` chain is skipped
+        assert_eq!(derived.recomputations(), 3);
+        assert_eq!(to_pay.get(), 6);
+    }
+
+    #[test]
+    fn should_stop_propagation_when_derived_value_did_not_change() {
+        let mut store = create_store();
+        let derived = store.derived_state();
+
+        let prices = derived.select(|state: &AppState| state.prices.clone());
+        let count = derived.derive(&prices, |prices| prices.len() > 1);
+        derived.derive(&count, |many| if *many { "many" } else { "few" });
+
+        store.dispatch(MyAction::AddPrice(1));
+        let recomputations = derived.recomputations();
+        store.dispatch(MyAction::AddPrice(2));
+
+        // `count` stays `true`, so the label is not computed again
+        assert_eq!(derived.recomputations(), recomputations + 2);
+    }
+
+    #[test]
+    fn should_recompute_shared_dependent_once_when_both_inputs_changed() {
+        let mut store = create_store();
+        let derived = store.derived_state();
+
+        let prices = derived.select(|state: &AppState| state.prices.clone());
+        let first = derived.derive(&prices, |prices| prices.len());
+        let second = derived.derive(&prices, |prices| prices.iter().sum::<u32>());
+        let both = derived.combine(&first, &second, |count, sum| format!("{}/{}", count, sum));
+
+        store.dispatch(MyAction::AddPrice(5));
+
+        assert_eq!(derived.recomputations(), 4);
+        assert_eq!(both.get(), "2/15");
+    }
+
+    #[test]
+    fn should_stop_recomputing_when_unsubscribed() {
+        let mut store = create_store();
+        let derived = store.derived_state();
+        let prices = derived.select(|state: &AppState| state.prices.clone());
+
+        store.unsubscribe(derived.token()).unwrap();
+        store.dispatch(MyAction::AddPrice(5));
+
+        assert_eq!(derived.recomputations(), 0);
+        assert_eq!(prices.get(), vec![10]);
+    }
+
+    #[test]
+    fn should_stop_recomputing_when_dropped() {
+        static SELECTED: AtomicUsize = AtomicUsize::new(0);
+
+        let mut store = create_store();
+        let derived = store.derived_state();
+        let clicks = derived.select(|state: &AppState| {
+            SELECTED.fetch_add(1, Ordering::SeqCst);
+            state.clicks
+        });
+
+        store.dispatch(MyAction::Click);
+        drop(clicks);
+        drop(derived);
+        store.dispatch(MyAction::Click);
+
+        // Once when created and once after the first dispatch
+        assert_eq!(SELECTED.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot use a selector of another derived state")]
+    fn should_panic_when_input_belongs_to_another_derived_state() {
+        let mut store = create_store();
+        let first = store.derived_state();
+        let second = store.derived_state();
+
+        let prices = first.select(|state: &AppState| state.prices.clone());
+        second.derive(&prices, |prices| prices.len());
+    }
+}
@@ -0,0 +1,196 @@
+#![cfg(feature = "serde")]
+
+#[cfg(test)]
+mod dump {
+    use redust::dump::{DumpError, DumpFormat};
+    use redust::Store;
+    use serde::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Item {
+        title: String,
+        done: bool,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Settings {
+        theme: Option<String>,
+        scale: f64,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct MyStore {
+        user: String,
+        visits: i64,
+        tags: Vec<String>,
+        settings: Settings,
+        items: Vec<Item>,
+        counters: BTreeMap<String, u8>,
+    }
+
+    #[derive(Debug)]
+    enum MyAction {
+        Visit,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Visit => MyStore {
+                visits: state.visits + 1,
+                ..state.clone()
+            },
+        }
+    }
+
+    fn state() -> MyStore {
+        MyStore {
+            user: "Ann \"the admin\"\n".to_string(),
+            visits: 0,
+            tags: vec!["a".to_string(), "b c".to_string()],
+            settings: Settings {
+                theme: None,
+                scale: 1.5,
+            },
+            items: vec![
+                Item {
+                    title: "milk".to_string(),
+                    done: false,
+                },
+                Item {
+                    title: "bread".to_string(),
+                    done: true,
+                },
+            ],
+            counters: vec![("first key".to_string(), 1)].into_iter().collect(),
+        }
+    }
+
+    fn assert_round_trip(format: DumpFormat) {
+        let mut store = Store::new(reducer, state());
+        store.dispatch(MyAction::Visit);
+
+        let dump = store.export(format).unwrap();
+        let imported = Store::import(reducer, dump.as_bytes(), format).unwrap();
+
+        assert_eq!(imported.state(), store.state());
+    }
+
+    #[test]
+    fn should_import_exported_state_when_json_is_used() {
+        assert_round_trip(DumpFormat::Json);
+    }
+
+    #[test]
+    fn should_import_hand_edited_state_when_json_is_used() {
+        let store = Store::new(reducer, state());
+        let dump = store
+            .export(DumpFormat::Json)
+            .unwrap()
+            .replace("\"visits\": 0", "\"visits\": 42");
+
+        let imported = Store::import(reducer, dump.as_bytes(), DumpFormat::Json).unwrap();
+
+        assert_eq!(imported.state().visits, 42);
+    }
+
+    #[test]
+    fn should_return_error_when_dump_does_not_match_the_state() {
+        let imported = Store::<MyStore, MyAction>::import(reducer, &b"{}"[..], DumpFormat::Json);
+
+        assert!(matches!(imported, Err(DumpError::Import(_))));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn should_import_exported_state_when_toml_is_used() {
+        assert_round_trip(DumpFormat::Toml);
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn should_write_tables_and_arrays_of_tables_when_toml_is_used() {
+        let dump = Store::new(reducer, state())
+            .export(DumpFormat::Toml)
+            .unwrap();
+
+        assert!(dump.starts_with("tags = [\"a\", \"b c\"]\n"));
+        assert!(dump.contains("\n[counters]\n\"first key\" = 1\n"));
+        assert!(dump.contains("\n[[items]]\ndone = false\ntitle = \"milk\"\n"));
+        assert!(dump.contains("\n[settings]\nscale = 1.5\n"));
+        assert!(!dump.contains("theme"));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn should_import_hand_written_state_when_toml_is_used() {
+        let dump = r#"
+            user = "Bob"
+            visits = 3
+            tags = []
+            counters = { }
+            settings.scale = 2.0
+            settings.theme = "dark"
+
+            [[items]]
+            title = "tea"
+            done = true
+        "#;
+
+        let imported = Store::import(reducer, dump.as_bytes(), DumpFormat::Toml).unwrap();
+
+        assert_eq!(imported.state().settings.theme.as_deref(), Some("dark"));
+        assert_eq!(imported.state().items[0].title, "tea");
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn should_return_error_when_state_is_not_a_table_for_toml() {
+        let store = Store::new(|state: &u8, _action: &MyAction| *state, 1);
+
+        assert!(matches!(
+            store.export(DumpFormat::Toml),
+            Err(DumpError::Export(_))
+        ));
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn should_import_exported_state_when_yaml_is_used() {
+        assert_round_trip(DumpFormat::Yaml);
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn should_write_nested_state_when_yaml_is_used() {
+        let dump = Store::new(reducer, state())
+            .export(DumpFormat::Yaml)
+            .unwrap();
+
+        assert!(dump.contains("\nsettings:\n  theme: null\n  scale: 1.5\n"));
+        assert!(dump.contains("\nitems:\n- title: milk\n  done: false\n"));
+        assert!(dump.contains("\ncounters:\n  first key: 1\n"));
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn should_import_hand_written_state_when_yaml_is_used() {
+        let dump = r#"
+user: Bob
+visits: 3
+tags: []
+counters: {}
+settings:
+  scale: 2.0
+  theme: dark
+items:
+  - title: tea
+    done: true
+"#;
+
+        let imported = Store::import(reducer, dump.as_bytes(), DumpFormat::Yaml).unwrap();
+
+        assert_eq!(imported.state().settings.theme.as_deref(), Some("dark"));
+        assert_eq!(imported.state().items[0].title, "tea");
+    }
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "serde")]
+
+#[cfg(test)]
+mod dynamic {
+    use redust::Store;
+    use serde_json::{json, Value};
+
+    fn todos(state: &Value, action: &Value) -> Value {
+        let mut todos = state.as_array().cloned().unwrap_or_default();
+        if action["type"] == "todos/add" {
+            todos.push(action["text"].clone());
+        }
+
+        Value::Array(todos)
+    }
+
+    fn filter(state: &Value, action: &Value) -> Value {
+        match action["type"].as_str() {
+            Some("filter/set") => action["filter"].clone(),
+            _ => state.clone(),
+        }
+    }
+
+    #[test]
+    fn should_reduce_each_path_when_action_was_dispatched() {
+        let mut store = Store::dynamic(json!({ "filter": "all" }));
+        store.register_reducer("/todos", todos);
+        store.register_reducer("/ui/filter", filter);
+
+        store
+            .dispatch(json!({ "type": "todos/add", "text": "milk" }))
+            .dispatch(json!({ "type": "filter/set", "filter": "done" }));
+
+        assert_eq!(
+            *store.state().value(),
+            json!({ "filter": "all", "todos": ["milk"], "ui": { "filter": "done" } })
+        );
+        assert_eq!(store.state().get("/todos/0"), Some(&json!("milk")));
+        assert_eq!(store.state().paths(), ["/todos", "/ui/filter"]);
+    }
+
+    #[test]
+    fn should_keep_value_when_reducer_was_unregistered() {
+        let mut store = Store::dynamic(json!({}));
+        store.register_reducer("/todos", todos);
+        store.dispatch(json!({ "type": "todos/add", "text": "milk" }));
+
+        assert!(store.unregister_reducer("/todos"));
+        assert!(!store.unregister_reducer("/todos"));
+        store.dispatch(json!({ "type": "todos/add", "text": "bread" }));
+
+        assert_eq!(*store.state().value(), json!({ "todos": ["milk"] }));
+    }
+
+    #[test]
+    fn should_replace_reducer_when_path_was_registered_again() {
+        let mut store = Store::dynamic(json!({ "todos": "all" }));
+        store.register_reducer("/todos", todos);
+        store.register_reducer("/todos", filter);
+        store.dispatch(json!({ "type": "filter/set", "filter": "done" }));
+
+        assert_eq!(store.state().paths(), ["/todos"]);
+        assert_eq!(*store.state().value(), json!({ "todos": "done" }));
+    }
+
+    #[test]
+    fn should_reduce_whole_state_when_path_is_empty() {
+        let mut store = Store::dynamic(json!(null));
+        store.register_reducer("", filter);
+        store.dispatch(json!({ "type": "filter/set", "filter": [1, 2] }));
+
+        assert_eq!(*store.state().value(), json!([1, 2]));
+    }
+}
@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod effect {
+    use redust::{spawn_effect, CancellationToken, DispatchQueue, Store, TakeLatest};
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum MyAction {
+        Search(u64),
+        Results(u64),
+    }
+
+    type MyStore = Vec<u64>;
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        let mut results = state.clone();
+        if let MyAction::Results(query) = action {
+            results.push(*query);
+        }
+
+        results
+    }
+
+    // Slower for smaller queries, so older searches finish last
+    fn search(action: &MyAction, token: &CancellationToken) -> Option<MyAction> {
+        let MyAction::Search(query) = action else {
+            return None;
+        };
+        thread::sleep(Duration::from_millis(60 - query * 20));
+
+        (!token.is_cancelled()).then_some(MyAction::Results(*query))
+    }
+
+    #[test]
+    fn should_dispatch_effect_action_when_it_was_not_cancelled() {
+        let queue = DispatchQueue::new();
+        let handle = spawn_effect(queue.dispatcher(), |_| Some(MyAction::Results(1)));
+        handle.join().unwrap();
+
+        assert_eq!(queue.pop(), Some(MyAction::Results(1)));
+    }
+
+    #[test]
+    fn should_drop_effect_action_when_it_was_cancelled() {
+        let queue = DispatchQueue::new();
+        let (started, wait_started) = mpsc::channel();
+        let (resume, wait_resume) = mpsc::channel::<()>();
+        let handle = spawn_effect(queue.dispatcher(), move |_| {
+            started.send(()).unwrap();
+            wait_resume.recv().unwrap();
+
+            Some(MyAction::Results(1))
+        });
+
+        wait_started.recv().unwrap();
+        handle.cancel();
+        resume.send(()).unwrap();
+
+        assert!(handle.token().is_cancelled());
+        handle.join().unwrap();
+        assert_eq!(queue.pop(), None);
+    }
+
+    #[test]
+    fn should_keep_only_latest_result_when_matching_actions_overlap() {
+        let queue = DispatchQueue::new();
+        let take_latest = TakeLatest::new(
+            queue.dispatcher(),
+            |action| matches!(action, MyAction::Search(_)),
+            search,
+        );
+
+        let mut store = Store::new(reducer, vec![]);
+        store.add_middleware("search", take_latest.clone());
+        store
+            .dispatch(MyAction::Search(0))
+            .dispatch(MyAction::Search(1))
+            .dispatch(MyAction::Search(2));
+
+        take_latest.wait();
+        thread::sleep(Duration::from_millis(80));
+        store.drain(&queue);
+
+        assert_eq!(*store.state(), [2]);
+    }
+}
@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod emitter {
+    use redust::{EmitterError, EventEmitter, Store, UnsubscribeError};
+    use std::sync::Mutex;
+
+    #[derive(Debug, Clone, Default)]
+    struct MyStore {
+        todos: Vec<&'static str>,
+        clicks: u8,
+    }
+
+    #[derive(Debug)]
+    enum MyAction {
+        AddTodo(&'static str),
+        Click,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        let mut new_state = state.clone();
+        match action {
+            MyAction::AddTodo(todo) => new_state.todos.push(todo),
+            MyAction::Click => new_state.clicks += 1,
+        }
+
+        new_state
+    }
+
+    fn emitter() -> EventEmitter<MyStore, MyAction> {
+        let mut emitter = EventEmitter::new(Store::new(reducer, MyStore::default()));
+        emitter.topic("todos", |state: &MyStore| state.todos.len());
+
+        emitter
+    }
+
+    #[test]
+    fn should_emit_topic_only_when_selected_value_changed() {
+        static EVENTS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+        let mut emitter = emitter();
+        emitter
+            .on("change", |state| {
+                EVENTS
+                    .lock()
+                    .unwrap()
+                    .push(format!("change {}", state.clicks))
+            })
+            .unwrap();
+        emitter
+            .on("todos", |state| {
+                EVENTS
+                    .lock()
+                    .unwrap()
+                    .push(format!("todos {}", state.todos.len()))
+            })
+            .unwrap();
+
+        emitter
+            .dispatch(MyAction::Click)
+            .dispatch(MyAction::AddTodo("milk"));
+
+        assert_eq!(*EVENTS.lock().unwrap(), ["change 1", "change 1", "todos 1"]);
+    }
+
+    #[test]
+    fn should_not_call_listener_when_it_was_removed() {
+        static CALLS: Mutex<u8> = Mutex::new(0);
+
+        let mut emitter = emitter();
+        let handle = emitter
+            .on("todos", |_| *CALLS.lock().unwrap() += 1)
+            .unwrap();
+        emitter.off(handle).unwrap();
+        emitter.dispatch(MyAction::AddTodo("milk"));
+
+        assert_eq!(*CALLS.lock().unwrap(), 0);
+        assert_eq!(
+            emitter.off(handle),
+            Err(UnsubscribeError::WrongToken(handle.id()))
+        );
+    }
+
+    #[test]
+    fn should_return_error_when_topic_is_unknown() {
+        let mut emitter = emitter();
+
+        assert_eq!(
+            emitter.on("clicks", |_| {}),
+            Err(EmitterError::UnknownTopic("clicks".to_string()))
+        );
+        assert!(emitter.has_topic("change"));
+        assert!(emitter.has_topic("todos"));
+    }
+}
@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod entity {
+    use redust::{EntityAdapter, EntityState};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Todo {
+        id: u32,
+        done: bool,
+    }
+
+    const TODOS: EntityAdapter<u32, Todo> = EntityAdapter::new(|todo| todo.id);
+    const BY_DONE: EntityAdapter<u32, Todo> =
+        EntityAdapter::sorted(|todo| todo.id, |a, b| a.done.cmp(&b.done));
+
+    fn todo(id: u32, done: bool) -> Todo {
+        Todo { id, done }
+    }
+
+    #[test]
+    fn should_keep_existing_entity_when_it_is_added_again() {
+        let mut state = EntityState::new();
+        TODOS.add_many(&mut state, vec![todo(1, false), todo(1, true)]);
+
+        assert_eq!(state.ids, [1]);
+        assert_eq!(TODOS.select_by_id(&state, &1), Some(&todo(1, false)));
+    }
+
+    #[test]
+    fn should_replace_entity_in_place_when_it_is_upserted() {
+        let mut state = EntityState::new();
+        TODOS.add_many(&mut state, vec![todo(2, false), todo(1, false)]);
+        TODOS.upsert_one(&mut state, todo(2, true));
+
+        assert_eq!(
+            TODOS.select_all(&state).cloned().collect::<Vec<_>>(),
+            [todo(2, true), todo(1, false)]
+        );
+    }
+
+    #[test]
+    fn should_remove_id_when_entity_was_removed() {
+        let mut state = EntityState::new();
+        TODOS.set_all(&mut state, vec![todo(1, false), todo(2, false)]);
+        TODOS.remove_one(&mut state, &1);
+
+        assert_eq!(state.ids, [2]);
+        assert_eq!(TODOS.select_total(&state), 1);
+    }
+
+    #[test]
+    fn should_keep_ids_sorted_when_entities_are_added() {
+        let mut state = EntityState::new();
+        BY_DONE.add_many(
+            &mut state,
+            vec![todo(1, true), todo(2, false), todo(3, true), todo(4, false)],
+        );
+
+        assert_eq!(state.ids, [2, 4, 1, 3]);
+    }
+
+    #[test]
+    fn should_move_entity_when_update_changes_its_order() {
+        let mut state = EntityState::new();
+        BY_DONE.set_all(
+            &mut state,
+            vec![todo(1, false), todo(2, false), todo(3, true)],
+        );
+        BY_DONE.upsert_one(&mut state, todo(1, true));
+
+        assert_eq!(
+            BY_DONE.select_all(&state).cloned().collect::<Vec<_>>(),
+            [todo(2, false), todo(3, true), todo(1, true)]
+        );
+    }
+
+    #[test]
+    fn should_sort_entities_when_all_were_set() {
+        let mut state = EntityState::new();
+        BY_DONE.set_all(
+            &mut state,
+            vec![todo(1, true), todo(2, false), todo(2, true)],
+        );
+
+        assert_eq!(state.ids, [1, 2]);
+        assert!(BY_DONE.is_sorted());
+        assert!(!TODOS.is_sorted());
+    }
+}
@@ -0,0 +1,152 @@
+#[cfg(test)]
+mod env {
+    use redust::{DispatchError, HistoryPolicy, Next, Store};
+
+    type MyStore = Vec<String>;
+
+    struct Env {
+        locale: &'static str,
+        max_items: usize,
+    }
+
+    #[derive(Debug, Clone)]
+    enum MyAction {
+        Greet,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction, env: &Env) -> MyStore {
+        match action {
+            MyAction::Greet if state.len() < env.max_items => {
+                let greeting = match env.locale {
+                    "de" => "Hallo",
+                    _ => "Hello",
+                };
+                let mut greetings = state.clone();
+                greetings.push(greeting.to_string());
+
+                greetings
+            }
+            MyAction::Greet => state.clone(),
+        }
+    }
+
+    fn env(locale: &'static str) -> Env {
+        Env {
+            locale,
+            max_items: 2,
+        }
+    }
+
+    #[test]
+    fn should_pass_env_to_reducer_when_action_was_dispatched() {
+        let mut store = Store::with_env(reducer, vec![], env("de"));
+
+        store.dispatch(MyAction::Greet);
+
+        assert_eq!(*store.state(), ["Hallo"]);
+        assert_eq!(store.env().locale, "de");
+    }
+
+    #[test]
+    fn should_respect_env_limits_when_many_actions_were_dispatched() {
+        let mut store = Store::with_env(reducer, vec![], env("en"));
+
+        store
+            .dispatch(MyAction::Greet)
+            .dispatch(MyAction::Greet)
+            .dispatch(MyAction::Greet);
+
+        assert_eq!(store.state().len(), 2);
+    }
+
+    #[test]
+    fn should_use_new_env_when_env_was_replaced() {
+        let mut store = Store::with_env(reducer, vec![], env("en"));
+        store.dispatch(MyAction::Greet);
+
+        store.set_env(env("de"));
+        store.dispatch(MyAction::Greet);
+
+        assert_eq!(*store.state(), ["Hello", "Hallo"]);
+    }
+
+    #[test]
+    fn should_return_state_without_env_when_store_was_consumed() {
+        let mut store = Store::with_env(reducer, vec![], env("en"));
+        store.dispatch(MyAction::Greet);
+
+        let state: MyStore = store.into_inner().unwrap();
+
+        assert_eq!(state, ["Hello"]);
+    }
+
+    #[test]
+    fn should_replay_history_with_env_when_travelled_back() {
+        let mut store = Store::with_env(reducer, vec![], env("de"));
+        store.enable_history(HistoryPolicy::unbounded().snapshot_every(3));
+        store.dispatch(MyAction::Greet).dispatch(MyAction::Greet);
+
+        store.travel_back(1).unwrap();
+
+        assert_eq!(*store.state(), ["Hallo"]);
+    }
+
+    #[test]
+    fn should_keep_env_and_history_when_store_was_split_into_parts() {
+        let mut store = Store::with_env(reducer, vec![], env("de"));
+        store.enable_history(HistoryPolicy::unbounded());
+        store.dispatch(MyAction::Greet);
+
+        let parts = store.into_parts();
+        assert!(parts.reducer().is_none());
+        assert_eq!(parts.env.locale, "de");
+        assert_eq!(parts.history_len(), 2);
+        assert_eq!(parts.history_state(0).as_deref(), Some(&vec![]));
+
+        let mut store = Store::from_parts(parts);
+        store.dispatch(MyAction::Greet);
+        assert_eq!(*store.state(), ["Hallo", "Hallo"]);
+
+        store.travel_back(2).unwrap();
+        assert!(store.state().is_empty());
+    }
+
+    #[test]
+    fn should_split_store_into_parts_when_env_is_unit() {
+        fn reducer(state: &u8, _action: &MyAction, _env: &()) -> u8 {
+            state + 1
+        }
+
+        let store = Store::with_env(reducer, 0, ());
+        let parts = store.into_parts();
+
+        assert!(parts.env_reducer().is_some());
+        assert_eq!(
+            *Store::from_parts(parts).dispatch(MyAction::Greet).state(),
+            1
+        );
+    }
+
+    #[test]
+    fn should_keep_env_when_store_was_forked() {
+        let store = Store::with_env(reducer, vec![], env("de"));
+
+        let mut fork = store.fork();
+        fork.dispatch(MyAction::Greet);
+
+        assert_eq!(*fork.state(), ["Hallo"]);
+        assert!(store.state().is_empty());
+    }
+
+    #[test]
+    fn should_run_middleware_when_store_has_env() {
+        let mut store = Store::with_env(reducer, vec![], env("en"));
+        store.add_middleware("drop", |_action, _next: Next<'_, MyStore, MyAction>| {
+            Err(DispatchError::Intercepted)
+        });
+
+        store.dispatch(MyAction::Greet);
+
+        assert!(store.state().is_empty());
+    }
+}
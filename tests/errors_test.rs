@@ -0,0 +1,190 @@
+#[cfg(test)]
+mod errors {
+    use redust::{
+        DispatchError, ErrorBehavior, ErrorContext, ErrorPhase, ErrorPolicy, FreezePolicy,
+        PanicIsolation, Store, StoreError,
+    };
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    type MyStore = u8;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Increment,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Increment => state + 1,
+        }
+    }
+
+    #[test]
+    fn should_receive_dispatch_errors_when_store_is_frozen() {
+        let mut store = Store::new(reducer, 0);
+        let errors = store.errors();
+        store.freeze(FreezePolicy::Reject);
+
+        store.dispatch(MyAction::Increment);
+        assert!(store.try_dispatch(MyAction::Increment).is_err());
+
+        assert_eq!(
+            errors.try_iter().collect::<Vec<_>>(),
+            [
+                StoreError::Dispatch(DispatchError::Frozen),
+                StoreError::Dispatch(DispatchError::Frozen)
+            ]
+        );
+    }
+
+    #[test]
+    fn should_not_receive_errors_when_frozen_store_ignores_actions() {
+        let mut store = Store::new(reducer, 0);
+        let errors = store.errors();
+        store.freeze(FreezePolicy::Ignore);
+
+        store.dispatch(MyAction::Increment);
+
+        assert!(errors.try_recv().is_err());
+    }
+
+    #[test]
+    fn should_receive_subscriber_failures_when_they_are_reported() {
+        let mut store = Store::new(reducer, 0);
+        let errors = store.errors();
+        store.isolate_panics(PanicIsolation::Unsubscribe);
+        let failing = store.subscribe_fallible(ErrorPolicy::Report, |_| Err("offline".into()));
+        let panicking = store.subscribe(|_| panic!("boom"));
+
+        store.dispatch(MyAction::Increment);
+
+        let received: Vec<_> = errors.try_iter().collect();
+        assert_eq!(
+            received[0],
+            StoreError::Subscriber {
+                token: failing.id(),
+                message: "offline".to_string()
+            }
+        );
+        assert!(matches!(
+            received[1],
+            StoreError::Subscriber { token, .. } if token == panicking.id()
+        ));
+        assert_eq!(store.take_subscriber_errors().len(), 2);
+    }
+
+    #[test]
+    fn should_deliver_errors_to_every_live_receiver() {
+        let mut store = Store::new(reducer, 0);
+        let first = store.errors();
+        let dropped = store.errors();
+        drop(dropped);
+        let last = store.errors();
+
+        store.report_error(StoreError::Effect("timeout".to_string()));
+
+        assert_eq!(
+            first.try_recv(),
+            Ok(StoreError::Effect("timeout".to_string()))
+        );
+        assert_eq!(
+            last.try_recv(),
+            Ok(StoreError::Effect("timeout".to_string()))
+        );
+    }
+
+    #[test]
+    fn should_call_error_handler_with_context_when_dispatch_failed() {
+        static CONTEXTS: Mutex<Vec<ErrorContext>> = Mutex::new(Vec::new());
+
+        let mut store = Store::new(reducer, 0);
+        store.set_error_handler(|_, context| CONTEXTS.lock().unwrap().push(context.clone()));
+        store.dispatch(MyAction::Increment);
+        store.freeze(FreezePolicy::Reject);
+        store.dispatch(MyAction::Increment);
+
+        assert_eq!(
+            *CONTEXTS.lock().unwrap(),
+            [ErrorContext {
+                phase: ErrorPhase::Dispatch,
+                action: Some("Increment".to_string()),
+                version: 1,
+            }]
+        );
+    }
+
+    #[test]
+    fn should_call_error_handler_with_notify_phase_when_subscriber_failed() {
+        static PHASES: Mutex<Vec<(ErrorPhase, Option<String>)>> = Mutex::new(Vec::new());
+
+        let mut store = Store::new(reducer, 0);
+        store.set_error_handler(|_, context| {
+            PHASES
+                .lock()
+                .unwrap()
+                .push((context.phase, context.action.clone()))
+        });
+        store.subscribe_fallible(ErrorPolicy::Report, |_| Err("offline".into()));
+        store.dispatch(MyAction::Increment);
+        store.report_error(StoreError::Effect("timeout".to_string()));
+
+        assert_eq!(
+            *PHASES.lock().unwrap(),
+            [
+                (ErrorPhase::Notify, Some("Increment".to_string())),
+                (ErrorPhase::Effect, None)
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Dispatch failure while handling Increment")]
+    fn should_panic_in_debug_when_behavior_is_panic_in_debug() {
+        let mut store = Store::new(reducer, 0);
+        store.set_error_behavior(ErrorBehavior::PanicInDebug);
+        store.freeze(FreezePolicy::Reject);
+
+        store.dispatch(MyAction::Increment);
+    }
+
+    #[test]
+    fn should_use_behavior_when_error_handler_was_removed() {
+        static CALLS: Mutex<u8> = Mutex::new(0);
+
+        let mut store = Store::new(reducer, 0);
+        store.set_error_handler(|_, _| *CALLS.lock().unwrap() += 1);
+        store.remove_error_handler();
+        store.freeze(FreezePolicy::Reject);
+        store.dispatch(MyAction::Increment);
+
+        assert_eq!(*CALLS.lock().unwrap(), 0);
+    }
+
+    #[test]
+    fn should_describe_action_only_when_dispatch_failed() {
+        static DESCRIBED: AtomicUsize = AtomicUsize::new(0);
+        static CONTEXTS: Mutex<Vec<Option<String>>> = Mutex::new(Vec::new());
+
+        struct Counted;
+
+        impl std::fmt::Debug for Counted {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                DESCRIBED.fetch_add(1, Ordering::SeqCst);
+                write!(f, "Counted")
+            }
+        }
+
+        let mut store = Store::new(|state: &u8, _action: &Counted| state + 1, 0);
+        store.set_error_handler(|_, context| CONTEXTS.lock().unwrap().push(context.action.clone()));
+        store.dispatch(Counted).dispatch(Counted);
+        assert_eq!(DESCRIBED.load(Ordering::SeqCst), 0);
+
+        store.freeze(FreezePolicy::Reject);
+        store.dispatch(Counted);
+
+        assert_eq!(DESCRIBED.load(Ordering::SeqCst), 1);
+        assert_eq!(*CONTEXTS.lock().unwrap(), [Some("Counted".to_string())]);
+    }
+}
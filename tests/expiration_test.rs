@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod expiration {
+    use redust::{Expiration, Store};
+    use std::time::{Duration, SystemTime};
+
+    type MyStore = Vec<u32>;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum MyAction {
+        Started(u32),
+        Expired(u32),
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        let mut sessions = state.clone();
+        match action {
+            MyAction::Started(id) => sessions.push(*id),
+            MyAction::Expired(id) => sessions.retain(|session| session != id),
+        }
+
+        sessions
+    }
+
+    fn seconds(value: u64) -> SystemTime {
+        SystemTime::UNIX_EPOCH + Duration::from_secs(value)
+    }
+
+    #[test]
+    fn should_dispatch_only_due_expirations_in_time_order() {
+        let mut store = Store::new(reducer, vec![1, 2, 3]);
+        store.expire_at(seconds(30), MyAction::Expired(3));
+        store.expire_at(seconds(10), MyAction::Expired(1));
+        store.expire_at(seconds(20), MyAction::Expired(2));
+
+        assert_eq!(store.next_expiration(), Some(seconds(10)));
+        assert_eq!(store.dispatch_expired(seconds(20)), 2);
+        assert_eq!(*store.state(), vec![3]);
+        assert_eq!(store.next_expiration(), Some(seconds(30)));
+    }
+
+    #[test]
+    fn should_not_dispatch_when_expiration_was_cancelled() {
+        let mut store = Store::new(reducer, vec![1, 2]);
+        store.expire_at(seconds(10), MyAction::Expired(1));
+        store.expire_at(seconds(10), MyAction::Expired(2));
+
+        let cancelled = store.cancel_expirations(|action| *action == MyAction::Expired(1));
+
+        assert_eq!(cancelled, 1);
+        assert_eq!(store.dispatch_expired(seconds(10)), 1);
+        assert_eq!(*store.state(), vec![1]);
+    }
+
+    #[test]
+    fn should_dispatch_restored_expirations_when_they_were_persisted() {
+        let mut store = Store::new(reducer, vec![]);
+        store.dispatch(MyAction::Started(1));
+        store.expire_at(seconds(10), MyAction::Expired(1));
+        let persisted = store.expirations().to_vec();
+
+        let mut restored = Store::new(reducer, store.state().clone());
+        restored.restore_expirations(persisted);
+
+        assert_eq!(
+            restored.expirations(),
+            &[Expiration {
+                at: seconds(10),
+                action: MyAction::Expired(1),
+            }]
+        );
+        assert_eq!(restored.dispatch_expired(seconds(11)), 1);
+        assert!(restored.state().is_empty());
+    }
+
+    #[test]
+    fn should_register_expiration_in_the_future_when_ttl_is_used() {
+        let mut store = Store::new(reducer, vec![1]);
+        store.expire_after(Duration::from_secs(60), MyAction::Expired(1));
+
+        assert_eq!(store.dispatch_expired(SystemTime::now()), 0);
+        assert_eq!(
+            store.dispatch_expired(SystemTime::now() + Duration::from_secs(61)),
+            1
+        );
+    }
+}
@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod fallible {
+    use redust::{ErrorPolicy, PanicIsolation, Store, UnsubscribeError};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    type MyStore = u8;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Increment,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Increment => state + 1,
+        }
+    }
+
+    #[test]
+    fn should_remove_failing_subscriber_when_policy_is_unsubscribe() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let mut store = Store::new(reducer, 0);
+        let token = store.subscribe_fallible(ErrorPolicy::Unsubscribe, |_state| {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+            Err("Always fails".into())
+        });
+
+        store
+            .dispatch(MyAction::Increment)
+            .dispatch(MyAction::Increment);
+
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            store.unsubscribe(token),
+            Err(UnsubscribeError::WrongToken(token.id()))
+        );
+    }
+
+    #[test]
+    fn should_call_failed_subscriber_again_when_policy_is_retry() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let mut store = Store::new(reducer, 0);
+        let token = store.subscribe_fallible(ErrorPolicy::Retry, |state| {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+            match *state {
+                1 => Err("Fails only for 1".into()),
+                _ => Ok(()),
+            }
+        });
+
+        store.dispatch(MyAction::Increment);
+        assert_eq!(store.retry_failed_subscribers(), 1);
+        assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+
+        let errors = store.take_subscriber_errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, token);
+        assert_eq!(errors[0].1.to_string(), "Fails only for 1");
+
+        store.dispatch(MyAction::Increment);
+        assert_eq!(store.retry_failed_subscribers(), 0);
+        assert_eq!(CALLS.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn should_isolate_panic_when_retried_subscriber_panicked() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let mut store = Store::new(reducer, 0);
+        store.isolate_panics(PanicIsolation::Report);
+        let token = store.subscribe_fallible(ErrorPolicy::Retry, |_state| {
+            match CALLS.fetch_add(1, Ordering::SeqCst) {
+                0 => Err("Fails on the first call".into()),
+                _ => panic!("Panics on retry"),
+            }
+        });
+
+        store.dispatch(MyAction::Increment);
+        assert_eq!(store.retry_failed_subscribers(), 1);
+        assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+
+        let errors = store.take_subscriber_errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, token);
+        assert_eq!(
+            errors[0].1.to_string(),
+            "Subscriber panicked: Panics on retry"
+        );
+    }
+
+    #[test]
+    fn should_collect_errors_when_policy_is_report() {
+        let mut store = Store::new(reducer, 0);
+        let token = store.subscribe_fallible(ErrorPolicy::Report, |state| {
+            Err(format!("Cannot handle {}", state).into())
+        });
+
+        store
+            .dispatch(MyAction::Increment)
+            .dispatch(MyAction::Increment);
+
+        let errors: Vec<_> = store
+            .take_subscriber_errors()
+            .into_iter()
+            .map(|(token, err)| (token, err.to_string()))
+            .collect();
+        assert_eq!(
+            errors,
+            vec![
+                (token, "Cannot handle 1".to_string()),
+                (token, "Cannot handle 2".to_string())
+            ]
+        );
+        assert!(store.take_subscriber_errors().is_empty());
+    }
+}
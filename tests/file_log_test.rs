@@ -0,0 +1,148 @@
+#[cfg(test)]
+mod file_log {
+    use redust::{FileLogger, LogRotation, Store};
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    type MyStore = u8;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Increment,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Increment => state + 1,
+        }
+    }
+
+    fn format(_: &MyAction, old_state: &MyStore, new_state: &MyStore) -> String {
+        format!("{}>{}", old_state, new_state)
+    }
+
+    fn log_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("redust-file-log-{}", name));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        dir
+    }
+
+    fn read(path: PathBuf) -> String {
+        std::fs::read_to_string(path).unwrap_or_default()
+    }
+
+    #[test]
+    fn should_append_transitions_when_file_already_exists() {
+        let dir = log_dir("append");
+        let path = dir.join("store.log");
+        std::fs::write(&path, "previous run\n").unwrap();
+
+        let logger = FileLogger::open(&path, format, LogRotation::Never, 1).unwrap();
+        let mut store = Store::new(reducer, 0);
+        store.add_middleware("file-logger", logger.clone());
+        store
+            .dispatch(MyAction::Increment)
+            .dispatch(MyAction::Increment);
+        logger.flush().unwrap();
+
+        assert_eq!(read(path), "previous run\n0>1\n1>2\n");
+        assert_eq!(logger.failed_writes(), 0);
+    }
+
+    #[test]
+    fn should_rotate_and_keep_limited_files_when_size_is_exceeded() {
+        let dir = log_dir("size");
+        let path = dir.join("store.log");
+
+        // Every record is 4 bytes, so each file holds two of them
+        let logger = FileLogger::open(&path, format, LogRotation::Size(8), 2).unwrap();
+        let mut store = Store::new(reducer, 0);
+        store.add_middleware("file-logger", logger.clone());
+        for _ in 0..7 {
+            store.dispatch(MyAction::Increment);
+        }
+        logger.flush().unwrap();
+
+        assert_eq!(read(path), "6>7\n");
+        assert_eq!(read(logger.rotated_path(1)), "4>5\n5>6\n");
+        assert_eq!(read(logger.rotated_path(2)), "2>3\n3>4\n");
+        assert!(!logger.rotated_path(3).exists());
+    }
+
+    #[test]
+    fn should_rotate_when_interval_has_passed() {
+        let dir = log_dir("interval");
+        let path = dir.join("store.log");
+
+        let logger = FileLogger::open(
+            &path,
+            format,
+            LogRotation::Interval(Duration::from_millis(20)),
+            1,
+        )
+        .unwrap();
+        let mut store = Store::new(reducer, 0);
+        store.add_middleware("file-logger", logger.clone());
+        store.dispatch(MyAction::Increment);
+        std::thread::sleep(Duration::from_millis(30));
+        store.dispatch(MyAction::Increment);
+        logger.flush().unwrap();
+
+        assert_eq!(read(logger.rotated_path(1)), "0>1\n");
+        assert_eq!(read(path), "1>2\n");
+    }
+
+    #[test]
+    fn should_use_debug_records_when_no_format_is_given() {
+        let dir = log_dir("debug");
+        let path = dir.join("store.log");
+
+        let logger = FileLogger::debug(&path, LogRotation::Never, 0).unwrap();
+        let mut store = Store::new(reducer, 41);
+        store.add_middleware("file-logger", logger.clone());
+        store.dispatch(MyAction::Increment);
+        logger.rotate().unwrap();
+        store.dispatch(MyAction::Increment);
+        logger.flush().unwrap();
+
+        assert_eq!(read(path), "Increment: 42 -> 43\n");
+    }
+
+    #[test]
+    fn should_reopen_file_when_rotated_file_was_removed() {
+        let dir = log_dir("removed");
+        let path = dir.join("store.log");
+
+        let logger = FileLogger::open(&path, format, LogRotation::Never, 2).unwrap();
+        let mut store = Store::new(reducer, 0);
+        store.add_middleware("file-logger", logger.clone());
+        store.dispatch(MyAction::Increment);
+        std::fs::remove_file(&path).unwrap();
+        logger.rotate().unwrap();
+        store.dispatch(MyAction::Increment);
+        logger.flush().unwrap();
+
+        assert_eq!(read(path), "1>2\n");
+        assert!(!logger.rotated_path(1).exists());
+        assert_eq!(logger.failed_writes(), 0);
+    }
+
+    #[test]
+    fn should_reopen_file_when_removed_file_is_not_kept() {
+        let dir = log_dir("removed-not-kept");
+        let path = dir.join("store.log");
+
+        let logger = FileLogger::open(&path, format, LogRotation::Never, 0).unwrap();
+        let mut store = Store::new(reducer, 0);
+        store.add_middleware("file-logger", logger.clone());
+        std::fs::remove_file(&path).unwrap();
+        logger.rotate().unwrap();
+        store.dispatch(MyAction::Increment);
+        logger.flush().unwrap();
+
+        assert_eq!(read(path), "0>1\n");
+        assert_eq!(logger.failed_writes(), 0);
+    }
+}
@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod filtered_subscription {
+    use redust::Store;
+    use std::sync::Mutex;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Page {
+        Home,
+        Settings,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct AppState {
+        page: Page,
+        clicks: u8,
+    }
+
+    #[derive(Debug)]
+    enum MyAction {
+        Open(Page),
+        Click,
+    }
+
+    fn reducer(state: &AppState, action: &MyAction) -> AppState {
+        match action {
+            MyAction::Open(page) => AppState {
+                page: *page,
+                ..*state
+            },
+            MyAction::Click => AppState {
+                clicks: state.clicks + 1,
+                ..*state
+            },
+        }
+    }
+
+    fn create_store() -> Store<AppState, MyAction> {
+        Store::new(
+            reducer,
+            AppState {
+                page: Page::Home,
+                clicks: 0,
+            },
+        )
+    }
+
+    #[test]
+    fn should_call_subscriber_only_while_filter_holds() {
+        static CLICKS: Mutex<Vec<u8>> = Mutex::new(Vec::new());
+
+        let mut store = create_store();
+        store.subscribe_filtered(
+            |state| state.page == Page::Settings,
+            |state| CLICKS.lock().unwrap().push(state.clicks),
+        );
+
+        store
+            .dispatch(MyAction::Click)
+            .dispatch(MyAction::Open(Page::Settings))
+            .dispatch(MyAction::Click)
+            .dispatch(MyAction::Click)
+            .dispatch(MyAction::Open(Page::Home))
+            .dispatch(MyAction::Click);
+
+        assert_eq!(*CLICKS.lock().unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn should_not_call_filtered_subscriber_when_it_was_unsubscribed() {
+        static CLICKS: Mutex<Vec<u8>> = Mutex::new(Vec::new());
+
+        let mut store = create_store();
+        let token = store.subscribe_filtered(
+            |_state| true,
+            |state| CLICKS.lock().unwrap().push(state.clicks),
+        );
+        store.unsubscribe(token).unwrap();
+
+        store.dispatch(MyAction::Click);
+
+        assert!(CLICKS.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod fixture {
+    use redust::{Fixture, Recorder, Store};
+
+    type MyStore = Vec<u8>;
+
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    enum MyAction {
+        Push(u8),
+        Pop,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        let mut new_state = state.clone();
+        match action {
+            MyAction::Push(value) => new_state.push(*value),
+            MyAction::Pop => {
+                new_state.pop();
+            }
+        }
+
+        new_state
+    }
+
+    #[test]
+    fn should_record_initial_state_and_actions_when_dispatch_was_called() {
+        let mut recorder = Recorder::new(reducer, vec![1]);
+        recorder.dispatch(MyAction::Push(2)).dispatch(MyAction::Pop);
+
+        assert_eq!(*recorder.state(), vec![1]);
+        assert_eq!(
+            recorder.fixture(),
+            &Fixture {
+                initial_state: vec![1],
+                actions: vec![MyAction::Push(2), MyAction::Pop],
+            }
+        );
+    }
+
+    #[test]
+    fn should_restore_the_same_state_when_fixture_was_replayed() {
+        let mut recorder = Recorder::new(reducer, vec![]);
+        recorder
+            .dispatch(MyAction::Push(1))
+            .dispatch(MyAction::Push(2))
+            .dispatch(MyAction::Push(3))
+            .dispatch(MyAction::Pop);
+
+        let expected_state = recorder.state().clone();
+        let store = Store::replay_fixture(reducer, recorder.into_fixture());
+
+        assert_eq!(*store.state(), expected_state);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_load_the_same_fixture_when_it_was_saved_to_json() {
+        let mut recorder = Recorder::new(reducer, vec![5]);
+        recorder.dispatch(MyAction::Push(6));
+
+        let json = recorder.fixture().to_json().unwrap();
+        let fixture: Fixture<MyStore, MyAction> = Fixture::from_json(&json).unwrap();
+
+        assert_eq!(&fixture, recorder.fixture());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_return_an_error_when_json_is_broken() {
+        let result: Result<Fixture<MyStore, MyAction>, _> = Fixture::from_json("{");
+
+        assert!(result.is_err());
+    }
+}
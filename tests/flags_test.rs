@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod flags {
+    use redust::{FlagDecision, FlagProvider, SliceError, Store};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Increment,
+    }
+
+    fn counter_reducer(state: &u8, action: &MyAction) -> u8 {
+        match action {
+            MyAction::Increment => state + 1,
+        }
+    }
+
+    type List = Vec<u8>;
+
+    fn list_reducer(state: &List, _action: &MyAction) -> List {
+        state.clone()
+    }
+
+    struct Toggle(AtomicBool);
+
+    impl FlagProvider for Toggle {
+        fn is_enabled(&self, _flag: &str) -> bool {
+            self.0.load(Ordering::SeqCst)
+        }
+    }
+
+    #[test]
+    fn should_pass_state_through_when_flag_is_disabled() {
+        let toggle = Arc::new(Toggle(AtomicBool::new(false)));
+        let mut store = Store::with_slices();
+        store
+            .inject_gated_reducer("counter", "counter", toggle.clone(), counter_reducer, || 0)
+            .unwrap();
+
+        store.dispatch(MyAction::Increment);
+        assert_eq!(store.state().get::<u8>("counter"), Some(&0));
+
+        toggle.0.store(true, Ordering::SeqCst);
+        store.dispatch(MyAction::Increment);
+        assert_eq!(store.state().get::<u8>("counter"), Some(&1));
+    }
+
+    #[test]
+    fn should_record_flag_decision_when_action_was_reduced() {
+        let toggle = Arc::new(Toggle(AtomicBool::new(false)));
+        let mut store = Store::with_slices();
+        store
+            .inject_gated_reducer("counter", "beta", toggle, counter_reducer, || 0)
+            .unwrap();
+        store
+            .inject_reducer("plain", counter_reducer, || 0)
+            .unwrap();
+
+        store.dispatch(MyAction::Increment);
+
+        assert_eq!(
+            store.state().flag_decisions(),
+            &[FlagDecision {
+                slice: "counter",
+                flag: "beta",
+                enabled: false,
+            }]
+        );
+        assert_eq!(store.state().get::<u8>("plain"), Some(&1));
+    }
+
+    #[test]
+    fn should_keep_slice_state_when_gated_reducer_was_reinjected() {
+        let toggle = Arc::new(Toggle(AtomicBool::new(true)));
+        let mut store = Store::with_slices();
+        store
+            .inject_reducer("counter", counter_reducer, || 0)
+            .unwrap();
+        store.dispatch(MyAction::Increment);
+
+        store
+            .inject_gated_reducer("counter", "beta", toggle, counter_reducer, || 0)
+            .unwrap();
+
+        assert_eq!(store.state().get::<u8>("counter"), Some(&1));
+    }
+
+    #[test]
+    fn should_create_initial_slice_when_gated_slice_is_used_for_the_first_time() {
+        static CREATED: AtomicBool = AtomicBool::new(false);
+        let toggle = Arc::new(Toggle(AtomicBool::new(false)));
+        let mut store = Store::with_slices();
+
+        store
+            .inject_gated_reducer("counter", "beta", toggle, counter_reducer, || {
+                CREATED.store(true, Ordering::SeqCst);
+                0
+            })
+            .unwrap();
+        assert!(!CREATED.load(Ordering::SeqCst));
+
+        assert_eq!(store.state().get::<u8>("counter"), Some(&0));
+        assert!(CREATED.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn should_return_error_when_gated_slice_type_does_not_match() {
+        let toggle = Arc::new(Toggle(AtomicBool::new(true)));
+        let mut store = Store::with_slices();
+        store
+            .inject_reducer("counter", counter_reducer, || 0)
+            .unwrap();
+
+        let result = store.inject_gated_reducer("counter", "beta", toggle, list_reducer, Vec::new);
+
+        assert_eq!(result, Err(SliceError::TypeMismatch("counter")));
+    }
+}
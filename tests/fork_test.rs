@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod fork {
+    use redust::Store;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    type MyStore = Vec<u8>;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Push(u8),
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Push(value) => {
+                let mut new_state = state.clone();
+                new_state.push(*value);
+
+                new_state
+            }
+        }
+    }
+
+    #[test]
+    fn should_keep_store_unchanged_when_fork_was_dispatched() {
+        let mut store = Store::new(reducer, vec![1]);
+        let mut fork = store.fork();
+
+        fork.dispatch(MyAction::Push(2));
+        store.dispatch(MyAction::Push(3));
+
+        assert_eq!(*fork.state(), vec![1, 2]);
+        assert_eq!(*store.state(), vec![1, 3]);
+    }
+
+    #[test]
+    fn should_share_state_and_version_when_fork_was_created() {
+        let mut store = Store::new(reducer, vec![1]);
+        store.dispatch(MyAction::Push(2));
+
+        let fork = store.fork();
+
+        assert!(Arc::ptr_eq(&fork.shared_state(), &store.shared_state()));
+        assert_eq!(fork.version(), store.version());
+    }
+
+    #[test]
+    fn should_not_notify_store_subscribers_when_fork_was_dispatched() {
+        let mut store = Store::new(reducer, vec![]);
+        store.subscribe(|_state| {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+        });
+
+        store.fork().dispatch(MyAction::Push(1));
+
+        assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+    }
+}
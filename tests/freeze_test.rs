@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod freeze {
+    use redust::{DispatchError, FreezePolicy, Store};
+
+    type MyStore = u8;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Increment,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Increment => state + 1,
+        }
+    }
+
+    #[test]
+    fn should_reject_actions_when_store_is_frozen_with_reject_policy() {
+        let mut store = Store::new(reducer, 0);
+        store.freeze(FreezePolicy::Reject);
+
+        let result = store.try_dispatch(MyAction::Increment);
+        assert_eq!(result.err(), Some(DispatchError::Frozen));
+
+        store.dispatch(MyAction::Increment);
+        assert_eq!(*store.state(), 0);
+        assert!(store.is_frozen());
+    }
+
+    #[test]
+    fn should_ignore_actions_when_store_is_frozen_with_ignore_policy() {
+        let mut store = Store::new(reducer, 0);
+        store.freeze(FreezePolicy::Ignore);
+
+        assert!(store.try_dispatch(MyAction::Increment).is_ok());
+        assert_eq!(*store.state(), 0);
+    }
+
+    #[test]
+    fn should_apply_actions_again_when_store_was_unfrozen() {
+        let mut store = Store::new(reducer, 0);
+        store.freeze(FreezePolicy::Reject);
+        store.dispatch(MyAction::Increment);
+
+        store.unfreeze();
+        store.dispatch(MyAction::Increment);
+
+        assert!(!store.is_frozen());
+        assert_eq!(*store.state(), 1);
+    }
+}
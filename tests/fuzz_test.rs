@@ -0,0 +1,74 @@
+#![cfg(feature = "fuzz")]
+
+#[cfg(test)]
+mod fuzz {
+    use redust::fuzz::{fuzz_actions, Arbitrary};
+    use std::panic;
+
+    type MyStore = Vec<u8>;
+
+    #[derive(Debug, Arbitrary)]
+    enum MyAction {
+        Push(u8),
+        Pop,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        let mut new_state = state.clone();
+        match action {
+            MyAction::Push(value) if new_state.len() < 4 => new_state.push(*value),
+            MyAction::Push(_) => {}
+            MyAction::Pop => {
+                new_state.pop();
+            }
+        }
+
+        new_state
+    }
+
+    fn unbounded_reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        let mut new_state = state.clone();
+        match action {
+            MyAction::Push(value) => new_state.push(*value),
+            MyAction::Pop => {
+                new_state.pop();
+            }
+        }
+
+        new_state
+    }
+
+    fn is_bounded(state: &MyStore) -> bool {
+        state.len() <= 4
+    }
+
+    #[test]
+    fn should_not_panic_when_invariant_holds_for_any_input() {
+        for seed in 0..=u8::MAX {
+            let data: Vec<u8> = (0..64).map(|index| seed.wrapping_mul(index)).collect();
+
+            fuzz_actions(reducer, vec![], &data, is_bounded);
+        }
+    }
+
+    #[test]
+    fn should_panic_with_action_when_invariant_was_violated() {
+        let violation = (0..=u8::MAX).find_map(|seed| {
+            let data: Vec<u8> = (0..64).map(|index| seed.wrapping_add(index)).collect();
+
+            panic::catch_unwind(|| fuzz_actions(unbounded_reducer, vec![], &data, is_bounded))
+                .err()
+                .and_then(|payload| payload.downcast_ref::<String>().cloned())
+        });
+
+        assert!(violation
+            .unwrap()
+            .starts_with("Invariant does not hold after action #"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invariant does not hold for the initial state")]
+    fn should_panic_when_initial_state_violates_invariant() {
+        fuzz_actions(reducer, vec![0; 5], &[0, 0, 0, 0], is_bounded);
+    }
+}
@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod global {
+    use redust::{GlobalStore, Store};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+
+    type MyStore = u8;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Increment,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Increment => state + 1,
+        }
+    }
+
+    fn create_store() -> Store<MyStore, MyAction> {
+        Store::new(reducer, 0)
+    }
+
+    #[test]
+    fn should_create_store_when_it_was_first_accessed() {
+        static STORE: GlobalStore<MyStore, MyAction> = GlobalStore::new(create_store);
+
+        assert!(!STORE.is_initialized());
+        assert_eq!(*STORE.read(), 0);
+        assert!(STORE.is_initialized());
+    }
+
+    #[test]
+    fn should_apply_actions_when_they_were_dispatched_from_many_threads() {
+        static STORE: GlobalStore<MyStore, MyAction> = GlobalStore::new(create_store);
+
+        let threads: Vec<_> = (0..4)
+            .map(|_| {
+                thread::spawn(|| {
+                    STORE.dispatch(MyAction::Increment);
+                })
+            })
+            .collect();
+        threads
+            .into_iter()
+            .for_each(|thread| thread.join().unwrap());
+
+        assert_eq!(*STORE.get().read(), 4);
+    }
+
+    #[test]
+    fn should_call_subscribers_when_they_were_added_through_write_access() {
+        static STORE: GlobalStore<MyStore, MyAction> = GlobalStore::new(create_store);
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        STORE.write().subscribe(|_state| {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+        });
+        STORE.dispatch(MyAction::Increment);
+
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,118 @@
+#![cfg(feature = "grpc")]
+
+#[cfg(test)]
+mod grpc {
+    use redust::codec::Codec;
+    use redust::grpc::{GrpcClient, GrpcError, GrpcStore};
+    use redust::{OverflowPolicy, Store};
+    use serde::{Deserialize, Serialize};
+    use tokio::net::TcpListener;
+    use tokio_stream::wrappers::TcpListenerStream;
+
+    type MyStore = Vec<String>;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    enum MyAction {
+        Log(String),
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Log(message) => {
+                let mut new_state = state.clone();
+                new_state.push(message.clone());
+
+                new_state
+            }
+        }
+    }
+
+    async fn serve(grpc: &GrpcStore<MyStore, MyAction>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(
+            tonic::transport::Server::builder()
+                .add_service(grpc.service())
+                .serve_with_incoming(TcpListenerStream::new(listener)),
+        );
+
+        endpoint
+    }
+
+    #[tokio::test]
+    async fn should_dispatch_action_when_client_called_dispatch() {
+        let mut store = Store::new(reducer, vec![]);
+        let grpc = GrpcStore::new(Codec::Json, &store).unwrap();
+        let mut client = GrpcClient::<MyStore, MyAction>::connect(serve(&grpc).await, Codec::Json)
+            .await
+            .unwrap();
+
+        client
+            .dispatch(&MyAction::Log("hello".to_string()))
+            .await
+            .unwrap();
+        grpc.pump(&mut store).unwrap();
+
+        assert_eq!(*store.state(), vec!["hello"]);
+        assert_eq!(client.state().await.unwrap(), vec!["hello"]);
+    }
+
+    #[tokio::test]
+    async fn should_stream_states_when_client_watches_state() {
+        let mut store = Store::new(reducer, vec![]);
+        let grpc = GrpcStore::new(Codec::Json, &store).unwrap();
+        let mut client = GrpcClient::<MyStore, MyAction>::connect(serve(&grpc).await, Codec::Json)
+            .await
+            .unwrap();
+        let mut states = client.watch_state().await.unwrap();
+
+        assert_eq!(states.next().await, Some(Ok(vec![])));
+
+        client
+            .dispatch(&MyAction::Log("hello".to_string()))
+            .await
+            .unwrap();
+        grpc.pump(&mut store).unwrap();
+
+        assert_eq!(states.next().await, Some(Ok(vec!["hello".to_string()])));
+    }
+
+    #[tokio::test]
+    async fn should_return_error_when_action_cannot_be_decoded() {
+        let store = Store::new(reducer, vec![]);
+        let grpc = GrpcStore::new(Codec::Json, &store).unwrap();
+        let mut client = GrpcClient::<MyStore, String>::connect(serve(&grpc).await, Codec::Json)
+            .await
+            .unwrap();
+
+        let result = client.dispatch(&"not an action".to_string()).await;
+
+        assert!(matches!(result, Err(GrpcError::Status(_))));
+    }
+
+    #[tokio::test]
+    async fn should_reject_action_when_queue_is_full() {
+        let mut store = Store::new(reducer, vec![]);
+        let grpc = GrpcStore::new(Codec::Json, &store)
+            .unwrap()
+            .with_max_pending_actions(1, OverflowPolicy::Reject);
+        let mut client = GrpcClient::<MyStore, MyAction>::connect(serve(&grpc).await, Codec::Json)
+            .await
+            .unwrap();
+
+        client
+            .dispatch(&MyAction::Log("first".to_string()))
+            .await
+            .unwrap();
+        let result = client.dispatch(&MyAction::Log("second".to_string())).await;
+        grpc.pump(&mut store).unwrap();
+
+        assert_eq!(
+            result,
+            Err(GrpcError::Status(
+                "Cannot enqueue an action: the queue is full".to_string()
+            ))
+        );
+        assert_eq!(*store.state(), vec!["first"]);
+    }
+}
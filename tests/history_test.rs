@@ -0,0 +1,153 @@
+#[cfg(test)]
+mod history {
+    use redust::{HistoryError, HistoryPolicy, Store};
+    use std::thread;
+    use std::time::Duration;
+
+    type MyStore = Vec<u8>;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum MyAction {
+        Push(u8),
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Push(value) => {
+                let mut new_state = state.clone();
+                new_state.push(*value);
+
+                new_state
+            }
+        }
+    }
+
+    fn push_all(store: &mut Store<MyStore, MyAction>, values: &[u8]) {
+        values.iter().for_each(|value| {
+            store.dispatch(MyAction::Push(*value));
+        });
+    }
+
+    #[test]
+    fn should_keep_every_state_when_history_is_unbounded() {
+        let mut store = Store::new(reducer, vec![]);
+        store.enable_history(HistoryPolicy::unbounded());
+        push_all(&mut store, &[1, 2, 3]);
+
+        assert_eq!(store.history_len(), 4);
+        assert!(store.history_state(0).unwrap().is_empty());
+        assert_eq!(*store.history_state(3).unwrap(), vec![1, 2, 3]);
+        assert_eq!(store.history_state(4), None);
+    }
+
+    #[test]
+    fn should_drop_oldest_entries_when_max_entries_was_exceeded() {
+        let mut store = Store::new(reducer, vec![]);
+        store.enable_history(HistoryPolicy::unbounded().max_entries(2));
+        push_all(&mut store, &[1, 2, 3, 4]);
+
+        assert_eq!(
+            store.history_actions(),
+            vec![&MyAction::Push(3), &MyAction::Push(4)]
+        );
+        assert_eq!(*store.history_state(0).unwrap(), vec![1, 2]);
+    }
+
+    #[test]
+    fn should_drop_oldest_snapshots_when_max_memory_was_exceeded() {
+        let mut store = Store::new(reducer, vec![]);
+        store.enable_history(
+            HistoryPolicy::unbounded().max_memory(5, |state: &MyStore| state.len()),
+        );
+        push_all(&mut store, &[1, 2, 3]);
+
+        // Snapshots of 2 and 3 items take 5 bytes
+        assert_eq!(store.history_len(), 3);
+        assert_eq!(*store.history_state(0).unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn should_drop_old_entries_when_max_age_was_exceeded() {
+        let mut store = Store::new(reducer, vec![]);
+        store.enable_history(HistoryPolicy::unbounded().max_age(Duration::from_millis(20)));
+        push_all(&mut store, &[1, 2]);
+
+        thread::sleep(Duration::from_millis(30));
+        push_all(&mut store, &[3]);
+
+        assert_eq!(store.history_actions(), vec![&MyAction::Push(3)]);
+        assert_eq!(*store.history_state(0).unwrap(), vec![1, 2]);
+    }
+
+    #[test]
+    fn should_recompute_states_when_only_every_nth_snapshot_is_kept() {
+        let mut store = Store::new(reducer, vec![]);
+        store.enable_history(HistoryPolicy::unbounded().snapshot_every(3).max_entries(4));
+        push_all(&mut store, &[1, 2, 3, 4, 5, 6]);
+
+        assert_eq!(store.history_actions().len(), 4);
+        assert_eq!(*store.history_state(0).unwrap(), vec![1, 2]);
+        assert_eq!(*store.history_state(2).unwrap(), vec![1, 2, 3, 4]);
+        assert_eq!(*store.history_state(4).unwrap(), vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn should_restore_state_when_travelled_back_and_forward() {
+        let mut store = Store::new(reducer, vec![]);
+        store.enable_history(HistoryPolicy::unbounded());
+        push_all(&mut store, &[1, 2, 3]);
+
+        store.travel_back(2).unwrap();
+        assert_eq!(*store.state(), vec![1]);
+
+        store.travel_to(3).unwrap();
+        assert_eq!(*store.state(), vec![1, 2, 3]);
+        assert_eq!(store.history_len(), 4);
+    }
+
+    #[test]
+    fn should_travel_from_restored_state_when_travelled_back_again() {
+        let mut store = Store::new(reducer, vec![]);
+        store.enable_history(HistoryPolicy::unbounded());
+        push_all(&mut store, &[1, 2, 3]);
+
+        store.travel_back(1).unwrap();
+        assert_eq!(*store.state(), vec![1, 2]);
+
+        store.travel_back(1).unwrap();
+        assert_eq!(*store.state(), vec![1]);
+    }
+
+    #[test]
+    fn should_return_error_when_travelled_beyond_history() {
+        let mut store = Store::new(reducer, vec![]);
+        assert_eq!(store.travel_back(1).err(), Some(HistoryError::Disabled));
+
+        store.enable_history(HistoryPolicy::unbounded());
+        push_all(&mut store, &[1]);
+
+        assert_eq!(
+            store.travel_back(2).err(),
+            Some(HistoryError::OutOfRange(2))
+        );
+    }
+
+    #[test]
+    fn should_continue_from_restored_state_when_dispatched_after_travel() {
+        let mut store = Store::new(reducer, vec![]);
+        store.enable_history(HistoryPolicy::unbounded().snapshot_every(2));
+        push_all(&mut store, &[1, 2, 3, 4]);
+
+        store.travel_back(3).unwrap();
+        push_all(&mut store, &[5, 6]);
+
+        assert_eq!(*store.state(), vec![1, 5, 6]);
+        assert_eq!(
+            store.history_actions(),
+            vec![&MyAction::Push(1), &MyAction::Push(5), &MyAction::Push(6)]
+        );
+        assert_eq!(*store.history_state(2).unwrap(), vec![1, 5]);
+        assert_eq!(*store.history_state(3).unwrap(), vec![1, 5, 6]);
+        assert_eq!(store.history_state(4), None);
+    }
+}
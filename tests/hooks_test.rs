@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod hooks {
+    use redust::{Store, UnsubscribeError};
+    use std::sync::Mutex;
+
+    type MyStore = u8;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum MyAction {
+        Increment,
+        Reset,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Increment => state + 1,
+            MyAction::Reset => 0,
+        }
+    }
+
+    #[test]
+    fn should_call_hooks_around_the_reducer() {
+        static BEFORE: Mutex<Vec<(MyAction, u8)>> = Mutex::new(Vec::new());
+        static AFTER: Mutex<Vec<(MyAction, u8, u8)>> = Mutex::new(Vec::new());
+
+        let mut store = Store::new(reducer, 5);
+        store.on_before_dispatch(|action, state| BEFORE.lock().unwrap().push((*action, *state)));
+        store.on_after_dispatch(|action, old_state, new_state| {
+            AFTER
+                .lock()
+                .unwrap()
+                .push((*action, *old_state, *new_state))
+        });
+
+        store
+            .dispatch(MyAction::Increment)
+            .dispatch(MyAction::Reset);
+
+        assert_eq!(
+            *BEFORE.lock().unwrap(),
+            vec![(MyAction::Increment, 5), (MyAction::Reset, 6)]
+        );
+        assert_eq!(
+            *AFTER.lock().unwrap(),
+            vec![(MyAction::Increment, 5, 6), (MyAction::Reset, 6, 0)]
+        );
+    }
+
+    #[test]
+    fn should_not_call_hook_when_it_was_removed() {
+        static CALLS: Mutex<u8> = Mutex::new(0);
+
+        let mut store = Store::new(reducer, 0);
+        let token = store.on_before_dispatch(|_action, _state| *CALLS.lock().unwrap() += 1);
+
+        store.dispatch(MyAction::Increment);
+        assert_eq!(store.remove_hook(token), Ok(()));
+        store.dispatch(MyAction::Increment);
+
+        assert_eq!(*CALLS.lock().unwrap(), 1);
+        assert_eq!(
+            store.remove_hook(token),
+            Err(UnsubscribeError::WrongToken(token.id()))
+        );
+    }
+}
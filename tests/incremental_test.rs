@@ -0,0 +1,183 @@
+#[cfg(test)]
+mod incremental {
+    use redust::{Query, Store};
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    #[derive(Clone)]
+    struct Document {
+        lines: Vec<String>,
+        show_hidden: bool,
+        cursor: usize,
+    }
+
+    #[derive(Debug)]
+    enum MyAction {
+        Edit(usize, &'static str),
+        ToggleHidden,
+        Move(usize),
+    }
+
+    fn reducer(state: &Document, action: &MyAction) -> Document {
+        let mut new_state = state.clone();
+        match action {
+            MyAction::Edit(line, text) => new_state.lines[*line] = text.to_string(),
+            MyAction::ToggleHidden => new_state.show_hidden = !new_state.show_hidden,
+            MyAction::Move(cursor) => new_state.cursor = *cursor,
+        }
+
+        new_state
+    }
+
+    fn create_store() -> Store<Document, MyAction> {
+        Store::new(
+            reducer,
+            Document {
+                lines: vec!["let a = 1".to_string(), "let b = a".to_string()],
+                show_hidden: false,
+                cursor: 0,
+            },
+        )
+    }
+
+    const LINE: Query<Document, usize, String> = Query::new("line", |context, line| {
+        let lines = context.input(|document| document.lines.clone());
+        lines[*line].clone()
+    });
+
+    const TOKENS: Query<Document, usize, usize> = Query::new("tokens", |context, line| {
+        context.query(&LINE, *line).split_whitespace().count()
+    });
+
+    const TOTAL: Query<Document, (), usize> = Query::new("total", |context, _| {
+        context.query(&TOKENS, 0) + context.query(&TOKENS, 1)
+    });
+
+    const VISIBLE: Query<Document, (), usize> = Query::new("visible", |context, _| {
+        if context.input(|document| document.show_hidden) {
+            context.query(&TOTAL, ())
+        } else {
+            context.query(&TOKENS, 0)
+        }
+    });
+
+    const CYCLE: Query<Document, u8, u8> =
+        Query::new("cycle", |context, key| context.query(&CYCLE, (key + 1) % 2));
+
+    #[test]
+    fn should_compute_queries_only_when_read() {
+        let mut store = create_store();
+        let queries = store.query_database();
+
+        store.dispatch(MyAction::Move(1));
+        assert_eq!(queries.executions(), 0);
+
+        assert_eq!(queries.get(&TOTAL, ()), 8);
+        // `TOTAL`, two `TOKENS` and two `LINE`
+        assert_eq!(queries.executions(), 5);
+    }
+
+    #[test]
+    fn should_reuse_results_when_inputs_did_not_change() {
+        let mut store = create_store();
+        let queries = store.query_database();
+        queries.get(&TOTAL, ());
+
+        store.dispatch(MyAction::Move(3));
+
+        assert_eq!(queries.get(&TOTAL, ()), 8);
+        assert_eq!(queries.executions(), 5);
+        assert_eq!(queries.revision(), 1);
+    }
+
+    #[test]
+    fn should_recompute_results_when_input_changed() {
+        let mut store = create_store();
+        let queries = store.query_database();
+        queries.get(&TOTAL, ());
+
+        store.dispatch(MyAction::Edit(1, "let b = a + 1"));
+
+        assert_eq!(queries.get(&TOTAL, ()), 10);
+    }
+
+    #[test]
+    fn should_stop_propagation_when_intermediate_result_did_not_change() {
+        let mut store = create_store();
+        let queries = store.query_database();
+        queries.get(&TOTAL, ());
+
+        store.dispatch(MyAction::Edit(1, "let c = a"));
+
+        assert_eq!(queries.get(&TOTAL, ()), 8);
+        // Both `LINE` read the changed lines, but only the second one changed.
+        // Its `TOKENS` stays the same, so `TOTAL` is reused
+        assert_eq!(queries.executions(), 5 + 3);
+    }
+
+    #[test]
+    fn should_memoize_results_per_key() {
+        let mut store = create_store();
+        let queries = store.query_database();
+
+        assert_eq!(queries.get(&LINE, 0), "let a = 1");
+        assert_eq!(queries.get(&LINE, 1), "let b = a");
+        assert_eq!(queries.get(&LINE, 0), "let a = 1");
+
+        assert_eq!(queries.executions(), 2);
+    }
+
+    #[test]
+    fn should_track_dependencies_read_by_last_computation() {
+        let mut store = create_store();
+        let queries = store.query_database();
+        assert_eq!(queries.get(&VISIBLE, ()), 4);
+
+        // `VISIBLE` does not read the second line while hidden lines are not shown
+        store.dispatch(MyAction::Edit(1, "let b = a + 1"));
+        assert_eq!(queries.get(&VISIBLE, ()), 4);
+
+        store.dispatch(MyAction::ToggleHidden);
+        assert_eq!(queries.get(&VISIBLE, ()), 10);
+    }
+
+    #[test]
+    fn should_compute_again_when_cleared() {
+        let mut store = create_store();
+        let queries = store.query_database();
+        queries.get(&LINE, 0);
+
+        queries.clear();
+        queries.get(&LINE, 0);
+
+        assert_eq!(queries.executions(), 2);
+    }
+
+    #[test]
+    fn should_keep_revision_when_unsubscribed() {
+        let mut store = create_store();
+        let queries = store.query_database();
+        queries.get(&LINE, 0);
+
+        store.unsubscribe(queries.token()).unwrap();
+        store.dispatch(MyAction::Edit(0, "let a = 2"));
+
+        assert_eq!(queries.revision(), 0);
+        assert_eq!(queries.get(&LINE, 0), "let a = 1");
+    }
+
+    #[test]
+    fn should_panic_when_queries_depend_on_each_other() {
+        let mut store = create_store();
+        let queries = store.query_database();
+
+        let result = catch_unwind(AssertUnwindSafe(|| queries.get(&CYCLE, 0)));
+
+        let message = result.unwrap_err();
+        assert_eq!(
+            message.downcast_ref::<String>().unwrap(),
+            "Cannot compute the query `cycle`, it depends on itself"
+        );
+        // The database is still usable
+        assert_eq!(queries.get(&LINE, 0), "let a = 1");
+    }
+}
@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod interceptors {
+    use redust::{DispatchError, Store};
+
+    type MyStore = u8;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Increment,
+        IncrementBy(u8),
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Increment => state + 1,
+            MyAction::IncrementBy(value) => state + value,
+        }
+    }
+
+    #[test]
+    fn should_apply_interceptors_in_registration_order() {
+        let mut store = Store::new(reducer, 0);
+
+        // Alias the `Increment` to `IncrementBy(1)`
+        store.intercept(|action, _state| match action {
+            MyAction::Increment => Some(MyAction::IncrementBy(1)),
+            action => Some(action),
+        });
+        // Double every increment
+        store.intercept(|action, _state| match action {
+            MyAction::IncrementBy(value) => Some(MyAction::IncrementBy(value * 2)),
+            action => Some(action),
+        });
+
+        store.dispatch(MyAction::Increment);
+
+        assert_eq!(*store.state(), 2);
+    }
+
+    #[test]
+    fn should_drop_action_when_interceptor_returned_none() {
+        let mut store = Store::new(reducer, 9);
+
+        // Do not allow values bigger than 10
+        store.intercept(|action, state| match action {
+            MyAction::Increment if *state >= 10 => None,
+            action => Some(action),
+        });
+
+        store.dispatch(MyAction::Increment);
+        assert_eq!(*store.state(), 10);
+
+        assert_eq!(
+            store.try_dispatch(MyAction::Increment).err(),
+            Some(DispatchError::Intercepted)
+        );
+        assert_eq!(*store.state(), 10);
+    }
+
+    #[test]
+    fn should_not_apply_interceptor_when_it_was_removed() {
+        let mut store = Store::new(reducer, 0);
+        let token = store.intercept(|_action, _state| None);
+
+        assert_eq!(store.remove_interceptor(token), Ok(()));
+        store.dispatch(MyAction::Increment);
+
+        assert_eq!(*store.state(), 1);
+    }
+}
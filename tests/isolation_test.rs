@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod isolation {
+    use redust::{PanicIsolation, Store, UnsubscribeError};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    type MyStore = u8;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Increment,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Increment => state + 1,
+        }
+    }
+
+    #[test]
+    fn should_call_other_subscribers_when_one_of_them_panicked() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let mut store = Store::new(reducer, 0);
+        store.isolate_panics(PanicIsolation::Report);
+
+        store.subscribe(|_state| panic!("Broken subscriber"));
+        store.subscribe(|_state| {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+        });
+
+        store
+            .dispatch(MyAction::Increment)
+            .dispatch(MyAction::Increment);
+
+        assert_eq!(*store.state(), 2);
+        assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+
+        let errors = store.take_subscriber_errors();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(
+            errors[0].1.to_string(),
+            "Subscriber panicked: Broken subscriber"
+        );
+    }
+
+    #[test]
+    fn should_remove_panicking_subscriber_when_isolation_is_unsubscribe() {
+        let mut store = Store::new(reducer, 0);
+        store.isolate_panics(PanicIsolation::Unsubscribe);
+
+        let token = store.subscribe_actions(|_action| true, |_action, _state| panic!("Broken"));
+        store
+            .dispatch(MyAction::Increment)
+            .dispatch(MyAction::Increment);
+
+        assert_eq!(store.take_subscriber_errors().len(), 1);
+        assert_eq!(
+            store.unsubscribe(token),
+            Err(UnsubscribeError::WrongToken(token.id()))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Broken subscriber")]
+    fn should_propagate_panic_when_isolation_is_disabled() {
+        let mut store = Store::new(reducer, 0);
+        store.isolate_panics(PanicIsolation::Report);
+        store.propagate_panics();
+
+        store.subscribe(|_state| panic!("Broken subscriber"));
+        store.dispatch(MyAction::Increment);
+    }
+}
@@ -0,0 +1,110 @@
+#![cfg(feature = "serde")]
+
+#[cfg(test)]
+mod json_patch {
+    use redust::json_patch::{apply, diff, JsonPatch, PatchError, PatchOperation};
+    use redust::Store;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use std::sync::Mutex;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct MyStore {
+        todos: Vec<String>,
+        filter: String,
+    }
+
+    #[derive(Debug)]
+    enum MyAction {
+        Add(&'static str),
+        Remove,
+        Filter(&'static str),
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        let mut new_state = state.clone();
+        match action {
+            MyAction::Add(todo) => new_state.todos.push(todo.to_string()),
+            MyAction::Remove => {
+                new_state.todos.pop();
+            }
+            MyAction::Filter(filter) => new_state.filter = filter.to_string(),
+        }
+
+        new_state
+    }
+
+    fn initial_state() -> MyStore {
+        MyStore {
+            todos: vec![],
+            filter: "all".to_string(),
+        }
+    }
+
+    #[test]
+    fn should_sync_another_store_when_patches_are_applied() {
+        static PATCHES: Mutex<Vec<JsonPatch>> = Mutex::new(Vec::new());
+
+        let mut store = Store::new(reducer, initial_state());
+        store.subscribe_json_patch(|patch| PATCHES.lock().unwrap().push(patch.clone()));
+        store
+            .dispatch(MyAction::Add("milk"))
+            .dispatch(MyAction::Add("bread"))
+            .dispatch(MyAction::Remove)
+            .dispatch(MyAction::Filter("done"));
+
+        let mut replica = Store::new(reducer, initial_state());
+        for patch in PATCHES.lock().unwrap().iter() {
+            replica.apply_json_patch(patch).unwrap();
+        }
+
+        assert_eq!(replica.state(), store.state());
+    }
+
+    #[test]
+    fn should_serialize_operations_as_rfc_6902_document() {
+        let patch = diff(
+            &json!({ "a/b": 1, "list": [1, 2] }),
+            &json!({ "a/b": 2, "list": [1] }),
+        );
+
+        assert_eq!(
+            serde_json::to_value(&patch).unwrap(),
+            json!([
+                { "op": "replace", "path": "/a~1b", "value": 2 },
+                { "op": "remove", "path": "/list/1" },
+            ])
+        );
+    }
+
+    #[test]
+    fn should_leave_document_unchanged_when_path_does_not_exist() {
+        let mut document = json!({ "a": 1 });
+        let patch = vec![
+            PatchOperation::Remove {
+                path: "/a".to_string(),
+            },
+            PatchOperation::Replace {
+                path: "/missing/b".to_string(),
+                value: json!(2),
+            },
+        ];
+
+        assert_eq!(
+            apply(&mut document, &patch),
+            Err(PatchError::InvalidPath("/missing/b".to_string()))
+        );
+        assert_eq!(document, json!({ "a": 1 }));
+    }
+
+    #[test]
+    fn should_not_emit_patch_when_state_did_not_change() {
+        static CALLS: Mutex<u32> = Mutex::new(0);
+
+        let mut store = Store::new(reducer, initial_state());
+        store.subscribe_json_patch(|_| *CALLS.lock().unwrap() += 1);
+        store.dispatch(MyAction::Filter("all"));
+
+        assert_eq!(*CALLS.lock().unwrap(), 0);
+    }
+}
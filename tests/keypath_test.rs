@@ -0,0 +1,169 @@
+#[cfg(test)]
+mod keypath {
+    use redust::{keypath, Store};
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Todo {
+        title: &'static str,
+        checked: bool,
+    }
+
+    #[derive(Clone)]
+    struct AppState {
+        todos: HashMap<u32, Todo>,
+        order: Vec<u32>,
+        clicks: u8,
+    }
+
+    #[derive(Debug)]
+    enum MyAction {
+        Add(u32, &'static str),
+        Toggle(u32),
+        Remove(u32),
+        Click,
+    }
+
+    fn reducer(state: &AppState, action: &MyAction) -> AppState {
+        let mut new_state = state.clone();
+        match action {
+            MyAction::Add(id, title) => {
+                new_state.todos.insert(
+                    *id,
+                    Todo {
+                        title,
+                        checked: false,
+                    },
+                );
+                new_state.order.push(*id);
+            }
+            MyAction::Toggle(id) => {
+                if let Some(todo) = new_state.todos.get_mut(id) {
+                    todo.checked = !todo.checked;
+                }
+            }
+            MyAction::Remove(id) => {
+                new_state.todos.remove(id);
+                new_state.order.retain(|item| item != id);
+            }
+            MyAction::Click => new_state.clicks += 1,
+        }
+
+        new_state
+    }
+
+    fn create_store() -> Store<AppState, MyAction> {
+        let mut store = Store::new(
+            reducer,
+            AppState {
+                todos: HashMap::new(),
+                order: vec![],
+                clicks: 0,
+            },
+        );
+        store.dispatch(MyAction::Add(1, "Buy milk"));
+
+        store
+    }
+
+    #[test]
+    fn should_return_nested_field_when_path_has_key() {
+        let store = create_store();
+        let id = 1;
+
+        let checked = keypath!(AppState => state.todos[id].checked);
+        let title = keypath!(AppState => state.todos[&id].title);
+
+        assert_eq!(checked.get(store.state()), Some(&false));
+        assert_eq!(title.get(store.state()), Some(&"Buy milk"));
+    }
+
+    #[test]
+    fn should_return_none_when_key_is_missing() {
+        let store = create_store();
+
+        let checked = keypath!(AppState => state.todos[2].checked);
+        let first = keypath!(AppState => state.order[5]);
+
+        assert_eq!(checked.get(store.state()), None);
+        assert_eq!(first.get(store.state()), None);
+    }
+
+    #[test]
+    fn should_follow_nested_keys_and_method_calls() {
+        let store = create_store();
+
+        let first = keypath!(AppState => state.todos[state.order[0]].title);
+        let title = keypath!(AppState => state.todos.get(&1)?.title);
+
+        assert_eq!(first.get(store.state()), Some(&"Buy milk"));
+        assert_eq!(title.get(store.state()), Some(&"Buy milk"));
+    }
+
+    #[test]
+    fn should_detect_change_only_when_field_changed() {
+        let mut store = create_store();
+        let checked = keypath!(AppState => state.todos[1].checked);
+
+        let before = store.shared_state();
+        store.dispatch(MyAction::Click);
+        assert!(!checked.changed(&before, store.state()));
+
+        store.dispatch(MyAction::Toggle(1));
+        assert!(checked.changed(&before, store.state()));
+    }
+
+    #[test]
+    fn should_call_watcher_only_when_field_changed() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let mut store = create_store();
+        store.watch(keypath!(state.todos[1].checked), |checked| {
+            assert_eq!(checked, Some(&true));
+            CALLS.fetch_add(1, Ordering::SeqCst);
+        });
+
+        store
+            .dispatch(MyAction::Click)
+            .dispatch(MyAction::Add(2, "Walk the dog"))
+            .dispatch(MyAction::Toggle(2))
+            .dispatch(MyAction::Toggle(1));
+
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn should_call_watcher_with_none_when_item_was_removed() {
+        static REMOVED: AtomicUsize = AtomicUsize::new(0);
+
+        let mut store = create_store();
+        store.watch(keypath!(state.todos[1]), |todo| {
+            if todo.is_none() {
+                REMOVED.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+
+        store
+            .dispatch(MyAction::Remove(1))
+            .dispatch(MyAction::Remove(1));
+
+        assert_eq!(REMOVED.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn should_stop_calling_watcher_when_unsubscribed() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let mut store = create_store();
+        let token = store.watch(keypath!(state.clicks), |_clicks| {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+        });
+
+        store.dispatch(MyAction::Click);
+        store.unsubscribe(token).unwrap();
+        store.dispatch(MyAction::Click);
+
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod last_action {
+    use redust::{FreezePolicy, MergeStrategy, Store, TestClock};
+    use std::time::{Duration, UNIX_EPOCH};
+
+    type MyStore = u8;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum MyAction {
+        Increment,
+        Decrement,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Increment => state + 1,
+            MyAction::Decrement => state - 1,
+        }
+    }
+
+    #[test]
+    fn should_return_none_when_nothing_was_dispatched() {
+        let store = Store::new(reducer, 0);
+
+        assert_eq!(store.last_action(), None);
+        assert_eq!(store.last_dispatched_at(), None);
+    }
+
+    #[test]
+    fn should_return_last_action_when_actions_were_dispatched() {
+        let mut store = Store::new(reducer, 0);
+
+        store
+            .dispatch(MyAction::Increment)
+            .dispatch(MyAction::Increment)
+            .dispatch(MyAction::Decrement);
+
+        assert_eq!(store.last_action(), Some(&MyAction::Decrement));
+    }
+
+    #[test]
+    fn should_keep_previous_action_when_store_is_frozen() {
+        let mut store = Store::new(reducer, 0);
+        store.dispatch(MyAction::Increment);
+
+        store.freeze(FreezePolicy::Ignore);
+        store.dispatch(MyAction::Decrement);
+
+        assert_eq!(store.last_action(), Some(&MyAction::Increment));
+    }
+
+    #[test]
+    fn should_return_time_of_store_clock_when_action_was_dispatched() {
+        let clock = TestClock::starting_at(UNIX_EPOCH);
+        let mut store = Store::new(reducer, 0);
+        store.set_clock(clock.clone());
+
+        clock.advance(Duration::from_secs(10));
+        store.dispatch(MyAction::Increment);
+        clock.advance(Duration::from_secs(10));
+
+        assert_eq!(
+            store.last_dispatched_at(),
+            Some(UNIX_EPOCH + Duration::from_secs(10))
+        );
+    }
+
+    #[test]
+    fn should_return_last_action_of_fork_when_fork_is_merged_later() {
+        let mut store = Store::new(reducer, 0);
+        let mut fork = store.fork();
+
+        fork.dispatch(MyAction::Increment)
+            .dispatch(MyAction::Decrement);
+        assert_eq!(fork.last_action(), Some(&MyAction::Decrement));
+
+        store.merge(fork, MergeStrategy::Replay).unwrap();
+        assert_eq!(*store.state(), 0);
+    }
+}
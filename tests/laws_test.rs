@@ -0,0 +1,88 @@
+#![cfg(feature = "proptest")]
+
+#[cfg(test)]
+mod laws {
+    use proptest::prelude::*;
+    use redust::laws::{action_sequence, assert_deterministic, assert_invariant, assert_total};
+
+    type MyStore = Vec<u8>;
+
+    #[derive(Debug, Clone)]
+    enum MyAction {
+        Push(u8),
+        Pop,
+        Clear,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        let mut new_state = state.clone();
+        match action {
+            MyAction::Push(value) => new_state.push(*value),
+            MyAction::Pop => {
+                new_state.pop();
+            }
+            MyAction::Clear => new_state.clear(),
+        }
+
+        new_state
+    }
+
+    fn actions() -> impl Strategy<Value = Vec<MyAction>> {
+        action_sequence(
+            vec![
+                any::<u8>().prop_map(MyAction::Push).boxed(),
+                Just(MyAction::Pop).boxed(),
+                Just(MyAction::Clear).boxed(),
+            ],
+            0..64,
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn reducer_should_be_deterministic(actions in actions()) {
+            assert_deterministic(reducer, &vec![], &actions)?;
+        }
+
+        #[test]
+        fn reducer_should_never_exceed_number_of_actions(actions in actions()) {
+            assert_invariant(reducer, vec![], &actions, |state| state.len() <= 64)?;
+        }
+
+        #[test]
+        fn reducer_should_never_panic(actions in actions()) {
+            assert_total(reducer, vec![], &actions)?;
+        }
+    }
+
+    #[test]
+    fn should_fail_when_invariant_is_broken() {
+        let result = assert_invariant(
+            reducer,
+            vec![],
+            &[MyAction::Push(1), MyAction::Push(2)],
+            |state| state.len() < 2,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_fail_when_reducer_panicked() {
+        fn pop_last(state: &MyStore, action: &MyAction) -> MyStore {
+            match action {
+                MyAction::Pop => state[..state.len() - 1].to_vec(),
+                _ => reducer(state, action),
+            }
+        }
+
+        let result = assert_total(
+            pop_last,
+            vec![],
+            &[MyAction::Push(1), MyAction::Pop, MyAction::Pop],
+        );
+
+        let message = result.unwrap_err().to_string();
+        assert!(message.contains("Reducer panicked on action #2 Pop with the state []"));
+    }
+}
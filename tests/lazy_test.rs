@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod lazy {
+    use redust::Store;
+    use std::fs;
+    use std::path::Path;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    type MyStore = u8;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Increment,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Increment => state + 1,
+        }
+    }
+
+    #[test]
+    fn should_not_compute_initial_state_until_it_was_accessed() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let store = Store::new_lazy(reducer, || {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+            10
+        });
+        assert!(!store.is_initialized());
+        assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+
+        assert_eq!(*store.state(), 10);
+        assert_eq!(*store.state(), 10);
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn should_compute_initial_state_when_first_action_was_dispatched() {
+        let mut store = Store::new_lazy(reducer, || 10);
+
+        store.dispatch(MyAction::Increment);
+
+        assert!(store.is_initialized());
+        assert_eq!(*store.state(), 11);
+    }
+
+    fn load(path: &Path) -> MyStore {
+        fs::read_to_string(path).unwrap().trim().parse().unwrap()
+    }
+
+    #[test]
+    fn should_compute_initial_state_with_captured_values() {
+        let path = std::env::temp_dir().join("redust_lazy_test_state");
+        fs::write(&path, "42").unwrap();
+
+        let store = Store::new_lazy(reducer, {
+            let path = path.clone();
+            move || load(&path)
+        });
+        assert!(!store.is_initialized());
+
+        assert_eq!(*store.state(), 42);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn should_be_initialized_when_store_was_created_with_state() {
+        let store = Store::new(reducer, 0);
+
+        assert!(store.is_initialized());
+    }
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "local-effects")]
+
+#[cfg(test)]
+mod local_effect {
+    use redust::local_effect::LocalEffects;
+    use redust::DispatchQueue;
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use tokio::sync::oneshot;
+
+    #[derive(Debug, PartialEq)]
+    enum MyAction {
+        Loaded(u8),
+    }
+
+    #[test]
+    fn should_dispatch_action_when_effect_holds_non_send_resource() {
+        let queue = DispatchQueue::new();
+        let mut effects = LocalEffects::new(queue.dispatcher()).unwrap();
+        let calls = Rc::new(Cell::new(0));
+
+        let effect_calls = Rc::clone(&calls);
+        let handle = effects.spawn(move |_token| async move {
+            effect_calls.set(effect_calls.get() + 1);
+            Some(MyAction::Loaded(1))
+        });
+        effects.run_until_idle();
+
+        assert!(handle.is_finished());
+        assert_eq!(calls.get(), 1);
+        assert_eq!(queue.pop(), Some(MyAction::Loaded(1)));
+    }
+
+    #[test]
+    fn should_drive_effects_when_runtime_runs_until_future_completes() {
+        let queue = DispatchQueue::new();
+        let effects = LocalEffects::new(queue.dispatcher()).unwrap();
+        let (sender, receiver) = oneshot::channel();
+
+        effects.spawn(move |_token| async move {
+            let value = receiver.await.ok()?;
+            Some(MyAction::Loaded(value))
+        });
+        effects.run_until(async move { sender.send(2).unwrap() });
+        effects.run_until(tokio::task::yield_now());
+
+        assert_eq!(queue.pop(), Some(MyAction::Loaded(2)));
+    }
+
+    #[test]
+    fn should_drop_action_when_effect_was_cancelled() {
+        let queue = DispatchQueue::new();
+        let mut effects = LocalEffects::new(queue.dispatcher()).unwrap();
+        let (_sender, receiver) = oneshot::channel::<u8>();
+
+        let handle = effects.spawn(move |_token| async move {
+            let value = receiver.await.ok()?;
+            Some(MyAction::Loaded(value))
+        });
+        handle.cancel();
+        effects.run_until_idle();
+
+        assert!(handle.token().is_cancelled());
+        assert!(handle.is_finished());
+        assert!(queue.is_empty());
+    }
+}
@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod local {
+    use redust::{LocalStore, LocalStoreError, Store};
+    use std::rc::Rc;
+    use std::thread;
+
+    type MyStore = Rc<u8>;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Increment,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Increment => Rc::new(**state + 1),
+        }
+    }
+
+    fn create_store() -> LocalStore<MyStore, MyAction> {
+        LocalStore::new(Store::new(reducer, Rc::new(0)))
+    }
+
+    #[test]
+    fn should_give_access_to_store_when_called_from_owner_thread() {
+        let store = create_store();
+
+        store.dispatch(MyAction::Increment).unwrap();
+        let state = store.with_store(|store| **store.state()).unwrap();
+
+        assert_eq!(state, 1);
+        assert!(store.is_owned_by_current_thread());
+    }
+
+    #[test]
+    fn should_return_error_when_called_from_another_thread() {
+        let store = create_store();
+        let owner = thread::current().id();
+
+        let result = thread::spawn(move || store.dispatch(MyAction::Increment))
+            .join()
+            .unwrap();
+
+        assert_eq!(result, Err(LocalStoreError::WrongThread { owner }));
+        assert_eq!(store.with_store(|store| **store.state()), Ok(0));
+    }
+
+    #[test]
+    fn should_return_error_when_store_was_accessed_reentrantly() {
+        let store = create_store();
+
+        let nested = store
+            .with_store(|_store| store.dispatch(MyAction::Increment))
+            .unwrap();
+
+        assert_eq!(nested, Err(LocalStoreError::AlreadyBorrowed));
+    }
+
+    #[test]
+    fn should_return_error_when_store_was_taken() {
+        let store = create_store();
+        store.dispatch(MyAction::Increment).unwrap();
+
+        let inner = store.take().unwrap();
+
+        assert_eq!(**inner.state(), 1);
+        assert_eq!(
+            store.dispatch(MyAction::Increment),
+            Err(LocalStoreError::Taken)
+        );
+    }
+}
@@ -0,0 +1,108 @@
+#![cfg(feature = "log")]
+
+#[cfg(test)]
+mod logging {
+    use log::{Level, LevelFilter, Log, Metadata, Record};
+    use redust::logging::{DISPATCH, EFFECT, ERROR, RETRY};
+    use redust::{spawn_effect, DispatchQueue, ErrorBehavior, FreezePolicy, RetryPolicy, Store};
+    use std::sync::{Mutex, Once};
+    use std::time::Duration;
+
+    static RECORDS: Mutex<Vec<(String, Level, String)>> = Mutex::new(vec![]);
+    static INIT: Once = Once::new();
+
+    struct Collector;
+
+    impl Log for Collector {
+        fn enabled(&self, _metadata: &Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &Record) {
+            RECORDS.lock().unwrap().push((
+                record.target().to_string(),
+                record.level(),
+                record.args().to_string(),
+            ));
+        }
+
+        fn flush(&self) {}
+    }
+
+    // The logger is global, so tests running in parallel share the records
+    fn records(target: &str, message: &str) -> Vec<Level> {
+        INIT.call_once(|| {
+            log::set_logger(&Collector).unwrap();
+            log::set_max_level(LevelFilter::Trace);
+        });
+
+        RECORDS
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(record_target, _, text)| record_target == target && text.contains(message))
+            .map(|(_, level, _)| *level)
+            .collect()
+    }
+
+    type MyStore = u8;
+
+    #[derive(Debug)]
+    enum MyAction {
+        SetTo(u8),
+    }
+
+    fn reducer(_state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::SetTo(value) => *value,
+        }
+    }
+
+    #[test]
+    fn should_log_reduced_action_when_logger_middleware_is_used() {
+        records(DISPATCH, "");
+        let mut store = Store::builder(reducer, 0).logger().build();
+
+        store.dispatch(MyAction::SetTo(17));
+
+        assert_eq!(records(DISPATCH, "SetTo(17): 0 -> 17"), [Level::Debug]);
+    }
+
+    #[test]
+    fn should_log_store_failure_when_error_behavior_is_log() {
+        records(ERROR, "");
+        let mut store = Store::new(reducer, 0);
+        store.set_error_behavior(ErrorBehavior::Log);
+        store.freeze(FreezePolicy::Reject);
+
+        let _ = store.try_dispatch(MyAction::SetTo(23));
+
+        assert_eq!(records(ERROR, "SetTo(23)"), [Level::Error]);
+    }
+
+    #[test]
+    fn should_log_every_failed_attempt_when_effect_is_retried() {
+        records(RETRY, "");
+        let policy = RetryPolicy::fixed(Duration::from_millis(1), 3);
+
+        let result = policy.run(|_attempt| Err::<(), _>("offline"));
+
+        assert!(result.is_err());
+        assert!(records(RETRY, "Attempt 2 failed").contains(&Level::Warn));
+        assert!(records(RETRY, "All 3 attempts failed").contains(&Level::Error));
+    }
+
+    #[test]
+    fn should_log_dropped_action_when_queue_was_closed() {
+        records(EFFECT, "");
+        let queue = DispatchQueue::new();
+        let dispatcher = queue.dispatcher();
+        drop(queue);
+
+        spawn_effect(dispatcher, |_token| Some(MyAction::SetTo(1)))
+            .join()
+            .unwrap();
+
+        assert!(!records(EFFECT, "Cannot enqueue the action of an effect").is_empty());
+    }
+}
@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod memory {
+    use redust::{HistoryPolicy, MemSize, MemoryReport, Store};
+    use std::collections::HashMap;
+    use std::mem::size_of;
+
+    type MyStore = Vec<u8>;
+
+    #[derive(Debug, Clone)]
+    enum MyAction {
+        Push(u8),
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Push(value) => {
+                let mut new_state = Vec::with_capacity(state.len() + 1);
+                new_state.extend_from_slice(state);
+                new_state.push(*value);
+
+                new_state
+            }
+        }
+    }
+
+    fn len_of(state: &MyStore) -> usize {
+        state.len()
+    }
+
+    #[test]
+    fn should_measure_owned_values_when_mem_size_is_called() {
+        assert_eq!(7u32.mem_size(), 4);
+        assert_eq!(
+            String::with_capacity(10).mem_size(),
+            size_of::<String>() + 10
+        );
+        assert_eq!(
+            Some(Box::new(1u64)).mem_size(),
+            size_of::<Option<Box<u64>>>() + 8
+        );
+
+        let mut map = HashMap::new();
+        map.insert(1u8, vec![1u16, 2]);
+        assert!(
+            map.mem_size() >= size_of::<HashMap<u8, Vec<u16>>>() + 1 + size_of::<Vec<u16>>() + 4
+        );
+    }
+
+    #[test]
+    fn should_report_state_and_subscriptions_when_history_is_disabled() {
+        let mut store = Store::new(reducer, vec![]);
+        store.subscribe(|_state| {});
+        store.subscribe(|_state| {});
+        store.dispatch(MyAction::Push(1));
+
+        assert_eq!(
+            store.memory_report(),
+            MemoryReport {
+                state: size_of::<MyStore>() + 1,
+                history_entries: 0,
+                history_snapshots: 0,
+                subscriptions: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn should_report_kept_snapshots_when_history_is_enabled() {
+        let mut store = Store::new(reducer, vec![]);
+        store.enable_history(HistoryPolicy::unbounded());
+        store
+            .dispatch(MyAction::Push(1))
+            .dispatch(MyAction::Push(2))
+            .dispatch(MyAction::Push(3));
+
+        let report = store.memory_report_with(len_of);
+
+        assert_eq!(report.state, 3);
+        assert_eq!(report.history_entries, 3);
+        // The latest snapshot is the current state
+        assert_eq!(report.history_snapshots, 1 + 2);
+        assert_eq!(report.total(), 6);
+    }
+
+    #[test]
+    fn should_count_travelled_to_snapshot_once_when_history_is_enabled() {
+        let mut store = Store::new(reducer, vec![]);
+        store.enable_history(HistoryPolicy::unbounded());
+        store
+            .dispatch(MyAction::Push(1))
+            .dispatch(MyAction::Push(2))
+            .travel_back(1)
+            .unwrap();
+
+        let report = store.memory_report_with(len_of);
+
+        assert_eq!(report.state, 1);
+        assert_eq!(report.history_snapshots, 2);
+        assert_eq!(report.total(), 3);
+    }
+
+    #[test]
+    fn should_stop_reporting_evicted_history_when_it_is_bounded() {
+        let mut store = Store::new(reducer, vec![]);
+        store.enable_history(HistoryPolicy::unbounded().max_entries(1));
+        store
+            .dispatch(MyAction::Push(1))
+            .dispatch(MyAction::Push(2));
+
+        let report = store.memory_report_with(len_of);
+
+        assert_eq!(report.history_entries, 1);
+        assert_eq!(report.history_snapshots, 1);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_estimate_with_json_length_when_serialized_size_is_used() {
+        let mut store = Store::new(reducer, vec![]);
+        store.dispatch(MyAction::Push(10));
+
+        assert_eq!(store.memory_report_with(redust::serialized_size).state, 4);
+    }
+}
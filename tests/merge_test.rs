@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod merge {
+    use redust::{DispatchError, FreezePolicy, MergeError, MergeStrategy, Store};
+    use std::sync::Arc;
+
+    type MyStore = Vec<u8>;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Push(u8),
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Push(value) => {
+                let mut new_state = state.clone();
+                new_state.push(*value);
+
+                new_state
+            }
+        }
+    }
+
+    fn keep_both(base: &MyStore, main: &MyStore, fork: &MyStore) -> MyStore {
+        let mut merged = main.clone();
+        merged.extend_from_slice(&fork[base.len()..]);
+
+        merged
+    }
+
+    fn unexpected(_base: &MyStore, _main: &MyStore, _fork: &MyStore) -> MyStore {
+        panic!("Resolver should not be called")
+    }
+
+    #[test]
+    fn should_call_resolver_when_both_stores_changed() {
+        let mut store = Store::new(reducer, vec![1]);
+        let mut fork = store.fork();
+
+        fork.dispatch(MyAction::Push(2));
+        store.dispatch(MyAction::Push(3));
+        store
+            .merge(fork, MergeStrategy::Resolve(keep_both))
+            .unwrap();
+
+        assert_eq!(*store.state(), vec![1, 3, 2]);
+    }
+
+    #[test]
+    fn should_take_fork_state_when_main_store_did_not_change() {
+        let mut store = Store::new(reducer, vec![1]);
+        let mut fork = store.fork();
+        fork.dispatch(MyAction::Push(2));
+        let fork_state = fork.shared_state();
+
+        store
+            .merge(fork, MergeStrategy::Resolve(unexpected))
+            .unwrap();
+
+        assert!(Arc::ptr_eq(&store.shared_state(), &fork_state));
+        assert_eq!(store.version(), 1);
+    }
+
+    #[test]
+    fn should_not_call_resolver_when_fork_did_not_change() {
+        let mut store = Store::new(reducer, vec![1]);
+        let fork = store.fork();
+        store.dispatch(MyAction::Push(2));
+
+        store
+            .merge(fork, MergeStrategy::Resolve(unexpected))
+            .unwrap();
+
+        assert_eq!(*store.state(), vec![1, 2]);
+        assert_eq!(store.version(), 1);
+    }
+
+    #[test]
+    fn should_dispatch_fork_actions_when_replay_strategy_is_used() {
+        let mut store = Store::new(reducer, vec![1]);
+        let mut fork = store.fork();
+
+        fork.dispatch(MyAction::Push(2)).dispatch(MyAction::Push(3));
+        store.dispatch(MyAction::Push(4));
+        store.merge(fork, MergeStrategy::Replay).unwrap();
+
+        assert_eq!(*store.state(), vec![1, 4, 2, 3]);
+    }
+
+    #[test]
+    fn should_return_error_when_replayed_action_was_rejected() {
+        let mut store = Store::new(reducer, vec![]);
+        let mut fork = store.fork();
+        fork.dispatch(MyAction::Push(1));
+        store.freeze(FreezePolicy::Reject);
+
+        assert_eq!(
+            store.merge(fork, MergeStrategy::Replay).err(),
+            Some(MergeError::Dispatch(DispatchError::Frozen))
+        );
+    }
+
+    #[test]
+    fn should_return_error_when_merged_store_is_not_a_fork() {
+        let mut store = Store::new(reducer, vec![]);
+        let other = Store::new(reducer, vec![1]);
+
+        assert_eq!(
+            store.merge(other, MergeStrategy::Replay).err(),
+            Some(MergeError::NotAFork)
+        );
+    }
+}
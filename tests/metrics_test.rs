@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod metrics {
+    use redust::{DispatchError, FreezePolicy, Store, REDUCER_DURATION_BUCKETS};
+    use std::thread;
+    use std::time::Duration;
+
+    type MyStore = u8;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Increment,
+        Sleep(Duration),
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Increment => state + 1,
+            MyAction::Sleep(duration) => {
+                thread::sleep(*duration);
+                *state
+            }
+        }
+    }
+
+    #[test]
+    fn should_not_collect_metrics_when_they_are_disabled() {
+        let mut store = Store::new(reducer, 0);
+        store.dispatch(MyAction::Increment);
+
+        assert_eq!(store.dispatch_metrics(), None);
+    }
+
+    #[test]
+    fn should_count_reduced_actions_when_metrics_are_enabled() {
+        let mut store = Store::new(reducer, 0);
+        store.enable_metrics();
+        store
+            .dispatch(MyAction::Increment)
+            .dispatch(MyAction::Increment);
+        store.freeze(FreezePolicy::Reject);
+        assert_eq!(
+            store.try_dispatch(MyAction::Increment).err(),
+            Some(DispatchError::Frozen)
+        );
+
+        let metrics = store.dispatch_metrics().unwrap();
+
+        assert_eq!(metrics.dispatched, 2);
+        assert_eq!(metrics.reducer_buckets.iter().sum::<u64>(), 2);
+    }
+
+    #[test]
+    fn should_put_slow_reducer_into_upper_bucket_when_it_is_measured() {
+        let mut store = Store::new(reducer, 0);
+        store.enable_metrics();
+        store.dispatch(MyAction::Sleep(Duration::from_millis(20)));
+
+        let metrics = store.dispatch_metrics().unwrap();
+        let bucket = metrics.reducer_buckets.iter().position(|count| *count == 1);
+
+        assert!(REDUCER_DURATION_BUCKETS[bucket.unwrap()] >= Duration::from_millis(20));
+        assert!(metrics.reducer_total >= Duration::from_millis(20));
+    }
+
+    #[test]
+    fn should_drop_metrics_when_they_are_disabled() {
+        let mut store = Store::new(reducer, 0);
+        store.enable_metrics();
+        store.dispatch(MyAction::Increment);
+        store.disable_metrics();
+        store.enable_metrics();
+
+        assert_eq!(store.dispatch_metrics().unwrap().dispatched, 0);
+    }
+}
@@ -0,0 +1,198 @@
+#[cfg(test)]
+mod middleware {
+    use redust::test::MockStore;
+    use redust::{compose, DispatchError, Middleware, MiddlewareStack, Next, Store};
+    use std::sync::Mutex;
+
+    type MyStore = Vec<&'static str>;
+
+    #[derive(Debug, Clone)]
+    enum MyAction {
+        Log(&'static str),
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Log(message) => {
+                let mut new_state = state.clone();
+                new_state.push(message);
+
+                new_state
+            }
+        }
+    }
+
+    // Records the name of the middleware before and after the rest of the chain
+    struct Trace(&'static str, &'static Mutex<Vec<String>>);
+
+    impl Middleware<MyStore, MyAction> for Trace {
+        fn handle(
+            &self,
+            action: MyAction,
+            next: Next<'_, MyStore, MyAction>,
+        ) -> Result<MyAction, DispatchError> {
+            self.1.lock().unwrap().push(format!("{} before", self.0));
+            let result = next.run(action);
+            self.1.lock().unwrap().push(format!("{} after", self.0));
+
+            result
+        }
+    }
+
+    struct Drop;
+
+    impl Middleware<MyStore, MyAction> for Drop {
+        fn handle(
+            &self,
+            _action: MyAction,
+            _next: Next<'_, MyStore, MyAction>,
+        ) -> Result<MyAction, DispatchError> {
+            Err(DispatchError::Intercepted)
+        }
+    }
+
+    #[test]
+    fn should_run_middleware_in_stack_order_when_action_was_dispatched() {
+        static TRACE: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+        let mut store = Store::new(reducer, vec![]);
+        store.apply_middleware(compose![
+            "first" => Trace("first", &TRACE),
+            "second" => Trace("second", &TRACE),
+        ]);
+
+        store.dispatch(MyAction::Log("hello"));
+
+        assert_eq!(*store.state(), vec!["hello"]);
+        assert_eq!(
+            *TRACE.lock().unwrap(),
+            vec![
+                "first before",
+                "second before",
+                "second after",
+                "first after"
+            ]
+        );
+    }
+
+    #[test]
+    fn should_not_reduce_action_when_middleware_dropped_it() {
+        let mut store = Store::new(reducer, vec![]);
+        store.add_middleware("drop", Drop);
+
+        assert_eq!(
+            store.try_dispatch(MyAction::Log("hello")).err(),
+            Some(DispatchError::Intercepted)
+        );
+        assert!(store.state().is_empty());
+    }
+
+    #[test]
+    fn should_describe_nested_stacks_with_prefixed_names() {
+        static TRACE: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+        let inner = MiddlewareStack::new()
+            .with("auth", Trace("auth", &TRACE))
+            .with("validate", Trace("validate", &TRACE));
+        let stack = MiddlewareStack::new()
+            .with("logger", Trace("logger", &TRACE))
+            .with_stack("security", inner)
+            .with("drop", Drop);
+
+        assert_eq!(
+            stack.names(),
+            vec!["logger", "security/auth", "security/validate", "drop"]
+        );
+        assert_eq!(
+            stack.describe(),
+            "1. logger\n2. security/auth\n3. security/validate\n4. drop\n5. reducer"
+        );
+    }
+
+    // Queues follow-up messages before the action is reduced
+    fn follow_up(
+        action: MyAction,
+        mut next: Next<'_, MyStore, MyAction>,
+    ) -> Result<MyAction, DispatchError> {
+        let MyAction::Log(message) = action;
+        match message {
+            "a" => {
+                next.dispatch(MyAction::Log("b"));
+                next.dispatch(MyAction::Log("c"));
+            }
+            "b" => next.dispatch(MyAction::Log("d")),
+            _ => {}
+        }
+
+        next.run(action)
+    }
+
+    #[test]
+    fn should_dispatch_queued_actions_in_order_when_middleware_dispatched_them() {
+        let mut store = Store::new(reducer, vec![]);
+        store.add_middleware("follow-up", follow_up);
+
+        store.dispatch(MyAction::Log("a"));
+
+        assert_eq!(*store.state(), ["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn should_pass_queued_actions_through_middleware_when_they_are_dispatched() {
+        static TRACE: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+        let mut store = Store::new(reducer, vec![]);
+        store.add_middleware("trace", Trace("trace", &TRACE));
+        store.add_middleware("follow-up", follow_up);
+
+        store.try_dispatch(MyAction::Log("b")).unwrap();
+
+        assert_eq!(*store.state(), ["b", "d"]);
+        assert_eq!(
+            *TRACE.lock().unwrap(),
+            ["trace before", "trace after", "trace before", "trace after"]
+        );
+    }
+
+    #[test]
+    fn should_dispatch_follow_up_with_new_state_when_action_was_reduced() {
+        let mut store = Store::new(reducer, vec![]);
+        store.add_middleware("count", |action, next: Next<'_, MyStore, MyAction>| {
+            next.run_and_dispatch(action, |_action, state: &MyStore| {
+                (state.len() == 2).then_some(MyAction::Log("two"))
+            })
+        });
+
+        store
+            .dispatch(MyAction::Log("one"))
+            .dispatch(MyAction::Log("one"));
+
+        assert_eq!(*store.state(), ["one", "one", "two"]);
+    }
+
+    #[test]
+    fn should_not_dispatch_queued_actions_when_action_was_dropped() {
+        let mut store = Store::new(reducer, vec![]);
+        store.add_middleware("count", |action, next: Next<'_, MyStore, MyAction>| {
+            next.run_and_dispatch(action, |_action, _state: &MyStore| {
+                Some(MyAction::Log("never"))
+            })
+        });
+        store.add_middleware("drop", Drop);
+
+        store.dispatch(MyAction::Log("a"));
+
+        assert!(store.state().is_empty());
+    }
+
+    #[test]
+    fn should_record_queued_actions_when_mock_store_dispatched_them() {
+        let mut store = MockStore::new(reducer, vec![]);
+        store.add_middleware("follow-up", follow_up);
+
+        store.dispatch(MyAction::Log("b"));
+
+        assert_eq!(store.states().len(), 2);
+        assert_eq!(store.states()[1], ["b", "d"]);
+    }
+}
@@ -0,0 +1,152 @@
+#[cfg(test)]
+mod migration {
+    use redust::{
+        Migration, MigrationError, Migrations, Store, Version, Versioned, VersionedState,
+    };
+
+    // Raw representation is a list of `key=value` pairs
+    type Raw = Vec<(String, String)>;
+
+    #[derive(Debug, PartialEq)]
+    struct Profile {
+        first_name: String,
+        last_name: String,
+    }
+
+    impl VersionedState for Profile {
+        type Raw = Raw;
+        const VERSION: Version = 2;
+
+        fn from_raw(raw: Raw) -> Self {
+            let get = |key: &str| {
+                raw.iter()
+                    .find(|(name, _)| name == key)
+                    .map(|(_, value)| value.clone())
+                    .unwrap_or_default()
+            };
+
+            Profile {
+                first_name: get("first_name"),
+                last_name: get("last_name"),
+            }
+        }
+
+        fn to_raw(&self) -> Raw {
+            vec![
+                ("first_name".to_string(), self.first_name.clone()),
+                ("last_name".to_string(), self.last_name.clone()),
+            ]
+        }
+    }
+
+    // Version 0 -> 1: `name` was split into `first_name` and `last_name`
+    struct SplitName;
+
+    impl Migration<Raw> for SplitName {
+        fn source_version(&self) -> Version {
+            0
+        }
+
+        fn migrate(&self, raw: Raw) -> Raw {
+            raw.into_iter()
+                .flat_map(|(key, value)| {
+                    if key != "name" {
+                        return vec![(key, value)];
+                    }
+
+                    let mut parts = value.splitn(2, ' ');
+                    let first_name = parts.next().unwrap_or_default().to_string();
+                    let last_name = parts.next().unwrap_or_default().to_string();
+
+                    vec![
+                        ("first_name".to_string(), first_name),
+                        ("last_name".to_string(), last_name),
+                    ]
+                })
+                .collect()
+        }
+    }
+
+    // Version 1 -> 2: last names are stored in upper case
+    struct UppercaseLastName;
+
+    impl Migration<Raw> for UppercaseLastName {
+        fn source_version(&self) -> Version {
+            1
+        }
+
+        fn migrate(&self, raw: Raw) -> Raw {
+            raw.into_iter()
+                .map(|(key, value)| match key.as_str() {
+                    "last_name" => (key, value.to_uppercase()),
+                    _ => (key, value),
+                })
+                .collect()
+        }
+    }
+
+    enum MyAction {}
+
+    fn reducer(state: &Profile, _action: &MyAction) -> Profile {
+        Profile {
+            first_name: state.first_name.clone(),
+            last_name: state.last_name.clone(),
+        }
+    }
+
+    fn migrations() -> Migrations<Raw> {
+        Migrations::new()
+            .register(SplitName)
+            .register(UppercaseLastName)
+    }
+
+    #[test]
+    fn should_apply_all_migrations_step_by_step_when_state_is_old() {
+        let saved = Versioned {
+            version: 0,
+            state: vec![("name".to_string(), "John Smith".to_string())],
+        };
+
+        let store = Store::hydrate(reducer, saved, &migrations()).unwrap();
+
+        assert_eq!(
+            *store.state(),
+            Profile {
+                first_name: "John".to_string(),
+                last_name: "SMITH".to_string(),
+            }
+        );
+        assert_eq!(store.dehydrate().version, 2);
+    }
+
+    #[test]
+    fn should_return_an_error_when_state_is_newer_than_supported() {
+        let saved = Versioned {
+            version: 3,
+            state: vec![],
+        };
+
+        let result = Store::hydrate(reducer, saved, &migrations());
+
+        assert_eq!(
+            result.err(),
+            Some(MigrationError::UnsupportedVersion {
+                found: 3,
+                supported: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn should_return_an_error_when_migration_is_missing() {
+        let saved = Versioned {
+            version: 0,
+            state: vec![],
+        };
+        let migrations = Migrations::new().register(UppercaseLastName);
+
+        let result = Store::hydrate(reducer, saved, &migrations);
+
+        assert_eq!(result.err(), Some(MigrationError::MissingMigration(0)));
+    }
+}
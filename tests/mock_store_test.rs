@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod mock_store {
+    use redust::test::MockStore;
+    use redust::{assert_dispatched, assert_state_seq, DispatchError, FreezePolicy, StoreError};
+
+    type MyStore = u8;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum MyAction {
+        Increment,
+        IncrementBy(u8),
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Increment => state + 1,
+            MyAction::IncrementBy(value) => state + value,
+        }
+    }
+
+    #[test]
+    fn should_record_actions_and_states_when_dispatch_was_called() {
+        let mut store = MockStore::new(reducer, 0);
+
+        store
+            .dispatch(MyAction::Increment)
+            .dispatch(MyAction::IncrementBy(5));
+
+        assert_eq!(
+            store.actions(),
+            &[MyAction::Increment, MyAction::IncrementBy(5)]
+        );
+        assert_eq!(*store.state(), 6);
+        assert_dispatched!(store, MyAction::IncrementBy(_));
+        assert_state_seq!(store, [1, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "No dispatched action matches")]
+    fn should_panic_when_action_was_not_dispatched() {
+        let mut store = MockStore::new(reducer, 0);
+        store.dispatch(MyAction::Increment);
+
+        assert_dispatched!(store, MyAction::IncrementBy(_));
+    }
+
+    #[test]
+    fn should_forget_recorded_data_when_clear_was_called() {
+        let mut store = MockStore::new(reducer, 0);
+        store.dispatch(MyAction::Increment);
+
+        store.clear();
+
+        assert!(store.actions().is_empty());
+        assert_state_seq!(store, []);
+        assert_eq!(*store.state(), 1);
+    }
+
+    #[test]
+    fn should_keep_last_action_when_mock_store_dispatched_it() {
+        let mut store = MockStore::new(reducer, 0);
+
+        store
+            .dispatch(MyAction::Increment)
+            .dispatch(MyAction::IncrementBy(5));
+
+        assert_eq!(store.last_action(), Some(&MyAction::IncrementBy(5)));
+        assert!(store.last_dispatched_at().is_some());
+    }
+
+    #[test]
+    fn should_report_error_when_mock_store_could_not_dispatch_action() {
+        let mut store = MockStore::new(reducer, 0);
+        let errors = store.errors();
+        store.freeze(FreezePolicy::Reject);
+
+        store.dispatch(MyAction::Increment);
+
+        assert_eq!(
+            errors.try_recv(),
+            Ok(StoreError::Dispatch(DispatchError::Frozen))
+        );
+        assert!(store.actions().is_empty());
+        assert_eq!(store.last_action(), None);
+    }
+}
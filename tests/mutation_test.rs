@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod mutation {
+    use redust::Store;
+    use std::hash::{Hash, Hasher};
+    use std::sync::atomic::{AtomicU8, Ordering};
+    use std::sync::Arc;
+
+    #[derive(Debug, Default)]
+    struct MyStore {
+        total: u8,
+        shared: Arc<AtomicU8>,
+    }
+
+    impl Hash for MyStore {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.total.hash(state);
+            self.shared.load(Ordering::SeqCst).hash(state);
+        }
+    }
+
+    #[derive(Debug)]
+    enum MyAction {
+        Increment,
+        IncrementShared,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Increment => MyStore {
+                total: state.total + 1,
+                shared: Arc::new(AtomicU8::new(state.shared.load(Ordering::SeqCst))),
+            },
+            MyAction::IncrementShared => {
+                state.shared.fetch_add(1, Ordering::SeqCst);
+
+                MyStore {
+                    total: state.total,
+                    shared: Arc::clone(&state.shared),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn should_not_panic_when_reducer_returned_new_state() {
+        let mut store = Store::new(reducer, MyStore::default());
+        store.check_mutations();
+
+        store.dispatch(MyAction::Increment);
+
+        assert_eq!(store.state().total, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Reducer mutated the previous state while reducing IncrementShared")]
+    fn should_panic_with_action_when_reducer_mutated_previous_state() {
+        let mut store = Store::new(reducer, MyStore::default());
+        store.check_mutations();
+
+        store.dispatch(MyAction::Increment);
+        store.dispatch(MyAction::IncrementShared);
+    }
+
+    #[test]
+    fn should_not_panic_when_checks_were_skipped() {
+        let mut store = Store::new(reducer, MyStore::default());
+        store.check_mutations();
+        store.skip_mutation_checks();
+
+        store.dispatch(MyAction::IncrementShared);
+
+        assert_eq!(store.state().shared.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn should_not_panic_when_checks_were_not_enabled() {
+        let mut store = Store::new(reducer, MyStore::default());
+
+        store.dispatch(MyAction::IncrementShared);
+
+        assert_eq!(store.state().shared.load(Ordering::SeqCst), 1);
+    }
+}
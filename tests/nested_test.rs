@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod nested {
+    use redust::{nest_actions, Store};
+
+    #[derive(Debug, PartialEq)]
+    enum CounterAction {
+        Increment,
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum NameAction {
+        Rename(&'static str),
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum AppAction {
+        Counter(CounterAction),
+        Name(NameAction),
+        Reset,
+    }
+
+    nest_actions!(AppAction {
+        Counter(CounterAction),
+        Name(NameAction),
+    });
+
+    type MyStore = (u8, &'static str);
+
+    fn reducer(state: &MyStore, action: &AppAction) -> MyStore {
+        match action {
+            AppAction::Counter(CounterAction::Increment) => (state.0 + 1, state.1),
+            AppAction::Name(NameAction::Rename(name)) => (state.0, name),
+            AppAction::Reset => (0, ""),
+        }
+    }
+
+    #[test]
+    fn should_wrap_slice_action_into_its_variant() {
+        assert_eq!(
+            AppAction::from(CounterAction::Increment),
+            AppAction::Counter(CounterAction::Increment)
+        );
+        assert_eq!(
+            AppAction::from(NameAction::Rename("redust")),
+            AppAction::Name(NameAction::Rename("redust"))
+        );
+    }
+
+    #[test]
+    fn should_dispatch_local_and_root_actions_when_dispatched_from() {
+        let mut store = Store::new(reducer, (0, ""));
+        store
+            .dispatch_from(CounterAction::Increment)
+            .dispatch_from(NameAction::Rename("redust"))
+            .dispatch_from(AppAction::Reset)
+            .dispatch_from(CounterAction::Increment);
+
+        assert_eq!(*store.state(), (1, ""));
+    }
+}
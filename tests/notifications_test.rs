@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod notifications {
+    use redust::{NotifyPolicy, Store};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    type MyStore = u8;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Increment,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Increment => state + 1,
+        }
+    }
+
+    #[test]
+    fn should_not_call_subscribers_when_notifications_are_paused() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let mut store = Store::new(reducer, 0);
+        store.subscribe(|_state| {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+        });
+
+        store.pause_notifications();
+        store
+            .dispatch(MyAction::Increment)
+            .dispatch(MyAction::Increment);
+
+        assert!(store.notifications_paused());
+        assert_eq!(*store.state(), 2);
+        assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn should_drop_missed_updates_when_resumed_with_drop_policy() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let mut store = Store::new(reducer, 0);
+        store.subscribe(|_state| {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+        });
+
+        store.pause_notifications();
+        store.dispatch(MyAction::Increment);
+        store.resume_notifications(NotifyPolicy::Drop);
+        assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+
+        store.dispatch(MyAction::Increment);
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn should_notify_once_when_resumed_with_catch_up_policy() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let mut store = Store::new(reducer, 0);
+        store.subscribe(|state| {
+            assert_eq!(*state, 3);
+            CALLS.fetch_add(1, Ordering::SeqCst);
+        });
+
+        store.pause_notifications();
+        store
+            .dispatch(MyAction::Increment)
+            .dispatch(MyAction::Increment)
+            .dispatch(MyAction::Increment);
+        store.resume_notifications(NotifyPolicy::CatchUp);
+
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn should_not_notify_when_resumed_with_catch_up_policy_without_updates() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let mut store = Store::new(reducer, 0);
+        store.subscribe(|_state| {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+        });
+
+        store.pause_notifications();
+        store.resume_notifications(NotifyPolicy::CatchUp);
+
+        assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+    }
+}
@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod optics {
+    use redust::{update, Store};
+    use std::collections::HashMap;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Todo {
+        title: &'static str,
+        checked: bool,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Todos {
+        todos: Vec<Todo>,
+    }
+
+    fn create_state() -> Todos {
+        Todos {
+            todos: vec![Todo {
+                title: "Buy milk",
+                checked: false,
+            }],
+        }
+    }
+
+    #[test]
+    fn should_change_nested_field_when_path_exists() {
+        let state = create_state();
+
+        let new_state = update!(state.todos[0].checked = true);
+
+        assert!(new_state.todos[0].checked);
+        assert_eq!(new_state.todos[0].title, "Buy milk");
+        assert_eq!(state, create_state());
+    }
+
+    #[test]
+    fn should_return_unchanged_copy_when_fallible_step_returned_none() {
+        let state = create_state();
+
+        let new_state = update!(state.todos.get_mut(1)?.checked = true);
+
+        assert_eq!(new_state, state);
+    }
+
+    #[test]
+    fn should_replace_value_behind_reference_when_path_is_dereferenced() {
+        let state = create_state();
+        let todo = Todo {
+            title: "Buy bread",
+            checked: true,
+        };
+
+        let new_state = update!(*state.todos.first_mut()? = todo.clone());
+
+        assert_eq!(new_state.todos, vec![todo]);
+    }
+
+    #[derive(Debug, Clone, Default)]
+    struct TodosById {
+        todos: HashMap<u8, Todo>,
+    }
+
+    enum TodosAction {
+        Add(u8, Todo),
+        Change(u8, Todo),
+        Check(u8),
+    }
+
+    fn reducer(state: &TodosById, action: &TodosAction) -> TodosById {
+        match action {
+            TodosAction::Add(id, todo) => {
+                let mut new_state = state.clone();
+                new_state.todos.insert(*id, todo.clone());
+
+                new_state
+            }
+            TodosAction::Change(id, todo) => update!(*state.todos.get_mut(id)? = todo.clone()),
+            TodosAction::Check(id) => update!(state.todos.get_mut(id)?.checked = true),
+        }
+    }
+
+    #[test]
+    fn should_change_map_entries_when_used_in_reducer() {
+        let todo = Todo {
+            title: "Buy milk",
+            checked: false,
+        };
+        let mut store = Store::new(reducer, TodosById::default());
+
+        store
+            .dispatch(TodosAction::Add(1, todo.clone()))
+            .dispatch(TodosAction::Check(1))
+            .dispatch(TodosAction::Check(2))
+            .dispatch(TodosAction::Change(
+                2,
+                Todo {
+                    title: "Buy bread",
+                    checked: false,
+                },
+            ));
+
+        assert_eq!(store.state().todos.len(), 1);
+        assert_eq!(
+            store.state().todos.get(&1),
+            Some(&Todo {
+                title: "Buy milk",
+                checked: true,
+            })
+        );
+    }
+}
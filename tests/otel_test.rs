@@ -0,0 +1,204 @@
+#![cfg(feature = "otel")]
+
+#[cfg(test)]
+mod otel {
+    use redust::otel::TracingMiddleware;
+    use redust::{spawn_async_effect, BlockingRuntime, DispatchError, FreezePolicy, Next, Store};
+    use std::cell::RefCell;
+    use std::fmt::Debug;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+    use tracing_core::span::Current;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct RecordedSpan {
+        name: &'static str,
+        parent: Option<u64>,
+        fields: Vec<(&'static str, String)>,
+    }
+
+    impl Visit for RecordedSpan {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.fields.push((field.name(), format!("{:?}", value)));
+        }
+    }
+
+    thread_local! {
+        static ENTERED: RefCell<Vec<(Id, &'static Metadata<'static>)>> = const { RefCell::new(vec![]) };
+    }
+
+    /// Keeps every span with its parent; ids are indexes into `spans` plus one
+    #[derive(Clone, Default)]
+    struct Recorder {
+        spans: Arc<Mutex<Vec<(RecordedSpan, &'static Metadata<'static>)>>>,
+    }
+
+    impl Recorder {
+        fn spans(&self) -> Vec<RecordedSpan> {
+            let spans = self.spans.lock().unwrap();
+            spans.iter().map(|(span, _)| span.clone()).collect()
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, attributes: &Attributes<'_>) -> Id {
+            let parent = match attributes.parent() {
+                Some(parent) => Some(parent.into_u64()),
+                None if attributes.is_contextual() => {
+                    ENTERED.with(|entered| entered.borrow().last().map(|(id, _)| id.into_u64()))
+                }
+                None => None,
+            };
+            let mut span = RecordedSpan {
+                name: attributes.metadata().name(),
+                parent,
+                fields: vec![],
+            };
+            attributes.record(&mut span);
+
+            let mut spans = self.spans.lock().unwrap();
+            spans.push((span, attributes.metadata()));
+            Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, id: &Id, values: &Record<'_>) {
+            let mut spans = self.spans.lock().unwrap();
+            values.record(&mut spans[id.into_u64() as usize - 1].0);
+        }
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, _event: &Event<'_>) {}
+
+        fn enter(&self, id: &Id) {
+            let metadata = self.spans.lock().unwrap()[id.into_u64() as usize - 1].1;
+            ENTERED.with(|entered| entered.borrow_mut().push((id.clone(), metadata)));
+        }
+
+        fn exit(&self, _id: &Id) {
+            ENTERED.with(|entered| entered.borrow_mut().pop());
+        }
+
+        fn current_span(&self) -> Current {
+            ENTERED.with(|entered| match entered.borrow().last() {
+                Some((id, metadata)) => Current::new(id.clone(), metadata),
+                None => Current::none(),
+            })
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum MyAction {
+        Fetch,
+        Loaded(&'static str),
+    }
+
+    type MyStore = Vec<&'static str>;
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        let mut users = state.clone();
+        if let MyAction::Loaded(user) = action {
+            users.push(user);
+        }
+
+        users
+    }
+
+    fn field(span: &RecordedSpan, name: &str) -> Option<String> {
+        span.fields
+            .iter()
+            .find(|(field, _)| *field == name)
+            .map(|(_, value)| value.clone())
+    }
+
+    #[test]
+    fn should_wrap_dispatch_in_span_when_tracing_middleware_is_added() {
+        let recorder = Recorder::default();
+        let mut store = Store::new(reducer, vec![]);
+        store.add_middleware("otel", TracingMiddleware);
+
+        tracing::subscriber::with_default(recorder.clone(), || {
+            store.dispatch(MyAction::Loaded("Ann"));
+        });
+
+        let spans = recorder.spans();
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].name, "redust.dispatch");
+        assert_eq!(
+            field(&spans[0], "action").as_deref(),
+            Some("Loaded(\"Ann\")")
+        );
+        assert_eq!(field(&spans[0], "error"), None);
+    }
+
+    #[test]
+    fn should_record_error_when_dispatch_fails() {
+        let recorder = Recorder::default();
+        let mut store = Store::new(reducer, vec![]);
+        store.add_middleware("otel", TracingMiddleware);
+        store.freeze(FreezePolicy::Reject);
+
+        tracing::subscriber::with_default(recorder.clone(), || {
+            assert_eq!(
+                store.try_dispatch(MyAction::Fetch).err(),
+                Some(DispatchError::Frozen)
+            );
+        });
+
+        let spans = recorder.spans();
+        assert_eq!(
+            field(&spans[0], "otel.status_code").as_deref(),
+            Some("\"ERROR\"")
+        );
+        assert!(field(&spans[0], "error").is_some());
+    }
+
+    #[test]
+    fn should_link_follow_up_action_to_dispatch_when_effect_enqueues_it() {
+        let recorder = Recorder::default();
+        let runtime = BlockingRuntime::new();
+        let (spawner, dispatcher) = (runtime.spawner(), runtime.dispatcher());
+
+        let mut store = Store::new(reducer, vec![]);
+        store.add_middleware("otel", TracingMiddleware);
+        store.add_middleware("fetch", move |action, next: Next<_, _>| {
+            let action = next.run(action)?;
+            if action == MyAction::Fetch {
+                spawn_async_effect(spawner.as_ref(), dispatcher.clone(), |_token| async {
+                    Some(MyAction::Loaded("Ann"))
+                });
+            }
+
+            Ok(action)
+        });
+
+        tracing::subscriber::with_default(recorder.clone(), || {
+            store.dispatch_blocking(&runtime, MyAction::Fetch);
+        });
+
+        let spans = recorder
+            .spans()
+            .into_iter()
+            .map(|span| (span.name, span.parent, field(&span, "action")))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            spans,
+            [
+                ("redust.dispatch", None, Some("Fetch".to_string())),
+                ("redust.effect", Some(1), None),
+                (
+                    "redust.dispatch",
+                    Some(2),
+                    Some("Loaded(\"Ann\")".to_string())
+                ),
+            ]
+        );
+        assert_eq!(*store.state(), ["Ann"]);
+    }
+}
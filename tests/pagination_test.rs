@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod pagination {
+    use redust::{EntityAdapter, PaginatedState, Store};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct User {
+        id: u32,
+        name: &'static str,
+    }
+
+    type MyStore = PaginatedState<u32, User>;
+
+    #[derive(Debug)]
+    enum MyAction {
+        PageLoaded {
+            page: usize,
+            users: Vec<User>,
+            next_key: Option<String>,
+            total: Option<usize>,
+        },
+        UserRenamed(User),
+        FilterChanged,
+    }
+
+    const USERS: EntityAdapter<u32, User> = EntityAdapter::new(|user| user.id);
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        let mut new_state = state.clone();
+        match action {
+            MyAction::PageLoaded {
+                page,
+                users,
+                next_key,
+                total,
+            } => USERS.page_loaded(
+                &mut new_state,
+                *page,
+                users.clone(),
+                next_key.clone(),
+                *total,
+            ),
+            MyAction::UserRenamed(user) => USERS.upsert_one(&mut new_state.entities, user.clone()),
+            MyAction::FilterChanged => USERS.reset_pages(&mut new_state),
+        }
+
+        new_state
+    }
+
+    fn user(id: u32, name: &'static str) -> User {
+        User { id, name }
+    }
+
+    fn names(users: Vec<&User>) -> Vec<&'static str> {
+        users.iter().map(|user| user.name).collect()
+    }
+
+    #[test]
+    fn should_select_page_in_server_order_when_page_was_loaded() {
+        let mut store = Store::new(reducer, PaginatedState::new(2));
+        store.dispatch(MyAction::PageLoaded {
+            page: 1,
+            users: vec![user(9, "Zoe"), user(3, "Ann")],
+            next_key: None,
+            total: Some(4),
+        });
+
+        assert_eq!(
+            names(USERS.select_page(store.state(), 1).unwrap()),
+            ["Zoe", "Ann"]
+        );
+        assert_eq!(USERS.select_page(store.state(), 0), None);
+        assert!(!store.state().is_loaded(0));
+        assert_eq!(store.state().offset(1), 2);
+        assert_eq!(store.state().page_count(), Some(2));
+    }
+
+    #[test]
+    fn should_keep_cursor_and_total_when_later_page_omits_total() {
+        let mut store = Store::new(reducer, PaginatedState::new(2));
+        store
+            .dispatch(MyAction::PageLoaded {
+                page: 0,
+                users: vec![user(1, "Ann"), user(2, "Bob")],
+                next_key: Some("after-2".to_string()),
+                total: Some(3),
+            })
+            .dispatch(MyAction::PageLoaded {
+                page: 1,
+                users: vec![user(3, "Cid")],
+                next_key: None,
+                total: None,
+            });
+
+        assert_eq!(store.state().next_key(0), Some("after-2"));
+        assert_eq!(store.state().next_key(1), None);
+        assert_eq!(store.state().total, Some(3));
+        assert_eq!(
+            USERS
+                .select_loaded(store.state())
+                .map(|user| user.name)
+                .collect::<Vec<_>>(),
+            ["Ann", "Bob", "Cid"]
+        );
+    }
+
+    #[test]
+    fn should_select_updated_entity_when_it_was_changed_after_loading() {
+        let mut store = Store::new(reducer, PaginatedState::new(2));
+        store
+            .dispatch(MyAction::PageLoaded {
+                page: 0,
+                users: vec![user(1, "Ann")],
+                next_key: None,
+                total: Some(1),
+            })
+            .dispatch(MyAction::UserRenamed(user(1, "Anna")));
+
+        assert_eq!(
+            names(USERS.select_page(store.state(), 0).unwrap()),
+            ["Anna"]
+        );
+    }
+
+    #[test]
+    fn should_forget_pages_when_they_were_reset() {
+        let mut store = Store::new(reducer, PaginatedState::new(2));
+        store
+            .dispatch(MyAction::PageLoaded {
+                page: 0,
+                users: vec![user(1, "Ann")],
+                next_key: None,
+                total: Some(1),
+            })
+            .dispatch(MyAction::FilterChanged);
+
+        assert!(store.state().pages.is_empty());
+        assert_eq!(store.state().total, None);
+        assert_eq!(USERS.select_total(&store.state().entities), 0);
+    }
+}
@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod parent {
+    use redust::{ParentStore, RouteError, Store};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug, Clone)]
+    enum CounterAction {
+        Increment,
+    }
+
+    #[derive(Debug, Clone)]
+    enum UnknownAction {
+        Noop,
+    }
+
+    fn counter_reducer(state: &u8, action: &CounterAction) -> u8 {
+        match action {
+            CounterAction::Increment => state + 1,
+        }
+    }
+
+    fn double_reducer(state: &u16, action: &CounterAction) -> u16 {
+        match action {
+            CounterAction::Increment => state * 2,
+        }
+    }
+
+    #[test]
+    fn should_route_action_to_every_store_which_accepts_it() {
+        let mut parent = ParentStore::new()
+            .mount("counter", Store::new(counter_reducer, 0))
+            .mount("double", Store::new(double_reducer, 1));
+
+        parent.dispatch(CounterAction::Increment).unwrap();
+
+        assert_eq!(parent.state().get::<u8>("counter"), Some(&1));
+        assert_eq!(parent.state().get::<u16>("double"), Some(&2));
+        assert_eq!(parent.state().keys(), vec!["counter", "double"]);
+    }
+
+    #[test]
+    fn should_return_error_when_no_store_accepts_action() {
+        let mut parent = ParentStore::new().mount("counter", Store::new(counter_reducer, 0));
+
+        assert!(matches!(
+            parent.dispatch(UnknownAction::Noop),
+            Err(RouteError::UnknownAction(_))
+        ));
+    }
+
+    #[test]
+    fn should_call_subscribers_of_mounted_store_when_action_was_routed() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let mut parent = ParentStore::new().mount("counter", Store::new(counter_reducer, 0));
+        parent
+            .store_mut::<u8, CounterAction>("counter")
+            .unwrap()
+            .subscribe(|_state| {
+                CALLS.fetch_add(1, Ordering::SeqCst);
+            });
+
+        parent.dispatch(CounterAction::Increment).unwrap();
+
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn should_return_none_when_state_has_another_type() {
+        let parent = ParentStore::new().mount("counter", Store::new(counter_reducer, 0));
+
+        assert_eq!(parent.state().get::<u16>("counter"), None);
+        assert!(parent.store::<u16, CounterAction>("counter").is_none());
+    }
+}
@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod produce {
+    use redust::{produce, Store};
+    use std::collections::HashMap;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Todo {
+        title: &'static str,
+        checked: bool,
+    }
+
+    #[derive(Debug, Clone, Default, PartialEq)]
+    struct MyStore {
+        todos: HashMap<u8, Todo>,
+        next_id: u8,
+    }
+
+    #[derive(Debug)]
+    enum MyAction {
+        Add(&'static str),
+        Check(u8),
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        produce(state, |draft| match action {
+            MyAction::Add(title) => {
+                let id = draft.next_id;
+                draft.todos.insert(
+                    id,
+                    Todo {
+                        title,
+                        checked: false,
+                    },
+                );
+                draft.next_id += 1;
+            }
+            MyAction::Check(id) => {
+                if let Some(todo) = draft.todos.get_mut(id) {
+                    todo.checked = true;
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn should_apply_recipe_when_reducer_mutated_draft() {
+        let mut store = Store::new(reducer, MyStore::default());
+
+        store
+            .dispatch(MyAction::Add("Buy milk"))
+            .dispatch(MyAction::Add("Walk the dog"))
+            .dispatch(MyAction::Check(1));
+
+        assert_eq!(store.state().next_id, 2);
+        assert!(!store.state().todos[&0].checked);
+        assert!(store.state().todos[&1].checked);
+    }
+
+    #[test]
+    fn should_keep_previous_state_when_draft_was_mutated() {
+        let state = reducer(&MyStore::default(), &MyAction::Add("Buy milk"));
+
+        let new_state = reducer(&state, &MyAction::Check(0));
+
+        assert!(!state.todos[&0].checked);
+        assert!(new_state.todos[&0].checked);
+    }
+
+    #[test]
+    fn should_not_copy_state_when_draft_was_only_read() {
+        let state = reducer(&MyStore::default(), &MyAction::Add("Buy milk"));
+
+        let new_state = produce(&state, |draft| {
+            assert_eq!(draft.todos[&0].title, "Buy milk");
+            assert!(!draft.is_modified());
+        });
+
+        assert_eq!(new_state, state);
+    }
+
+    #[test]
+    fn should_read_changes_from_draft_when_it_was_modified() {
+        let state = MyStore::default();
+
+        produce(&state, |draft| {
+            draft.next_id = 5;
+
+            assert!(draft.is_modified());
+            assert_eq!(draft.next_id, 5);
+            assert_eq!(draft.original().next_id, 0);
+        });
+    }
+}
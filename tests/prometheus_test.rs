@@ -0,0 +1,83 @@
+#![cfg(feature = "prometheus")]
+
+#[cfg(test)]
+mod prometheus {
+    use redust::prometheus::Exporter;
+    use redust::{DispatchQueue, Store};
+
+    type MyStore = u8;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Increment,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Increment => state + 1,
+        }
+    }
+
+    #[test]
+    fn should_render_every_family_when_store_and_queue_are_exported() {
+        let mut store = Store::new(reducer, 0);
+        store.enable_metrics();
+        store.subscribe(|_state| {});
+        store
+            .dispatch(MyAction::Increment)
+            .dispatch(MyAction::Increment);
+        let queue = DispatchQueue::new();
+        queue.dispatcher().dispatch(MyAction::Increment).unwrap();
+
+        let text = Exporter::new()
+            .store("counter", &store)
+            .queue("actions", &queue)
+            .gather();
+
+        assert!(text.contains("# TYPE redust_dispatched_actions_total counter\n"));
+        assert!(text.contains("redust_dispatched_actions_total{store=\"counter\"} 2\n"));
+        assert!(text.contains("# TYPE redust_reducer_duration_seconds histogram\n"));
+        assert!(text
+            .contains("redust_reducer_duration_seconds_bucket{store=\"counter\",le=\"+Inf\"} 2\n"));
+        assert!(text.contains("redust_reducer_duration_seconds_count{store=\"counter\"} 2\n"));
+        assert!(text.contains("redust_subscribers{store=\"counter\"} 1\n"));
+        assert!(text.contains("redust_queue_depth{queue=\"actions\"} 1\n"));
+    }
+
+    #[test]
+    fn should_make_buckets_cumulative_when_histogram_is_rendered() {
+        let mut store = Store::new(reducer, 0);
+        store.enable_metrics();
+        store.dispatch(MyAction::Increment);
+
+        let text = Exporter::new().store("counter", &store).gather();
+        let buckets = text
+            .lines()
+            .filter(|line| line.starts_with("redust_reducer_duration_seconds_bucket"))
+            .map(|line| line.rsplit(' ').next().unwrap().parse::<u64>().unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(buckets.len(), 8);
+        assert!(buckets.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(buckets.last(), Some(&1));
+    }
+
+    #[test]
+    fn should_report_only_subscribers_when_metrics_are_disabled() {
+        let store = Store::new(reducer, 0);
+
+        let text = Exporter::new().store("counter", &store).gather();
+
+        assert!(!text.contains("redust_dispatched_actions_total"));
+        assert!(text.contains("redust_subscribers{store=\"counter\"} 0\n"));
+    }
+
+    #[test]
+    fn should_escape_label_values_when_names_contain_quotes() {
+        let store = Store::new(reducer, 0);
+
+        let text = Exporter::new().store("say \"hi\"", &store).gather();
+
+        assert!(text.contains("redust_subscribers{store=\"say \\\"hi\\\"\"} 0\n"));
+    }
+}
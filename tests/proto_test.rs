@@ -0,0 +1,125 @@
+#![cfg(feature = "prost")]
+
+#[cfg(test)]
+mod proto {
+    use redust::proto::{self, ProtoConvert, ProtoError};
+    use redust::Store;
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct TodosMessage {
+        #[prost(string, repeated, tag = "1")]
+        titles: Vec<String>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct ActionMessage {
+        #[prost(oneof = "action_message::Kind", tags = "1, 2")]
+        kind: Option<action_message::Kind>,
+    }
+
+    mod action_message {
+        #[derive(Clone, PartialEq, prost::Oneof)]
+        pub enum Kind {
+            #[prost(string, tag = "1")]
+            Add(String),
+            #[prost(bool, tag = "2")]
+            Clear(bool),
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Todos {
+        titles: Vec<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum TodosAction {
+        Add(String),
+        Clear,
+    }
+
+    impl ProtoConvert for Todos {
+        type Message = TodosMessage;
+
+        fn to_proto(&self) -> TodosMessage {
+            TodosMessage {
+                titles: self.titles.clone(),
+            }
+        }
+
+        fn from_proto(message: TodosMessage) -> Result<Self, ProtoError> {
+            Ok(Todos {
+                titles: message.titles,
+            })
+        }
+    }
+
+    impl ProtoConvert for TodosAction {
+        type Message = ActionMessage;
+
+        fn to_proto(&self) -> ActionMessage {
+            let kind = match self {
+                TodosAction::Add(title) => action_message::Kind::Add(title.clone()),
+                TodosAction::Clear => action_message::Kind::Clear(true),
+            };
+
+            ActionMessage { kind: Some(kind) }
+        }
+
+        fn from_proto(message: ActionMessage) -> Result<Self, ProtoError> {
+            match message.kind {
+                Some(action_message::Kind::Add(title)) => Ok(TodosAction::Add(title)),
+                Some(action_message::Kind::Clear(_)) => Ok(TodosAction::Clear),
+                None => Err(ProtoError::Unmapped("action kind is not set".to_string())),
+            }
+        }
+    }
+
+    fn reducer(state: &Todos, action: &TodosAction) -> Todos {
+        let mut new_state = state.clone();
+        match action {
+            TodosAction::Add(title) => new_state.titles.push(title.clone()),
+            TodosAction::Clear => new_state.titles.clear(),
+        }
+
+        new_state
+    }
+
+    #[test]
+    fn should_dispatch_action_when_it_was_decoded_from_proto() {
+        let mut store = Store::new(reducer, Todos { titles: vec![] });
+
+        store
+            .dispatch_proto(&proto::encode(&TodosAction::Add("Buy milk".to_string())))
+            .unwrap();
+
+        assert_eq!(store.state().titles, vec!["Buy milk".to_string()]);
+    }
+
+    #[test]
+    fn should_restore_state_when_snapshot_was_encoded_as_proto() {
+        let mut store = Store::new(reducer, Todos { titles: vec![] });
+        store.dispatch(TodosAction::Add("Buy milk".to_string()));
+
+        let snapshot = store.snapshot_proto();
+        let restored = Store::restore_proto(reducer, &snapshot).unwrap();
+
+        assert_eq!(restored.state(), store.state());
+    }
+
+    #[test]
+    fn should_return_error_when_message_cannot_be_mapped_to_action() {
+        let bytes = proto::encode(&TodosAction::Clear);
+        let empty = ActionMessage { kind: None };
+
+        assert_eq!(proto::decode::<TodosAction>(&bytes), Ok(TodosAction::Clear));
+        assert!(matches!(
+            proto::decode::<TodosAction>(&prost::Message::encode_to_vec(&empty)),
+            Err(ProtoError::Unmapped(_))
+        ));
+        assert!(matches!(
+            proto::decode::<TodosAction>(&[0xff]),
+            Err(ProtoError::Decode(_))
+        ));
+    }
+}
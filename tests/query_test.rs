@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod query {
+    use redust::{MutationEndpoint, QueryCache, QueryEndpoint, QueryStatus, Store, Tag};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Data {
+        Posts(Vec<u32>),
+        Post(u32),
+    }
+
+    #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord)]
+    enum Key {
+        Posts,
+        Post(u32),
+    }
+
+    type MyStore = QueryCache<Key, Data>;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Started(Key),
+        Fulfilled(Key, Data),
+        Rejected(Key, String),
+        PostAdded(u32),
+        PostEdited(u32),
+    }
+
+    const QUERIES: QueryEndpoint<Key, Data> = QueryEndpoint::new(|_, data| match data {
+        Data::Posts(ids) => ids
+            .iter()
+            .map(|id| Tag::id("Post", id))
+            .chain(Some(Tag::id("Post", "LIST")))
+            .collect(),
+        Data::Post(id) => vec![Tag::id("Post", id)],
+    });
+    const ADD_POST: MutationEndpoint<u32, ()> =
+        MutationEndpoint::new(|_, _| vec![Tag::id("Post", "LIST")]);
+    const EDIT_POST: MutationEndpoint<u32, ()> =
+        MutationEndpoint::new(|id, _| vec![Tag::id("Post", id)]);
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        let mut cache = state.clone();
+        match action {
+            MyAction::Started(key) => QUERIES.query_started(&mut cache, key.clone()),
+            MyAction::Fulfilled(key, data) => {
+                QUERIES.query_fulfilled(&mut cache, key.clone(), data.clone())
+            }
+            MyAction::Rejected(key, error) => {
+                QUERIES.query_rejected(&mut cache, key.clone(), error.clone())
+            }
+            MyAction::PostAdded(id) => {
+                ADD_POST.mutation_fulfilled(&mut cache, id, &());
+            }
+            MyAction::PostEdited(id) => {
+                EDIT_POST.mutation_fulfilled(&mut cache, id, &());
+            }
+        }
+
+        cache
+    }
+
+    fn loaded_store() -> Store<MyStore, MyAction> {
+        let mut store = Store::new(reducer, QueryCache::new());
+        store
+            .dispatch(MyAction::Fulfilled(Key::Posts, Data::Posts(vec![1, 2])))
+            .dispatch(MyAction::Fulfilled(Key::Post(1), Data::Post(1)))
+            .dispatch(MyAction::Fulfilled(Key::Post(2), Data::Post(2)));
+
+        store
+    }
+
+    fn stale(store: &Store<MyStore, MyAction>) -> Vec<Key> {
+        store.state().stale_keys().cloned().collect()
+    }
+
+    #[test]
+    fn should_mark_list_stale_when_item_was_added() {
+        let mut store = loaded_store();
+        store.dispatch(MyAction::PostAdded(3));
+
+        assert_eq!(stale(&store), [Key::Posts]);
+    }
+
+    #[test]
+    fn should_mark_item_and_list_stale_when_item_was_edited() {
+        let mut store = loaded_store();
+        store.dispatch(MyAction::PostEdited(2));
+
+        assert_eq!(stale(&store), [Key::Posts, Key::Post(2)]);
+        assert!(!store.state().needs_fetch(&Key::Post(1)));
+    }
+
+    #[test]
+    fn should_keep_stale_data_until_refetch_was_fulfilled() {
+        let mut store = loaded_store();
+        store
+            .dispatch(MyAction::PostEdited(1))
+            .dispatch(MyAction::Started(Key::Post(1)));
+
+        let entry = store.state().get(&Key::Post(1)).unwrap();
+        assert_eq!(entry.status, QueryStatus::Pending);
+        assert_eq!(entry.data, Some(Data::Post(1)));
+        assert!(!store.state().needs_fetch(&Key::Post(1)));
+
+        store.dispatch(MyAction::Fulfilled(Key::Post(1), Data::Post(1)));
+        assert_eq!(stale(&store), [Key::Posts]);
+    }
+
+    #[test]
+    fn should_need_fetch_when_query_was_rejected() {
+        let mut store = Store::new(reducer, QueryCache::new());
+        store
+            .dispatch(MyAction::Started(Key::Post(1)))
+            .dispatch(MyAction::Rejected(Key::Post(1), "timeout".to_string()));
+
+        assert_eq!(
+            store.state().get(&Key::Post(1)).unwrap().status,
+            QueryStatus::Rejected("timeout".to_string())
+        );
+        assert!(store.state().needs_fetch(&Key::Post(1)));
+        assert!(store.state().needs_fetch(&Key::Posts));
+    }
+
+    #[test]
+    fn should_match_every_id_when_tag_has_only_kind() {
+        assert!(Tag::kind("Post").matches(&Tag::id("Post", 1)));
+        assert!(!Tag::id("Post", 1).matches(&Tag::id("Post", 2)));
+        assert!(!Tag::kind("User").matches(&Tag::id("Post", 1)));
+    }
+}
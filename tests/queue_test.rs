@@ -0,0 +1,152 @@
+#[cfg(test)]
+mod queue {
+    use redust::{DispatchQueue, OverflowPolicy, Priority, QueueError, StarvationPolicy, Store};
+    use std::thread;
+
+    type MyStore = Vec<u8>;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Push(u8),
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Push(value) => {
+                let mut new_state = state.clone();
+                new_state.push(*value);
+
+                new_state
+            }
+        }
+    }
+
+    #[test]
+    fn should_dispatch_actions_from_many_threads_when_queue_was_drained() {
+        let queue = DispatchQueue::new();
+
+        let producers: Vec<_> = (0..4)
+            .map(|value| {
+                let dispatcher = queue.dispatcher();
+                thread::spawn(move || dispatcher.dispatch(MyAction::Push(value)).unwrap())
+            })
+            .collect();
+        producers
+            .into_iter()
+            .for_each(|producer| producer.join().unwrap());
+
+        let mut store = Store::new(reducer, vec![]);
+        assert_eq!(store.drain(&queue), 4);
+
+        let mut state = store.state().clone();
+        state.sort();
+        assert_eq!(state, vec![0, 1, 2, 3]);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn should_dispatch_urgent_actions_first_when_priorities_differ() {
+        let queue = DispatchQueue::new().starvation_policy(StarvationPolicy::Strict);
+        let dispatcher = queue.dispatcher();
+
+        dispatcher
+            .dispatch_with_priority(MyAction::Push(1), Priority::Low)
+            .unwrap();
+        dispatcher.dispatch(MyAction::Push(2)).unwrap();
+        dispatcher
+            .dispatch_with_priority(MyAction::Push(3), Priority::Urgent)
+            .unwrap();
+        dispatcher
+            .dispatch_with_priority(MyAction::Push(4), Priority::High)
+            .unwrap();
+        dispatcher.dispatch(MyAction::Push(5)).unwrap();
+
+        let mut store = Store::new(reducer, vec![]);
+        store.drain(&queue);
+
+        assert_eq!(*store.state(), vec![3, 4, 2, 5, 1]);
+    }
+
+    #[test]
+    fn should_serve_starving_action_when_it_waited_too_long() {
+        let queue = DispatchQueue::new().starvation_policy(StarvationPolicy::MaxWait(2));
+        let dispatcher = queue.dispatcher();
+
+        dispatcher
+            .dispatch_with_priority(MyAction::Push(0), Priority::Low)
+            .unwrap();
+        (1..=4).for_each(|value| {
+            dispatcher
+                .dispatch_with_priority(MyAction::Push(value), Priority::High)
+                .unwrap()
+        });
+
+        let mut store = Store::new(reducer, vec![]);
+        store.drain(&queue);
+
+        assert_eq!(*store.state(), vec![1, 2, 0, 3, 4]);
+    }
+
+    #[test]
+    fn should_return_an_error_when_queue_was_dropped() {
+        let queue = DispatchQueue::new();
+        let dispatcher = queue.dispatcher();
+
+        drop(queue);
+
+        assert_eq!(
+            dispatcher.dispatch(MyAction::Push(1)),
+            Err(QueueError::Closed)
+        );
+    }
+
+    #[test]
+    fn should_return_an_error_when_bounded_queue_is_full_with_reject_policy() {
+        let queue = DispatchQueue::new().bounded(2, OverflowPolicy::Reject);
+        let dispatcher = queue.dispatcher();
+
+        dispatcher.dispatch(MyAction::Push(1)).unwrap();
+        dispatcher.dispatch(MyAction::Push(2)).unwrap();
+
+        assert_eq!(
+            dispatcher.dispatch(MyAction::Push(3)),
+            Err(QueueError::Full)
+        );
+        assert_eq!(queue.len(), 2);
+    }
+
+    #[test]
+    fn should_drop_actions_when_bounded_queue_is_full_with_drop_policies() {
+        let mut store = Store::new(reducer, vec![]);
+
+        let queue = DispatchQueue::new().bounded(2, OverflowPolicy::DropOldest);
+        let dispatcher = queue.dispatcher();
+        (1..=3).for_each(|value| dispatcher.dispatch(MyAction::Push(value)).unwrap());
+        store.drain(&queue);
+        assert_eq!(*store.state(), vec![2, 3]);
+
+        let queue = DispatchQueue::new().bounded(2, OverflowPolicy::DropNewest);
+        let dispatcher = queue.dispatcher();
+        (4..=6).for_each(|value| dispatcher.dispatch(MyAction::Push(value)).unwrap());
+        store.drain(&queue);
+        assert_eq!(*store.state(), vec![2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn should_block_producer_until_queue_was_drained_with_block_policy() {
+        let queue = DispatchQueue::new().bounded(1, OverflowPolicy::Block);
+        let dispatcher = queue.dispatcher();
+        dispatcher.dispatch(MyAction::Push(1)).unwrap();
+
+        let producer = thread::spawn(move || dispatcher.dispatch(MyAction::Push(2)));
+
+        let mut store = Store::new(reducer, vec![]);
+        while store.state().len() < 2 {
+            store.drain(&queue);
+            thread::yield_now();
+        }
+
+        assert_eq!(producer.join().unwrap(), Ok(()));
+        assert_eq!(*store.state(), vec![1, 2]);
+    }
+}
@@ -0,0 +1,288 @@
+#![cfg(feature = "serde")]
+
+#[cfg(test)]
+mod remote {
+    use redust::codec::Codec;
+    use redust::remote::{RemoteError, RemoteLimits, RemoteServer, RemoteStore};
+    use redust::{OverflowPolicy, QueueError, Store};
+    use serde::{Deserialize, Serialize};
+    use std::io::{Read, Write};
+    use std::net::TcpStream;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    type MyStore = Vec<String>;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    enum MyAction {
+        Log(String),
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Log(message) => {
+                let mut new_state = state.clone();
+                new_state.push(message.clone());
+
+                new_state
+            }
+        }
+    }
+
+    fn pump_until(
+        server: &RemoteServer<MyStore, MyAction>,
+        store: &mut Store<MyStore, MyAction>,
+        len: usize,
+    ) {
+        while store.state().len() < len {
+            server.pump(store).unwrap();
+            thread::yield_now();
+        }
+    }
+
+    #[test]
+    fn should_dispatch_action_when_client_sent_it() {
+        let mut store = Store::new(reducer, vec![]);
+        let server = RemoteServer::bind("127.0.0.1:0", Codec::Json, &store).unwrap();
+        let mut client =
+            RemoteStore::<MyStore, MyAction>::connect(server.local_addr(), Codec::Json).unwrap();
+
+        client
+            .dispatch(MyAction::Log("first".to_string()))
+            .unwrap()
+            .dispatch(MyAction::Log("second".to_string()))
+            .unwrap();
+        pump_until(&server, &mut store, 2);
+
+        assert_eq!(*store.state(), vec!["first", "second"]);
+        assert_eq!(client.state().unwrap(), vec!["first", "second"]);
+    }
+
+    #[test]
+    fn should_receive_state_updates_when_client_subscribed() {
+        let mut store = Store::new(reducer, vec![]);
+        let server = RemoteServer::bind("127.0.0.1:0", Codec::Json, &store).unwrap();
+        let mut subscription =
+            RemoteStore::<MyStore, MyAction>::connect(server.local_addr(), Codec::Json)
+                .unwrap()
+                .subscribe()
+                .unwrap();
+        let mut client =
+            RemoteStore::<MyStore, MyAction>::connect(server.local_addr(), Codec::Json).unwrap();
+
+        assert!(subscription.recv().unwrap().is_empty());
+
+        client.dispatch(MyAction::Log("hello".to_string())).unwrap();
+        pump_until(&server, &mut store, 1);
+
+        assert_eq!(subscription.recv().unwrap(), vec!["hello"]);
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn should_exchange_actions_when_message_pack_is_used() {
+        let mut store = Store::new(reducer, vec![]);
+        let server = RemoteServer::bind("127.0.0.1:0", Codec::MessagePack, &store).unwrap();
+        let mut client =
+            RemoteStore::<MyStore, MyAction>::connect(server.local_addr(), Codec::MessagePack)
+                .unwrap();
+
+        client.dispatch(MyAction::Log("hello".to_string())).unwrap();
+        pump_until(&server, &mut store, 1);
+
+        assert_eq!(client.state().unwrap(), vec!["hello"]);
+    }
+
+    #[test]
+    fn should_return_recent_actions_when_client_asked_for_them() {
+        let mut store = Store::new(reducer, vec![]);
+        let server = RemoteServer::bind("127.0.0.1:0", Codec::Json, &store).unwrap();
+        let mut client =
+            RemoteStore::<MyStore, MyAction>::connect(server.local_addr(), Codec::Json).unwrap();
+
+        client.dispatch(MyAction::Log("hello".to_string())).unwrap();
+        pump_until(&server, &mut store, 1);
+
+        assert!(matches!(
+            client.recent_actions().unwrap().as_slice(),
+            [MyAction::Log(message)] if message == "hello"
+        ));
+    }
+
+    #[test]
+    fn should_restore_previous_state_when_client_travelled_back() {
+        let mut store = Store::new(reducer, vec![]);
+        let server = RemoteServer::bind("127.0.0.1:0", Codec::Json, &store).unwrap();
+        let mut client =
+            RemoteStore::<MyStore, MyAction>::connect(server.local_addr(), Codec::Json).unwrap();
+
+        client.dispatch(MyAction::Log("first".to_string())).unwrap();
+        pump_until(&server, &mut store, 1);
+        client
+            .dispatch(MyAction::Log("second".to_string()))
+            .unwrap();
+        pump_until(&server, &mut store, 2);
+
+        client.travel_back(1).unwrap();
+        while store.state().len() == 2 {
+            server.pump(&mut store).unwrap();
+            thread::yield_now();
+        }
+
+        assert_eq!(*store.state(), vec!["first"]);
+        assert_eq!(client.state().unwrap(), vec!["first"]);
+    }
+
+    #[test]
+    fn should_expose_configured_store_when_built_with_devtools() {
+        let (mut store, server) = Store::configure(reducer, vec![])
+            .build_with_devtools("127.0.0.1:0", Codec::Json)
+            .unwrap();
+        let mut client =
+            RemoteStore::<MyStore, MyAction>::connect(server.local_addr(), Codec::Json).unwrap();
+
+        client.dispatch(MyAction::Log("first".to_string())).unwrap();
+        pump_until(&server, &mut store, 1);
+
+        assert_eq!(client.state().unwrap(), ["first"]);
+    }
+
+    #[test]
+    fn should_close_connection_when_frame_exceeds_limit() {
+        let store = Store::new(reducer, vec![]);
+        let limits = RemoteLimits {
+            max_frame_size: 64,
+            ..RemoteLimits::default()
+        };
+        let server =
+            RemoteServer::bind_with_limits("127.0.0.1:0", Codec::Json, &store, limits).unwrap();
+        let mut stream = TcpStream::connect(server.local_addr()).unwrap();
+
+        // Only the header, the server must not wait for a gigabyte
+        stream.write_all(&u32::MAX.to_be_bytes()).unwrap();
+
+        let mut response = vec![];
+        stream.read_to_end(&mut response).unwrap();
+        let message = String::from_utf8_lossy(&response[4..]);
+        assert!(message.contains("exceeds the limit of 64 bytes"));
+    }
+
+    #[test]
+    fn should_close_connection_when_connection_limit_reached() {
+        let store = Store::new(reducer, vec![]);
+        let limits = RemoteLimits {
+            max_connections: 1,
+            ..RemoteLimits::default()
+        };
+        let server =
+            RemoteServer::bind_with_limits("127.0.0.1:0", Codec::Json, &store, limits).unwrap();
+
+        let mut first =
+            RemoteStore::<MyStore, MyAction>::connect(server.local_addr(), Codec::Json).unwrap();
+        assert!(first.state().unwrap().is_empty());
+
+        let mut second =
+            RemoteStore::<MyStore, MyAction>::connect(server.local_addr(), Codec::Json).unwrap();
+        assert!(matches!(second.state(), Err(RemoteError::Io(_))));
+        assert!(first.state().unwrap().is_empty());
+    }
+
+    #[test]
+    fn should_drop_subscriber_when_it_stopped_reading() {
+        let mut store = Store::new(reducer, vec![]);
+        store.dispatch(MyAction::Log("x".repeat(1 << 20)));
+        let limits = RemoteLimits {
+            write_timeout: Duration::from_millis(20),
+            ..RemoteLimits::default()
+        };
+        let server =
+            RemoteServer::bind_with_limits("127.0.0.1:0", Codec::Json, &store, limits).unwrap();
+        let _subscription =
+            RemoteStore::<MyStore, MyAction>::connect(server.local_addr(), Codec::Json)
+                .unwrap()
+                .subscribe()
+                .unwrap();
+        while server.subscriber_count() == 0 {
+            thread::yield_now();
+        }
+
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while server.subscriber_count() > 0 && Instant::now() < deadline {
+            let published = Instant::now();
+            server.publish(&store).unwrap();
+            // Never blocked by the subscriber
+            assert!(published.elapsed() < Duration::from_secs(1));
+        }
+
+        assert_eq!(server.subscriber_count(), 0);
+    }
+
+    #[test]
+    fn should_reject_action_when_queue_of_server_is_full() {
+        let mut store = Store::new(reducer, vec![]);
+        let limits = RemoteLimits {
+            max_pending_actions: 1,
+            overflow_policy: OverflowPolicy::Reject,
+            ..RemoteLimits::default()
+        };
+        let server =
+            RemoteServer::bind_with_limits("127.0.0.1:0", Codec::Json, &store, limits).unwrap();
+        let mut client =
+            RemoteStore::<MyStore, MyAction>::connect(server.local_addr(), Codec::Json).unwrap();
+
+        client.dispatch(MyAction::Log("first".to_string())).unwrap();
+        assert_eq!(
+            client.dispatch(MyAction::Log("second".to_string())).err(),
+            Some(RemoteError::Queue(QueueError::Full))
+        );
+
+        assert_eq!(server.pump(&mut store).unwrap(), 1);
+        client.dispatch(MyAction::Log("third".to_string())).unwrap();
+        pump_until(&server, &mut store, 2);
+        assert_eq!(*store.state(), vec!["first", "third"]);
+    }
+
+    #[test]
+    fn should_not_record_action_when_queue_of_server_rejected_it() {
+        let store = Store::new(reducer, vec![]);
+        let limits = RemoteLimits {
+            max_pending_actions: 1,
+            overflow_policy: OverflowPolicy::Reject,
+            ..RemoteLimits::default()
+        };
+        let server =
+            RemoteServer::bind_with_limits("127.0.0.1:0", Codec::Json, &store, limits).unwrap();
+        let mut client =
+            RemoteStore::<MyStore, MyAction>::connect(server.local_addr(), Codec::Json).unwrap();
+
+        client.dispatch(MyAction::Log("first".to_string())).unwrap();
+        assert!(client
+            .dispatch(MyAction::Log("second".to_string()))
+            .is_err());
+
+        assert!(matches!(
+            client.recent_actions().unwrap().as_slice(),
+            [MyAction::Log(message)] if message == "first"
+        ));
+    }
+
+    #[test]
+    fn should_close_connections_when_server_was_dropped() {
+        let store = Store::new(reducer, vec![]);
+        let server = RemoteServer::bind("127.0.0.1:0", Codec::Json, &store).unwrap();
+        let mut client =
+            RemoteStore::<MyStore, MyAction>::connect(server.local_addr(), Codec::Json).unwrap();
+        let mut subscription =
+            RemoteStore::<MyStore, MyAction>::connect(server.local_addr(), Codec::Json)
+                .unwrap()
+                .subscribe()
+                .unwrap();
+        assert!(client.state().unwrap().is_empty());
+        assert!(subscription.recv().unwrap().is_empty());
+
+        drop(server);
+
+        assert!(matches!(client.state(), Err(RemoteError::Io(_))));
+        assert!(matches!(subscription.recv(), Err(RemoteError::Io(_))));
+    }
+}
@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod replay {
+    use redust::{Checkpoint, Fixture, Recorder, ReplayError};
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    static IMPURE: AtomicBool = AtomicBool::new(false);
+
+    type MyStore = Vec<u8>;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum MyAction {
+        Push(u8),
+        PushImpure(u8),
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        let mut new_state = state.clone();
+        match action {
+            MyAction::Push(value) => new_state.push(*value),
+            // Depends on the global flag instead of the state and the action
+            MyAction::PushImpure(value) if IMPURE.load(Ordering::SeqCst) => {
+                new_state.push(value + 1)
+            }
+            MyAction::PushImpure(value) => new_state.push(*value),
+        }
+
+        new_state
+    }
+
+    fn pure_reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        let mut new_state = state.clone();
+        match action {
+            MyAction::Push(value) | MyAction::PushImpure(value) => new_state.push(*value),
+        }
+
+        new_state
+    }
+
+    #[test]
+    fn should_verify_replay_when_recorded_with_pure_reducer() {
+        let mut recorder = Recorder::new(pure_reducer, vec![]);
+        recorder
+            .checkpoint_every(2)
+            .dispatch(MyAction::Push(1))
+            .dispatch(MyAction::Push(2))
+            .dispatch(MyAction::Push(3))
+            .checkpoint();
+
+        let checkpoints = recorder.checkpoints().to_vec();
+        assert_eq!(
+            checkpoints,
+            vec![
+                Checkpoint {
+                    actions: 2,
+                    state: vec![1, 2],
+                },
+                Checkpoint {
+                    actions: 3,
+                    state: vec![1, 2, 3],
+                },
+            ]
+        );
+        assert_eq!(
+            recorder
+                .into_fixture()
+                .verify_replay(pure_reducer, &checkpoints),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn should_flag_first_divergent_action_when_reducer_is_impure() {
+        let mut recorder = Recorder::new(reducer, vec![]);
+        recorder
+            .checkpoint_every(1)
+            .dispatch(MyAction::Push(1))
+            .dispatch(MyAction::PushImpure(2))
+            .dispatch(MyAction::Push(3));
+
+        let checkpoints = recorder.checkpoints().to_vec();
+        let fixture = recorder.into_fixture();
+
+        IMPURE.store(true, Ordering::SeqCst);
+        let result = fixture.verify_replay(reducer, &checkpoints);
+        IMPURE.store(false, Ordering::SeqCst);
+
+        let err = result.unwrap_err();
+        assert_eq!(
+            err,
+            ReplayError::Diverged {
+                since: 1,
+                checkpoint: Checkpoint {
+                    actions: 2,
+                    state: vec![1, 2],
+                },
+                actual: vec![1, 3],
+            }
+        );
+        assert_eq!(err.to_string(), "Replay diverged at action #1");
+    }
+
+    #[test]
+    fn should_return_error_when_checkpoint_is_after_the_last_action() {
+        let fixture = Fixture {
+            initial_state: vec![],
+            actions: vec![MyAction::Push(1)],
+        };
+        let checkpoints = [Checkpoint {
+            actions: 2,
+            state: vec![1, 1],
+        }];
+
+        assert_eq!(
+            fixture.verify_replay(pure_reducer, &checkpoints),
+            Err(ReplayError::OutOfRange(2))
+        );
+    }
+}
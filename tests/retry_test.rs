@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod retry {
+    use redust::{DispatchQueue, RetryExhausted, RetryPolicy, Store};
+    use std::time::Duration;
+
+    type MyStore = Vec<String>;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Loaded(u32),
+        Failed(RetryExhausted<String>),
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        let mut log = state.clone();
+        match action {
+            MyAction::Loaded(attempt) => log.push(format!("loaded on {}", attempt)),
+            MyAction::Failed(failure) => log.push(failure.to_string()),
+        }
+
+        log
+    }
+
+    fn millis(value: u64) -> Duration {
+        Duration::from_millis(value)
+    }
+
+    #[test]
+    fn should_cap_exponential_delay_when_it_exceeds_max() {
+        let policy = RetryPolicy::exponential(millis(10), millis(50), 10);
+
+        let delays: Vec<_> = (1..6).map(|failed| policy.delay(failed)).collect();
+
+        assert_eq!(
+            delays,
+            [
+                Some(millis(10)),
+                Some(millis(20)),
+                Some(millis(40)),
+                Some(millis(50)),
+                Some(millis(50))
+            ]
+        );
+        assert_eq!(policy.delay(10), None);
+    }
+
+    #[test]
+    fn should_return_max_delay_when_doubling_overflows() {
+        let policy = RetryPolicy::exponential(millis(100), Duration::from_secs(1), u32::MAX);
+
+        assert_eq!(policy.delay(4), Some(millis(800)));
+        assert_eq!(policy.delay(5), Some(Duration::from_secs(1)));
+        assert_eq!(policy.delay(64), Some(Duration::from_secs(1)));
+        assert_eq!(policy.delay(0), None);
+    }
+
+    #[test]
+    fn should_shorten_delay_within_jitter_when_jitter_is_set() {
+        let policy = RetryPolicy::fixed(millis(100), 3).with_jitter(0.5);
+
+        assert_eq!(policy.jittered_delay(1, 0.0), Some(millis(100)));
+        assert_eq!(policy.jittered_delay(1, 1.0), Some(millis(50)));
+        assert_eq!(policy.jittered_delay(3, 0.5), None);
+    }
+
+    #[test]
+    fn should_return_last_error_when_all_attempts_failed() {
+        let policy = RetryPolicy::fixed(millis(1), 3).with_jitter(1.0);
+        let mut attempts = vec![];
+
+        let result: Result<(), _> = policy.run(|attempt| {
+            attempts.push(attempt);
+            Err(format!("error {}", attempt))
+        });
+
+        assert_eq!(attempts, [1, 2, 3]);
+        assert_eq!(
+            result,
+            Err(RetryExhausted {
+                attempts: 3,
+                error: "error 3".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn should_dispatch_actions_of_effects_with_their_own_policies() {
+        let queue = DispatchQueue::new();
+        let succeeding = RetryPolicy::fixed(millis(1), 3).spawn(
+            queue.dispatcher(),
+            |attempt| match attempt {
+                2 => Ok(MyAction::Loaded(attempt)),
+                _ => Err("offline".to_string()),
+            },
+            MyAction::Failed,
+        );
+        succeeding.join().unwrap();
+        let failing = RetryPolicy::exponential(millis(1), millis(2), 2).spawn(
+            queue.dispatcher(),
+            |_| Err("offline".to_string()),
+            MyAction::Failed,
+        );
+        failing.join().unwrap();
+
+        let mut store = Store::new(reducer, vec![]);
+        store.drain(&queue);
+
+        assert_eq!(
+            *store.state(),
+            ["loaded on 2", "The effect failed after 2 attempts: offline"]
+        );
+    }
+}
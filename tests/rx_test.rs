@@ -0,0 +1,102 @@
+#![cfg(feature = "rxrust")]
+
+#[cfg(test)]
+mod rx {
+    use redust::rx::DispatchObserver;
+    use redust::{DispatchQueue, Store};
+    use rxrust::prelude::*;
+    use std::sync::{Arc, Mutex};
+
+    type MyStore = Vec<u8>;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum MyAction {
+        Push(u8),
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Push(value) => {
+                let mut new_state = state.clone();
+                new_state.push(*value);
+
+                new_state
+            }
+        }
+    }
+
+    #[test]
+    fn should_emit_shared_states_when_actions_were_dispatched() {
+        let mut store = Store::new(reducer, vec![]);
+        let states = Arc::new(Mutex::new(vec![]));
+
+        let sink = Arc::clone(&states);
+        store
+            .observe_states()
+            .into_shared()
+            .subscribe(move |state| sink.lock().unwrap().push(state));
+        store.dispatch(MyAction::Push(1));
+
+        let states = states.lock().unwrap();
+        assert_eq!(states.len(), 1);
+        assert!(Arc::ptr_eq(&states[0], &store.shared_state()));
+    }
+
+    #[test]
+    fn should_emit_actions_when_notifications_are_paused() {
+        let mut store = Store::new(reducer, vec![]);
+        let actions = Arc::new(Mutex::new(vec![]));
+        let states = Arc::new(Mutex::new(0));
+
+        let sink = Arc::clone(&actions);
+        store
+            .observe_actions()
+            .into_shared()
+            .subscribe(move |action| sink.lock().unwrap().push(action));
+        let counter = Arc::clone(&states);
+        store
+            .observe_states()
+            .into_shared()
+            .subscribe(move |_state| *counter.lock().unwrap() += 1);
+
+        store.pause_notifications();
+        store
+            .dispatch(MyAction::Push(1))
+            .dispatch(MyAction::Push(2));
+
+        assert_eq!(
+            *actions.lock().unwrap(),
+            vec![MyAction::Push(1), MyAction::Push(2)]
+        );
+        assert_eq!(*states.lock().unwrap(), 0);
+    }
+
+    #[test]
+    fn should_dispatch_emitted_actions_when_store_was_drained() {
+        let queue = DispatchQueue::new();
+        let mut source = LocalSubject::new();
+
+        LocalObservable::actual_subscribe(
+            source.clone().map(MyAction::Push),
+            Subscriber::local(DispatchObserver::new(queue.dispatcher())),
+        );
+        source.next(1);
+        source.next(2);
+
+        let mut store = Store::new(reducer, vec![]);
+        store.drain(&queue);
+
+        assert_eq!(*store.state(), vec![1, 2]);
+    }
+
+    #[test]
+    fn should_stop_observer_when_queue_was_dropped() {
+        let queue = DispatchQueue::new();
+        let mut observer = DispatchObserver::new(queue.dispatcher());
+        drop(queue);
+
+        observer.next(MyAction::Push(1));
+
+        assert!(observer.is_stopped());
+    }
+}
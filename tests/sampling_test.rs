@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod sampling {
+    use redust::{DispatchQueue, PanicIsolation, Sample, Store, TestClock};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    type MyStore = u8;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Increment,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Increment => state + 1,
+        }
+    }
+
+    #[test]
+    fn should_call_subscriber_on_every_nth_update_when_sample_every_was_used() {
+        static LAST_SEEN: AtomicUsize = AtomicUsize::new(0);
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let mut store = Store::new(reducer, 0);
+        store.subscribe_sampled(Sample::Every(3), |state| {
+            LAST_SEEN.store(*state as usize, Ordering::SeqCst);
+            CALLS.fetch_add(1, Ordering::SeqCst);
+        });
+
+        (0..7).for_each(|_| {
+            store.dispatch(MyAction::Increment);
+        });
+
+        assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+        assert_eq!(LAST_SEEN.load(Ordering::SeqCst), 6);
+
+        // The latest state is delivered on flush
+        store.flush_sampled();
+        assert_eq!(CALLS.load(Ordering::SeqCst), 3);
+        assert_eq!(LAST_SEEN.load(Ordering::SeqCst), 7);
+
+        // Nothing is pending anymore
+        store.flush_sampled();
+        assert_eq!(CALLS.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn should_call_subscriber_at_most_once_per_interval_when_sample_interval_was_used() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let mut store = Store::new(reducer, 0);
+        store.subscribe_sampled(Sample::Interval(Duration::from_secs(3600)), |_state| {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+        });
+
+        (0..5).for_each(|_| {
+            store.dispatch(MyAction::Increment);
+        });
+
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn should_deliver_trailing_state_when_interval_elapsed_without_dispatches() {
+        static LAST_SEEN: AtomicUsize = AtomicUsize::new(0);
+
+        let clock = TestClock::new();
+        let mut store = Store::new(reducer, 0);
+        store.set_clock(clock.clone());
+        store.subscribe_sampled(Sample::Interval(Duration::from_secs(1)), |state| {
+            LAST_SEEN.store(*state as usize, Ordering::SeqCst);
+        });
+
+        store
+            .dispatch(MyAction::Increment)
+            .dispatch(MyAction::Increment);
+        assert_eq!(LAST_SEEN.load(Ordering::SeqCst), 1);
+
+        // Not delivered before the interval elapsed
+        store.dispatch_due();
+        assert_eq!(LAST_SEEN.load(Ordering::SeqCst), 1);
+
+        clock.advance(Duration::from_secs(1));
+        store.dispatch_due();
+        assert_eq!(LAST_SEEN.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn should_deliver_trailing_state_when_queue_was_drained() {
+        static LAST_SEEN: AtomicUsize = AtomicUsize::new(0);
+
+        let queue = DispatchQueue::new();
+        let mut store = Store::new(reducer, 0);
+        store.subscribe_sampled(Sample::Every(3), |state| {
+            LAST_SEEN.store(*state as usize, Ordering::SeqCst);
+        });
+
+        (0..4).for_each(|_| queue.dispatcher().dispatch(MyAction::Increment).unwrap());
+        store.drain(&queue);
+
+        assert_eq!(LAST_SEEN.load(Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn should_not_flush_sampled_subscriber_when_notifications_are_paused() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let mut store = Store::new(reducer, 0);
+        store.subscribe_sampled(Sample::Every(2), |_state| {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+        });
+        store.dispatch(MyAction::Increment);
+
+        store.pause_notifications();
+        store.flush_sampled();
+        assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn should_isolate_panic_when_flushed_sampled_subscriber_panicked() {
+        let mut store = Store::new(reducer, 0);
+        store.isolate_panics(PanicIsolation::Unsubscribe);
+        store.subscribe_sampled(Sample::Every(2), |state| {
+            if *state == 1 {
+                panic!("Cannot sample 1");
+            }
+        });
+        store.dispatch(MyAction::Increment);
+
+        store.flush_sampled();
+
+        let errors = store.take_subscriber_errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].1.to_string(),
+            "Subscriber panicked: Cannot sample 1"
+        );
+    }
+
+    #[test]
+    fn should_not_call_sampled_subscriber_when_we_unsubscribed() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let mut store = Store::new(reducer, 0);
+        let token = store.subscribe_sampled(Sample::Every(1), |_state| {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+        });
+
+        assert_eq!(store.unsubscribe(token), Ok(()));
+        store.dispatch(MyAction::Increment);
+
+        assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+    }
+}
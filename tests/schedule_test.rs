@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod schedule {
+    use redust::{Cron, DispatchQueue, ScheduleError, Scheduler};
+    use std::thread;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum MyAction {
+        Poll,
+        Report,
+    }
+
+    fn at(seconds: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(seconds)
+    }
+
+    // 2024-02-28 23:59:30 UTC, Wednesday
+    const LEAP_EVE: u64 = 1_709_164_770;
+
+    #[test]
+    fn should_return_next_minute_when_cron_matches_every_minute() {
+        let cron = Cron::parse("* * * * *").unwrap();
+
+        assert_eq!(cron.next_after(at(LEAP_EVE)), Some(at(LEAP_EVE + 30)));
+    }
+
+    #[test]
+    fn should_return_leap_day_when_cron_matches_february_29() {
+        let cron = Cron::parse("30 12 29 2 *").unwrap();
+
+        // 2024-02-29 12:30:00 UTC
+        assert_eq!(cron.next_after(at(LEAP_EVE)), Some(at(1_709_209_800)));
+    }
+
+    #[test]
+    fn should_match_weekdays_with_steps_and_ranges() {
+        let cron = Cron::parse("*/15 9-17 * * 6,0").unwrap();
+
+        // 2024-03-02 09:00:00 UTC, Saturday
+        assert_eq!(cron.next_after(at(LEAP_EVE)), Some(at(1_709_370_000)));
+    }
+
+    #[test]
+    fn should_match_both_day_fields_when_day_of_month_has_step_of_any_day() {
+        let cron = Cron::parse("0 0 */2 * 1").unwrap();
+
+        // 2024-03-11 00:00:00 UTC, the first Monday on an odd day
+        assert_eq!(cron.next_after(at(LEAP_EVE)), Some(at(1_710_115_200)));
+    }
+
+    #[test]
+    fn should_return_error_when_cron_is_invalid() {
+        assert_eq!(
+            Cron::parse("* * *"),
+            Err(ScheduleError::InvalidCron(
+                "expected 5 fields, found 3".to_string()
+            ))
+        );
+        assert_eq!(
+            Cron::parse("60 * * * *"),
+            Err(ScheduleError::InvalidCron("invalid field `60`".to_string()))
+        );
+        assert_eq!(Cron::parse("0 0 31 2 *"), Err(ScheduleError::NeverFires));
+    }
+
+    #[test]
+    fn should_enqueue_actions_until_schedule_was_cancelled() {
+        let queue = DispatchQueue::new();
+        let scheduler = Scheduler::new(queue.dispatcher());
+
+        let polling = scheduler
+            .every(Duration::from_millis(5), MyAction::Poll)
+            .unwrap();
+        let reporting = scheduler
+            .every(Duration::from_secs(60), MyAction::Report)
+            .unwrap();
+        thread::sleep(Duration::from_millis(40));
+
+        polling.cancel();
+        assert_eq!(scheduler.len(), 1);
+        let enqueued = queue.len();
+        thread::sleep(Duration::from_millis(30));
+
+        assert!(enqueued >= 2);
+        assert_eq!(queue.len(), enqueued);
+        assert!(std::iter::from_fn(|| queue.pop()).all(|action| action == MyAction::Poll));
+
+        reporting.cancel();
+        assert!(scheduler.is_empty());
+    }
+
+    #[test]
+    fn should_return_error_when_interval_is_zero() {
+        let queue = DispatchQueue::new();
+        let scheduler = Scheduler::new(queue.dispatcher());
+
+        let result = scheduler.every(Duration::ZERO, MyAction::Poll);
+
+        assert!(matches!(result, Err(ScheduleError::ZeroInterval)));
+        assert!(scheduler.is_empty());
+    }
+}
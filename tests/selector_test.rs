@@ -0,0 +1,164 @@
+#[cfg(test)]
+mod selector {
+    use redust::Store;
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    struct Noop;
+
+    impl Wake for Noop {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    fn poll<F: Future + ?Sized>(future: Pin<&mut F>) -> Poll<F::Output> {
+        let waker = Waker::from(Arc::new(Noop));
+        future.poll(&mut Context::from_waker(&waker))
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = Box::pin(future);
+        loop {
+            if let Poll::Ready(output) = poll(future.as_mut()) {
+                return output;
+            }
+        }
+    }
+
+    /// Future which is pending on the first poll
+    struct YieldOnce(bool);
+
+    impl Future for YieldOnce {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+            if self.0 {
+                return Poll::Ready(());
+            }
+            self.0 = true;
+            Poll::Pending
+        }
+    }
+
+    #[derive(Debug)]
+    struct MyStore {
+        items: Vec<u32>,
+        selected: usize,
+    }
+
+    #[derive(Debug)]
+    enum MyAction {
+        Push(u32),
+        Select(usize),
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Push(value) => {
+                let mut items = state.items.clone();
+                items.push(*value);
+
+                MyStore {
+                    items,
+                    selected: state.selected,
+                }
+            }
+            MyAction::Select(index) => MyStore {
+                items: state.items.clone(),
+                selected: *index,
+            },
+        }
+    }
+
+    fn initial() -> MyStore {
+        MyStore {
+            items: vec![1, 2],
+            selected: 0,
+        }
+    }
+
+    fn items(state: &MyStore) -> Vec<u32> {
+        state.items.clone()
+    }
+
+    #[test]
+    fn should_resolve_once_when_input_did_not_change() {
+        let mut store = Store::new(reducer, initial());
+        let sum = store.select_async(items, |items| {
+            Box::pin(async move {
+                CALLS.fetch_add(1, Ordering::SeqCst);
+                items.iter().sum::<u32>()
+            })
+        });
+
+        assert_eq!(sum.cached(), None);
+        assert_eq!(block_on(sum.get()), 3);
+        store.dispatch(MyAction::Select(1));
+        assert_eq!(block_on(sum.get()), 3);
+
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn should_invalidate_cache_when_input_changed() {
+        let mut store = Store::new(reducer, initial());
+        let sum = store.select_async(items, |items| {
+            Box::pin(async move { items.iter().sum::<u32>() })
+        });
+        block_on(sum.get());
+
+        store.dispatch(MyAction::Push(3));
+
+        assert_eq!(sum.cached(), None);
+        assert_eq!(block_on(sum.get()), 6);
+        assert_eq!(sum.cached(), Some(6));
+    }
+
+    #[test]
+    fn should_invalidate_cache_when_notifications_are_paused() {
+        let mut store = Store::new(reducer, initial());
+        let sum = store.select_async(items, |items| {
+            Box::pin(async move { items.iter().sum::<u32>() })
+        });
+        block_on(sum.get());
+
+        store.pause_notifications();
+        store.dispatch(MyAction::Push(3));
+
+        assert_eq!(block_on(sum.get()), 6);
+    }
+
+    #[test]
+    fn should_resolve_again_when_input_changed_during_resolving() {
+        let mut store = Store::new(reducer, initial());
+        let sum = store.select_async(items, |items| {
+            Box::pin(async move {
+                YieldOnce(false).await;
+                items.iter().sum::<u32>()
+            })
+        });
+
+        let mut pending = Box::pin(sum.get());
+        assert!(poll(pending.as_mut()).is_pending());
+        store.dispatch(MyAction::Push(3));
+
+        assert_eq!(block_on(pending), 6);
+    }
+
+    #[test]
+    fn should_resolve_again_when_cache_was_invalidated_manually() {
+        let mut store = Store::new(reducer, initial());
+        let sum = store.select_async(items, |items| {
+            Box::pin(async move { items.iter().sum::<u32>() })
+        });
+        block_on(sum.get());
+
+        sum.invalidate();
+
+        assert_eq!(sum.cached(), None);
+    }
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "shared-memory")]
+
+#[cfg(test)]
+mod shared_memory {
+    use redust::codec::Codec;
+    use redust::shared_memory::{SharedMemoryError, SharedStateReader, SharedStateWriter};
+    use std::path::PathBuf;
+    use std::thread;
+
+    type MyStore = Vec<u32>;
+
+    fn region_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("redust-{}-{}", name, std::process::id()))
+    }
+
+    #[test]
+    fn should_return_none_when_nothing_was_published() {
+        let path = region_path("empty");
+        let _writer = SharedStateWriter::<MyStore>::create(&path, 64, Codec::Json).unwrap();
+        let reader = SharedStateReader::<MyStore>::open(&path, Codec::Json).unwrap();
+
+        assert_eq!(reader.read(), Ok(None));
+        assert_eq!(reader.sequence(), 0);
+    }
+
+    #[test]
+    fn should_read_latest_state_when_it_was_published() {
+        let path = region_path("latest");
+        let mut writer = SharedStateWriter::create(&path, 64, Codec::Json).unwrap();
+        let reader = SharedStateReader::<MyStore>::open(&path, Codec::Json).unwrap();
+
+        writer.publish(&vec![1]).unwrap();
+        let sequence = writer.publish(&vec![1, 2]).unwrap();
+
+        assert_eq!(
+            reader.read_with_sequence(),
+            Ok(Some((sequence, vec![1, 2])))
+        );
+        assert_eq!(reader.sequence(), sequence);
+    }
+
+    #[test]
+    fn should_return_error_when_state_does_not_fit() {
+        let path = region_path("too-large");
+        let mut writer = SharedStateWriter::create(&path, 4, Codec::Json).unwrap();
+
+        assert_eq!(
+            writer.publish(&vec![1, 2, 3]),
+            Err(SharedMemoryError::TooLarge {
+                size: 7,
+                capacity: 4
+            })
+        );
+    }
+
+    #[test]
+    fn should_never_read_torn_state_when_writer_publishes_concurrently() {
+        let path = region_path("concurrent");
+        let mut writer = SharedStateWriter::create(&path, 4096, Codec::Json).unwrap();
+        writer.publish(&vec![0; 100]).unwrap();
+        let reader = SharedStateReader::<MyStore>::open(&path, Codec::Json).unwrap();
+
+        let handle = thread::spawn(move || {
+            for value in 1..2000 {
+                writer.publish(&vec![value; 100]).unwrap();
+            }
+        });
+
+        while !handle.is_finished() {
+            if let Ok(Some(state)) = reader.read() {
+                assert!(state.iter().all(|value| *value == state[0]));
+            }
+        }
+        handle.join().unwrap();
+
+        assert_eq!(reader.read(), Ok(Some(vec![1999; 100])));
+    }
+}
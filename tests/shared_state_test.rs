@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod shared_state {
+    use redust::Store;
+    use std::sync::Arc;
+
+    type MyStore = Vec<u32>;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Push(u32),
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Push(value) => {
+                let mut new_state = state.clone();
+                new_state.push(*value);
+
+                new_state
+            }
+        }
+    }
+
+    #[test]
+    fn should_share_the_same_state_when_nothing_was_dispatched() {
+        let store = Store::new(reducer, vec![1]);
+
+        assert!(Arc::ptr_eq(&store.shared_state(), &store.shared_state()));
+    }
+
+    #[test]
+    fn should_keep_snapshot_unchanged_when_action_was_dispatched() {
+        let mut store = Store::new(reducer, vec![1]);
+        let snapshot = store.shared_state();
+
+        store.dispatch(MyAction::Push(2));
+
+        assert_eq!(*snapshot, vec![1]);
+        assert_eq!(*store.state(), vec![1, 2]);
+    }
+
+    #[test]
+    fn should_return_shared_state_when_snapshot_is_still_alive() {
+        let mut store = Store::new(reducer, vec![1]);
+        store.dispatch(MyAction::Push(2));
+        let snapshot = store.shared_state();
+
+        let shared = store.into_inner().unwrap_err();
+        assert!(Arc::ptr_eq(&shared, &snapshot));
+        assert_eq!(*snapshot, vec![1, 2]);
+    }
+
+    #[test]
+    fn should_return_state_when_snapshots_were_dropped() {
+        #[derive(Debug, PartialEq)]
+        struct NotClone(u32);
+
+        fn reducer(state: &NotClone, _action: &MyAction) -> NotClone {
+            NotClone(state.0 + 1)
+        }
+
+        let mut store = Store::new(reducer, NotClone(0));
+        let snapshot = store.shared_state();
+        store.dispatch(MyAction::Push(1));
+        drop(snapshot);
+
+        assert_eq!(store.into_inner(), Ok(NotClone(1)));
+    }
+
+    #[test]
+    fn should_return_the_same_state_when_store_was_turned_into_shared() {
+        let store = Store::new(reducer, vec![1]);
+        let snapshot = store.shared_state();
+
+        assert!(Arc::ptr_eq(&store.into_shared(), &snapshot));
+    }
+
+    #[test]
+    fn should_return_snapshot_when_concurrent_store_is_read() {
+        let store = Store::new(reducer, vec![1]).into_concurrent();
+        let snapshot = store.snapshot();
+
+        store.dispatch(MyAction::Push(2));
+
+        assert_eq!(*snapshot, vec![1]);
+        assert_eq!(*store.snapshot(), vec![1, 2]);
+    }
+}
@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod shared {
+    use redust::Store;
+    use std::sync::Arc;
+    use std::thread;
+
+    type MyStore = Vec<u8>;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Push(u8),
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Push(value) => {
+                let mut new_state = state.to_vec();
+                new_state.push(*value);
+
+                new_state
+            }
+        }
+    }
+
+    #[test]
+    fn should_keep_snapshot_unchanged_when_action_was_dispatched() {
+        let mut store = Store::new(reducer, vec![1]);
+        let snapshot = store.state_arc();
+
+        store.dispatch(MyAction::Push(2));
+
+        assert_eq!(*snapshot, vec![1]);
+        assert_eq!(*store.state_arc(), vec![1, 2]);
+    }
+
+    #[test]
+    fn should_share_the_same_allocation_when_snapshot_was_taken() {
+        let store = Store::new(reducer, vec![1]);
+
+        assert!(Arc::ptr_eq(&store.state_arc(), &store.shared_state()));
+    }
+
+    #[test]
+    fn should_read_snapshot_from_another_thread() {
+        let mut store = Store::new(reducer, vec![]);
+        store.dispatch(MyAction::Push(3));
+
+        let snapshot = store.state_arc();
+        let len = thread::spawn(move || snapshot.len()).join().unwrap();
+
+        assert_eq!(len, 1);
+    }
+}
@@ -0,0 +1,342 @@
+#[cfg(test)]
+mod slices {
+    use redust::{
+        DispatchError, FreezePolicy, HistoryPolicy, Next, Slice, SliceError, Slices, Store,
+        StoreError,
+    };
+    use std::panic::{self, AssertUnwindSafe};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum MyAction {
+        Increment,
+        Push(u8),
+    }
+
+    fn counter_reducer(state: &u8, action: &MyAction) -> u8 {
+        match action {
+            MyAction::Increment => state + 1,
+            _ => *state,
+        }
+    }
+
+    type List = Vec<u8>;
+
+    fn list_reducer(state: &List, action: &MyAction) -> List {
+        match action {
+            MyAction::Push(value) => {
+                let mut new_state = state.clone();
+                new_state.push(*value);
+
+                new_state
+            }
+            _ => state.clone(),
+        }
+    }
+
+    fn double_counter_reducer(state: &u8, action: &MyAction) -> u8 {
+        match action {
+            MyAction::Increment => state + 2,
+            _ => *state,
+        }
+    }
+
+    struct Counter;
+
+    impl Slice for Counter {
+        const KEY: &'static str = "counter";
+        type State = u8;
+    }
+
+    struct Other;
+
+    impl Slice for Other {
+        const KEY: &'static str = "other";
+        type State = u8;
+    }
+
+    #[test]
+    fn should_preserve_existing_slices_when_new_reducer_was_injected() {
+        let mut store = Store::with_slices();
+        store
+            .inject_reducer("counter", counter_reducer, || 0)
+            .unwrap();
+
+        store.dispatch(MyAction::Increment);
+        assert_eq!(store.state().get::<u8>("counter"), Some(&1));
+
+        store
+            .inject_reducer("list", list_reducer, || vec![1])
+            .unwrap();
+        assert_eq!(store.state().get::<u8>("counter"), Some(&1));
+        assert_eq!(store.state().get::<Vec<u8>>("list"), Some(&vec![1]));
+
+        store
+            .dispatch(MyAction::Push(2))
+            .dispatch(MyAction::Increment);
+        assert_eq!(store.state().get::<u8>("counter"), Some(&2));
+        assert_eq!(store.state().get::<Vec<u8>>("list"), Some(&vec![1, 2]));
+    }
+
+    #[test]
+    fn should_keep_slice_state_when_reducer_was_injected_twice() {
+        let mut store = Store::with_slices();
+        store
+            .inject_reducer("counter", counter_reducer, || 0)
+            .unwrap();
+        store.dispatch(MyAction::Increment);
+
+        store
+            .inject_reducer("counter", double_counter_reducer, || 0)
+            .unwrap();
+        assert_eq!(store.state().get::<u8>("counter"), Some(&1));
+
+        store.dispatch(MyAction::Increment);
+        assert_eq!(store.state().get::<u8>("counter"), Some(&3));
+    }
+
+    #[test]
+    fn should_not_create_initial_slice_when_slice_was_already_injected() {
+        let mut store = Store::with_slices();
+        store
+            .inject_reducer("counter", counter_reducer, || 0)
+            .unwrap();
+        store.dispatch(MyAction::Increment);
+
+        store
+            .inject_reducer("counter", double_counter_reducer, || {
+                panic!("the existing slice state is kept")
+            })
+            .unwrap();
+
+        assert_eq!(store.state().get::<u8>("counter"), Some(&1));
+    }
+
+    #[test]
+    fn should_return_none_when_slice_type_does_not_match() {
+        let mut store = Store::with_slices();
+        store
+            .inject_reducer("counter", counter_reducer, || 0)
+            .unwrap();
+
+        assert_eq!(store.state().get::<Vec<u8>>("counter"), None);
+        assert_eq!(store.state().get::<u8>("unknown"), None);
+    }
+
+    #[test]
+    fn should_create_initial_slice_when_slice_is_used_for_the_first_time() {
+        static CREATED: AtomicUsize = AtomicUsize::new(0);
+        let mut store = Store::with_slices();
+
+        store
+            .inject_reducer("counter", counter_reducer, || {
+                CREATED.fetch_add(1, Ordering::SeqCst);
+                0
+            })
+            .unwrap();
+        assert_eq!(CREATED.load(Ordering::SeqCst), 0);
+
+        store.dispatch(MyAction::Increment);
+        assert_eq!(CREATED.load(Ordering::SeqCst), 1);
+        assert_eq!(store.state().get::<u8>("counter"), Some(&1));
+        assert_eq!(CREATED.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn should_return_error_when_slice_of_another_type_was_injected_under_the_same_key() {
+        let mut store = Store::with_slices();
+        store
+            .inject_reducer("counter", counter_reducer, || 0)
+            .unwrap();
+        store.dispatch(MyAction::Increment);
+
+        let result = store.inject_reducer("counter", list_reducer, || vec![1]);
+
+        assert_eq!(result, Err(SliceError::TypeMismatch("counter")));
+        assert_eq!(store.state().get::<u8>("counter"), Some(&1));
+    }
+
+    #[test]
+    fn should_reduce_only_target_slice_when_action_was_dispatched_to_it() {
+        let mut store = Store::with_slices();
+        store
+            .inject_reducer("counter", counter_reducer, || 0)
+            .unwrap();
+        store
+            .inject_reducer("other", counter_reducer, || 0)
+            .unwrap();
+
+        store.dispatch_to::<Counter>(MyAction::Increment);
+
+        assert_eq!(store.state().get::<u8>("counter"), Some(&1));
+        assert_eq!(store.state().get::<u8>("other"), Some(&0));
+        assert_eq!(store.version(), 1);
+    }
+
+    #[test]
+    fn should_notify_only_scoped_subscriptions_when_dispatched_to_slice() {
+        static CALLS: Mutex<Vec<&str>> = Mutex::new(Vec::new());
+
+        let mut store = Store::with_slices();
+        store
+            .inject_reducer("counter", counter_reducer, || 0)
+            .unwrap();
+        store
+            .inject_reducer("other", counter_reducer, || 0)
+            .unwrap();
+        store.subscribe(|_| CALLS.lock().unwrap().push("root"));
+        store.subscribe_slice::<Counter>(|_| CALLS.lock().unwrap().push("counter"));
+        store.subscribe_slice::<Other>(|_| CALLS.lock().unwrap().push("other"));
+
+        store.dispatch_to::<Counter>(MyAction::Increment);
+        assert_eq!(*CALLS.lock().unwrap(), ["counter"]);
+
+        CALLS.lock().unwrap().clear();
+        store.dispatch(MyAction::Increment);
+        assert_eq!(*CALLS.lock().unwrap(), ["root", "counter", "other"]);
+    }
+
+    #[test]
+    fn should_drop_action_when_target_slice_was_not_injected() {
+        let mut store = Store::with_slices();
+        store
+            .inject_reducer("counter", counter_reducer, || 0)
+            .unwrap();
+
+        store.dispatch_to::<Other>(MyAction::Increment);
+
+        assert_eq!(store.version(), 0);
+        assert_eq!(store.state().get::<u8>("counter"), Some(&0));
+    }
+
+    #[test]
+    fn should_record_action_when_dispatched_to_slice() {
+        let mut store = Store::with_slices();
+        store
+            .inject_reducer("counter", counter_reducer, || 0)
+            .unwrap();
+        store
+            .inject_reducer("other", counter_reducer, || 0)
+            .unwrap();
+        store.enable_history(HistoryPolicy::unbounded().snapshot_every(10));
+        store.enable_metrics();
+
+        store
+            .dispatch_to::<Counter>(MyAction::Increment)
+            .dispatch(MyAction::Increment);
+
+        assert_eq!(store.last_action(), Some(&MyAction::Increment));
+        assert_eq!(store.dispatch_metrics().unwrap().dispatched, 2);
+        let targeted = store.history_state(1).unwrap();
+        assert_eq!(targeted.get::<u8>("counter"), Some(&1));
+        assert_eq!(targeted.get::<u8>("other"), Some(&0));
+        // Replayed by the root reducer from the snapshot of the targeted dispatch
+        let latest = store.history_state(2).unwrap();
+        assert_eq!(latest.get::<u8>("counter"), Some(&2));
+        assert_eq!(latest.get::<u8>("other"), Some(&1));
+    }
+
+    #[test]
+    fn should_report_error_when_dispatched_to_slice_of_frozen_store() {
+        let mut store = Store::with_slices();
+        store
+            .inject_reducer("counter", counter_reducer, || 0)
+            .unwrap();
+        let errors = store.errors();
+        store.freeze(FreezePolicy::Reject);
+
+        store.dispatch_to::<Counter>(MyAction::Increment);
+
+        assert_eq!(
+            errors.try_iter().collect::<Vec<_>>(),
+            [StoreError::Dispatch(DispatchError::Frozen)]
+        );
+        assert_eq!(store.state().get::<u8>("counter"), Some(&0));
+    }
+
+    #[test]
+    fn should_reject_action_when_interceptor_dropped_slice_action() {
+        let mut store = Store::with_slices();
+        store
+            .inject_reducer("counter", counter_reducer, || 0)
+            .unwrap();
+        let errors = store.errors();
+        store.intercept(|action, _state| match action {
+            MyAction::Increment => None,
+            action => Some(action),
+        });
+
+        store.dispatch_to::<Counter>(MyAction::Increment);
+
+        assert_eq!(
+            errors.try_iter().collect::<Vec<_>>(),
+            [StoreError::Dispatch(DispatchError::Intercepted)]
+        );
+        assert_eq!(store.state().get::<u8>("counter"), Some(&0));
+    }
+
+    #[test]
+    fn should_dispatch_queued_actions_when_dispatched_to_slice() {
+        fn panicking_follow_up(
+            action: MyAction,
+            mut next: Next<'_, Slices<MyAction>, MyAction>,
+        ) -> Result<MyAction, DispatchError> {
+            if action == MyAction::Increment {
+                next.dispatch(MyAction::Push(1));
+                panic!("Broken middleware");
+            }
+
+            next.run(action)
+        }
+
+        let mut store = Store::with_slices();
+        store
+            .inject_reducer("counter", counter_reducer, || 0)
+            .unwrap();
+        store
+            .inject_reducer("list", list_reducer, Vec::new)
+            .unwrap();
+        store.add_middleware("follow-up", panicking_follow_up);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            store.dispatch(MyAction::Increment);
+        }));
+        assert!(result.is_err());
+
+        store.dispatch_to::<Counter>(MyAction::Push(2));
+
+        assert_eq!(store.state().get::<List>("list"), Some(&vec![1]));
+    }
+
+    #[test]
+    fn should_not_clone_other_slices_when_dispatched_to_slice() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+        struct Large;
+
+        impl Clone for Large {
+            fn clone(&self) -> Self {
+                CLONES.fetch_add(1, Ordering::SeqCst);
+                Large
+            }
+        }
+
+        fn large_reducer(_state: &Large, _action: &MyAction) -> Large {
+            Large
+        }
+
+        let mut store = Store::with_slices();
+        store
+            .inject_reducer("counter", counter_reducer, || 0)
+            .unwrap();
+        store
+            .inject_reducer("large", large_reducer, || Large)
+            .unwrap();
+
+        store.dispatch_to::<Counter>(MyAction::Increment);
+
+        assert_eq!(CLONES.load(Ordering::SeqCst), 0);
+    }
+}
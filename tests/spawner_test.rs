@@ -0,0 +1,344 @@
+#[cfg(test)]
+mod spawner {
+    use redust::{
+        dispatch_after, spawn_async_effect, spawn_local_effect, BoxFuture, Debouncer,
+        DispatchQueue, SpawnError, Spawner, TestClock,
+    };
+    use std::sync::{Arc, Mutex};
+    use std::task::{Context, Poll, Waker};
+    use std::time::Duration;
+
+    #[derive(Debug, PartialEq)]
+    enum MyAction {
+        Search(&'static str),
+    }
+
+    /// Runs spawned futures only when asked; sleeping completes on the next poll
+    #[derive(Default)]
+    struct ManualSpawner {
+        futures: Mutex<Vec<BoxFuture<()>>>,
+    }
+
+    impl ManualSpawner {
+        fn run(&self) {
+            let mut context = Context::from_waker(Waker::noop());
+            let futures = std::mem::take(&mut *self.futures.lock().unwrap());
+            futures.into_iter().for_each(|mut future| {
+                let ready = (0..2).any(|_| future.as_mut().poll(&mut context).is_ready());
+                assert!(ready);
+            });
+        }
+    }
+
+    impl Spawner for ManualSpawner {
+        fn spawn(&self, future: BoxFuture<()>) {
+            self.futures.lock().unwrap().push(future);
+        }
+
+        fn sleep(&self, _duration: Duration) -> BoxFuture<()> {
+            let mut slept = false;
+            Box::pin(std::future::poll_fn(move |_context| {
+                if slept {
+                    Poll::Ready(())
+                } else {
+                    slept = true;
+                    Poll::Pending
+                }
+            }))
+        }
+    }
+
+    #[cfg(any(feature = "async-std", feature = "tokio"))]
+    fn wait_until(condition: impl Fn() -> bool) {
+        use std::thread;
+        use std::time::Instant;
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !condition() && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn should_run_effect_on_custom_spawner_when_it_was_spawned() {
+        let spawner = ManualSpawner::default();
+        let queue = DispatchQueue::new();
+
+        let handle = spawn_async_effect(&spawner, queue.dispatcher(), |_token| async {
+            Some(MyAction::Search("redux"))
+        });
+        assert!(!handle.is_finished());
+
+        spawner.run();
+        assert!(handle.is_finished());
+        assert_eq!(queue.pop(), Some(MyAction::Search("redux")));
+    }
+
+    #[test]
+    fn should_dispatch_action_when_it_was_scheduled_and_not_cancelled() {
+        let spawner = ManualSpawner::default();
+        let queue = DispatchQueue::new();
+
+        let sent = dispatch_after(
+            &spawner,
+            queue.dispatcher(),
+            Duration::from_millis(10),
+            MyAction::Search("sent"),
+        );
+        let cancelled = dispatch_after(
+            &spawner,
+            queue.dispatcher(),
+            Duration::from_millis(10),
+            MyAction::Search("cancelled"),
+        );
+        cancelled.cancel();
+        spawner.run();
+
+        assert!(sent.is_finished() && cancelled.is_finished());
+        assert_eq!(queue.pop(), Some(MyAction::Search("sent")));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn should_dispatch_only_last_action_when_previous_ones_were_debounced() {
+        let spawner = Arc::new(ManualSpawner::default());
+        let queue = DispatchQueue::new();
+        let clock = TestClock::new();
+        let debouncer = Debouncer::with_clock(
+            Arc::clone(&spawner) as Arc<dyn Spawner>,
+            queue.dispatcher(),
+            Duration::from_millis(20),
+            Arc::new(clock.clone()),
+        );
+
+        debouncer.dispatch(MyAction::Search("r"));
+        clock.advance(Duration::from_millis(15));
+        debouncer.dispatch(MyAction::Search("re"));
+        debouncer.dispatch(MyAction::Search("redux"));
+        clock.advance(Duration::from_millis(20));
+        spawner.run();
+
+        assert_eq!(queue.pop(), Some(MyAction::Search("redux")));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn should_wait_for_delay_when_debounced_action_was_replaced() {
+        let spawner = Arc::new(ManualSpawner::default());
+        let queue = DispatchQueue::new();
+        let clock = TestClock::new();
+        let debouncer = Debouncer::with_clock(
+            Arc::clone(&spawner) as Arc<dyn Spawner>,
+            queue.dispatcher(),
+            Duration::from_millis(20),
+            Arc::new(clock.clone()),
+        );
+
+        debouncer.dispatch(MyAction::Search("r"));
+        clock.advance(Duration::from_millis(15));
+        debouncer.dispatch(MyAction::Search("redux"));
+        clock.advance(Duration::from_millis(15));
+
+        // The timer keeps waiting until 20ms passed since the last action
+        let mut timer = spawner.futures.lock().unwrap().pop().unwrap();
+        let mut context = Context::from_waker(Waker::noop());
+        assert!(timer.as_mut().poll(&mut context).is_pending());
+        assert!(queue.is_empty());
+
+        clock.advance(Duration::from_millis(5));
+        assert!(timer.as_mut().poll(&mut context).is_ready());
+        assert_eq!(queue.pop(), Some(MyAction::Search("redux")));
+    }
+
+    #[test]
+    fn should_drop_action_when_debouncer_was_cancelled() {
+        let spawner = Arc::new(ManualSpawner::default());
+        let queue = DispatchQueue::new();
+        let clock = TestClock::new();
+        let debouncer = Debouncer::with_clock(
+            Arc::clone(&spawner) as Arc<dyn Spawner>,
+            queue.dispatcher(),
+            Duration::from_millis(20),
+            Arc::new(clock.clone()),
+        );
+
+        debouncer.dispatch(MyAction::Search("redux"));
+        debouncer.cancel();
+        clock.advance(Duration::from_millis(20));
+        spawner.run();
+
+        assert!(queue.is_empty());
+    }
+
+    #[cfg(feature = "async-std")]
+    #[test]
+    fn should_dispatch_action_when_async_effect_finished_on_async_std() {
+        use redust::AsyncStdSpawner;
+
+        let queue = DispatchQueue::new();
+        let handle = spawn_async_effect(&AsyncStdSpawner, queue.dispatcher(), |_token| async {
+            Some(MyAction::Search("redux"))
+        });
+
+        wait_until(|| handle.is_finished());
+        assert_eq!(queue.pop(), Some(MyAction::Search("redux")));
+    }
+
+    #[cfg(feature = "async-std")]
+    #[test]
+    fn should_dispatch_only_last_action_when_debouncer_was_called_in_succession() {
+        use redust::AsyncStdSpawner;
+
+        let queue = DispatchQueue::new();
+        let debouncer = Debouncer::new(
+            Arc::new(AsyncStdSpawner),
+            queue.dispatcher(),
+            Duration::from_millis(20),
+        );
+
+        debouncer.dispatch(MyAction::Search("r"));
+        debouncer.dispatch(MyAction::Search("re"));
+        debouncer.dispatch(MyAction::Search("redux"));
+
+        wait_until(|| !queue.is_empty());
+        std::thread::sleep(Duration::from_millis(50));
+        assert_eq!(queue.pop(), Some(MyAction::Search("redux")));
+        assert!(queue.is_empty());
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn should_dispatch_action_when_delay_elapsed_on_tokio() {
+        use redust::TokioSpawner;
+
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+        let spawner = TokioSpawner::new(runtime.handle().clone());
+        let queue = DispatchQueue::new();
+
+        let handle = dispatch_after(
+            &spawner,
+            queue.dispatcher(),
+            Duration::from_millis(10),
+            MyAction::Search("redux"),
+        );
+
+        wait_until(|| handle.is_finished());
+        assert_eq!(queue.pop(), Some(MyAction::Search("redux")));
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn should_drop_action_when_scheduled_dispatch_was_cancelled() {
+        use redust::TokioSpawner;
+
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+        let spawner = TokioSpawner::new(runtime.handle().clone());
+        let queue = DispatchQueue::new();
+
+        let handle = dispatch_after(
+            &spawner,
+            queue.dispatcher(),
+            Duration::from_millis(10),
+            MyAction::Search("redux"),
+        );
+        handle.cancel();
+
+        wait_until(|| handle.is_finished());
+        assert!(handle.token().is_cancelled());
+        assert!(queue.is_empty());
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn should_return_no_spawner_when_called_outside_of_runtime() {
+        assert!(redust::TokioSpawner::current().is_none());
+    }
+
+    #[test]
+    fn should_return_error_when_spawner_does_not_support_local_futures() {
+        let queue = DispatchQueue::new();
+
+        let result = spawn_local_effect(
+            &ManualSpawner::default(),
+            queue.dispatcher(),
+            |_token| async { Some(MyAction::Search("redux")) },
+        );
+
+        assert!(matches!(result, Err(SpawnError::LocalUnsupported)));
+    }
+
+    #[cfg(feature = "async-std")]
+    #[test]
+    fn should_run_local_effect_when_thread_drives_async_std() {
+        use redust::AsyncStdSpawner;
+        use std::rc::Rc;
+
+        let queue = DispatchQueue::new();
+        let cache = Rc::new("redux");
+        let handle = spawn_local_effect(
+            &AsyncStdSpawner,
+            queue.dispatcher(),
+            move |_token| async move { Some(MyAction::Search(*cache)) },
+        )
+        .unwrap();
+
+        async_std::task::block_on(async {
+            while !handle.is_finished() {
+                async_std::task::yield_now().await;
+            }
+        });
+        assert_eq!(queue.pop(), Some(MyAction::Search("redux")));
+    }
+
+    #[cfg(feature = "smol")]
+    #[test]
+    fn should_dispatch_action_when_delay_elapsed_on_smol() {
+        use redust::SmolSpawner;
+
+        let spawner = SmolSpawner::new();
+        let queue = DispatchQueue::new();
+
+        let handle = dispatch_after(
+            &spawner,
+            queue.dispatcher(),
+            Duration::from_millis(10),
+            MyAction::Search("redux"),
+        );
+        spawner.block_on(async {
+            while !handle.is_finished() {
+                async_io::Timer::after(Duration::from_millis(1)).await;
+            }
+        });
+
+        assert_eq!(queue.pop(), Some(MyAction::Search("redux")));
+    }
+
+    #[cfg(feature = "smol")]
+    #[test]
+    fn should_run_local_effect_when_thread_drives_smol() {
+        use redust::SmolSpawner;
+        use std::rc::Rc;
+
+        let spawner = SmolSpawner::new();
+        let queue = DispatchQueue::new();
+        let cache = Rc::new("redux");
+
+        let handle = spawn_local_effect(&spawner, queue.dispatcher(), move |_token| async move {
+            Some(MyAction::Search(*cache))
+        })
+        .unwrap();
+        spawner.block_on(async {
+            while !handle.is_finished() {
+                async_io::Timer::after(Duration::from_millis(1)).await;
+            }
+        });
+
+        assert_eq!(queue.pop(), Some(MyAction::Search("redux")));
+    }
+}
@@ -69,4 +69,50 @@ mod state {
         store.dispatch(MyAction::Add(4));
         assert_eq!(*store.state(), vec![1, 2, 3, 4])
     }
+
+    #[test]
+    fn should_return_state_by_value_when_store_was_consumed() {
+        type MyStore = Vec<u8>;
+        enum MyAction {
+            Add(u8),
+        }
+
+        fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+            match action {
+                MyAction::Add(new_value) => {
+                    let mut new_state = state.clone();
+                    new_state.push(*new_value);
+
+                    new_state
+                }
+            }
+        }
+
+        let mut store = Store::new(reducer, vec![1]);
+        store.dispatch(MyAction::Add(2));
+
+        assert_eq!(store.into_inner(), Ok(vec![1, 2]));
+    }
+
+    #[test]
+    fn should_return_reducer_and_state_when_store_was_split_into_parts() {
+        type MyStore = u8;
+        enum MyAction {
+            Increment,
+        }
+
+        fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+            match action {
+                MyAction::Increment => state + 1,
+            }
+        }
+
+        let store = Store::new_lazy(reducer, || 1);
+        let parts = store.into_parts();
+        assert_eq!(*parts.state, 1);
+
+        let mut store = Store::new(parts.reducer().unwrap(), *parts.state);
+        store.dispatch(MyAction::Increment);
+        assert_eq!(*store.state(), 2);
+    }
 }
@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod strict {
+    use redust::{NotifyPolicy, SlowTarget, Store};
+    use std::thread;
+    use std::time::Duration;
+
+    type MyStore = u8;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Increment,
+        SlowIncrement,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Increment => state + 1,
+            MyAction::SlowIncrement => {
+                thread::sleep(Duration::from_millis(10));
+                state + 1
+            }
+        }
+    }
+
+    #[test]
+    fn should_report_reducer_when_it_exceeded_budget() {
+        let mut store = Store::new(reducer, 0);
+        store.strict_mode(Duration::from_millis(5));
+
+        store
+            .dispatch(MyAction::Increment)
+            .dispatch(MyAction::SlowIncrement);
+
+        let slow_calls = store.take_slow_calls();
+        assert_eq!(slow_calls.len(), 1);
+        assert_eq!(slow_calls[0].target, SlowTarget::Reducer);
+        assert_eq!(slow_calls[0].action.as_deref(), Some("SlowIncrement"));
+        assert!(slow_calls[0].elapsed > slow_calls[0].budget);
+        assert_eq!(slow_calls[0].budget, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn should_report_subscriber_without_action_when_notifications_were_resumed() {
+        let mut store = Store::new(reducer, 0);
+        store.strict_mode(Duration::from_millis(5));
+        let token = store.subscribe(|_state| thread::sleep(Duration::from_millis(10)));
+
+        store.pause_notifications();
+        store.dispatch(MyAction::Increment);
+        store.resume_notifications(NotifyPolicy::CatchUp);
+
+        let slow_calls = store.take_slow_calls();
+        assert_eq!(slow_calls.len(), 1);
+        assert_eq!(slow_calls[0].target, SlowTarget::Subscriber(token.id()));
+        assert_eq!(slow_calls[0].action, None);
+    }
+
+    #[test]
+    fn should_clear_slow_calls_when_they_were_taken() {
+        let mut store = Store::new(reducer, 0);
+        store.strict_mode(Duration::from_millis(5));
+
+        store.dispatch(MyAction::SlowIncrement);
+
+        assert_eq!(store.take_slow_calls().len(), 1);
+        assert!(store.take_slow_calls().is_empty());
+    }
+
+    #[test]
+    fn should_not_report_when_strict_mode_was_disabled() {
+        let mut store = Store::new(reducer, 0);
+        store.strict_mode(Duration::from_millis(5));
+        store.disable_strict_mode();
+
+        store.dispatch(MyAction::SlowIncrement);
+
+        assert!(store.take_slow_calls().is_empty());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn should_report_reducer_with_backtrace_when_deadline_was_exceeded() {
+        let mut store = Store::new(reducer, 0);
+        store.reducer_deadline(Duration::from_millis(5));
+        store.subscribe(|_state| thread::sleep(Duration::from_millis(10)));
+
+        store
+            .dispatch(MyAction::Increment)
+            .dispatch(MyAction::SlowIncrement);
+
+        let slow_calls = store.take_slow_calls();
+        assert_eq!(slow_calls.len(), 1);
+        assert_eq!(slow_calls[0].target, SlowTarget::Reducer);
+        assert!(slow_calls[0].to_string().starts_with("The reducer took"));
+        assert!(slow_calls[0]
+            .to_string()
+            .ends_with("while handling SlowIncrement"));
+        assert!(slow_calls[0].backtrace.is_some());
+    }
+
+    #[test]
+    fn should_not_capture_backtrace_when_strict_mode_is_used() {
+        let mut store = Store::new(reducer, 0);
+        store.strict_mode(Duration::from_millis(5));
+        store.dispatch(MyAction::SlowIncrement);
+
+        assert_eq!(store.take_slow_calls()[0].backtrace, None);
+    }
+}
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod subscription {
-    use redust::{Store, UnsubscribeError};
+    use redust::{Store, SubscriptionToken, UnsubscribeError};
 
     #[test]
     fn should_call_one_subscription_when_dispatch_called() {
@@ -61,19 +61,148 @@ mod subscription {
         }
 
         let mut store = Store::new(reducer, 0);
-        store.subscribe(|state| {
+        let wrong_token = store.subscribe(|state| {
             assert_eq!(*state, 1);
         });
 
         store.dispatch(MyAction::Increment);
 
-        let wrong_token = 99;
+        store.unsubscribe(wrong_token).unwrap();
         let result = store.unsubscribe(wrong_token);
 
         if let Err(token) = result {
-            assert_eq!(token, UnsubscribeError::WrongToken(wrong_token));
+            assert_eq!(token, UnsubscribeError::WrongToken(wrong_token.id()));
         } else {
             panic!("Have to be true");
         }
     }
+
+    #[test]
+    fn should_call_subscribers_in_subscription_order() {
+        use std::sync::Mutex;
+
+        static CALLS: Mutex<Vec<&str>> = Mutex::new(Vec::new());
+
+        type MyStore = u8;
+
+        #[derive(Debug)]
+        enum MyAction {
+            Increment,
+        }
+        fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+            match action {
+                MyAction::Increment => state + 1,
+            }
+        }
+
+        let mut store = Store::new(reducer, 0);
+        store.subscribe(|_state| CALLS.lock().unwrap().push("first"));
+        store.subscribe_actions(
+            |_action| true,
+            |_action, _state| CALLS.lock().unwrap().push("second"),
+        );
+        let third = store.subscribe(|_state| CALLS.lock().unwrap().push("third"));
+        store.subscribe(|_state| CALLS.lock().unwrap().push("fourth"));
+
+        // Unsubscribing does not change the order of other subscribers
+        store.unsubscribe(third).unwrap();
+        store.subscribe(|_state| CALLS.lock().unwrap().push("fifth"));
+
+        (0..10).for_each(|_| {
+            store.dispatch(MyAction::Increment);
+        });
+
+        let calls = CALLS.lock().unwrap();
+        assert_eq!(calls.len(), 40);
+        calls.chunks(4).for_each(|chunk| {
+            assert_eq!(chunk, &["first", "second", "fourth", "fifth"]);
+        });
+    }
+
+    #[test]
+    fn should_keep_subscription_when_token_of_another_store_was_passed() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        #[derive(Debug)]
+        enum MyAction {
+            Increment,
+        }
+        fn reducer(state: &u8, _action: &MyAction) -> u8 {
+            state + 1
+        }
+
+        let mut first = Store::new(reducer, 0);
+        let mut second = Store::new(reducer, 0);
+        let first_token = first.subscribe(|_state| {});
+        let second_token = second.subscribe(|_state| {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+        });
+
+        // Both tokens have the same position, only the store tells them apart
+        assert_eq!(first_token.to_string(), second_token.to_string());
+        assert_eq!(
+            second.unsubscribe(first_token),
+            Err(UnsubscribeError::ForeignToken(first_token.id()))
+        );
+
+        second.dispatch(MyAction::Increment);
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+        assert_eq!(first.unsubscribe(first_token), Ok(()));
+    }
+
+    #[test]
+    fn should_unsubscribe_tokens_kept_outside_of_the_store() {
+        #[derive(Debug)]
+        enum MyAction {
+            Increment,
+        }
+        fn reducer(state: &u8, _action: &MyAction) -> u8 {
+            state + 1
+        }
+
+        struct Component {
+            tokens: Vec<SubscriptionToken<u8, MyAction>>,
+        }
+
+        let mut store = Store::new(reducer, 0);
+        let component = Component {
+            tokens: vec![store.subscribe(|_state| {}), store.subscribe(|_state| {})],
+        };
+
+        store.dispatch(MyAction::Increment);
+        for token in component.tokens {
+            assert_eq!(store.unsubscribe(token), Ok(()));
+        }
+    }
+
+    #[test]
+    fn should_issue_distinct_tokens_when_more_than_256_subscriptions_registered() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        #[derive(Debug)]
+        enum MyAction {
+            Increment,
+        }
+        fn reducer(state: &u8, _action: &MyAction) -> u8 {
+            state.wrapping_add(1)
+        }
+
+        let mut store = Store::new(reducer, 0);
+        let tokens: Vec<_> = (0..300)
+            .map(|_| {
+                store.subscribe(|_state| {
+                    CALLS.fetch_add(1, Ordering::SeqCst);
+                })
+            })
+            .collect();
+
+        assert_eq!(store.unsubscribe(tokens[299]), Ok(()));
+        store.dispatch(MyAction::Increment);
+
+        assert_eq!(CALLS.load(Ordering::SeqCst), 299);
+    }
 }
@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod ticker {
+    use redust::{DispatchQueue, ScheduleError, Store, Ticker};
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    type MyStore = Vec<Instant>;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Tick(Instant),
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Tick(at) => {
+                let mut ticks = state.clone();
+                ticks.push(*at);
+
+                ticks
+            }
+        }
+    }
+
+    #[test]
+    fn should_dispatch_ticks_at_fixed_rate_when_ticker_is_running() {
+        let queue = DispatchQueue::new();
+        let interval = Duration::from_millis(5);
+        let ticker = Ticker::start(queue.dispatcher(), interval, MyAction::Tick).unwrap();
+
+        thread::sleep(Duration::from_millis(60));
+        ticker.stop();
+
+        let mut store = Store::new(reducer, vec![]);
+        store.drain(&queue);
+
+        let ticks = store.state();
+        assert!(ticks.len() >= 2);
+        assert!(ticks.windows(2).all(|pair| pair[1] - pair[0] >= interval));
+    }
+
+    #[test]
+    fn should_not_dispatch_ticks_when_ticker_was_stopped() {
+        let queue = DispatchQueue::new();
+        let ticker =
+            Ticker::start(queue.dispatcher(), Duration::from_millis(5), MyAction::Tick).unwrap();
+        ticker.stop();
+        let stopped = queue.len();
+
+        thread::sleep(Duration::from_millis(30));
+
+        assert_eq!(queue.len(), stopped);
+    }
+
+    #[test]
+    fn should_stop_when_queue_was_dropped() {
+        let queue = DispatchQueue::new();
+        let ticker =
+            Ticker::start(queue.dispatcher(), Duration::from_millis(1), MyAction::Tick).unwrap();
+        drop(queue);
+
+        thread::sleep(Duration::from_millis(30));
+
+        assert!(!ticker.is_running());
+    }
+
+    #[test]
+    fn should_return_error_when_interval_is_zero() {
+        let queue = DispatchQueue::new();
+
+        let result = Ticker::start(queue.dispatcher(), Duration::ZERO, MyAction::Tick);
+
+        assert!(matches!(result, Err(ScheduleError::ZeroInterval)));
+    }
+}
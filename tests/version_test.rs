@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod version {
+    use redust::{DispatchError, FreezePolicy, Store};
+
+    type MyStore = u8;
+
+    #[derive(Debug)]
+    enum MyAction {
+        Increment,
+    }
+
+    fn reducer(state: &MyStore, action: &MyAction) -> MyStore {
+        match action {
+            MyAction::Increment => state + 1,
+        }
+    }
+
+    #[test]
+    fn should_increase_version_when_action_was_reduced() {
+        let mut store = Store::new(reducer, 0);
+        assert_eq!(store.version(), 0);
+
+        store
+            .dispatch(MyAction::Increment)
+            .dispatch(MyAction::Increment);
+
+        assert_eq!(store.version(), 2);
+    }
+
+    #[test]
+    fn should_not_increase_version_when_action_was_rejected() {
+        let mut store = Store::new(reducer, 0);
+        store.freeze(FreezePolicy::Reject);
+
+        store.dispatch(MyAction::Increment);
+
+        assert_eq!(store.version(), 0);
+    }
+
+    #[test]
+    fn should_dispatch_when_version_matches() {
+        let mut store = Store::new(reducer, 0);
+        store.dispatch(MyAction::Increment);
+
+        store.dispatch_if_version(1, MyAction::Increment).unwrap();
+
+        assert_eq!(*store.state(), 2);
+        assert_eq!(store.version(), 2);
+    }
+
+    #[test]
+    fn should_return_error_when_another_action_was_dispatched_in_between() {
+        let mut store = Store::new(reducer, 0);
+        let version = store.version();
+        store.dispatch(MyAction::Increment);
+
+        assert_eq!(
+            store
+                .dispatch_if_version(version, MyAction::Increment)
+                .err(),
+            Some(DispatchError::VersionMismatch {
+                expected: 0,
+                actual: 1
+            })
+        );
+        assert_eq!(*store.state(), 1);
+    }
+}
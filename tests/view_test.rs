@@ -0,0 +1,179 @@
+#[cfg(test)]
+mod view {
+    use redust::Store;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+
+    #[derive(Clone)]
+    struct AppState {
+        todos: Vec<&'static str>,
+        clicks: u8,
+    }
+
+    #[derive(Debug)]
+    enum MyAction {
+        AddTodo(&'static str),
+        Click,
+    }
+
+    fn reducer(state: &AppState, action: &MyAction) -> AppState {
+        let mut new_state = state.clone();
+        match action {
+            MyAction::AddTodo(todo) => new_state.todos.push(todo),
+            MyAction::Click => new_state.clicks += 1,
+        }
+
+        new_state
+    }
+
+    fn create_store() -> Store<AppState, MyAction> {
+        Store::new(
+            reducer,
+            AppState {
+                todos: vec![],
+                clicks: 0,
+            },
+        )
+    }
+
+    #[test]
+    fn should_return_projected_state_when_view_was_created() {
+        let mut store = create_store();
+        store.dispatch(MyAction::AddTodo("Buy milk"));
+
+        let todos = store.view(|state: &AppState| &state.todos);
+
+        assert_eq!(*todos.state(), vec!["Buy milk"]);
+    }
+
+    #[test]
+    fn should_call_view_subscriber_only_when_projected_part_changed() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let mut store = create_store();
+        store
+            .view(|state: &AppState| &state.todos)
+            .subscribe(|_todos| {
+                CALLS.fetch_add(1, Ordering::SeqCst);
+            });
+
+        store
+            .dispatch(MyAction::Click)
+            .dispatch(MyAction::AddTodo("Buy milk"))
+            .dispatch(MyAction::Click);
+
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn should_not_call_view_subscriber_when_it_was_unsubscribed() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let mut store = create_store();
+        let clicks = store.view(|state: &AppState| &state.clicks);
+        let token = clicks.subscribe(|_clicks| {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+        });
+        assert!(clicks.unsubscribe(token));
+        assert!(!clicks.unsubscribe(token));
+
+        store.dispatch(MyAction::Click);
+
+        assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn should_return_borrowed_slice_when_view_projected_unsized_part() {
+        let mut store = create_store();
+        store
+            .dispatch(MyAction::AddTodo("Buy milk"))
+            .dispatch(MyAction::AddTodo("Walk the dog"));
+
+        let todos = store.view(|state: &AppState| &state.todos[1..]);
+
+        assert_eq!(*todos.state(), ["Walk the dog"]);
+    }
+
+    #[test]
+    fn should_call_view_subscriber_when_projected_slice_changed() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let mut store = create_store();
+        store
+            .view(|state: &AppState| state.todos.as_slice())
+            .subscribe(|todos| {
+                assert_eq!(todos, ["Buy milk"]);
+                CALLS.fetch_add(1, Ordering::SeqCst);
+            });
+
+        store
+            .dispatch(MyAction::Click)
+            .dispatch(MyAction::AddTodo("Buy milk"));
+
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn should_observe_part_when_it_cannot_be_cloned() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        #[derive(PartialEq)]
+        struct Counter(u8);
+
+        fn reducer(state: &Counter, _action: &MyAction) -> Counter {
+            Counter(state.0 + 1)
+        }
+
+        let mut store = Store::new(reducer, Counter(0));
+        store
+            .view(|counter: &Counter| counter)
+            .subscribe(|counter| {
+                assert_eq!(counter.0, 1);
+                CALLS.fetch_add(1, Ordering::SeqCst);
+            });
+
+        store.dispatch(MyAction::Click);
+
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn should_return_latest_projected_state_when_store_dispatched_after_view_was_created() {
+        let mut store = create_store();
+        let todos = store.view(|state: &AppState| &state.todos);
+        let snapshot = todos.state();
+
+        store.dispatch(MyAction::AddTodo("Buy milk"));
+
+        assert!(snapshot.is_empty());
+        assert_eq!(*todos.state(), vec!["Buy milk"]);
+    }
+
+    #[test]
+    fn should_read_view_when_it_was_sent_to_another_thread() {
+        let mut store = create_store();
+        let clicks = store.view(|state: &AppState| &state.clicks);
+        store.dispatch(MyAction::Click);
+
+        let clicks = thread::spawn(move || *clicks.state()).join().unwrap();
+
+        assert_eq!(clicks, 1);
+    }
+
+    #[test]
+    fn should_stop_updating_view_when_it_was_removed_from_store() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let mut store = create_store();
+        let clicks = store.view(|state: &AppState| &state.clicks);
+        clicks.subscribe(|_clicks| {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+        });
+        store.unsubscribe(clicks.token()).unwrap();
+
+        store.dispatch(MyAction::Click);
+
+        assert_eq!(*clicks.state(), 0);
+        assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+    }
+}